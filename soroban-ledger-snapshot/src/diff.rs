@@ -0,0 +1,345 @@
+use std::{collections::BTreeMap, fmt};
+
+use soroban_env_host::xdr::{
+    ContractDataDurability, ContractExecutable, LedgerEntry, LedgerEntryData, LedgerKey, ScAddress,
+    ScMap, ScVal,
+};
+
+use crate::LedgerSnapshot;
+
+/// The changes between two [`LedgerSnapshot`]s, returned by
+/// [`LedgerSnapshot::diff`].
+///
+/// Entries are matched by their ledger key, so the order of the entries in the
+/// snapshots does not affect the diff. Changes are listed in the order of the
+/// ledger keys.
+///
+/// The [`Display`][fmt::Display] form decodes the keys and values of contract
+/// data entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LedgerSnapshotDiff {
+    /// The fields of the ledger info that changed.
+    pub ledger_info: Vec<LedgerInfoChange>,
+    /// The entries only in the other snapshot.
+    pub added: Vec<Entry>,
+    /// The entries only in the original snapshot.
+    pub removed: Vec<Entry>,
+    /// The entries in both snapshots that changed.
+    pub modified: Vec<EntryChange>,
+    /// The entries in both snapshots with a live until ledger that changed.
+    pub ttl_changes: Vec<TtlChange>,
+}
+
+/// A field of the ledger info that changed, with its values displayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerInfoChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// A ledger entry that was added or removed, with its live until ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub key: Box<LedgerKey>,
+    pub entry: Box<LedgerEntry>,
+    pub live_until: Option<u32>,
+}
+
+/// A ledger entry that changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryChange {
+    pub key: Box<LedgerKey>,
+    pub before: Box<LedgerEntry>,
+    pub after: Box<LedgerEntry>,
+}
+
+/// A ledger entry with a live until ledger that changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TtlChange {
+    pub key: Box<LedgerKey>,
+    pub before: Option<u32>,
+    pub after: Option<u32>,
+}
+
+impl LedgerSnapshot {
+    /// Returns the changes from this snapshot to the other snapshot.
+    pub fn diff(&self, other: &LedgerSnapshot) -> LedgerSnapshotDiff {
+        let mut diff = LedgerSnapshotDiff::default();
+
+        let mut info = |field: &'static str, before: String, after: String| {
+            if before != after {
+                diff.ledger_info.push(LedgerInfoChange {
+                    field,
+                    before,
+                    after,
+                });
+            }
+        };
+        info(
+            "protocol_version",
+            self.protocol_version.to_string(),
+            other.protocol_version.to_string(),
+        );
+        info(
+            "sequence_number",
+            self.sequence_number.to_string(),
+            other.sequence_number.to_string(),
+        );
+        info(
+            "timestamp",
+            self.timestamp.to_string(),
+            other.timestamp.to_string(),
+        );
+        info("network_id", hex(&self.network_id), hex(&other.network_id));
+        info(
+            "base_reserve",
+            self.base_reserve.to_string(),
+            other.base_reserve.to_string(),
+        );
+        info(
+            "min_persistent_entry_ttl",
+            self.min_persistent_entry_ttl.to_string(),
+            other.min_persistent_entry_ttl.to_string(),
+        );
+        info(
+            "min_temp_entry_ttl",
+            self.min_temp_entry_ttl.to_string(),
+            other.min_temp_entry_ttl.to_string(),
+        );
+        info(
+            "max_entry_ttl",
+            self.max_entry_ttl.to_string(),
+            other.max_entry_ttl.to_string(),
+        );
+
+        let before: BTreeMap<_, _> = self.ledger_entries.iter().map(|(k, v)| (k, v)).collect();
+        let after: BTreeMap<_, _> = other.ledger_entries.iter().map(|(k, v)| (k, v)).collect();
+        for (k, (entry, live_until)) in &before {
+            match after.get(k) {
+                None => diff.removed.push(Entry {
+                    key: (*k).clone(),
+                    entry: entry.clone(),
+                    live_until: *live_until,
+                }),
+                Some((other_entry, other_live_until)) => {
+                    if entry != other_entry {
+                        diff.modified.push(EntryChange {
+                            key: (*k).clone(),
+                            before: entry.clone(),
+                            after: other_entry.clone(),
+                        });
+                    }
+                    if live_until != other_live_until {
+                        diff.ttl_changes.push(TtlChange {
+                            key: (*k).clone(),
+                            before: *live_until,
+                            after: *other_live_until,
+                        });
+                    }
+                }
+            }
+        }
+        for (k, (entry, live_until)) in &after {
+            if !before.contains_key(k) {
+                diff.added.push(Entry {
+                    key: (*k).clone(),
+                    entry: entry.clone(),
+                    live_until: *live_until,
+                });
+            }
+        }
+
+        diff
+    }
+}
+
+impl LedgerSnapshotDiff {
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.ledger_info.is_empty()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
+            && self.ttl_changes.is_empty()
+    }
+}
+
+impl fmt::Display for LedgerSnapshotDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in &self.ledger_info {
+            writeln!(f, "~ ledger info {}: {} -> {}", c.field, c.before, c.after)?;
+        }
+        for (sign, entries) in [('-', &self.removed), ('+', &self.added)] {
+            for e in entries {
+                writeln!(
+                    f,
+                    "{sign} {} = {}{}",
+                    DisplayLedgerKey(&e.key),
+                    DisplayLedgerEntry(&e.entry),
+                    DisplayLiveUntil(e.live_until)
+                )?;
+            }
+        }
+        for c in &self.modified {
+            writeln!(f, "~ {}", DisplayLedgerKey(&c.key))?;
+            if c.before.data == c.after.data {
+                writeln!(
+                    f,
+                    "    last modified ledger: {} -> {}",
+                    c.before.last_modified_ledger_seq, c.after.last_modified_ledger_seq
+                )?;
+            } else {
+                writeln!(f, "    - {}", DisplayLedgerEntry(&c.before))?;
+                writeln!(f, "    + {}", DisplayLedgerEntry(&c.after))?;
+            }
+        }
+        for c in &self.ttl_changes {
+            writeln!(
+                f,
+                "~ {} live until: {} -> {}",
+                DisplayLedgerKey(&c.key),
+                DisplayOption(c.before),
+                DisplayOption(c.after)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays an [`ScVal`] in a readable form, similar to the form of the
+/// values in Rust, with addresses as strkeys.
+pub struct DisplayScVal<'a>(pub &'a ScVal);
+
+impl fmt::Display for DisplayScVal<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            ScVal::Bool(v) => write!(f, "{v}"),
+            ScVal::Void => write!(f, "()"),
+            ScVal::Error(e) => write!(f, "{e:?}"),
+            ScVal::U32(v) => write!(f, "{v}u32"),
+            ScVal::I32(v) => write!(f, "{v}i32"),
+            ScVal::U64(v) => write!(f, "{v}u64"),
+            ScVal::I64(v) => write!(f, "{v}i64"),
+            ScVal::Timepoint(v) => write!(f, "Timepoint({})", v.0),
+            ScVal::Duration(v) => write!(f, "Duration({})", v.0),
+            ScVal::U128(v) => write!(f, "{v}u128"),
+            ScVal::I128(v) => write!(f, "{v}i128"),
+            ScVal::U256(v) => write!(f, "{v}u256"),
+            ScVal::I256(v) => write!(f, "{v}i256"),
+            ScVal::Bytes(v) => write!(f, "0x{}", hex(v.as_slice())),
+            ScVal::String(v) => write!(f, "{:?}", v.0.to_utf8_string_lossy()),
+            ScVal::Symbol(v) => write!(f, "{}", v.0.to_utf8_string_lossy()),
+            ScVal::Vec(v) => {
+                write!(f, "[")?;
+                for (i, v) in v.iter().flat_map(|v| v.iter()).enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", DisplayScVal(v))?;
+                }
+                write!(f, "]")
+            }
+            ScVal::Map(m) => fmt_map(f, m.as_ref()),
+            ScVal::Address(a) => write!(f, "{a}"),
+            ScVal::ContractInstance(i) => {
+                match &i.executable {
+                    ContractExecutable::Wasm(hash) => {
+                        write!(f, "ContractInstance(Wasm({}), ", hex(&hash.0))?
+                    }
+                    e => write!(f, "ContractInstance({e:?}, ")?,
+                }
+                fmt_map(f, i.storage.as_ref())?;
+                write!(f, ")")
+            }
+            ScVal::LedgerKeyContractInstance => write!(f, "LedgerKeyContractInstance"),
+            ScVal::LedgerKeyNonce(n) => write!(f, "LedgerKeyNonce({})", n.nonce),
+            v => write!(f, "{v:?}"),
+        }
+    }
+}
+
+fn fmt_map(f: &mut fmt::Formatter<'_>, m: Option<&ScMap>) -> fmt::Result {
+    write!(f, "{{")?;
+    for (i, e) in m.iter().flat_map(|m| m.iter()).enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}: {}", DisplayScVal(&e.key), DisplayScVal(&e.val))?;
+    }
+    write!(f, "}}")
+}
+
+/// Displays a [`LedgerKey`] in a readable form, decoding the key of contract
+/// data entries.
+pub struct DisplayLedgerKey<'a>(pub &'a LedgerKey);
+
+impl fmt::Display for DisplayLedgerKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            LedgerKey::ContractData(k) => write!(
+                f,
+                "ContractData({}, {}, {})",
+                k.contract,
+                durability(k.durability),
+                DisplayScVal(&k.key)
+            ),
+            LedgerKey::ContractCode(k) => write!(f, "ContractCode({})", hex(&k.hash.0)),
+            LedgerKey::Account(k) => {
+                write!(f, "Account({})", ScAddress::Account(k.account_id.clone()))
+            }
+            k => write!(f, "{}", json(k)),
+        }
+    }
+}
+
+/// Displays the data of a [`LedgerEntry`] in a readable form, decoding the
+/// value of contract data entries.
+pub struct DisplayLedgerEntry<'a>(pub &'a LedgerEntry);
+
+impl fmt::Display for DisplayLedgerEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0.data {
+            LedgerEntryData::ContractData(e) => write!(f, "{}", DisplayScVal(&e.val)),
+            LedgerEntryData::ContractCode(e) => write!(f, "wasm ({} bytes)", e.code.len()),
+            data => write!(f, "{}", json(data)),
+        }
+    }
+}
+
+struct DisplayLiveUntil(Option<u32>);
+
+impl fmt::Display for DisplayLiveUntil {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(v) => write!(f, " (live until {v})"),
+            None => Ok(()),
+        }
+    }
+}
+
+struct DisplayOption(Option<u32>);
+
+impl fmt::Display for DisplayOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(v) => write!(f, "{v}"),
+            None => write!(f, "none"),
+        }
+    }
+}
+
+fn durability(d: ContractDataDurability) -> &'static str {
+    match d {
+        ContractDataDurability::Temporary => "temporary",
+        ContractDataDurability::Persistent => "persistent",
+    }
+}
+
+fn hex(b: &[u8]) -> String {
+    b.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn json(v: &impl serde::Serialize) -> String {
+    serde_json::to_string(v).unwrap_or_else(|e| format!("<{e}>"))
+}
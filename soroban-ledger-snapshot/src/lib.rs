@@ -13,6 +13,12 @@ use soroban_env_host::{
     HostError, LedgerInfo,
 };
 
+mod diff;
+pub use diff::{
+    DisplayLedgerEntry, DisplayLedgerKey, DisplayScVal, Entry, EntryChange, LedgerInfoChange,
+    LedgerSnapshotDiff, TtlChange,
+};
+
 #[cfg(test)]
 mod tests;
 
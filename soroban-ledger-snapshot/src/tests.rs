@@ -1,9 +1,13 @@
 use soroban_env_host::{
     storage::SnapshotSource,
-    xdr::{LedgerKey, Limits, ReadXdr},
+    xdr::{
+        ContractDataDurability, ContractDataEntry, ContractId, ExtensionPoint, Hash, LedgerEntry,
+        LedgerEntryData, LedgerEntryExt, LedgerKey, LedgerKeyContractData, Limits, ReadXdr,
+        ScAddress, ScVal,
+    },
 };
 
-use crate::LedgerSnapshot;
+use crate::{Entry, EntryChange, LedgerInfoChange, LedgerSnapshot, LedgerSnapshotDiff, TtlChange};
 use std::rc::Rc;
 
 // Tuple of (ledger key in xdr base64, live_until_ledger_seq u32) for the entries in the test snapshots
@@ -55,3 +59,107 @@ fn test_snapshot_roundtrip() {
     let written_normalized = written_str.replace("\r\n", "\n");
     assert_eq!(written_normalized, expected_normalized);
 }
+
+fn contract_data(key: u32, val: u32) -> (Box<LedgerKey>, Box<LedgerEntry>) {
+    let contract = ScAddress::Contract(ContractId(Hash([1; 32])));
+    let key = ScVal::Vec(Some(
+        vec![
+            ScVal::Symbol("Balance".try_into().unwrap()),
+            ScVal::U32(key),
+        ]
+        .try_into()
+        .unwrap(),
+    ));
+    let entry = LedgerEntry {
+        last_modified_ledger_seq: 0,
+        data: LedgerEntryData::ContractData(ContractDataEntry {
+            ext: ExtensionPoint::V0,
+            contract: contract.clone(),
+            key: key.clone(),
+            durability: ContractDataDurability::Persistent,
+            val: ScVal::U32(val),
+        }),
+        ext: LedgerEntryExt::V0,
+    };
+    (
+        Box::new(LedgerKey::ContractData(LedgerKeyContractData {
+            contract,
+            key,
+            durability: ContractDataDurability::Persistent,
+        })),
+        Box::new(entry),
+    )
+}
+
+#[test]
+fn test_snapshot_diff() {
+    let (k1, e1) = contract_data(1, 10);
+    let (k2, e2) = contract_data(2, 20);
+    let (k3, e3) = contract_data(3, 30);
+    let (_, e2_modified) = contract_data(2, 21);
+
+    let before = LedgerSnapshot {
+        sequence_number: 1,
+        ledger_entries: vec![
+            (k1.clone(), (e1.clone(), Some(100))),
+            (k2.clone(), (e2.clone(), Some(100))),
+        ],
+        ..Default::default()
+    };
+    let after = LedgerSnapshot {
+        sequence_number: 5,
+        // The order of the entries does not matter.
+        ledger_entries: vec![
+            (k3.clone(), (e3.clone(), Some(200))),
+            (k2.clone(), (e2_modified.clone(), Some(150))),
+        ],
+        ..Default::default()
+    };
+
+    let diff = before.diff(&after);
+    assert_eq!(
+        diff,
+        LedgerSnapshotDiff {
+            ledger_info: vec![LedgerInfoChange {
+                field: "sequence_number",
+                before: "1".to_string(),
+                after: "5".to_string(),
+            }],
+            added: vec![Entry {
+                key: k3,
+                entry: e3,
+                live_until: Some(200),
+            }],
+            removed: vec![Entry {
+                key: k1,
+                entry: e1,
+                live_until: Some(100),
+            }],
+            modified: vec![EntryChange {
+                key: k2.clone(),
+                before: e2,
+                after: e2_modified,
+            }],
+            ttl_changes: vec![TtlChange {
+                key: k2,
+                before: Some(100),
+                after: Some(150),
+            }],
+        }
+    );
+    assert_eq!(
+        diff.to_string(),
+        "\
+~ ledger info sequence_number: 1 -> 5
+- ContractData(CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526, persistent, [Balance, 1u32]) = 10u32 (live until 100)
++ ContractData(CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526, persistent, [Balance, 3u32]) = 30u32 (live until 200)
+~ ContractData(CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526, persistent, [Balance, 2u32])
+    - 20u32
+    + 21u32
+~ ContractData(CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526, persistent, [Balance, 2u32]) live until: 100 -> 150
+"
+    );
+
+    assert!(before.diff(&before).is_empty());
+    assert_eq!(before.diff(&before).to_string(), "");
+}
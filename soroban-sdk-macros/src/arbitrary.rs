@@ -1,6 +1,119 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
-use syn::{DataEnum, DataStruct, Ident, Path, Visibility};
+use syn::{
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Expr, Field, Ident, Path, Type,
+    Visibility,
+};
+
+/// The constraint of an `#[arbitrary(...)]` attribute on a field of a contract
+/// type, that the derived prototype honours when generating the field.
+enum FieldConstraint {
+    /// `range = a..=b`, generate an integer in the inclusive range.
+    Range(Expr),
+    /// `len = a..=b`, generate a collection with a length in the inclusive
+    /// range.
+    Len(Expr),
+    /// `with = f`, generate the prototype of the field with the function.
+    With(Expr),
+}
+
+impl FieldConstraint {
+    fn from_attrs(attrs: &[Attribute]) -> Result<Option<Self>, Error> {
+        let mut constraint = None;
+        for attr in attrs.iter().filter(|a| a.path().is_ident("arbitrary")) {
+            attr.parse_nested_meta(|meta| {
+                let make = if meta.path.is_ident("range") {
+                    FieldConstraint::Range
+                } else if meta.path.is_ident("len") {
+                    FieldConstraint::Len
+                } else if meta.path.is_ident("with") {
+                    FieldConstraint::With
+                } else {
+                    return Err(meta.error("expected `range`, `len` or `with`"));
+                };
+                if constraint.is_some() {
+                    return Err(meta.error("only one of `range`, `len` or `with` can be given"));
+                }
+                constraint = Some(make(meta.value()?.parse()?));
+                Ok(())
+            })?;
+        }
+        Ok(constraint)
+    }
+
+    /// Returns the attribute that makes `derive(Arbitrary)` generate the
+    /// prototype of the field with the constraint.
+    fn arbitrary_attr(&self, path: &Path) -> TokenStream2 {
+        match self {
+            FieldConstraint::Range(range) => quote! {
+                #[arbitrary(with = |u: &mut arbitrary::Unstructured| u.int_in_range(#range))]
+            },
+            FieldConstraint::Len(len) => quote! {
+                #[arbitrary(with = |u: &mut arbitrary::Unstructured| {
+                    #path::testutils::arbitrary::ArbitraryLen::arbitrary_len(u, #len)
+                })]
+            },
+            FieldConstraint::With(f) => quote! {
+                #[arbitrary(with = #f)]
+            },
+        }
+    }
+
+    /// Returns the strategy that generates the prototype of the field with the
+    /// constraint.
+    fn strategy(&self, path: &Path, field_type: &Type) -> TokenStream2 {
+        match self {
+            FieldConstraint::Range(range) => quote! {
+                {
+                    let range: core::ops::RangeInclusive<
+                        <#field_type as #path::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                    > = #range;
+                    #path::testutils::proptest::proptest::strategy::Strategy::boxed(range)
+                }
+            },
+            FieldConstraint::Len(len) => quote! {
+                #path::testutils::arbitrary::ArbitraryLen::strategy_len(#len)
+            },
+            FieldConstraint::With(f) => quote! {
+                #path::testutils::proptest::strategy_from_fn(#f)
+            },
+        }
+    }
+}
+
+fn field_constraint(field: &Field) -> Option<FieldConstraint> {
+    // Errors are reported by validate_field_attrs.
+    FieldConstraint::from_attrs(&field.attrs).ok().flatten()
+}
+
+fn fields_of(input: &mut DeriveInput) -> Vec<&mut Field> {
+    match &mut input.data {
+        Data::Struct(s) => s.fields.iter_mut().collect(),
+        Data::Enum(e) => e
+            .variants
+            .iter_mut()
+            .flat_map(|v| v.fields.iter_mut())
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    }
+}
+
+/// Returns the errors in the `#[arbitrary(...)]` attributes of the fields of
+/// the contract type, and removes the attributes, since they are only
+/// meaningful to the derived prototype.
+///
+/// The attributes are validated whether or not the testutils feature is
+/// enabled, so that invalid attributes are not only reported in tests.
+pub fn take_field_attrs(input: &mut DeriveInput) -> TokenStream2 {
+    let mut errors = Vec::new();
+    for field in fields_of(input) {
+        if let Err(e) = FieldConstraint::from_attrs(&field.attrs) {
+            errors.push(e.to_compile_error());
+        }
+        field.attrs.retain(|a| !a.path().is_ident("arbitrary"));
+    }
+    quote! { #(#errors)* }
+}
 
 pub fn derive_arbitrary_struct(
     path: &Path,
@@ -39,14 +152,17 @@ fn derive_arbitrary_struct_common(
         .iter()
         .map(|field| {
             let field_type = &field.ty;
+            let attr = field_constraint(field).map(|c| c.arbitrary_attr(path));
             match &field.ident {
                 Some(ident) => {
                     quote! {
+                        #attr
                         #ident: <#field_type as #path::testutils::arbitrary::SorobanArbitrary>::Prototype
                     }
                 }
                 None => {
                     quote! {
+                        #attr
                         <#field_type as #path::testutils::arbitrary::SorobanArbitrary>::Prototype
                     }
                 }
@@ -99,6 +215,26 @@ fn derive_arbitrary_struct_common(
         },
     };
 
+    let (fields_strategy, fields_pat, fields_ctor) = quote_fields_strategy(path, &data.fields);
+    let arbitrary_strategy = match field_type {
+        FieldType::Named => quote! {
+            #path::testutils::proptest::proptest::strategy::Strategy::boxed(
+                #path::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                    #fields_strategy,
+                    |#fields_pat| #arbitrary_type_ident { #(#fields_ctor,)* },
+                )
+            )
+        },
+        FieldType::Unnamed => quote! {
+            #path::testutils::proptest::proptest::strategy::Strategy::boxed(
+                #path::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                    #fields_strategy,
+                    |#fields_pat| #arbitrary_type_ident ( #(#fields_ctor,)* ),
+                )
+            )
+        },
+    };
+
     quote_arbitrary(
         path,
         vis,
@@ -106,9 +242,51 @@ fn derive_arbitrary_struct_common(
         arbitrary_type_ident,
         arbitrary_type_decl,
         arbitrary_ctor,
+        arbitrary_strategy,
     )
 }
 
+/// Returns a strategy that generates the prototypes of the fields as nested
+/// pairs, `(a, (b, ()))`, so that any number of fields can be generated, the
+/// pattern that destructures the pairs, and the fields to construct from the
+/// pattern.
+fn quote_fields_strategy(
+    path: &Path,
+    fields: &syn::Fields,
+) -> (TokenStream2, TokenStream2, Vec<TokenStream2>) {
+    let mut strategy = quote! { #path::testutils::proptest::proptest::strategy::Just(()) };
+    let mut pat = quote! { () };
+    let mut ctor = Vec::new();
+    for (i, field) in fields.iter().enumerate().rev() {
+        let field_type = &field.ty;
+        let binding = format_ident!("field_{}", i);
+        let field_strategy = match field_constraint(field) {
+            Some(c) => c.strategy(path, field_type),
+            // Fields whose prototypes have no strategy, because their types
+            // have hand-written SorobanArbitrary impls, are generated from
+            // bytes, selected by autoref.
+            None => quote! {
+                {
+                    use #path::testutils::proptest::{FieldStrategy as _, FieldStrategyFallback as _};
+                    (&#path::testutils::proptest::Field::<
+                        <#field_type as #path::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                    >::new()).field_strategy()
+                }
+            },
+        };
+        strategy = quote! {
+            (#field_strategy, #strategy)
+        };
+        pat = quote! { (#binding, #pat) };
+        ctor.push(match &field.ident {
+            Some(ident) => quote! { #ident: #binding },
+            None => quote! { #binding },
+        });
+    }
+    ctor.reverse();
+    (strategy, pat, ctor)
+}
+
 pub fn derive_arbitrary_enum(
     path: &Path,
     vis: &Visibility,
@@ -128,16 +306,19 @@ pub fn derive_arbitrary_enum(
                 .iter()
                 .map(|field| {
                     let field_type = &field.ty;
+                    let attr = field_constraint(field).map(|c| c.arbitrary_attr(path));
                     match &field.ident {
                         Some(ident) => {
                             field_types = Some(FieldType::Named);
                             quote! {
+                                #attr
                                 #ident: <#field_type as #path::testutils::arbitrary::SorobanArbitrary>::Prototype
                             }
                         }
                         None => {
                             field_types = Some(FieldType::Unnamed);
                             quote! {
+                                #attr
                                 <#field_type as #path::testutils::arbitrary::SorobanArbitrary>::Prototype
                             }
                         }
@@ -243,6 +424,34 @@ pub fn derive_arbitrary_enum(
         }
     };
 
+    let variant_strategies: Vec<TokenStream2> = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            let (fields_strategy, fields_pat, fields_ctor) =
+                quote_fields_strategy(path, &variant.fields);
+            let variant_ctor = match &variant.fields {
+                syn::Fields::Unit => quote! { #arbitrary_type_ident::#variant_ident },
+                syn::Fields::Named(_) => {
+                    quote! { #arbitrary_type_ident::#variant_ident { #(#fields_ctor,)* } }
+                }
+                syn::Fields::Unnamed(_) => {
+                    quote! { #arbitrary_type_ident::#variant_ident ( #(#fields_ctor,)* ) }
+                }
+            };
+            quote! {
+                #path::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    #path::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        #fields_strategy,
+                        |#fields_pat| #variant_ctor,
+                    )
+                )
+            }
+        })
+        .collect();
+    let arbitrary_strategy = quote_union_strategy(path, &variant_strategies);
+
     quote_arbitrary(
         path,
         vis,
@@ -250,9 +459,22 @@ pub fn derive_arbitrary_enum(
         arbitrary_type_ident,
         arbitrary_type_decl,
         arbitrary_ctor,
+        arbitrary_strategy,
     )
 }
 
+/// Returns a strategy that generates a value from one of the strategies,
+/// shrinking towards the strategies listed first.
+fn quote_union_strategy(path: &Path, strategies: &[TokenStream2]) -> TokenStream2 {
+    quote! {
+        #path::testutils::proptest::proptest::strategy::Strategy::boxed(
+            #path::testutils::proptest::proptest::strategy::Union::new(
+                std::vec![#(#strategies,)*]
+            )
+        )
+    }
+}
+
 pub fn derive_arbitrary_enum_int(
     path: &Path,
     vis: &Visibility,
@@ -294,6 +516,22 @@ pub fn derive_arbitrary_enum_int(
         }
     };
 
+    let variant_strategies: Vec<TokenStream2> = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            quote! {
+                #path::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    #path::testutils::proptest::proptest::strategy::Just(
+                        #arbitrary_type_ident::#variant_ident
+                    )
+                )
+            }
+        })
+        .collect();
+    let arbitrary_strategy = quote_union_strategy(path, &variant_strategies);
+
     quote_arbitrary(
         path,
         vis,
@@ -301,6 +539,7 @@ pub fn derive_arbitrary_enum_int(
         arbitrary_type_ident,
         arbitrary_type_decl,
         arbitrary_ctor,
+        arbitrary_strategy,
     )
 }
 
@@ -311,6 +550,7 @@ fn quote_arbitrary(
     arbitrary_type_ident: Ident,
     arbitrary_type_decl: TokenStream2,
     arbitrary_ctor: TokenStream2,
+    arbitrary_strategy: TokenStream2,
 ) -> TokenStream2 {
     quote! {
         // This allows us to create a scope to import std and arbitrary, while
@@ -336,6 +576,12 @@ fn quote_arbitrary(
                     Ok(#arbitrary_ctor)
                 }
             }
+
+            impl #path::testutils::proptest::PrototypeStrategy for #arbitrary_type_ident {
+                fn strategy() -> #path::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+                    #path::testutils::proptest::recursive_strategy(|| #arbitrary_strategy)
+                }
+            }
         };
     }
 }
@@ -5,9 +5,15 @@ use syn::{Error, FnArg, LitStr, Path, Type, TypePath, TypeReference};
 
 use syn::ext::IdentExt as _;
 
+use sha2::{Digest, Sha256};
+use stellar_xdr::WriteXdr;
+
 use crate::{
-    attribute::pass_through_attr_to_gen_code, map_type::map_type, stellar_xdr::ScSpecTypeDef,
-    symbol, syn_ext,
+    attribute::{is_attr_cfg, pass_through_attr_to_gen_code},
+    derive_spec_fn::fn_spec_entry,
+    map_type::map_type,
+    stellar_xdr::ScSpecTypeDef,
+    symbol, syn_ext, DEFAULT_XDR_RW_LIMITS,
 };
 
 fn is_muxed_address_type(arg: &FnArg) -> bool {
@@ -60,6 +66,18 @@ pub fn derive_client_type(crate_path: &Path, ty: &str, name: &str) -> TokenStrea
                 allow_non_root_auth: bool,
             }
 
+            impl<'a> #crate_path::testutils::ContractClient for #client_ident<'a> {
+                fn new(env: &#crate_path::Env, address: &#crate_path::Address) -> Self {
+                    Self::new(env, address)
+                }
+
+                fn client_spec() -> &'static #crate_path::testutils::ClientSpec {
+                    static SPEC: #crate_path::testutils::ClientSpec =
+                        #crate_path::testutils::ClientSpec::new();
+                    &SPEC
+                }
+            }
+
             impl<'a> #client_ident<'a> {
                 pub fn new(env: &#crate_path::Env, address: &#crate_path::Address) -> Self {
                     Self {
@@ -149,6 +167,8 @@ pub fn derive_client_type(crate_path: &Path, ty: &str, name: &str) -> TokenStrea
 }
 
 pub fn derive_client_impl(crate_path: &Path, name: &str, fns: &[syn_ext::Fn]) -> TokenStream {
+    let spec_ctor = derive_client_spec_ctor(crate_path, name, fns);
+
     // Map the traits methods to methods for the Client.
     let mut errors = Vec::<Error>::new();
     let fns: Vec<_> = fns
@@ -343,5 +363,63 @@ pub fn derive_client_impl(crate_path: &Path, name: &str, fns: &[syn_ext::Fn]) ->
         impl<'a> #client_ident<'a> {
             #(#fns)*
         }
+
+        #spec_ctor
+    }
+}
+
+/// Generates a ctor registering the spec of the functions with the client, for
+/// the client's `ContractClient::spec` in tests.
+fn derive_client_spec_ctor(crate_path: &Path, name: &str, fns: &[syn_ext::Fn]) -> TokenStream {
+    if cfg!(not(feature = "testutils")) {
+        return quote!();
+    }
+
+    let (spec_xdr_lits, attrs, hash_parts): (Vec<_>, Vec<_>, Vec<_>) = fns
+        .iter()
+        .filter(|f| !f.ident.unraw().to_string().starts_with("__"))
+        .map(|f| {
+            // Types that have no spec type are given as Val, the client
+            // functions for them are still generated.
+            let spec_entry = fn_spec_entry(
+                &f.ident,
+                &f.attrs,
+                &f.inputs,
+                &f.output,
+                &ScSpecTypeDef::Val,
+                &mut Vec::new(),
+            );
+            let spec_xdr = spec_entry.to_xdr(DEFAULT_XDR_RW_LIMITS).unwrap();
+            let attrs = f
+                .attrs
+                .iter()
+                .filter(|attr| is_attr_cfg(attr))
+                .collect::<Vec<_>>();
+            let hash_part = format!("{}{}", quote!(#(#attrs)*), f.ident.unraw());
+            (
+                proc_macro2::Literal::byte_string(&spec_xdr),
+                attrs,
+                hash_part,
+            )
+        })
+        .multiunzip();
+    if spec_xdr_lits.is_empty() {
+        return quote!();
+    }
+
+    let client_ident = format_ident!("{}", name);
+    let fns_hash = format!("{:x}", Sha256::digest(hash_parts.join(",").as_bytes()));
+    let ctor_ident = format_ident!("__{name}__{fns_hash}_spec_ctor");
+    quote! {
+        #[doc(hidden)]
+        #[#crate_path::reexports_for_macros::ctor::ctor(crate_path=#crate_path::reexports_for_macros::ctor)]
+        #[allow(non_snake_case)]
+        fn #ctor_ident() {
+            #(
+                #(#attrs)*
+                <#client_ident as #crate_path::testutils::ContractClient>::client_spec()
+                    .register(#spec_xdr_lits);
+            )*
+        }
     }
 }
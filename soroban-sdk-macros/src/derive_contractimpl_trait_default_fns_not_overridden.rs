@@ -99,6 +99,7 @@ fn derive(args: &Args) -> Result<TokenStream2, Error> {
     output.extend(derive_contract_function_registration_ctor(
         &args.crate_path,
         &impl_ty,
+        &args.spec_name,
         Some(trait_ident),
         &fns,
     ));
@@ -103,12 +103,30 @@ pub fn derive_type_error_enum_int(
         None,
     );
 
+    // Registration of the spec for coverage reports in tests.
+    let coverage_ctor = if cfg!(feature = "testutils") {
+        let spec_xdr_lit = proc_macro2::Literal::byte_string(spec_xdr.as_slice());
+        let ctor_ident = format_ident!("__{}__error_spec_ctor", enum_ident.unraw());
+        quote! {
+            #[doc(hidden)]
+            #[#path::reexports_for_macros::ctor::ctor(crate_path=#path::reexports_for_macros::ctor)]
+            #[allow(non_snake_case)]
+            fn #ctor_ident() {
+                #path::testutils::coverage::register_error_spec(module_path!(), #spec_xdr_lit);
+            }
+        }
+    } else {
+        quote!()
+    };
+
     // Output.
     quote! {
         #spec_gen
 
         #spec_shaking_impl
 
+        #coverage_ctor
+
         impl TryFrom<#path::Error> for #enum_ident {
             type Error = #path::Error;
             #[inline(always)]
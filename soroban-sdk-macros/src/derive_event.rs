@@ -341,7 +341,7 @@ fn derive_impls(args: &ContractEventArgs, input: &DeriveInput) -> Result<TokenSt
     };
 
     // Output.
-    let output = quote! {
+    let mut output = quote! {
         #spec_gen
 
         #spec_shaking_impl
@@ -363,5 +363,65 @@ fn derive_impls(args: &ContractEventArgs, input: &DeriveInput) -> Result<TokenSt
         }
     };
 
+    // Additional output when testutils are enabled. Events with reference
+    // fields cannot be decoded, because the decoded values would have no
+    // owner.
+    let has_ref_fields = fields
+        .iter()
+        .any(|f| matches!(f.ty, syn::Type::Reference(_)));
+    if cfg!(feature = "testutils") && !has_ref_fields {
+        let field_types = fields.iter().map(|f| (f.ident.as_ref().unwrap(), &f.ty));
+        let (topic_fields, data_fields): (Vec<_>, Vec<_>) = field_types
+            .zip(params_with_idents.iter())
+            .partition(|(_, (_, p))| p.location == ScSpecEventParamLocationV0::TopicList);
+        let topic_count = topic_fields.len();
+        let topic_idents = topic_fields.iter().map(|((ident, _), _)| ident);
+        let data_idents = data_fields.iter().map(|((ident, _), _)| ident);
+        let data_names = data_fields.iter().map(|(_, (_, p))| p.name.to_string());
+        let field_tys = topic_fields
+            .iter()
+            .chain(data_fields.iter())
+            .map(|((_, ty), _)| ty);
+        let data_format = match args.data_format {
+            DataFormat::SingleValue => quote!(SingleValue),
+            DataFormat::Vec => quote!(Vec),
+            DataFormat::Map => quote!(Map),
+        };
+        // Fields with generic types are only decodable if their types are.
+        let decode_where = if input.generics.type_params().next().is_some() {
+            let where_predicates = gen_where.iter().flat_map(|w| w.predicates.iter());
+            quote! {
+                where
+                    #(#where_predicates,)*
+                    #(#field_tys: #path::TryFromVal<#path::Env, #path::Val>,)*
+            }
+        } else {
+            quote!(#gen_where)
+        };
+        output.extend(quote! {
+            impl #gen_impl #path::testutils::Event for #ident #gen_types #decode_where {
+                fn decode(env: &#path::Env, event: &#path::xdr::ContractEvent) -> Result<Self, #path::xdr::Error> {
+                    use #path::TryFromVal;
+                    let (topics, data) = #path::testutils::decode_event(
+                        env,
+                        event,
+                        &[#(#prefix_topics),*],
+                        #topic_count,
+                        #path::xdr::ScSpecEventDataFormat::#data_format,
+                        &[#(#data_names),*],
+                    )?;
+                    let mut topics = topics.iter();
+                    let mut data = data.iter();
+                    Ok(Self {
+                        #(#topic_idents: TryFromVal::try_from_val(env, topics.next().unwrap())
+                            .map_err(|_| #path::xdr::Error::Invalid)?,)*
+                        #(#data_idents: TryFromVal::try_from_val(env, data.next().unwrap())
+                            .map_err(|_| #path::xdr::Error::Invalid)?,)*
+                    })
+                }
+            }
+        });
+    }
+
     errors.finish_with(output)
 }
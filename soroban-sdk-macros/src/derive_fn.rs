@@ -237,6 +237,7 @@ pub fn derive_pub_fn(
 pub fn derive_contract_function_registration_ctor(
     crate_path: &Path,
     ty: &Type,
+    spec_ty: &Type,
     trait_ident: Option<&Path>,
     fns: &[syn_ext::Fn],
 ) -> TokenStream2 {
@@ -245,7 +246,13 @@ pub fn derive_contract_function_registration_ctor(
     }
 
     let ty_str = ty_to_safe_ident_str(ty);
-    let (idents, wrap_idents, attrs, hash_parts): (Vec<_>, Vec<_>, Vec<_>, Vec<_>) = fns
+    let (idents, wrap_idents, spec_fn_idents, attrs, hash_parts): (
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+    ) = fns
         .iter()
         .map(|f| {
             let attrs = f
@@ -255,6 +262,7 @@ pub fn derive_contract_function_registration_ctor(
                 .collect::<Vec<_>>();
             let ident_str = f.ident.unraw().to_string();
             let wrap_ident = format_ident!("__{}__{}__invoke_raw_slice", ty_str, ident_str);
+            let spec_fn_ident = format_ident!("spec_xdr_{}", f.ident);
             // Prefix the cfg attrs so cfg-gated registrations and their inverse-cfg
             // defaults do not collide on the same internal ctor symbol. The ident
             // is concatenated as a bare string (not interpolated through `quote!`,
@@ -262,7 +270,7 @@ pub fn derive_contract_function_registration_ctor(
             // cfg attrs are unchanged.
             let cfg_prefix = quote!(#(#attrs)*).to_string();
             let hash_part = format!("{cfg_prefix}{ident_str}");
-            (ident_str, wrap_ident, attrs, hash_part)
+            (ident_str, wrap_ident, spec_fn_ident, attrs, hash_part)
         })
         .multiunzip();
 
@@ -290,6 +298,12 @@ pub fn derive_contract_function_registration_ctor(
                     #[allow(deprecated)]
                     &#wrap_idents,
                 );
+                #(#attrs)*
+                #crate_path::testutils::coverage::register_function_spec(
+                    core::any::type_name::<#ty>(),
+                    module_path!(),
+                    &const { <#spec_ty>::#spec_fn_idents() },
+                );
             )*
         }
     }
@@ -36,6 +36,78 @@ pub fn derive_fn_spec(
     // Collect errors as they are encountered and emit them at the end.
     let mut errors = Vec::<Error>::new();
 
+    let spec_entry = fn_spec_entry(
+        ident,
+        attrs,
+        inputs,
+        output,
+        &ScSpecTypeDef::I32,
+        &mut errors,
+    );
+    let spec_xdr = spec_entry.to_xdr(DEFAULT_XDR_RW_LIMITS).unwrap();
+    let spec_xdr_lit = proc_macro2::Literal::byte_string(spec_xdr.as_slice());
+    let spec_xdr_len = spec_xdr.len();
+    let spec_ident = format_ident!("__SPEC_XDR_FN_{}", ident.unraw().to_string().to_uppercase());
+    let spec_fn_ident = format_ident!("spec_xdr_{}", ident);
+
+    // If errors have occurred, render them instead.
+    if !errors.is_empty() {
+        let compile_errors = errors.iter().map(Error::to_compile_error);
+        return Err(quote! { #(#compile_errors)* });
+    }
+
+    // Filter attributes to those that should be passed through to the generated code.
+    let attrs = attrs
+        .iter()
+        .filter(|attr| pass_through_attr_to_gen_code(attr))
+        .collect::<Vec<_>>();
+
+    let ty_str = ty_to_safe_ident_str(ty);
+    let hidden_mod_ident = format_ident!("__{}__{}__spec", ty_str, ident);
+    let exported = if export {
+        Some(quote! {
+            #[doc(hidden)]
+            #(#attrs)*
+            #[allow(non_snake_case)]
+            pub mod #hidden_mod_ident {
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                #[allow(non_upper_case_globals)]
+                #(#attrs)*
+                #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")]
+                pub static #spec_ident: [u8; #spec_xdr_len] = super::#ty::#spec_fn_ident();
+            }
+        })
+    } else {
+        None
+    };
+
+    // Generated code.
+    Ok(quote! {
+        #exported
+
+        impl #ty {
+            #[allow(non_snake_case)]
+            #(#attrs)*
+            pub const fn #spec_fn_ident() -> [u8; #spec_xdr_len] {
+                *#spec_xdr_lit
+            }
+        }
+    })
+}
+
+/// Returns the spec entry of the function.
+///
+/// Errors are pushed for inputs and outputs that are not supported, and the
+/// fallback type is used in their place.
+pub fn fn_spec_entry(
+    ident: &Ident,
+    attrs: &[Attribute],
+    inputs: &Punctuated<FnArg, Comma>,
+    output: &ReturnType,
+    fallback: &ScSpecTypeDef,
+    errors: &mut Vec<Error>,
+) -> ScSpecEntry {
     // Prepare the env input.
     let env_input = inputs.first().and_then(|a| match a {
         FnArg::Typed(pat_type) => {
@@ -112,7 +184,7 @@ pub fn derive_fn_spec(
                         ScSpecFunctionInputV0 {
                             doc: "".try_into().unwrap(),
                             name: "arg".try_into().unwrap(),
-                            type_: ScSpecTypeDef::I32,
+                            type_: fallback.clone(),
                         }
                     }
                 }
@@ -122,7 +194,7 @@ pub fn derive_fn_spec(
                 ScSpecFunctionInputV0 {
                     doc: "".try_into().unwrap(),
                     name: "".try_into().unwrap(),
-                    type_: ScSpecTypeDef::I32,
+                    type_: fallback.clone(),
                 }
             }
         })
@@ -134,15 +206,14 @@ pub fn derive_fn_spec(
             Ok(spec) => spec,
             Err(e) => {
                 errors.push(e);
-                ScSpecTypeDef::I32
+                fallback.clone()
             }
         }],
         ReturnType::Default => vec![],
     };
 
-    // Generated code spec.
     let name = &ident.unraw().to_string();
-    let spec_entry = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+    ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
         doc: docs_from_attrs(attrs),
         name: name.try_into().unwrap_or_else(|_| {
             errors.push(Error::new(
@@ -157,55 +228,5 @@ pub fn derive_fn_spec(
         }),
         inputs: spec_args.try_into().unwrap(),
         outputs: spec_result.try_into().unwrap(),
-    });
-    let spec_xdr = spec_entry.to_xdr(DEFAULT_XDR_RW_LIMITS).unwrap();
-    let spec_xdr_lit = proc_macro2::Literal::byte_string(spec_xdr.as_slice());
-    let spec_xdr_len = spec_xdr.len();
-    let spec_ident = format_ident!("__SPEC_XDR_FN_{}", ident.unraw().to_string().to_uppercase());
-    let spec_fn_ident = format_ident!("spec_xdr_{}", ident);
-
-    // If errors have occurred, render them instead.
-    if !errors.is_empty() {
-        let compile_errors = errors.iter().map(Error::to_compile_error);
-        return Err(quote! { #(#compile_errors)* });
-    }
-
-    // Filter attributes to those that should be passed through to the generated code.
-    let attrs = attrs
-        .iter()
-        .filter(|attr| pass_through_attr_to_gen_code(attr))
-        .collect::<Vec<_>>();
-
-    let ty_str = ty_to_safe_ident_str(ty);
-    let hidden_mod_ident = format_ident!("__{}__{}__spec", ty_str, ident);
-    let exported = if export {
-        Some(quote! {
-            #[doc(hidden)]
-            #(#attrs)*
-            #[allow(non_snake_case)]
-            pub mod #hidden_mod_ident {
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                #[allow(non_upper_case_globals)]
-                #(#attrs)*
-                #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")]
-                pub static #spec_ident: [u8; #spec_xdr_len] = super::#ty::#spec_fn_ident();
-            }
-        })
-    } else {
-        None
-    };
-
-    // Generated code.
-    Ok(quote! {
-        #exported
-
-        impl #ty {
-            #[allow(non_snake_case)]
-            #(#attrs)*
-            pub const fn #spec_fn_ident() -> [u8; #spec_xdr_len] {
-                *#spec_xdr_lit
-            }
-        }
     })
 }
@@ -316,6 +316,7 @@ pub fn contractimpl(metadata: TokenStream, input: TokenStream) -> TokenStream {
             let cfs = derive_contract_function_registration_ctor(
                 crate_path,
                 ty,
+                ty,
                 trait_ident,
                 &pub_methods_fns,
             );
@@ -454,7 +455,7 @@ pub fn contracttype(metadata: TokenStream, input: TokenStream) -> TokenStream {
         Ok(v) => v,
         Err(e) => return e.write_errors().into(),
     };
-    let input = parse_macro_input!(input as DeriveInput);
+    let mut input = parse_macro_input!(input as DeriveInput);
     let vis = &input.vis;
     let ident = &input.ident;
     let attrs = &input.attrs;
@@ -497,9 +498,11 @@ pub fn contracttype(metadata: TokenStream, input: TokenStream) -> TokenStream {
         )
         .to_compile_error(),
     };
+    let field_attrs_error = arbitrary::take_field_attrs(&mut input);
     quote! {
         #input
         #export_error
+        #field_attrs_error
         #derived
     }
     .into()
@@ -1290,7 +1290,7 @@ impl<const N: usize> BytesN<N> {
 #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
 impl<const N: usize> crate::testutils::BytesN<N> for BytesN<N> {
     fn random(env: &Env) -> BytesN<N> {
-        BytesN::from_array(env, &crate::testutils::random())
+        BytesN::from_array(env, &crate::testutils::random(env))
     }
 }
 
@@ -271,10 +271,15 @@ impl Deployer {
     /// }
     /// ```
     pub fn upload_contract_wasm(&self, contract_wasm: impl IntoVal<Env, Bytes>) -> BytesN<32> {
-        self.env
-            .upload_wasm(contract_wasm.into_val(&self.env).to_object())
+        let contract_wasm: Bytes = contract_wasm.into_val(&self.env);
+        let wasm_hash: BytesN<32> = self
+            .env
+            .upload_wasm(contract_wasm.to_object())
             .unwrap_infallible()
-            .into_val(&self.env)
+            .into_val(&self.env);
+        #[cfg(any(test, feature = "testutils"))]
+        self.env.cover_uploaded_wasm(&wasm_hash, &contract_wasm);
+        wasm_hash
     }
 
     /// Replaces the executable of the current contract with the provided Wasm.
@@ -477,14 +482,18 @@ impl DeployerWithAddress {
     #[deprecated(note = "use deploy_contract")]
     pub fn deploy(&self, wasm_hash: impl IntoVal<Env, BytesN<32>>) -> Address {
         let env = &self.env;
+        let wasm_hash: BytesN<32> = wasm_hash.into_val(env);
         let address_obj = env
             .create_contract(
                 self.address.to_object(),
-                wasm_hash.into_val(env).to_object(),
+                wasm_hash.to_object(),
                 self.salt.to_object(),
             )
             .unwrap_infallible();
-        unsafe { Address::unchecked_new(env.clone(), address_obj) }
+        let address = unsafe { Address::unchecked_new(env.clone(), address_obj) };
+        #[cfg(any(test, feature = "testutils"))]
+        env.cover_deployed_wasm(&address, &wasm_hash);
+        address
     }
 
     /// Deploy a contract that uses Wasm executable with provided hash.
@@ -507,15 +516,19 @@ impl DeployerWithAddress {
         A: ConstructorArgs,
     {
         let env = &self.env;
+        let wasm_hash: BytesN<32> = wasm_hash.into_val(env);
         let address_obj = env
             .create_contract_with_constructor(
                 self.address.to_object(),
-                wasm_hash.into_val(env).to_object(),
+                wasm_hash.to_object(),
                 self.salt.to_object(),
                 constructor_args.into_val(env).to_object(),
             )
             .unwrap_infallible();
-        unsafe { Address::unchecked_new(env.clone(), address_obj) }
+        let address = unsafe { Address::unchecked_new(env.clone(), address_obj) };
+        #[cfg(any(test, feature = "testutils"))]
+        env.cover_deployed_wasm(&address, &wasm_hash);
+        address
     }
 
     /// Deploy a contract that uses the provided executable.
@@ -546,6 +559,11 @@ impl DeployerWithAddress {
         A: ConstructorArgs,
     {
         let env = &self.env;
+        #[cfg(any(test, feature = "testutils"))]
+        let deployed_wasm_hash = match &executable {
+            ContractExecutable::Wasm(wasm_hash) => Some(wasm_hash.clone()),
+            ContractExecutable::ExternalRef(_) => None,
+        };
         let address_obj = match executable {
             ContractExecutable::Wasm(wasm_hash) => env
                 .create_contract_with_constructor(
@@ -569,7 +587,12 @@ impl DeployerWithAddress {
                 .unwrap_infallible()
             }
         };
-        unsafe { Address::unchecked_new(env.clone(), address_obj) }
+        let address = unsafe { Address::unchecked_new(env.clone(), address_obj) };
+        #[cfg(any(test, feature = "testutils"))]
+        if let Some(wasm_hash) = deployed_wasm_hash {
+            env.cover_deployed_wasm(&address, &wasm_hash);
+        }
+        address
     }
 }
 
@@ -252,17 +252,37 @@ enum EnvTestState {
         generators: Rc<RefCell<Generators>>,
         auth_snapshot: Rc<RefCell<AuthSnapshot>>,
         snapshot: Option<Rc<LedgerSnapshot>>,
+        state: Rc<TestState>,
     },
     Contract,
 }
 
+/// The state the testutils keep for an [Env] created by a test, shared by all
+/// clones of the [Env], and with the host's invocation hook.
+#[cfg(any(test, feature = "testutils"))]
+struct TestState {
+    snapshot_source: Rc<dyn internal::storage::SnapshotSource>,
+    rolled_back_events: RefCell<Option<internal::events::Events>>,
+    // Shared with the function sets of the native contracts registered.
+    trace_recorder: Rc<RefCell<trace::Recorder>>,
+    event_journal: RefCell<Journal>,
+    archival: RefCell<Archival>,
+    // Shared weakly with the guards of the invariants.
+    invariants: Rc<RefCell<Invariants>>,
+    coverage: RefCell<coverage::Contracts>,
+    footprint_recorder: RefCell<FootprintRecorder>,
+    network_config: RefCell<NetworkConfig>,
+}
+
 /// Adapts a [`ContractFunctionSet`] into the function set the host dispatches
 /// native contract calls to.
 ///
 /// Shared by contract registration and Wasm upload, both of which hand the host
 /// a native contract to dispatch to.
+///
+/// Records the frames of the contract for tracing with the recorder, if any.
 #[cfg(any(test, feature = "testutils"))]
-struct InternalContractFunctionSet<T: ContractFunctionSet>(T);
+struct InternalContractFunctionSet<T: ContractFunctionSet>(T, Option<Rc<RefCell<trace::Recorder>>>);
 
 #[cfg(any(test, feature = "testutils"))]
 impl<T: ContractFunctionSet> internal::ContractFunctionSet for InternalContractFunctionSet<T> {
@@ -273,14 +293,14 @@ impl<T: ContractFunctionSet> internal::ContractFunctionSet for InternalContractF
             // code is running as the contract.
             test_state: EnvTestState::Contract,
         };
-        self.0.call(
-            crate::Symbol::try_from_val(&env, func)
-                .unwrap_infallible()
-                .to_string()
-                .as_str(),
-            env,
-            args,
-        )
+        let func = crate::Symbol::try_from_val(&env, func)
+            .unwrap_infallible()
+            .to_string();
+        let _frame = self.1.as_ref().map(|recorder| {
+            let contract = internal::Env::get_current_contract_address(env_impl).unwrap();
+            trace::NativeFrameGuard::enter(recorder, contract.into(), &func, args)
+        });
+        self.0.call(func.as_str(), env, args)
     }
 }
 
@@ -317,6 +337,85 @@ impl EnvTestState {
             }
         }
     }
+
+    fn trace_recorder(&self) -> Option<&Rc<RefCell<trace::Recorder>>> {
+        match self {
+            Self::Test { state, .. } => Some(&state.trace_recorder),
+            // Contracts registered inside a contract function are not traced.
+            Self::Contract => None,
+        }
+    }
+
+    fn snapshot_source(&self) -> &Rc<dyn internal::storage::SnapshotSource> {
+        match self {
+            Self::Test { state, .. } => &state.snapshot_source,
+            Self::Contract => {
+                panic!("checkpoints are unavailable inside a contract function and must be used only from the test code outside the contract function")
+            }
+        }
+    }
+
+    fn rolled_back_events(&self) -> &RefCell<Option<internal::events::Events>> {
+        match self {
+            Self::Test { state, .. } => &state.rolled_back_events,
+            Self::Contract => {
+                panic!("events are unavailable inside a contract function and must be accessed only from the test code outside the contract function")
+            }
+        }
+    }
+
+    fn archival(&self) -> &RefCell<Archival> {
+        match self {
+            Self::Test { state, .. } => &state.archival,
+            Self::Contract => {
+                panic!("archived entries are unavailable inside a contract function and must be accessed only from the test code outside the contract function")
+            }
+        }
+    }
+
+    fn invariants(&self) -> &Rc<RefCell<Invariants>> {
+        match self {
+            Self::Test { state, .. } => &state.invariants,
+            Self::Contract => {
+                panic!("invariants are unavailable inside a contract function and must be added only from the test code outside the contract function")
+            }
+        }
+    }
+
+    fn coverage(&self) -> Option<&RefCell<coverage::Contracts>> {
+        match self {
+            Self::Test { state, .. } => Some(&state.coverage),
+            // Contracts registered inside a contract function are not covered.
+            Self::Contract => None,
+        }
+    }
+
+    fn network_config(&self) -> &RefCell<NetworkConfig> {
+        match self {
+            Self::Test { state, .. } => &state.network_config,
+            Self::Contract => {
+                panic!("network config is unavailable inside a contract function and must be accessed only from the test code outside the contract function")
+            }
+        }
+    }
+
+    fn footprint_recorder(&self) -> Option<&RefCell<FootprintRecorder>> {
+        match self {
+            Self::Test { state, .. } => Some(&state.footprint_recorder),
+            // Calls made inside a contract function are not top level
+            // invocations.
+            Self::Contract => None,
+        }
+    }
+
+    fn event_journal(&self) -> &RefCell<Journal> {
+        match self {
+            Self::Test { state, .. } => &state.event_journal,
+            Self::Contract => {
+                panic!("the event journal is unavailable inside a contract function and must be accessed only from the test code outside the contract function")
+            }
+        }
+    }
 }
 
 /// Config for changing the default behavior of the Env when used in tests.
@@ -326,6 +425,13 @@ pub struct EnvTestConfig {
     /// Capture a test snapshot when the Env is dropped, causing a test snapshot
     /// JSON file to be written to disk when the Env is no longer referenced.
     /// Defaults to true.
+    ///
+    /// When the `SOROBAN_CHECK_SNAPSHOTS` environment variable is set to `1`,
+    /// the test snapshot is compared to the file on disk instead of written,
+    /// and the test fails showing the differences if they differ or the file
+    /// does not exist. An empty test snapshot, for which no file is written,
+    /// fails the test if the file exists. Set the `SOROBAN_UPDATE_SNAPSHOTS` environment variable
+    /// to `1` to write the test snapshot files regardless.
     pub capture_snapshot_at_drop: bool,
     // NOTE: Next time a field needs to be added to EnvTestConfig it will be a breaking change,
     // take the opportunity to make the current field private, new fields private, and settable via
@@ -475,6 +581,10 @@ impl Env {
     where
         T: TryFromVal<Env, Val>,
     {
+        #[cfg(any(test, feature = "testutils"))]
+        let archived = self.checkpoint_archived();
+        #[cfg(any(test, feature = "testutils"))]
+        self.capture_footprint();
         let rv = internal::Env::call(
             self,
             contract_address.to_object(),
@@ -482,6 +592,10 @@ impl Env {
             args.to_object(),
         )
         .unwrap_infallible();
+        #[cfg(any(test, feature = "testutils"))]
+        self.check_archived_access(archived);
+        #[cfg(any(test, feature = "testutils"))]
+        self.check_invariants(contract_address, func, &args);
         T::try_from_val(self, &rv)
             .map_err(|_| ConversionError)
             .unwrap()
@@ -500,6 +614,10 @@ impl Env {
         E: TryFrom<Error>,
         E::Error: Into<InvokeError>,
     {
+        #[cfg(any(test, feature = "testutils"))]
+        let archived = self.checkpoint_archived();
+        #[cfg(any(test, feature = "testutils"))]
+        self.capture_footprint();
         let rv = internal::Env::try_call(
             self,
             contract_address.to_object(),
@@ -507,6 +625,10 @@ impl Env {
             args.to_object(),
         )
         .unwrap_infallible();
+        #[cfg(any(test, feature = "testutils"))]
+        self.check_archived_access(archived);
+        #[cfg(any(test, feature = "testutils"))]
+        self.check_invariants(contract_address, func, &args);
         match internal::Error::try_from_val(self, &rv) {
             Ok(err) => Err(E::try_from(err).map_err(Into::into)),
             Err(ConversionError) => Ok(T::try_from_val(self, &rv)),
@@ -638,15 +760,22 @@ impl Env {
 }
 
 #[cfg(any(test, feature = "testutils"))]
-use crate::testutils::cost_estimate::CostEstimate;
+use crate::testutils::cost_estimate::{
+    CostEstimate, FootprintRecorder, LedgerState, NetworkConfig,
+};
 #[cfg(any(test, feature = "testutils"))]
 use crate::{
     auth,
     testutils::{
-        budget::Budget, cost_estimate::NetworkInvocationResourceLimits, default_ledger_info,
-        Address as _, AuthSnapshot, AuthorizedInvocation, ContractFunctionSet, EventsSnapshot,
-        Generators, Ledger as _, MockAuth, MockAuthContract, Register, Snapshot,
-        SnapshotSourceInput, StellarAssetContract, StellarAssetIssuer,
+        budget::Budget,
+        cost_estimate::NetworkInvocationResourceLimits,
+        coverage, default_ledger_info, mock_auth_entry,
+        trace::{self, Trace},
+        Address as _, Archival, AuthSnapshot, AuthorizedInvocation, Checkpoint, ContractClient,
+        ContractFunctionSet, EventJournal, EventsSnapshot, FnContract, Generators, Invariants,
+        Journal, Keypair, Ledger as _, MockAuth, MockAuthContract, MockAuthInvoke, MockContract,
+        Register, Snapshot, SnapshotSourceInput, StellarAssetContract, StellarAssetIssuer,
+        Transaction,
     },
     Bytes, BytesN, ConstructorArgs,
 };
@@ -661,6 +790,19 @@ use std::{path::Path, rc::Rc};
 #[cfg(any(test, feature = "testutils"))]
 use xdr::{LedgerEntry, LedgerKey, LedgerKeyContractData, SorobanAuthorizationEntry};
 
+#[cfg(any(test, feature = "testutils"))]
+struct EmptySnapshotSource();
+
+#[cfg(any(test, feature = "testutils"))]
+impl internal::storage::SnapshotSource for EmptySnapshotSource {
+    fn get(
+        &self,
+        _key: &Rc<xdr::LedgerKey>,
+    ) -> Result<Option<(Rc<xdr::LedgerEntry>, Option<u32>)>, soroban_env_host::HostError> {
+        Ok(None)
+    }
+}
+
 #[cfg(any(test, feature = "testutils"))]
 #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
 impl Env {
@@ -681,21 +823,46 @@ impl Env {
 
     /// Create an Env with the test config.
     pub fn new_with_config(config: EnvTestConfig) -> Env {
-        struct EmptySnapshotSource();
+        let rf = Rc::new(EmptySnapshotSource());
 
-        impl internal::storage::SnapshotSource for EmptySnapshotSource {
-            fn get(
-                &self,
-                _key: &Rc<xdr::LedgerKey>,
-            ) -> Result<Option<(Rc<xdr::LedgerEntry>, Option<u32>)>, soroban_env_host::HostError>
-            {
-                Ok(None)
-            }
-        }
+        Env::new_for_testutils(config, rf, None, None, None)
+    }
 
+    /// Create an Env where every generated value is derived from the seed.
+    ///
+    /// The seed drives the generated addresses, nonces, mux ids and Wasm
+    /// hashes, the seed of the host's base PRNG, and the bytes of
+    /// [`BytesN::random`][crate::testutils::BytesN::random]. Two Envs created
+    /// with the same seed, that execute the same test, generate the same
+    /// values.
+    ///
+    /// If the test panics, the seed is printed so that the failing run can be
+    /// reproduced.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{testutils::Address as _, Address, Env};
+    ///
+    /// # fn main() {
+    /// let env1 = Env::new_with_seed(7);
+    /// let env2 = Env::new_with_seed(7);
+    /// assert_eq!(
+    ///     Address::generate(&env1).to_string().to_string(),
+    ///     Address::generate(&env2).to_string().to_string(),
+    /// );
+    /// # }
+    /// ```
+    pub fn new_with_seed(seed: u64) -> Env {
         let rf = Rc::new(EmptySnapshotSource());
+        let generators = Rc::new(RefCell::new(Generators::with_seed(seed)));
 
-        Env::new_for_testutils(config, rf, None, None, None)
+        Env::new_for_testutils(EnvTestConfig::default(), rf, Some(generators), None, None)
+    }
+
+    /// Returns the seed the Env was created with using
+    /// [`new_with_seed`][Env::new_with_seed], if any.
+    pub fn seed(&self) -> Option<u64> {
+        self.with_generator(|g| g.seed())
     }
 
     /// Change the test config of an Env.
@@ -745,7 +912,8 @@ impl Env {
             1
         };
 
-        let storage = internal::storage::Storage::with_recording_footprint(recording_footprint);
+        let storage =
+            internal::storage::Storage::with_recording_footprint(recording_footprint.clone());
         let budget = internal::budget::Budget::default();
         let env_impl = internal::EnvImpl::with_storage_and_budget(storage, budget.clone());
         env_impl
@@ -756,14 +924,35 @@ impl Env {
         env_impl
             .set_diagnostic_level(internal::DiagnosticLevel::Debug)
             .unwrap();
-        env_impl.set_base_prng_seed([0; 32]).unwrap();
+        let generators = generators.unwrap_or_default();
+        let prng_seed = (*generators).borrow().prng_seed();
+        env_impl.set_base_prng_seed(prng_seed).unwrap();
 
         let auth_snapshot = Rc::new(RefCell::new(AuthSnapshot::default()));
         let auth_snapshot_in_hook = auth_snapshot.clone();
+        let state = Rc::new(TestState {
+            snapshot_source: recording_footprint,
+            rolled_back_events: RefCell::new(None),
+            trace_recorder: Rc::new(RefCell::new(trace::Recorder::default())),
+            event_journal: RefCell::new(Journal::default()),
+            archival: RefCell::new(Archival::default()),
+            invariants: Rc::new(RefCell::new(Invariants::default())),
+            coverage: RefCell::new(coverage::Contracts::default()),
+            footprint_recorder: RefCell::new(FootprintRecorder::default()),
+            network_config: RefCell::new(NetworkConfig::mainnet()),
+        });
+        let state_in_hook = state.clone();
         env_impl
             .set_invocation_hook(Some(Rc::new(move |host, event| {
                 match event {
-                    InvocationEvent::Start => {}
+                    InvocationEvent::Start => {
+                        // The host clears its events at the start of each
+                        // invocation, so any events restored by a rollback are
+                        // superseded by the events of the new invocation.
+                        state_in_hook.rolled_back_events.borrow_mut().take();
+                        state_in_hook.trace_recorder.borrow_mut().clear();
+                        state_in_hook.footprint_recorder.borrow_mut().start();
+                    }
                     InvocationEvent::Finish => {
                         let new_auths = host
                             .get_authenticated_authorizations()
@@ -771,6 +960,17 @@ impl Env {
                             // it means that no auth has occurred.
                             .unwrap();
                         (*auth_snapshot_in_hook).borrow_mut().0.push(new_auths);
+                        state_in_hook
+                            .event_journal
+                            .borrow_mut()
+                            .record(|| host.get_events().unwrap().0);
+                        if coverage::enabled() {
+                            state_in_hook
+                                .coverage
+                                .borrow_mut()
+                                .record(&host.get_events().unwrap().0);
+                        }
+                        state_in_hook.footprint_recorder.borrow_mut().finish();
                     }
                 }
             })))
@@ -786,9 +986,10 @@ impl Env {
                 test_name,
                 number,
                 config,
-                generators: generators.unwrap_or_default(),
+                generators,
                 snapshot,
                 auth_snapshot,
+                state,
             },
         };
 
@@ -823,6 +1024,54 @@ impl Env {
         CostEstimate::new(self.clone())
     }
 
+    /// Returns the call tree of the last top level invocation.
+    ///
+    /// The tree has a frame for each contract call made during the
+    /// invocation, including calls that failed, with the arguments, the
+    /// result, the events emitted, the storage accessed and the resources used
+    /// by the call. See [`trace::Frame`] for the limits of what is available
+    /// for each frame.
+    ///
+    /// The trace is displayable, to print the call tree when debugging a test.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, Env, testutils::trace::Trace};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn add(a: u32, b: u32) -> u32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Contract, ());
+    ///     let client = ContractClient::new(&env, &contract_id);
+    ///
+    ///     client.add(&1, &2);
+    ///
+    ///     let trace = env.trace();
+    ///     println!("{trace}");
+    ///     assert_eq!(trace.0[0].result, Ok(3u32.into()));
+    /// }
+    /// ```
+    pub fn trace(&self) -> Trace {
+        let events = self.host().get_events().unwrap();
+        let recorder = self
+            .test_state
+            .trace_recorder()
+            .expect("the trace is unavailable inside a contract function and must be accessed only from the test code outside the contract function");
+        Trace::new(self, &events.0, &recorder.borrow())
+    }
+
     /// Register a contract with the [Env] for testing.
     ///
     /// Pass the contract type when the contract is defined in the current crate
@@ -1071,7 +1320,10 @@ impl Env {
         let wasm_hash = wasm_hash.into_val(self);
         self.env_impl
             .register_native_contract_as_wasm(
-                Rc::new(InternalContractFunctionSet(contract)),
+                Rc::new(InternalContractFunctionSet(
+                    contract,
+                    self.test_state.trace_recorder().cloned(),
+                )),
                 wasm_hash.to_object(),
             )
             .unwrap();
@@ -1173,11 +1425,18 @@ impl Env {
             .unwrap();
         let register_result = self.env_impl.register_test_contract_with_constructor(
             contract_id.to_object(),
-            Rc::new(InternalContractFunctionSet(contract)),
+            Rc::new(InternalContractFunctionSet(
+                contract,
+                self.test_state.trace_recorder().cloned(),
+            )),
             constructor_args,
         );
         self.env_impl.set_auth_manager(prev_auth_manager).unwrap();
         register_result.unwrap();
+        self.cover(
+            &contract_id,
+            coverage::Code::Native(core::any::type_name::<T>()),
+        );
         contract_id
     }
 
@@ -1254,33 +1513,93 @@ impl Env {
         let wasm_hash: BytesN<32> = self.deployer().upload_contract_wasm(contract_wasm);
         self.register_contract_with_optional_contract_id_and_executable(
             contract_id,
-            xdr::ContractExecutable::Wasm(xdr::Hash(wasm_hash.into())),
+            xdr::ContractExecutable::Wasm(xdr::Hash(wasm_hash.to_array())),
             constructor_args.into_val(self),
         )
     }
 
-    /// Register the built-in Stellar Asset Contract with provided admin address.
-    ///
-    /// Returns a utility struct that contains the contract ID of the registered
-    /// token contract, as well as methods to read and update issuer flags.
-    ///
-    /// The contract will wrap a randomly-generated Stellar asset. This function
-    /// is useful for using in the tests when an arbitrary token contract
-    /// instance is needed.
-    pub fn register_stellar_asset_contract_v2(&self, admin: Address) -> StellarAssetContract {
-        let issuer_pk = self.with_generator(|mut g| g.address());
-        let issuer_id = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
-            issuer_pk.clone(),
-        )));
+    /// Associates the registered contract with its code for the coverage
+    /// report, if collecting coverage is enabled.
+    fn cover(&self, contract_id: &Address, code: coverage::Code) {
+        if let Some(contracts) = self.test_state.coverage().filter(|_| coverage::enabled()) {
+            contracts.borrow_mut().register(contract_id.into(), code);
+        }
+    }
+
+    /// Reads the spec of the uploaded Wasm for the coverage report, if
+    /// collecting coverage is enabled.
+    pub(crate) fn cover_uploaded_wasm(&self, wasm_hash: &BytesN<32>, wasm: &Bytes) {
+        if coverage::enabled() {
+            let mut buf = std::vec![0; wasm.len() as usize];
+            wasm.copy_into_slice(&mut buf);
+            coverage::register_wasm(wasm_hash.to_array(), &buf);
+        }
+    }
+
+    /// Associates the contract deployed with the Wasm with the Wasm for the
+    /// coverage report, if collecting coverage is enabled.
+    pub(crate) fn cover_deployed_wasm(&self, contract_id: &Address, wasm_hash: &BytesN<32>) {
+        self.cover(contract_id, coverage::Code::Wasm(wasm_hash.to_array()));
+    }
+
+    /// Enables or disables capturing the ledger entries before top level
+    /// invocations.
+    pub(crate) fn set_footprint_recording(&self, enabled: bool) {
+        if let Some(recorder) = self.test_state.footprint_recorder() {
+            recorder.borrow_mut().set_enabled(enabled);
+        }
+    }
+
+    /// Captures the ledger entries before a top level invocation, for
+    /// computing its footprint, if footprint recording is enabled. Does
+    /// nothing inside a contract function.
+    fn capture_footprint(&self) {
+        if let Some(recorder) = self.test_state.footprint_recorder() {
+            recorder.borrow_mut().capture(self);
+        }
+    }
+
+    /// Returns the number of top level invocations made, including failed
+    /// invocations.
+    pub(crate) fn invocation_count(&self) -> u32 {
+        self.test_state.event_journal().borrow().invocations
+    }
+
+    /// Returns the ledger entries before the last top level invocation, if
+    /// they were captured.
+    pub(crate) fn last_invocation_ledger_state(&self) -> Option<Rc<LedgerState>> {
+        self.test_state.footprint_recorder()?.borrow().last()
+    }
+
+    /// Returns the network config that costs are estimated with.
+    pub(crate) fn network_config(&self) -> NetworkConfig {
+        self.test_state.network_config().borrow().clone()
+    }
 
+    /// Sets the network config that costs are estimated with.
+    pub(crate) fn set_network_config(&self, config: NetworkConfig) {
+        *self.test_state.network_config().borrow_mut() = config;
+    }
+
+    /// Returns the entry in the snapshot source the Env was created from.
+    pub(crate) fn snapshot_source_entry(
+        &self,
+        key: &Rc<xdr::LedgerKey>,
+    ) -> Option<internal::storage::EntryWithLiveUntil> {
+        self.test_state.snapshot_source().get(key).unwrap()
+    }
+
+    /// Adds an account entry with no balance and no signers other than the
+    /// master key to the ledger, if the account does not exist.
+    pub(crate) fn create_account_entry(&self, account_id: &xdr::AccountId) {
         let k = Rc::new(xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
-            account_id: issuer_id.clone(),
+            account_id: account_id.clone(),
         }));
 
         if self.host().get_ledger_entry(&k).unwrap().is_none() {
             let v = Rc::new(xdr::LedgerEntry {
                 data: xdr::LedgerEntryData::Account(xdr::AccountEntry {
-                    account_id: issuer_id.clone(),
+                    account_id: account_id.clone(),
                     balance: 0,
                     flags: 0,
                     home_domain: Default::default(),
@@ -1296,6 +1615,52 @@ impl Env {
             });
             self.host().add_ledger_entry(&k, &v, None).unwrap();
         }
+    }
+
+    /// Register a mock contract that stands in for a contract with the
+    /// interface of the client `C`.
+    ///
+    /// Returns the address of the mock contract, and a handle for scripting
+    /// the responses of its functions and inspecting the calls made to it.
+    /// The client can be any client generated by
+    /// [`contractimpl`][crate::contractimpl],
+    /// [`contractclient`][crate::contractclient] or
+    /// [`contractimport`][crate::contractimport].
+    ///
+    /// See [`testutils::mock_contract`][crate::testutils::mock_contract] for
+    /// how responses are scripted, and an example.
+    pub fn register_mock<C: ContractClient>(&self) -> (Address, MockContract<C>) {
+        let mock = MockContract::register(self);
+        (mock.address().clone(), mock)
+    }
+
+    /// Register a contract with functions that are closures.
+    ///
+    /// Returns a builder that functions are added to, and that registers the
+    /// contract when [`register`][FnContract::register] is called. The
+    /// builder can optionally check the functions against a spec.
+    ///
+    /// See [`testutils::fn_contract`][crate::testutils::fn_contract] for an
+    /// example.
+    pub fn register_fn_contract(&self) -> FnContract {
+        FnContract::new(self)
+    }
+
+    /// Register the built-in Stellar Asset Contract with provided admin address.
+    ///
+    /// Returns a utility struct that contains the contract ID of the registered
+    /// token contract, as well as methods to read and update issuer flags.
+    ///
+    /// The contract will wrap a randomly-generated Stellar asset. This function
+    /// is useful for using in the tests when an arbitrary token contract
+    /// instance is needed.
+    pub fn register_stellar_asset_contract_v2(&self, admin: Address) -> StellarAssetContract {
+        let issuer_pk = self.with_generator(|mut g| g.address());
+        let issuer_id = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
+            issuer_pk.clone(),
+        )));
+
+        self.create_account_entry(&issuer_id);
 
         let asset = xdr::Asset::CreditAlphanum4(xdr::AlphaNum4 {
             asset_code: xdr::AssetCode4([b'a', b'a', b'a', 0]),
@@ -1347,7 +1712,11 @@ impl Env {
         executable: xdr::ContractExecutable,
         constructor_args: Vec<Val>,
     ) -> Address {
-        if let Some(contract_id) = contract_id.into() {
+        let wasm_hash = match &executable {
+            xdr::ContractExecutable::Wasm(hash) => Some(BytesN::from_array(self, &hash.0)),
+            _ => None,
+        };
+        let contract_id = if let Some(contract_id) = contract_id.into() {
             self.register_contract_with_contract_id_and_executable(
                 contract_id,
                 executable,
@@ -1356,7 +1725,11 @@ impl Env {
             contract_id.clone()
         } else {
             self.register_contract_with_source(executable, constructor_args)
+        };
+        if let Some(wasm_hash) = wasm_hash {
+            self.cover_deployed_wasm(&contract_id, &wasm_hash);
         }
+        contract_id
     }
 
     fn register_contract_with_source(
@@ -1411,6 +1784,23 @@ impl Env {
             .unwrap();
     }
 
+    /// Set authorizations of invocations signed by keypairs in the
+    /// environment, which will be consumed by contracts when they invoke
+    /// [`Address::require_auth`] or [`Address::require_auth_for_args`]
+    /// functions.
+    ///
+    /// Each authorization is signed with a real signature that the host
+    /// verifies, see [`Keypair::sign_auth`][crate::testutils::Keypair::sign_auth].
+    /// Replaces any authorizations set previously, and disables any mocking of
+    /// auths, in the same way as [`set_auths`][Self::set_auths].
+    pub fn sign_auths(&self, auths: &[(&Keypair, &MockAuthInvoke)]) {
+        let auths = auths
+            .iter()
+            .map(|(keypair, invoke)| keypair.sign_auth(*invoke))
+            .collect::<std::vec::Vec<_>>();
+        self.set_auths(&auths);
+    }
+
     /// Mock authorizations in the environment which will cause matching invokes
     /// of [`Address::require_auth`] and [`Address::require_auth_for_args`] to
     /// pass.
@@ -1470,6 +1860,68 @@ impl Env {
         self.env_impl.set_authorization_entries(auths).unwrap();
     }
 
+    /// Mock the authorizations recorded in the environment, such as those
+    /// returned by [`auths`][Self::auths], so that a flow that ran with
+    /// [`mock_all_auths`][Self::mock_all_auths] can be replayed requiring
+    /// exactly the recorded authorizations.
+    ///
+    /// Behaves like [`mock_auths`][Self::mock_auths]: matching invokes of
+    /// [`Address::require_auth`] and [`Address::require_auth_for_args`] pass,
+    /// and authorizations not matching a recorded auth fail.
+    ///
+    /// The authorizations of accounts cannot be mocked, convert the recorded
+    /// invocations into authorization entries and sign them with
+    /// [`Keypair::sign_auth`][crate::testutils::Keypair::sign_auth] instead.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, Env, Address, testutils::Address as _};
+    ///
+    /// #[contract]
+    /// pub struct HelloContract;
+    ///
+    /// #[contractimpl]
+    /// impl HelloContract {
+    ///     pub fn hello(env: Env, from: Address) {
+    ///         from.require_auth();
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(HelloContract, ());
+    ///     let client = HelloContractClient::new(&env, &contract_id);
+    ///     let addr = Address::generate(&env);
+    ///
+    ///     env.mock_all_auths();
+    ///     client.hello(&addr);
+    ///     let auths = env.auths();
+    ///
+    ///     env.mock_recorded_auths(&auths);
+    ///     client.hello(&addr);
+    /// }
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// If any of the addresses is an account.
+    pub fn mock_recorded_auths(&self, auths: &[(Address, AuthorizedInvocation)]) {
+        for (address, _) in auths {
+            if let xdr::ScAddress::Account(_) = xdr::ScAddress::from(address) {
+                panic!("the recorded auth of account {address:?} cannot be mocked, sign it with Keypair::sign_auth instead");
+            }
+            self.register_at(address, MockAuthContract, ());
+        }
+        let auths = auths
+            .iter()
+            .map(|(address, invocation)| mock_auth_entry(address, invocation.into()))
+            .collect::<std::vec::Vec<_>>();
+        self.env_impl.set_authorization_entries(auths).unwrap();
+    }
+
     /// Mock all calls to the [`Address::require_auth`] and
     /// [`Address::require_auth_for_args`] functions in invoked contracts,
     /// having them succeed as if authorization was provided.
@@ -2077,9 +2529,7 @@ impl Env {
     /// Create an events snapshot from the Env's current state.
     pub(crate) fn to_events_snapshot(&self) -> EventsSnapshot {
         EventsSnapshot(
-            self.host()
-                .get_events()
-                .unwrap()
+            self.host_events()
                 .0
                 .into_iter()
                 .filter(|e| match e.event.type_ {
@@ -2098,6 +2548,557 @@ impl Env {
         )
     }
 
+    /// Returns the events of the last invocation.
+    ///
+    /// If the Env has been rolled back to a checkpoint since the last
+    /// invocation, returns the events captured by the checkpoint instead.
+    pub(crate) fn host_events(&self) -> internal::events::Events {
+        if let Some(events) = &*self.test_state.rolled_back_events().borrow() {
+            return events.clone();
+        }
+        self.host().get_events().unwrap()
+    }
+
+    /// Start recording the events of every successful top level invocation
+    /// in the event journal.
+    ///
+    /// [`Events::all`][crate::testutils::Events::all] only returns the events
+    /// of the last invocation. The journal returned by
+    /// [`Env::event_journal`] keeps the events of all the invocations made
+    /// since the journal was enabled, tagged with the invocation they were
+    /// published in.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, contractevent, contractimpl, Env};
+    ///
+    /// #[contractevent]
+    /// #[derive(Debug, PartialEq)]
+    /// pub struct Incremented {
+    ///     pub v: u32,
+    /// }
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn incr(env: Env, v: u32) -> u32 {
+    ///         Incremented { v: v + 1 }.publish(&env);
+    ///         v + 1
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Contract, ());
+    ///     let client = ContractClient::new(&env, &contract_id);
+    ///     env.enable_event_journal();
+    ///
+    ///     client.incr(&1);
+    ///     let checkpoint = env.checkpoint();
+    ///     client.incr(&2);
+    ///     client.incr(&3);
+    ///
+    ///     let journal = env.event_journal();
+    ///     assert_eq!(journal.entries().len(), 3);
+    ///     assert_eq!(
+    ///         journal.since(&checkpoint).decoded::<Incremented>(),
+    ///         [Incremented { v: 3 }, Incremented { v: 4 }],
+    ///     );
+    /// }
+    /// ```
+    pub fn enable_event_journal(&self) {
+        self.test_state.event_journal().borrow_mut().enabled = true;
+    }
+
+    /// Returns the event journal, with the events of every successful top
+    /// level invocation since [`Env::enable_event_journal`] was called.
+    ///
+    /// ### Panics
+    ///
+    /// If the event journal is not enabled.
+    pub fn event_journal(&self) -> EventJournal {
+        let journal = self.test_state.event_journal().borrow();
+        if !journal.enabled {
+            panic!("the event journal is not enabled, enable it with Env::enable_event_journal");
+        }
+        EventJournal::new(self, Rc::downgrade(self.test_state.generators()), &journal)
+    }
+
+    /// Add an invariant that is checked after every top level invocation.
+    ///
+    /// The invariant is a closure that panics if the invariant does not hold,
+    /// such as with `assert!`. It is called after every contract invocation
+    /// made from the test with a generated client, including `try_` functions
+    /// of the client, or with [`Env::invoke_contract`] and
+    /// [`Env::try_invoke_contract`]. Invariants are called in the order they
+    /// were added.
+    ///
+    /// Invariants may call contracts to read their state. Those calls do not
+    /// check the invariants again.
+    ///
+    /// The invariant is checked until the returned guard is dropped. Values
+    /// captured by an invariant that hold the Env, such as clients and
+    /// addresses, keep the Env alive until then, so keep the guard in a
+    /// variable declared after the Env, so that it is dropped before the Env
+    /// and the test snapshot is written when the Env is dropped.
+    ///
+    /// ### Panics
+    ///
+    /// The invocation that is followed by a broken invariant panics, with a
+    /// message naming the invariant by the order it was added in, the
+    /// contract, function and arguments of the invocation, and the message
+    /// the invariant panicked with.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+    /// use soroban_sdk::testutils::Address as _;
+    ///
+    /// #[contracttype]
+    /// pub enum DataKey {
+    ///     Supply,
+    ///     Balance(Address),
+    /// }
+    ///
+    /// #[contract]
+    /// pub struct Token;
+    ///
+    /// #[contractimpl]
+    /// impl Token {
+    ///     pub fn mint(env: Env, to: Address, amount: i128) {
+    ///         let storage = env.storage().persistent();
+    ///         let supply: i128 = storage.get(&DataKey::Supply).unwrap_or(0);
+    ///         let balance = Self::balance(env.clone(), to.clone());
+    ///         storage.set(&DataKey::Supply, &(supply + amount));
+    ///         storage.set(&DataKey::Balance(to), &(balance + amount));
+    ///     }
+    ///
+    ///     pub fn balance(env: Env, id: Address) -> i128 {
+    ///         let key = DataKey::Balance(id);
+    ///         env.storage().persistent().get(&key).unwrap_or(0)
+    ///     }
+    ///
+    ///     pub fn supply(env: Env) -> i128 {
+    ///         let key = DataKey::Supply;
+    ///         env.storage().persistent().get(&key).unwrap_or(0)
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Token, ());
+    ///     let client = TokenClient::new(&env, &contract_id);
+    ///     let users = [Address::generate(&env), Address::generate(&env)];
+    ///
+    ///     let (id, u) = (contract_id.clone(), users.clone());
+    ///     let _invariant = env.add_invariant(move |env| {
+    ///         let c = TokenClient::new(env, &id);
+    ///         let sum: i128 = u.iter().map(|u| c.balance(u)).sum();
+    ///         assert_eq!(sum, c.supply(), "sum of balances must equal supply");
+    ///     });
+    ///
+    ///     client.mint(&users[0], &10);
+    ///     client.mint(&users[1], &5);
+    /// }
+    /// ```
+    pub fn add_invariant(
+        &self,
+        invariant: impl Fn(&Env) + 'static,
+    ) -> crate::testutils::InvariantGuard {
+        Invariants::add(self.test_state.invariants(), Rc::new(invariant))
+    }
+
+    /// Checks the invariants after a top level invocation.
+    ///
+    /// Invocations made by contracts use an Env without test state, and are
+    /// not top level invocations, so are not checked.
+    fn check_invariants(&self, contract_address: &Address, func: &crate::Symbol, args: &Vec<Val>) {
+        if let EnvTestState::Test { state, .. } = &self.test_state {
+            Invariants::check(&state.invariants, self, || {
+                crate::testutils::describe_call(self, contract_address, func, args)
+            });
+        }
+    }
+
+    /// Create a checkpoint of the Env's current state.
+    ///
+    /// The checkpoint can be restored later with [`Env::rollback`], undoing
+    /// any changes made to ledger entries, TTLs, ledger info, generators,
+    /// authorization mode, recorded authorizations, events and the event
+    /// journal since the checkpoint was created.
+    ///
+    /// Contracts registered after the checkpoint are unreachable after a
+    /// rollback, because their instances are removed from the ledger.
+    ///
+    /// ### Examples
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, Env, testutils::Ledger as _};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn set(env: Env, v: u32) {
+    ///         env.storage().instance().set(&(), &v);
+    ///     }
+    ///     pub fn get(env: Env) -> u32 {
+    ///         env.storage().instance().get(&()).unwrap_or_default()
+    ///     }
+    /// }
+    ///
+    /// #[test]
+    /// fn test() {
+    /// # }
+    /// # fn main() {
+    ///     let env = Env::default();
+    ///     let contract_id = env.register(Contract, ());
+    ///     let client = ContractClient::new(&env, &contract_id);
+    ///     client.set(&1);
+    ///
+    ///     let checkpoint = env.checkpoint();
+    ///
+    ///     client.set(&2);
+    ///     env.ledger().set_sequence_number(1000);
+    ///     assert_eq!(client.get(), 2);
+    ///
+    ///     env.rollback(&checkpoint);
+    ///     assert_eq!(client.get(), 1);
+    ///     assert_eq!(env.ledger().sequence(), 0);
+    /// }
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            env: Rc::downgrade(self.test_state.generators()),
+            entries: self.host().get_stored_entries().unwrap(),
+            ledger_info: self.ledger().get(),
+            generators: (*self.test_state.generators()).borrow().clone(),
+            auth_manager: self.host().snapshot_auth_manager().unwrap(),
+            auth_snapshot: (*self.test_state.auth_snapshot()).borrow().clone(),
+            events: self.host_events(),
+            invocations: self.test_state.event_journal().borrow().invocations,
+        }
+    }
+
+    /// Restore the Env's state to the checkpoint.
+    ///
+    /// The checkpoint is not consumed and can be rolled back to again.
+    ///
+    /// See [`Env::checkpoint`] for details on what state is restored.
+    ///
+    /// ### Panics
+    ///
+    /// If the checkpoint was created by a different Env.
+    pub fn rollback(&self, checkpoint: &Checkpoint) {
+        if !std::ptr::eq(
+            checkpoint.env.as_ptr(),
+            Rc::as_ptr(self.test_state.generators()),
+        ) {
+            panic!("checkpoint was created by a different Env and cannot be rolled back to");
+        }
+
+        // Entries loaded or created after the checkpoint are restored to their
+        // value in the snapshot source the Env was created from, which is the
+        // value the Env would have loaded had they never been accessed.
+        let host = self.host();
+        let source = self.test_state.snapshot_source();
+        let checkpointed = checkpoint
+            .entries
+            .iter()
+            .map(|(k, _)| k.clone())
+            .collect::<std::collections::BTreeSet<_>>();
+        for (k, _) in host.get_stored_entries().unwrap() {
+            if !checkpointed.contains(&k) {
+                let v = source.get(&k).unwrap();
+                host.setup_storage_entry(k, v, internal::storage::AccessType::ReadOnly)
+                    .unwrap();
+            }
+        }
+        for (k, v) in &checkpoint.entries {
+            host.setup_storage_entry(
+                k.clone(),
+                v.clone(),
+                internal::storage::AccessType::ReadOnly,
+            )
+            .unwrap();
+        }
+
+        self.ledger().set(checkpoint.ledger_info.clone());
+        *(*self.test_state.generators()).borrow_mut() = checkpoint.generators.clone();
+        host.set_auth_manager(checkpoint.auth_manager.clone())
+            .unwrap();
+        *(*self.test_state.auth_snapshot()).borrow_mut() = checkpoint.auth_snapshot.clone();
+        *self.test_state.rolled_back_events().borrow_mut() = Some(checkpoint.events.clone());
+        self.test_state
+            .event_journal()
+            .borrow_mut()
+            .truncate(checkpoint.invocations);
+        self.test_state.archival().borrow_mut().keys = None;
+    }
+
+    /// Run the function and then restore the Env's state to what it was
+    /// before the function ran.
+    ///
+    /// Returns the value returned by the function.
+    ///
+    /// Equivalent to creating a checkpoint with [`Env::checkpoint`] before
+    /// calling the function, and rolling back to it with [`Env::rollback`]
+    /// after.
+    pub fn scoped<T>(&self, f: impl FnOnce() -> T) -> T {
+        let checkpoint = self.checkpoint();
+        let t = f();
+        self.rollback(&checkpoint);
+        t
+    }
+
+    /// Create a simulated transaction made of several host function
+    /// invocations, such as uploading Wasm, deploying a contract and calling
+    /// it, that share resource limits and a footprint, and are rolled back
+    /// together if any of them fails.
+    ///
+    /// See [`testutils::transaction`][crate::testutils::transaction] for an
+    /// example.
+    pub fn transaction(&self) -> Transaction {
+        Transaction::new(self)
+    }
+
+    /// Deletes the temporary entries that have a live until ledger before the
+    /// current ledger sequence number.
+    ///
+    /// See [`Ledger::advance`][crate::testutils::Ledger::advance].
+    pub(crate) fn delete_expired_temporary_entries(&self) {
+        let host = self.host();
+        for k in self.expired_keys() {
+            if !is_persistent(&k) {
+                host.setup_storage_entry(k, None, internal::storage::AccessType::ReadOnly)
+                    .unwrap();
+            }
+        }
+    }
+
+    /// Restores an archived ledger entry.
+    ///
+    /// See [`Ledger::restore`][crate::testutils::Ledger::restore].
+    pub(crate) fn restore_archived_entry(&self, key: &xdr::LedgerKey) {
+        let li = self.ledger().get();
+        // Restored entries live for the minimum persistent entry TTL,
+        // including the ledger they are restored in.
+        let live_until = li
+            .sequence_number
+            .saturating_add(li.min_persistent_entry_ttl)
+            .saturating_sub(1);
+
+        let key = Rc::new(key.clone());
+        let entry = match self
+            .host()
+            .get_stored_entries()
+            .unwrap()
+            .into_iter()
+            .find(|(k, _)| *k == key)
+        {
+            Some((_, entry)) => entry,
+            None => self.snapshot_source_entry(&key),
+        };
+        let entry = match entry {
+            Some((entry, Some(entry_live_until)))
+                if is_persistent(&key) && entry_live_until < li.sequence_number =>
+            {
+                entry
+            }
+            _ => panic!("ledger entry is not archived: {key:?}"),
+        };
+        self.host()
+            .setup_storage_entry(
+                key.clone(),
+                Some((entry, Some(live_until))),
+                internal::storage::AccessType::ReadOnly,
+            )
+            .unwrap();
+        if let Some((_, keys)) = &mut self.test_state.archival().borrow_mut().keys {
+            keys.remove(&key);
+        }
+    }
+
+    /// Returns the keys of the archived ledger entries, which are the
+    /// persistent entries with a live until ledger before the current ledger
+    /// sequence number.
+    ///
+    /// See [`Ledger::advance`][crate::testutils::Ledger::advance].
+    pub fn archived_ledger_entries(&self) -> std::vec::Vec<xdr::LedgerKey> {
+        self.find_archived_keys()
+            .iter()
+            .map(|k| (**k).clone())
+            .collect()
+    }
+
+    /// Sets whether archived ledger entries are restored automatically when a
+    /// contract invocation accesses them.
+    ///
+    /// Auto-restore is enabled by default, emulating transactions that restore
+    /// the archived entries in their footprint before accessing them, see
+    /// [`_migrating::v23_archived_testing`][crate::_migrating::v23_archived_testing].
+    ///
+    /// When disabled, a contract invocation made with a contract client or
+    /// [`Env::invoke_contract`] that accesses an archived entry, including
+    /// the instance or Wasm code of a contract, fails as its transaction would
+    /// on the network: its changes to the ledger entries are rolled back and it
+    /// panics. Archived entries must then be restored with
+    /// [`Ledger::restore`][crate::testutils::Ledger::restore] before they are
+    /// accessed.
+    ///
+    /// Entries accessed with [`Env::as_contract`] are always restored
+    /// automatically.
+    pub fn set_auto_restore(&self, enabled: bool) {
+        self.test_state.archival().borrow_mut().auto_restore = enabled;
+    }
+
+    /// Returns the keys of the archived entries, and remembers them for the
+    /// current ledger sequence number.
+    fn find_archived_keys(&self) -> std::collections::BTreeSet<Rc<xdr::LedgerKey>> {
+        let sequence_number = self.ledger().sequence();
+        let keys: std::collections::BTreeSet<_> = self
+            .expired_keys()
+            .into_iter()
+            .filter(|k| is_persistent(k))
+            .collect();
+        self.test_state.archival().borrow_mut().keys = Some((sequence_number, keys.clone()));
+        keys
+    }
+
+    /// Returns the keys of the entries that have a live until ledger before
+    /// the current ledger sequence number, both of the entries the host has
+    /// loaded, and of the entries of the snapshot the Env was created from
+    /// that it has not.
+    fn expired_keys(&self) -> std::vec::Vec<Rc<xdr::LedgerKey>> {
+        let sequence_number = self.ledger().sequence();
+        let stored = self.host().get_stored_entries().unwrap();
+        let loaded: std::collections::BTreeSet<_> = stored.iter().map(|(k, _)| k).collect();
+        let mut keys: std::vec::Vec<_> = stored
+            .iter()
+            .filter_map(|(k, v)| match v {
+                Some((_, Some(live_until))) if *live_until < sequence_number => Some(k.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let mut archival = self.test_state.archival().borrow_mut();
+        let snapshot = archival.snapshot.get_or_insert_with(|| {
+            let mut expiring = std::collections::BTreeMap::<_, std::vec::Vec<_>>::new();
+            for (k, (_, live_until)) in self.test_state.snapshot().iter().flat_map(|s| s.entries())
+            {
+                if let Some(live_until) = live_until {
+                    expiring
+                        .entry(*live_until)
+                        .or_default()
+                        .push(Rc::new((**k).clone()));
+                }
+            }
+            expiring
+        });
+        keys.extend(
+            snapshot
+                .range(..sequence_number)
+                .flat_map(|(_, keys)| keys)
+                .filter(|k| !loaded.contains(k))
+                .cloned(),
+        );
+        keys
+    }
+
+    /// Returns the ledger entries before the top level invocation about to be
+    /// made, to roll back to if it accesses an archived entry, and the keys of
+    /// the archived entries. Returns `None` if auto-restore is enabled, there
+    /// are no archived entries, or inside a contract function.
+    ///
+    /// The archived keys are found again only when the ledger sequence number
+    /// has changed since they were last found.
+    #[allow(clippy::type_complexity)]
+    fn checkpoint_archived(
+        &self,
+    ) -> Option<(
+        std::vec::Vec<(
+            Rc<xdr::LedgerKey>,
+            Option<crate::testutils::EntryWithLiveUntil>,
+        )>,
+        std::collections::BTreeSet<Rc<xdr::LedgerKey>>,
+    )> {
+        let EnvTestState::Test { state, .. } = &self.test_state else {
+            return None;
+        };
+        let archival = &state.archival;
+        let sequence_number = self.ledger().sequence();
+        let cached = match &archival.borrow().keys {
+            _ if archival.borrow().auto_restore => return None,
+            Some((at, keys)) if *at == sequence_number => Some(keys.clone()),
+            _ => None,
+        };
+        let keys = cached.unwrap_or_else(|| self.find_archived_keys());
+        if keys.is_empty() {
+            return None;
+        }
+        Some((self.host().get_stored_entries().unwrap(), keys))
+    }
+
+    /// Rolls back the changes the invocation made to the ledger entries and
+    /// panics if it accessed any of the archived entries, which the host would
+    /// otherwise have restored.
+    #[allow(clippy::type_complexity)]
+    fn check_archived_access(
+        &self,
+        archived: Option<(
+            std::vec::Vec<(
+                Rc<xdr::LedgerKey>,
+                Option<crate::testutils::EntryWithLiveUntil>,
+            )>,
+            std::collections::BTreeSet<Rc<xdr::LedgerKey>>,
+        )>,
+    ) {
+        let Some((before, keys)) = archived else {
+            return;
+        };
+        let sequence_number = self.ledger().sequence();
+        let is_live = |v: Option<&Option<crate::testutils::EntryWithLiveUntil>>| matches!(v, Some(Some((_, Some(live_until)))) if *live_until >= sequence_number);
+        let before: std::collections::BTreeMap<_, _> = before.into_iter().collect();
+        let host = self.host();
+        let after = host.get_stored_entries().unwrap();
+        let after_map: std::collections::BTreeMap<_, _> = after.iter().cloned().collect();
+        // Entries that were live before the invocation were restored outside
+        // of an invocation, e.g. in `as_contract`.
+        let (restored, accessed): (std::vec::Vec<_>, std::vec::Vec<_>) = keys
+            .iter()
+            .filter(|k| is_live(after_map.get(*k)))
+            .partition(|k| is_live(before.get(*k)));
+        if !accessed.is_empty() {
+            // Entries loaded by the invocation are restored to their value in
+            // the snapshot source, the same way as in `Env::rollback`.
+            for (k, v) in after {
+                let v_before = match before.get(&k) {
+                    Some(v_before) => v_before.clone(),
+                    None => self.snapshot_source_entry(&k),
+                };
+                if v != v_before {
+                    host.setup_storage_entry(k, v_before, internal::storage::AccessType::ReadOnly)
+                        .unwrap();
+                }
+            }
+            let accessed: std::vec::Vec<_> = accessed.into_iter().map(|k| (**k).clone()).collect();
+            panic!("contract invocation accessed archived ledger entries, which are not restored automatically because auto-restore is disabled, and must be restored with `Ledger::restore` first: {accessed:?}");
+        }
+        if let Some((_, keys)) = &mut self.test_state.archival().borrow_mut().keys {
+            for k in restored {
+                keys.remove(k);
+            }
+        }
+    }
+
     /// Get the budget that tracks the resources consumed for the environment.
     #[deprecated(note = "use cost_estimate().budget()")]
     pub fn budget(&self) -> Budget {
@@ -2116,8 +3117,15 @@ impl Drop for Env {
         let EnvTestState::Test { config, .. } = &self.test_state else {
             return;
         };
+        if self.env_impl.can_finish() && std::thread::panicking() {
+            if let Some(seed) = self.seed() {
+                std::eprintln!("Env seed: {seed}, reproduce with Env::new_with_seed({seed})");
+            }
+        }
         if self.env_impl.can_finish() && config.capture_snapshot_at_drop {
-            self.to_test_snapshot_file();
+            if let Err(e) = self.to_test_snapshot_file() {
+                panic!("{e}");
+            }
         }
     }
 }
@@ -2138,10 +3146,21 @@ impl Env {
     /// No file will be created if the environment has no meaningful data such
     /// as stored entries or events.
     ///
+    /// If snapshots are being checked, see
+    /// [`EnvTestConfig::capture_snapshot_at_drop`], the snapshot is compared to
+    /// the file instead, and an error describing the differences is returned
+    /// if they differ. Snapshots are not checked while
+    /// the thread is panicking, because the test has already failed.
+    ///
+    /// ### Errors
+    ///
+    /// If snapshots are being checked and the file does not exist, cannot be
+    /// read, or differs from the snapshot.
+    ///
     /// ### Panics
     ///
     /// If there is any error writing the file.
-    pub(crate) fn to_test_snapshot_file(&self) {
+    pub(crate) fn to_test_snapshot_file(&self) -> Result<(), std::string::String> {
         // If there's no test state, or no test name, we're not in a test
         // context, so don't write snapshots. An Env without test state would
         // panic if its test state were read.
@@ -2151,19 +3170,11 @@ impl Env {
             ..
         } = &self.test_state
         else {
-            return;
+            return Ok(());
         };
 
         let snapshot = self.to_snapshot();
 
-        // Don't write a snapshot that has no data in it.
-        if snapshot.ledger.entries().into_iter().count() == 0
-            && snapshot.events.0.is_empty()
-            && snapshot.auth.0.is_empty()
-        {
-            return;
-        }
-
         // Determine path to write test snapshots to.
         // Break up the test name into directories, using :: as the separator.
         // The :: module separator cannot be written into the filename because
@@ -2179,10 +3190,99 @@ impl Env {
             .join(&test_name_path)
             .with_extension(format!("{number}.json"));
 
+        if check_test_snapshots() {
+            if std::thread::panicking() {
+                return Ok(());
+            }
+            return check_test_snapshot_file(&snapshot, &p);
+        }
+
+        // Don't write a snapshot that has no data in it.
+        if is_empty_snapshot(&snapshot) {
+            return Ok(());
+        }
+
         // Write test snapshots to file.
         eprintln!("Writing test snapshot file for test {test_name:?} to {p:?}.");
         snapshot.write_file(p).unwrap();
+        Ok(())
+    }
+}
+
+/// Returns true if the ledger entry of the key is archived when it expires,
+/// rather than deleted.
+#[cfg(any(test, feature = "testutils"))]
+fn is_persistent(key: &xdr::LedgerKey) -> bool {
+    match key {
+        xdr::LedgerKey::ContractData(k) => k.durability == xdr::ContractDataDurability::Persistent,
+        xdr::LedgerKey::ContractCode(_) => true,
+        _ => false,
+    }
+}
+
+/// Returns true if test snapshots are checked against the files on disk rather
+/// than written, which is the case when the `SOROBAN_CHECK_SNAPSHOTS`
+/// environment variable is set to `1`, unless the `SOROBAN_UPDATE_SNAPSHOTS`
+/// environment variable is also set to `1`.
+#[cfg(any(test, feature = "testutils"))]
+fn check_test_snapshots() -> bool {
+    check_test_snapshots_with(|name| std::env::var_os(name))
+}
+
+/// Returns true if test snapshots are checked, with the environment variables
+/// looked up with the function.
+#[cfg(any(test, feature = "testutils"))]
+pub(crate) fn check_test_snapshots_with(var: impl Fn(&str) -> Option<std::ffi::OsString>) -> bool {
+    let is_set = |name| var(name).is_some_and(|v| v == "1");
+    is_set("SOROBAN_CHECK_SNAPSHOTS") && !is_set("SOROBAN_UPDATE_SNAPSHOTS")
+}
+
+/// Returns true if the snapshot has no data in it, in which case no test
+/// snapshot file is written for it.
+#[cfg(any(test, feature = "testutils"))]
+fn is_empty_snapshot(snapshot: &Snapshot) -> bool {
+    snapshot.ledger.entries().into_iter().count() == 0
+        && snapshot.events.0.is_empty()
+        && snapshot.auth.0.is_empty()
+}
+
+/// Compares the snapshot to the test snapshot file at the path, returning an
+/// error describing the differences if they differ.
+///
+/// An empty snapshot is expected to have no test snapshot file, since none is
+/// written for it.
+#[cfg(any(test, feature = "testutils"))]
+pub(crate) fn check_test_snapshot_file(
+    snapshot: &Snapshot,
+    p: &std::path::Path,
+) -> Result<(), std::string::String> {
+    if is_empty_snapshot(snapshot) {
+        if p.exists() {
+            return Err(format!(
+                "test snapshot is empty, but the test snapshot file {p:?} exists, delete it"
+            ));
+        }
+        return Ok(());
+    }
+    let update = "run the test with SOROBAN_UPDATE_SNAPSHOTS=1 to update it";
+    let existing = match Snapshot::read_file(p) {
+        Ok(existing) => existing,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(format!("test snapshot file {p:?} does not exist, {update}"));
+        }
+        Err(e) => {
+            return Err(format!(
+                "test snapshot file {p:?} could not be read: {e}, {update}"
+            ));
+        }
+    };
+    let diff = existing.diff(snapshot);
+    if diff.is_empty() {
+        return Ok(());
     }
+    Err(format!(
+        "test snapshot differs from the test snapshot file {p:?}, {update}\n\n{diff}"
+    ))
 }
 
 #[doc(hidden)]
@@ -138,9 +138,7 @@ impl testutils::Events for Events {
         let env = self.env();
         let vec: std::vec::Vec<xdr::ContractEvent> = self
             .env()
-            .host()
-            .get_events()
-            .unwrap()
+            .host_events()
             .0
             .into_iter()
             .filter_map(|e| {
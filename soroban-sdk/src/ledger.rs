@@ -181,4 +181,22 @@ impl testutils::Ledger for Ledger {
         let env = self.env();
         env.host().with_mut_ledger_info(f).unwrap();
     }
+
+    fn advance(&self, ledgers: u32, seconds_per_ledger: u64) {
+        self.with_mut(|li| {
+            li.sequence_number = li
+                .sequence_number
+                .checked_add(ledgers)
+                .expect("ledger sequence number overflowed");
+            li.timestamp = u64::from(ledgers)
+                .checked_mul(seconds_per_ledger)
+                .and_then(|seconds| li.timestamp.checked_add(seconds))
+                .expect("ledger timestamp overflowed");
+        });
+        self.env().delete_expired_temporary_entries();
+    }
+
+    fn restore(&self, key: &crate::xdr::LedgerKey) {
+        self.env().restore_archived_entry(key);
+    }
 }
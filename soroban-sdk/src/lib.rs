@@ -658,6 +658,12 @@ pub use soroban_sdk_macros::contractmeta;
 /// Includes the type in the contract spec so that clients can generate bindings
 /// for the type.
 ///
+/// When the `testutils` feature is enabled, also derives a prototype for
+/// fuzzing and property testing the type, see `testutils::arbitrary`. The
+/// values generated for fields can be constrained with the
+/// `#[arbitrary(range = ...)]`, `#[arbitrary(len = ...)]` and
+/// `#[arbitrary(with = ...)]` attributes.
+///
 /// ### Examples
 ///
 /// Defining a contract type that is a struct and use it in a contract.
@@ -996,12 +1002,19 @@ pub use soroban_sdk_macros::contracttype;
 /// # }
 /// # #[cfg(feature = "testutils")]
 /// # fn main() {
+///     use soroban_sdk::testutils::Events as _;
+///
 ///     let env = Env::default();
 ///     let contract_id = env.register(Contract, ());
 ///     let client = ContractClient::new(&env, &contract_id);
 ///
 ///     assert_eq!(client.increment(&1), 1);
 ///     assert_eq!(client.increment(&10), 11);
+///     // Decode the events published by the last invocation.
+///     assert_eq!(
+///         env.events().all().decoded::<Increment>(),
+///         [Increment { change: 10, count: 11 }],
+///     );
 ///     assert_eq!(
 ///         client.get_state(),
 ///         State {
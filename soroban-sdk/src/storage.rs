@@ -199,7 +199,10 @@ impl Storage {
         V: IntoVal<Env, Val>,
     {
         let env = &self.env;
-        internal::Env::put_contract_data(env, key.into_val(env), val.into_val(env), storage_type)
+        let key = key.into_val(env);
+        #[cfg(any(test, feature = "testutils"))]
+        crate::testutils::trace::record_storage_access(storage_type, key, true);
+        internal::Env::put_contract_data(env, key, val.into_val(env), storage_type)
             .unwrap_infallible();
     }
 
@@ -314,16 +317,23 @@ impl Storage {
         K: IntoVal<Env, Val>,
     {
         let env = &self.env;
-        internal::Env::del_contract_data(env, key.into_val(env), storage_type).unwrap_infallible();
+        let key = key.into_val(env);
+        #[cfg(any(test, feature = "testutils"))]
+        crate::testutils::trace::record_storage_access(storage_type, key, true);
+        internal::Env::del_contract_data(env, key, storage_type).unwrap_infallible();
     }
 
     fn has_internal(&self, key: Val, storage_type: StorageType) -> bool {
+        #[cfg(any(test, feature = "testutils"))]
+        crate::testutils::trace::record_storage_access(storage_type, key, false);
         internal::Env::has_contract_data(&self.env, key, storage_type)
             .unwrap_infallible()
             .into()
     }
 
     fn get_internal(&self, key: Val, storage_type: StorageType) -> Val {
+        #[cfg(any(test, feature = "testutils"))]
+        crate::testutils::trace::record_storage_access(storage_type, key, false);
         internal::Env::get_contract_data(&self.env, key, storage_type).unwrap_infallible()
     }
 }
@@ -3,6 +3,9 @@
 mod address;
 mod address_payload;
 mod auth;
+mod auth_expected;
+mod auth_keypair;
+mod auth_replay;
 mod bytes_alloc_vec;
 mod bytes_buffer;
 mod bytes_slice;
@@ -14,6 +17,7 @@ mod contract_custom_account_impl;
 mod contract_docs;
 mod contract_duration;
 mod contract_event;
+mod contract_event_decode;
 mod contract_executable_ref;
 mod contract_fn;
 mod contract_invoke;
@@ -23,6 +27,7 @@ mod contract_overlapping_type_fn_names;
 mod contract_snapshot;
 mod contract_store;
 mod contract_timepoint;
+mod contract_udt_arbitrary_attrs;
 mod contract_udt_enum;
 mod contract_udt_enum_error;
 mod contract_udt_enum_int;
@@ -36,6 +41,9 @@ mod contractimpl_trait_call_resolution;
 mod contractimport;
 mod contractimport_with_error;
 mod cost_estimate;
+mod cost_footprint;
+mod cost_profile;
+mod coverage;
 mod crypto_bls12_381;
 mod crypto_bn254;
 mod crypto_ed25519;
@@ -45,18 +53,34 @@ mod crypto_secp256k1;
 mod crypto_secp256r1;
 mod crypto_sha256;
 mod delegate_auth;
+mod differential;
 mod env;
+mod env_checkpoint;
+mod env_event_journal;
+mod env_invariant;
+mod env_seed;
 mod env_test_state_in_contract;
+mod env_trace;
 mod env_upload;
+mod fn_contract;
+mod fuzz_spec;
+mod ledger_advance;
 mod max_ttl;
+mod mock_contract;
 mod muxed_address;
+mod network_config;
 mod num_checked_arith;
 mod prng;
 mod prng_range;
 mod proptest_scval_cmp;
+mod proptest_strategy;
 mod proptest_val_cmp;
 mod register_at_stellar_asset_contract;
+mod snapshot_check;
+mod snapshot_diff;
 mod snapshot_source_native_wasm_hash;
+mod state_machine;
 mod storage_testutils;
 mod token_client;
+mod transaction;
 mod vec_slice;
@@ -0,0 +1,246 @@
+use crate as soroban_sdk;
+use expect_test::expect;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, EnvTestConfig, ExpectedAuths, ExpectedInvocation},
+    Address, Env, IntoVal,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn hello(_env: Env, from: Address, _n: u32) {
+        from.require_auth();
+    }
+
+    pub fn both(_env: Env, x: Address, y: Address) {
+        x.require_auth();
+        y.require_auth();
+    }
+
+    pub fn forward_n(env: Env, from: Address, other: Address, ns: soroban_sdk::Vec<u32>) {
+        from.require_auth();
+        for n in ns.iter() {
+            ContractClient::new(&env, &other).hello(&from, &n);
+        }
+    }
+
+    pub fn call_n(env: Env, from: Address, other: Address, ns: soroban_sdk::Vec<u32>) {
+        for n in ns.iter() {
+            ContractClient::new(&env, &other).hello(&from, &n);
+        }
+    }
+
+    pub fn forward(env: Env, from: Address, others: soroban_sdk::Vec<Address>) {
+        from.require_auth();
+        for other in others.iter() {
+            ContractClient::new(&env, &other).hello(&from, &7);
+        }
+    }
+}
+
+struct Setup {
+    env: Env,
+    a: Address,
+    b: Address,
+    c: Address,
+    user: Address,
+}
+
+fn setup() -> Setup {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+    Setup {
+        a: env.register(Contract, ()),
+        b: env.register(Contract, ()),
+        c: env.register(Contract, ()),
+        user: Address::generate(&env),
+        env,
+    }
+}
+
+#[test]
+fn test_matches_exact_tree() {
+    let Setup { env, a, b, c, user } = setup();
+    ContractClient::new(&env, &a).forward(&user, &soroban_sdk::vec![&env, b.clone(), c.clone()]);
+
+    ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&a, "forward")
+                .args((&user, soroban_sdk::vec![&env, b.clone(), c.clone()]).into_val(&env))
+                .sub_invocation(
+                    ExpectedInvocation::contract(&b, "hello").args((&user, 7u32).into_val(&env)),
+                )
+                .sub_invocation(
+                    ExpectedInvocation::contract(&c, "hello").args((&user, 7u32).into_val(&env)),
+                ),
+        )
+        .assert_matches(&env.auths());
+}
+
+#[test]
+fn test_matches_ignoring_args_and_extra_sub_invocations() {
+    let Setup { env, a, b, c, user } = setup();
+    ContractClient::new(&env, &a).forward(&user, &soroban_sdk::vec![&env, b.clone(), c.clone()]);
+
+    ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&a, "forward")
+                .sub_invocation(ExpectedInvocation::contract(&c, "hello"))
+                .allow_extra_sub_invocations(),
+        )
+        .assert_matches(&env.auths());
+
+    // Without allowing extra sub-invocations the tree does not match.
+    assert!(ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&a, "forward")
+                .sub_invocation(ExpectedInvocation::contract(&c, "hello")),
+        )
+        .matches(&env.auths())
+        .is_err());
+}
+
+#[test]
+fn test_allow_extra_auths() {
+    let Setup { env, a, user, .. } = setup();
+    let other = Address::generate(&env);
+    ContractClient::new(&env, &a).both(&other, &user);
+
+    let auths = env.auths();
+    assert!(ExpectedAuths::new()
+        .auth(&user, ExpectedInvocation::contract(&a, "both"))
+        .matches(&auths)
+        .is_err());
+    ExpectedAuths::new()
+        .auth(&user, ExpectedInvocation::contract(&a, "both"))
+        .allow_extra_auths()
+        .assert_matches(&auths);
+    ExpectedAuths::new()
+        .auth(&other, ExpectedInvocation::contract(&a, "both"))
+        .auth(&user, ExpectedInvocation::contract(&a, "both"))
+        .assert_matches(&auths);
+}
+
+#[test]
+fn test_mismatch_diff() {
+    let Setup { env, a, b, c, user } = setup();
+    ContractClient::new(&env, &a).forward(&user, &soroban_sdk::vec![&env, b.clone(), c.clone()]);
+
+    let err = ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&a, "forward")
+                .sub_invocation(
+                    ExpectedInvocation::contract(&b, "hello").args((&user, 8u32).into_val(&env)),
+                )
+                .sub_invocation(ExpectedInvocation::contract(&a, "hello")),
+        )
+        .matches(&env.auths())
+        .unwrap_err();
+
+    expect![[r#"
+          CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4
+              CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM.forward(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4, [CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4, CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M])
+        -         CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4.hello(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4, 8u32)
+        +         CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4.hello(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4, 7u32)
+        -         CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM.hello(..)
+        +         CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M.hello(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4, 7u32)
+    "#]]
+    .assert_eq(&err.to_string());
+}
+
+#[test]
+#[should_panic(expected = "recorded auths do not match the expected auths")]
+fn test_assert_matches_panics() {
+    let Setup { env, a, user, .. } = setup();
+    ContractClient::new(&env, &a).hello(&user, &1);
+
+    ExpectedAuths::new()
+        .auth(&user, ExpectedInvocation::contract(&a, "forward"))
+        .assert_matches(&env.auths());
+}
+
+#[test]
+fn test_matches_same_function_by_args() {
+    let Setup {
+        env, a, b, user, ..
+    } = setup();
+    ContractClient::new(&env, &a).forward_n(&user, &b, &soroban_sdk::vec![&env, 1, 2]);
+
+    // The expected invocation is matched with the recorded invocation with the
+    // same arguments, rather than the first invocation of the function.
+    ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&a, "forward_n")
+                .sub_invocation(
+                    ExpectedInvocation::contract(&b, "hello").args((&user, 2u32).into_val(&env)),
+                )
+                .allow_extra_sub_invocations(),
+        )
+        .assert_matches(&env.auths());
+    ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&a, "forward_n")
+                .sub_invocation(
+                    ExpectedInvocation::contract(&b, "hello").args((&user, 1u32).into_val(&env)),
+                )
+                .sub_invocation(
+                    ExpectedInvocation::contract(&b, "hello").args((&user, 2u32).into_val(&env)),
+                ),
+        )
+        .assert_matches(&env.auths());
+}
+
+#[test]
+fn test_matches_same_function_auths_by_args() {
+    let Setup {
+        env, a, b, user, ..
+    } = setup();
+    // Each call to `hello` is authorized on its own, because `call_n` does not
+    // require the auth of the user.
+    env.mock_all_auths_allowing_non_root_auth();
+    ContractClient::new(&env, &a).call_n(&user, &b, &soroban_sdk::vec![&env, 1, 2]);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2);
+    ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&b, "hello").args((&user, 2u32).into_val(&env)),
+        )
+        .allow_extra_auths()
+        .assert_matches(&auths);
+    ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&b, "hello").args((&user, 1u32).into_val(&env)),
+        )
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&b, "hello").args((&user, 2u32).into_val(&env)),
+        )
+        .assert_matches(&auths);
+    // The second expected auth has no recorded auth left to match.
+    assert!(ExpectedAuths::new()
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&b, "hello").args((&user, 2u32).into_val(&env)),
+        )
+        .auth(
+            &user,
+            ExpectedInvocation::contract(&b, "hello").args((&user, 1u32).into_val(&env)),
+        )
+        .allow_extra_auths()
+        .matches(&auths)
+        .is_err());
+}
@@ -0,0 +1,245 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{
+        Address as _, AuthorizedFunction, AuthorizedInvocation, EnvTestConfig, Keypair,
+        Ledger as _, MockAuthInvoke,
+    },
+    xdr, Address, Env, IntoVal, Symbol,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn hello(_env: Env, from: Address) {
+        from.require_auth();
+    }
+
+    pub fn forward(env: Env, from: Address, other: Address) {
+        from.require_auth();
+        ContractClient::new(&env, &other).hello(&from);
+    }
+}
+
+fn new_env() -> Env {
+    Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    })
+}
+
+#[test]
+fn test_generate_adds_account_to_ledger() {
+    let env = new_env();
+    let keypair = Keypair::generate(&env);
+
+    let address = keypair.address();
+    assert!(address.to_string().to_string().starts_with('G'));
+    assert_eq!(
+        xdr::ScAddress::from(&address),
+        xdr::ScAddress::Account(xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(
+            xdr::Uint256(keypair.public_key())
+        )))
+    );
+    let key = xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
+        account_id: xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
+            keypair.public_key(),
+        ))),
+    });
+    assert!(env
+        .to_ledger_snapshot()
+        .entries()
+        .into_iter()
+        .any(|(k, _)| **k == key));
+    assert_ne!(Keypair::generate(&env).public_key(), keypair.public_key());
+}
+
+#[test]
+fn test_generate_secret_key_is_not_a_generated_address() {
+    let env = new_env();
+    let keypair = Keypair::generate(&env);
+    let after = Address::generate(&env);
+
+    // Generating the keypair does not use the generated addresses, so its
+    // secret key is not the address generated before or after it.
+    let other = new_env();
+    assert_eq!(after, Address::generate(&other));
+    let secret = keypair.signing_key().to_bytes();
+    for address in [&after, &Address::generate(&other)] {
+        let xdr::ScAddress::Contract(xdr::ContractId(xdr::Hash(id))) = address.into() else {
+            panic!("generated address is a contract address");
+        };
+        assert_ne!(secret, id);
+    }
+}
+
+#[test]
+fn test_sign_auths() {
+    let env = new_env();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let keypair = Keypair::generate(&env);
+    let addr = keypair.address();
+
+    env.sign_auths(&[(
+        &keypair,
+        &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "hello",
+            args: (&addr,).into_val(&env),
+            sub_invokes: &[],
+        },
+    )]);
+    client.hello(&addr);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            addr.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_id.clone(),
+                    Symbol::new(&env, "hello"),
+                    (&addr,).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+#[test]
+fn test_sign_auths_with_sub_invocations() {
+    let env = new_env();
+    let contract_a = env.register(Contract, ());
+    let contract_b = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_a);
+    let keypair = Keypair::generate(&env);
+    let addr = keypair.address();
+
+    env.sign_auths(&[(
+        &keypair,
+        &MockAuthInvoke {
+            contract: &contract_a,
+            fn_name: "forward",
+            args: (&addr, &contract_b).into_val(&env),
+            sub_invokes: &[MockAuthInvoke {
+                contract: &contract_b,
+                fn_name: "hello",
+                args: (&addr,).into_val(&env),
+                sub_invokes: &[],
+            }],
+        },
+    )]);
+    client.forward(&addr, &contract_b);
+}
+
+#[test]
+fn test_sign_auths_for_different_args_fails() {
+    let env = new_env();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let keypair = Keypair::generate(&env);
+    let other = Keypair::generate(&env);
+
+    env.sign_auths(&[(
+        &keypair,
+        &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "hello",
+            args: (&other.address(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    )]);
+    assert!(client.try_hello(&keypair.address()).is_err());
+}
+
+#[test]
+fn test_invalid_signature_fails() {
+    let env = new_env();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let keypair = Keypair::generate(&env);
+    let addr = keypair.address();
+
+    let mut entry = keypair.sign_auth(&MockAuthInvoke {
+        contract: &contract_id,
+        fn_name: "hello",
+        args: (&addr,).into_val(&env),
+        sub_invokes: &[],
+    });
+    // Sign for a different nonce than the nonce in the entry.
+    let xdr::SorobanCredentials::Address(credentials) = &mut entry.credentials else {
+        unreachable!()
+    };
+    credentials.nonce += 1;
+
+    env.set_auths(&[entry]);
+    assert!(client.try_hello(&addr).is_err());
+}
+
+#[test]
+fn test_sign_auth_for_different_network_fails() {
+    let env = new_env();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let keypair = Keypair::generate(&env);
+    let addr = keypair.address();
+
+    let entry = keypair.sign_auth(&MockAuthInvoke {
+        contract: &contract_id,
+        fn_name: "hello",
+        args: (&addr,).into_val(&env),
+        sub_invokes: &[],
+    });
+    env.ledger().set_network_id([1; 32]);
+
+    env.set_auths(&[entry]);
+    assert!(client.try_hello(&addr).is_err());
+}
+
+#[test]
+fn test_signed_auth_is_not_replayable() {
+    let env = new_env();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let keypair = Keypair::generate(&env);
+    let addr = keypair.address();
+
+    let entry = keypair.sign_auth(&MockAuthInvoke {
+        contract: &contract_id,
+        fn_name: "hello",
+        args: (&addr,).into_val(&env),
+        sub_invokes: &[],
+    });
+
+    env.set_auths(core::slice::from_ref(&entry));
+    client.hello(&addr);
+    env.set_auths(&[entry]);
+    assert!(client.try_hello(&addr).is_err());
+}
+
+#[test]
+fn test_sign_auth_with_expired_signature_fails() {
+    let env = new_env();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let keypair = Keypair::generate(&env);
+    let addr = keypair.address();
+
+    let sequence = env.ledger().sequence();
+    let entry = keypair.sign_auth_with_expiration(
+        &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "hello",
+            args: (&addr,).into_val(&env),
+            sub_invokes: &[],
+        },
+        sequence,
+    );
+    env.ledger().set_sequence_number(sequence + 1);
+
+    env.set_auths(&[entry]);
+    assert!(client.try_hello(&addr).is_err());
+}
@@ -0,0 +1,113 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, AuthorizedInvocation, EnvTestConfig, Keypair},
+    xdr, Address, Env,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn hello(_env: Env, from: Address) {
+        from.require_auth();
+    }
+
+    pub fn forward(env: Env, from: Address, other: Address) {
+        from.require_auth();
+        ContractClient::new(&env, &other).hello(&from);
+    }
+}
+
+fn new_env() -> Env {
+    Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    })
+}
+
+#[test]
+fn test_recorded_invocation_converts_to_xdr_and_back() {
+    let env = new_env();
+    env.mock_all_auths();
+    let a = env.register(Contract, ());
+    let b = env.register(Contract, ());
+    let user = Address::generate(&env);
+    ContractClient::new(&env, &a).forward(&user, &b);
+
+    let (_, invocation) = env.auths().pop().unwrap();
+    let xdr_invocation: xdr::SorobanAuthorizedInvocation = (&invocation).into();
+    assert_eq!(xdr_invocation.sub_invocations.len(), 1);
+    assert_eq!(
+        AuthorizedInvocation::from_xdr(&env, &xdr_invocation),
+        invocation
+    );
+}
+
+#[test]
+fn test_mock_recorded_auths_replays_flow() {
+    let env = new_env();
+    let a = env.register(Contract, ());
+    let b = env.register(Contract, ());
+    let user = Address::generate(&env);
+    let client = ContractClient::new(&env, &a);
+
+    env.mock_all_auths();
+    client.forward(&user, &b);
+    let recorded = env.auths();
+
+    env.mock_recorded_auths(&recorded);
+    client.forward(&user, &b);
+    assert_eq!(env.auths(), recorded);
+}
+
+#[test]
+fn test_mock_recorded_auths_rejects_other_auths() {
+    let env = new_env();
+    let a = env.register(Contract, ());
+    let b = env.register(Contract, ());
+    let user = Address::generate(&env);
+    let client = ContractClient::new(&env, &a);
+
+    env.mock_all_auths();
+    client.hello(&user);
+    let recorded = env.auths();
+
+    env.mock_recorded_auths(&recorded);
+    assert!(client.try_forward(&user, &b).is_err());
+}
+
+#[test]
+fn test_recorded_auths_signed_by_keypair() {
+    let env = new_env();
+    let a = env.register(Contract, ());
+    let b = env.register(Contract, ());
+    let keypair = Keypair::generate(&env);
+    let user = keypair.address();
+    let client = ContractClient::new(&env, &a);
+
+    env.mock_all_auths();
+    client.forward(&user, &b);
+    let recorded = env.auths();
+
+    let entries = recorded
+        .iter()
+        .map(|(_, invocation)| keypair.sign_auth(invocation))
+        .collect::<std::vec::Vec<_>>();
+    env.set_auths(&entries);
+    client.forward(&user, &b);
+    assert_eq!(env.auths(), recorded);
+}
+
+#[test]
+#[should_panic(expected = "cannot be mocked, sign it with Keypair::sign_auth instead")]
+fn test_mock_recorded_auths_of_account_panics() {
+    let env = new_env();
+    let a = env.register(Contract, ());
+    let keypair = Keypair::generate(&env);
+    let client = ContractClient::new(&env, &a);
+
+    env.mock_all_auths();
+    client.hello(&keypair.address());
+    env.mock_recorded_auths(&env.auths());
+}
@@ -0,0 +1,251 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractevent, contractimpl, contracttype, symbol_short,
+    testutils::{Address as _, Event as _, Events as _},
+    vec, xdr, Address, Env, Event, String, Symbol, Vec,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Detail {
+    pub a: u32,
+    pub b: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+    pub memo: Option<u64>,
+}
+
+#[contractevent(topics = ["custom", "prefix"], data_format = "vec")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VecData {
+    #[topic]
+    pub kind: Symbol,
+    pub x: u32,
+    pub detail: Detail,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Single {
+    pub values: Vec<u32>,
+}
+
+#[contractevent(data_format = "map", sparse = false)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotSparse {
+    pub a: Option<u32>,
+    pub b: u32,
+}
+
+#[contractevent(topics = [])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Empty {}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn publish(env: Env, from: Address, to: Address) {
+        Transfer {
+            from: from.clone(),
+            to: to.clone(),
+            amount: 100,
+            memo: None,
+        }
+        .publish(&env);
+        VecData {
+            kind: symbol_short!("k"),
+            x: 1,
+            detail: Detail {
+                a: 2,
+                b: String::from_str(&env, "b"),
+            },
+        }
+        .publish(&env);
+        Transfer {
+            from: to,
+            to: from,
+            amount: 5,
+            memo: Some(7),
+        }
+        .publish(&env);
+    }
+}
+
+#[test]
+fn test_decode_roundtrip() {
+    let env = Env::default();
+    let id = Address::generate(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    let transfer = Transfer {
+        from: from.clone(),
+        to: to.clone(),
+        amount: 10,
+        memo: None,
+    };
+    assert_eq!(
+        Transfer::decode(&env, &transfer.to_xdr(&env, &id)),
+        Ok(transfer)
+    );
+
+    let transfer = Transfer {
+        from,
+        to,
+        amount: 10,
+        memo: Some(3),
+    };
+    assert_eq!(
+        Transfer::decode(&env, &transfer.to_xdr(&env, &id)),
+        Ok(transfer)
+    );
+
+    let vec_data = VecData {
+        kind: symbol_short!("k"),
+        x: 1,
+        detail: Detail {
+            a: 2,
+            b: String::from_str(&env, "b"),
+        },
+    };
+    assert_eq!(
+        VecData::decode(&env, &vec_data.to_xdr(&env, &id)),
+        Ok(vec_data)
+    );
+
+    let single = Single {
+        values: vec![&env, 1, 2, 3],
+    };
+    assert_eq!(Single::decode(&env, &single.to_xdr(&env, &id)), Ok(single));
+
+    let not_sparse = NotSparse { a: None, b: 1 };
+    assert_eq!(
+        NotSparse::decode(&env, &not_sparse.to_xdr(&env, &id)),
+        Ok(not_sparse)
+    );
+
+    assert_eq!(
+        Empty::decode(&env, &Empty {}.to_xdr(&env, &id)),
+        Ok(Empty {})
+    );
+}
+
+#[test]
+fn test_decode_other_event_fails() {
+    let env = Env::default();
+    let id = Address::generate(&env);
+
+    let transfer = Transfer {
+        from: Address::generate(&env),
+        to: Address::generate(&env),
+        amount: 10,
+        memo: None,
+    }
+    .to_xdr(&env, &id);
+    assert_eq!(VecData::decode(&env, &transfer), Err(xdr::Error::Invalid));
+    assert_eq!(Single::decode(&env, &transfer), Err(xdr::Error::Invalid));
+    assert_eq!(Empty::decode(&env, &transfer), Err(xdr::Error::Invalid));
+
+    // Same topics, but data that has a field of the wrong type.
+    let mut wrong_data = transfer.clone();
+    let xdr::ContractEventBody::V0(body) = &mut wrong_data.body;
+    body.data = xdr::ScVal::Map(Some(
+        std::vec![xdr::ScMapEntry {
+            key: xdr::ScVal::Symbol("amount".try_into().unwrap()),
+            val: xdr::ScVal::Bool(true),
+        }]
+        .try_into()
+        .unwrap(),
+    ));
+    assert_eq!(
+        Transfer::decode(&env, &wrong_data),
+        Err(xdr::Error::Invalid)
+    );
+
+    // Same topics, but data that has an unknown field.
+    let mut extra_field = transfer.clone();
+    let xdr::ContractEventBody::V0(body) = &mut extra_field.body;
+    let xdr::ScVal::Map(Some(map)) = &mut body.data else {
+        panic!("data is not a map");
+    };
+    let mut entries = map.to_vec();
+    entries.push(xdr::ScMapEntry {
+        key: xdr::ScVal::Symbol("zzz".try_into().unwrap()),
+        val: xdr::ScVal::U32(1),
+    });
+    *map = entries.try_into().unwrap();
+    assert_eq!(
+        Transfer::decode(&env, &extra_field),
+        Err(xdr::Error::Invalid)
+    );
+
+    // Diagnostic events are not decoded.
+    let mut diagnostic = transfer;
+    diagnostic.type_ = xdr::ContractEventType::Diagnostic;
+    assert_eq!(
+        Transfer::decode(&env, &diagnostic),
+        Err(xdr::Error::Invalid)
+    );
+}
+
+#[test]
+fn test_contract_events_decoded_and_contains() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &id);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    client.publish(&from, &to);
+
+    let events = env.events().all();
+    assert_eq!(
+        events.decoded::<Transfer>(),
+        [
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                amount: 100,
+                memo: None,
+            },
+            Transfer {
+                from: to.clone(),
+                to: from.clone(),
+                amount: 5,
+                memo: Some(7),
+            },
+        ]
+    );
+    assert_eq!(events.decoded::<VecData>().len(), 1);
+    assert_eq!(events.decoded::<Single>(), []);
+    assert_eq!(
+        events
+            .filter_by_contract(&Address::generate(&env))
+            .decoded::<Transfer>(),
+        []
+    );
+
+    assert!(events.contains(&Transfer {
+        from: to.clone(),
+        to: from.clone(),
+        amount: 5,
+        memo: Some(7),
+    }));
+    assert!(!events.contains(&Transfer {
+        from: to,
+        to: from,
+        amount: 5,
+        memo: None,
+    }));
+    assert!(!events.contains(&Empty {}));
+}
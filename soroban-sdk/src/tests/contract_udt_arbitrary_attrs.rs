@@ -0,0 +1,114 @@
+//! Check that the `#[arbitrary(...)]` attributes on the fields of contract
+//! types constrain the values that the derived prototypes generate.
+
+use crate as soroban_sdk;
+use crate::testutils::arbitrary::arbitrary::{self, Arbitrary, Unstructured};
+use crate::testutils::arbitrary::SorobanArbitrary;
+use crate::testutils::proptest::prototype;
+use crate::{contracttype, Address, Bytes, Env, IntoVal, Map, String, Symbol, Vec};
+use proptest::test_runner::{Config, TestRunner};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deposit {
+    pub from: Address,
+    #[arbitrary(range = 1..=1_000_000)]
+    pub amount: i128,
+    #[arbitrary(len = 1..=3)]
+    pub memos: Vec<u32>,
+    #[arbitrary(len = 2..=2)]
+    pub tags: Map<Symbol, u32>,
+    #[arbitrary(len = 4..=8)]
+    pub data: Bytes,
+    #[arbitrary(len = 0..=5)]
+    pub note: String,
+    #[arbitrary(len = 1..=100)]
+    pub name: Symbol,
+    #[arbitrary(with = even)]
+    pub ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pair(
+    #[arbitrary(range = 10..=20)] pub u64,
+    #[arbitrary(with = |u: &mut Unstructured| Ok(u.int_in_range(0..=1)? - 5))] pub i32,
+);
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Action {
+    None,
+    Pay(#[arbitrary(range = 1..=9)] u32),
+}
+
+fn even(u: &mut Unstructured) -> arbitrary::Result<u32> {
+    Ok(u.int_in_range(0..=u32::MAX / 2)? * 2)
+}
+
+fn check_deposit(d: &Deposit) {
+    assert!((1..=1_000_000).contains(&d.amount));
+    assert!((1..=3).contains(&d.memos.len()));
+    assert!(d.tags.len() <= 2);
+    assert!((4..=8).contains(&d.data.len()));
+    assert!(d.note.len() <= 5 * 4);
+    assert!(d.name.to_string().len() <= 32);
+    assert_eq!(d.ledger % 2, 0);
+}
+
+fn check_pair(p: &Pair) {
+    assert!((10..=20).contains(&p.0));
+    assert!((-5..=-4).contains(&p.1));
+}
+
+fn check_action(a: &Action) {
+    if let Action::Pay(n) = a {
+        assert!((1..=9).contains(n));
+    }
+}
+
+fn generate<T: SorobanArbitrary>(env: &Env, seed: u64) -> T {
+    let mut data = [0u8; 1024];
+    StdRng::seed_from_u64(seed).fill_bytes(&mut data);
+    let proto = T::Prototype::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    proto.into_val(env)
+}
+
+#[test]
+fn test_arbitrary_honours_field_attrs() {
+    let env = Env::default();
+    for seed in 0..100 {
+        check_deposit(&generate::<Deposit>(&env, seed));
+        check_pair(&generate::<Pair>(&env, seed));
+        check_action(&generate::<Action>(&env, seed));
+    }
+}
+
+#[test]
+fn test_strategy_honours_field_attrs() {
+    let env = Env::default();
+    let mut runner = TestRunner::new(Config {
+        cases: 100,
+        failure_persistence: None,
+        ..Config::default()
+    });
+    runner
+        .run(&prototype::<Deposit>(), |proto| {
+            check_deposit(&proto.into_val(&env));
+            Ok(())
+        })
+        .unwrap();
+    runner
+        .run(&prototype::<Pair>(), |proto| {
+            check_pair(&proto.into_val(&env));
+            Ok(())
+        })
+        .unwrap();
+    runner
+        .run(&prototype::<Action>(), |proto| {
+            check_action(&proto.into_val(&env));
+            Ok(())
+        })
+        .unwrap();
+}
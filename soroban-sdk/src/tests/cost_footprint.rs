@@ -0,0 +1,186 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{cost_estimate::FootprintEntry, EnvTestConfig, Ledger as _},
+    xdr::{
+        ContractDataDurability, Hash, LedgerKey, LedgerKeyContractCode, LedgerKeyContractData,
+        ScAddress, ScVal,
+    },
+    Address, Env, Symbol,
+};
+
+mod contract_data {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(file = "test_wasms/test_contract_data.wasm");
+}
+
+#[contract]
+pub struct Counter;
+
+const COUNT: Symbol = symbol_short!("count");
+
+#[contractimpl]
+impl Counter {
+    pub fn incr(env: Env) -> u32 {
+        let count = Self::get(env.clone()) + 1;
+        env.storage().persistent().set(&COUNT, &count);
+        count
+    }
+
+    pub fn get(env: Env) -> u32 {
+        env.storage().persistent().get(&COUNT).unwrap_or(0)
+    }
+
+    pub fn extend(env: Env) {
+        env.storage().persistent().extend_ttl(&COUNT, 5000, 5000);
+    }
+}
+
+fn env() -> Env {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.cost_estimate().enable_footprint();
+    env
+}
+
+fn data_key(contract: &Address, key: ScVal) -> LedgerKey {
+    LedgerKey::ContractData(LedgerKeyContractData {
+        contract: ScAddress::from(contract),
+        key,
+        durability: ContractDataDurability::Persistent,
+    })
+}
+
+fn instance_key(contract: &Address) -> LedgerKey {
+    data_key(contract, ScVal::LedgerKeyContractInstance)
+}
+
+/// Returns the keys of the contract data entries, leaving out the code that
+/// natively registered contracts have a hash of, but no Wasm for.
+fn data_keys(entries: &[FootprintEntry]) -> std::vec::Vec<LedgerKey> {
+    entries
+        .iter()
+        .map(|e| e.key.clone())
+        .filter(|k| matches!(k, LedgerKey::ContractData(_)))
+        .collect()
+}
+
+#[test]
+fn test_footprint_wasm() {
+    let env = env();
+    let id = env.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&env, &id);
+    let code_key = LedgerKey::ContractCode(LedgerKeyContractCode {
+        hash: Hash(
+            env.deployer()
+                .upload_contract_wasm(contract_data::WASM)
+                .to_array(),
+        ),
+    });
+    let k1_key = data_key(&id, symbol_short!("k1").try_into().unwrap());
+
+    // Creating an entry writes it and pays rent for it.
+    client.put(&symbol_short!("k1"), &symbol_short!("v1"));
+    let footprint = env.cost_estimate().footprint();
+    assert_eq!(
+        footprint.read_only_keys(),
+        [instance_key(&id), code_key.clone()]
+    );
+    assert_eq!(footprint.read_write_keys(), std::vec![k1_key.clone()]);
+    let k1 = &footprint.read_write[0];
+    assert_eq!(k1.old_size_bytes, 0);
+    assert!(k1.new_size_bytes > 0);
+    assert_eq!(k1.old_live_until_ledger, None);
+    assert!(k1.new_live_until_ledger.is_some());
+    assert!(k1.rent_fee > 0);
+    assert_eq!(footprint.rent_fee(), k1.rent_fee);
+    assert_eq!(
+        footprint.rent_fee(),
+        env.cost_estimate().fee().persistent_entry_rent
+    );
+    // Reading the instance and code pays no rent.
+    assert!(footprint.read_only.iter().all(|e| e.rent_fee == 0));
+
+    // Deleting the entry writes it without paying rent.
+    client.del(&symbol_short!("k1"));
+    let footprint = env.cost_estimate().footprint();
+    assert_eq!(footprint.read_write_keys(), [k1_key]);
+    let k1 = &footprint.read_write[0];
+    assert!(k1.old_size_bytes > 0);
+    assert_eq!(k1.new_size_bytes, 0);
+    assert_eq!(k1.rent_fee, 0);
+}
+
+#[test]
+fn test_footprint_native() {
+    let env = env();
+    let id = env.register(Counter, ());
+    let client = CounterClient::new(&env, &id);
+    let count_key = data_key(&id, COUNT.into());
+
+    // Reading a missing entry puts it in the read-only footprint.
+    assert_eq!(client.get(), 0);
+    let footprint = env.cost_estimate().footprint();
+    assert_eq!(
+        data_keys(&footprint.read_only),
+        [count_key.clone(), instance_key(&id)]
+    );
+    assert!(footprint.read_write.is_empty());
+    assert_eq!(footprint.read_only[0].new_size_bytes, 0);
+    assert_eq!(footprint.rent_fee(), 0);
+
+    client.incr();
+    let footprint = env.cost_estimate().footprint();
+    assert_eq!(data_keys(&footprint.read_only), [instance_key(&id)]);
+    assert_eq!(footprint.read_write_keys(), std::vec![count_key.clone()]);
+    assert!(footprint.rent_fee() > 0);
+
+    // Updating an entry without growing it pays no rent.
+    client.incr();
+    let footprint = env.cost_estimate().footprint();
+    assert_eq!(footprint.read_write_keys(), std::vec![count_key.clone()]);
+    assert_eq!(footprint.rent_fee(), 0);
+
+    // Reading an entry that was already loaded is observed for native
+    // contracts.
+    assert_eq!(client.get(), 2);
+    let footprint = env.cost_estimate().footprint();
+    assert_eq!(
+        data_keys(&footprint.read_only),
+        [count_key.clone(), instance_key(&id)]
+    );
+
+    // Extending the TTL of an entry only reads it, and pays rent for the
+    // extension.
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 100);
+    client.extend();
+    let footprint = env.cost_estimate().footprint();
+    assert!(footprint.read_write.is_empty());
+    let count = &footprint.read_only[0];
+    assert_eq!(count.key, count_key);
+    assert_eq!(count.old_size_bytes, count.new_size_bytes);
+    assert!(count.new_live_until_ledger > count.old_live_until_ledger);
+    assert!(count.rent_fee > 0);
+}
+
+#[test]
+#[should_panic(expected = "Invocation footprint is not available")]
+fn test_footprint_unavailable_after_register() {
+    let env = env();
+    let id = env.register(Counter, ());
+    CounterClient::new(&env, &id).incr();
+    env.register(Counter, ());
+    env.cost_estimate().footprint();
+}
+
+#[test]
+#[should_panic(expected = "Invocation footprint is not available")]
+fn test_footprint_unavailable_when_not_enabled() {
+    let env = env();
+    env.cost_estimate().disable_footprint();
+    let id = env.register(Counter, ());
+    CounterClient::new(&env, &id).incr();
+    env.cost_estimate().footprint();
+}
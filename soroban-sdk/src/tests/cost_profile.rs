@@ -0,0 +1,234 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    xdr::{self, ContractCostType, ScSymbol},
+    Address, Env,
+};
+
+mod contract_data {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(file = "test_wasms/test_contract_data.wasm");
+}
+
+mod inner {
+    use crate as soroban_sdk;
+    use soroban_sdk::{contract, contractimpl, Env, Vec};
+
+    #[contract]
+    pub struct Inner;
+
+    #[contractimpl]
+    impl Inner {
+        pub fn store(env: Env, k: u32, v: u32) -> u32 {
+            env.storage().persistent().set(&k, &v);
+            v * 2
+        }
+
+        pub fn heavy(env: Env, n: u32) -> u32 {
+            let mut v = Vec::new(&env);
+            for i in 0..n {
+                v.push_back(i);
+            }
+            v.len()
+        }
+    }
+}
+
+#[contract]
+pub struct Outer;
+
+#[contractimpl]
+impl Outer {
+    pub fn call(env: Env, inner: Address, k: u32, v: u32) -> u32 {
+        let client = inner::InnerClient::new(&env, &inner);
+        client.store(&k, &v) + client.heavy(&100)
+    }
+
+    pub fn call_wasm(env: Env, data: Address) {
+        contract_data::Client::new(&env, &data).put(&symbol_short!("k"), &symbol_short!("v"));
+    }
+}
+
+fn sym(s: &str) -> ScSymbol {
+    s.try_into().unwrap()
+}
+
+#[test]
+fn test_profile_attributes_costs_to_frames() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    client.call(&inner_id, &1, &2);
+
+    let profile = e.cost_estimate().profile();
+    assert_eq!(profile.0.len(), 1);
+    let outer = &profile.0[0];
+    assert_eq!(outer.contract, xdr::ScAddress::from(&outer_id));
+    assert_eq!(outer.function, sym("call"));
+    assert_eq!(
+        outer.cpu_insns,
+        e.cost_estimate().resources().instructions as u64
+    );
+
+    let functions: std::vec::Vec<_> = outer.sub_frames.iter().map(|f| &f.function).collect();
+    assert_eq!(functions, [&sym("store"), &sym("heavy")]);
+    let store = &outer.sub_frames[0];
+    let heavy = &outer.sub_frames[1];
+    assert!(heavy.cpu_insns > store.cpu_insns);
+
+    // The self costs of the frames add up to the cost of the invocation.
+    assert_eq!(
+        outer.self_cpu_insns + store.self_cpu_insns + heavy.self_cpu_insns,
+        outer.cpu_insns
+    );
+    assert_eq!(
+        outer.self_mem_bytes + store.self_mem_bytes + heavy.self_mem_bytes,
+        outer.mem_bytes
+    );
+}
+
+#[test]
+fn test_profile_by_function() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    client.call(&inner_id, &1, &2);
+
+    let profile = e.cost_estimate().profile();
+    let functions = profile.by_function();
+    assert_eq!(functions.len(), 3);
+    assert!(functions
+        .windows(2)
+        .all(|w| w[0].self_cpu_insns >= w[1].self_cpu_insns));
+    for f in &functions {
+        assert_eq!(f.calls, 1);
+        let frame = profile
+            .frames()
+            .into_iter()
+            .find(|p| p.function == f.function)
+            .unwrap();
+        assert_eq!(f.self_cpu_insns, frame.self_cpu_insns);
+        assert_eq!(f.self_mem_bytes, frame.self_mem_bytes);
+    }
+}
+
+#[test]
+fn test_profile_of_wasm_frames() {
+    let e = Env::default();
+    let data_id = e.register(contract_data::WASM, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    client.call_wasm(&data_id);
+
+    let profile = e.cost_estimate().profile();
+    let outer = &profile.0[0];
+    assert_eq!(outer.sub_frames.len(), 1);
+    let put = &outer.sub_frames[0];
+    assert_eq!(put.function, sym("put"));
+    assert!(put.cpu_insns > 0);
+    assert_eq!(outer.self_cpu_insns + put.self_cpu_insns, outer.cpu_insns);
+
+    let put_stack = format!("{}.call_wasm;{}.put", outer.contract, put.contract);
+    let folded = profile.to_folded_cpu_insns();
+    let put_lines: std::vec::Vec<_> = folded
+        .lines()
+        .filter(|l| l.starts_with(&put_stack))
+        .collect();
+    assert_eq!(put_lines, [format!("{put_stack} {}", put.self_cpu_insns)]);
+}
+
+#[test]
+fn test_profile_to_folded() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    client.call(&inner_id, &1, &2);
+
+    let profile = e.cost_estimate().profile();
+    let outer = &profile.0[0];
+    let outer_name = format!("{}.call", outer.contract);
+    let heavy_name = format!("{outer_name};{}.heavy", outer.sub_frames[1].contract);
+
+    let folded = profile.to_folded_cpu_insns();
+    let mut total = 0;
+    for line in folded.lines() {
+        let (stack, value) = line.rsplit_once(' ').unwrap();
+        assert!(stack.starts_with(&outer_name));
+        total += value.parse::<u64>().unwrap();
+    }
+    assert_eq!(total, outer.cpu_insns);
+    assert!(folded
+        .lines()
+        .any(|l| l == format!("{heavy_name} {}", outer.sub_frames[1].self_cpu_insns)));
+
+    let folded = profile.to_folded_mem_bytes();
+    let total: u64 = folded
+        .lines()
+        .map(|l| l.rsplit_once(' ').unwrap().1.parse::<u64>().unwrap())
+        .sum();
+    assert_eq!(total, outer.mem_bytes);
+}
+
+#[test]
+fn test_profile_to_json() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    client.call(&inner_id, &1, &2);
+
+    let profile = e.cost_estimate().profile();
+    let json: serde_json::Value = serde_json::from_str(&profile.to_json()).unwrap();
+    let outer = &json["frames"][0];
+    assert_eq!(
+        outer["contract"],
+        xdr::ScAddress::from(&outer_id).to_string()
+    );
+    assert_eq!(outer["function"], "call");
+    assert_eq!(outer["cpu_insns"], profile.0[0].cpu_insns);
+    assert_eq!(outer["sub_frames"][1]["function"], "heavy");
+    assert_eq!(
+        outer["sub_frames"][1]["self_cpu_insns"],
+        profile.0[0].sub_frames[1].self_cpu_insns
+    );
+}
+
+#[test]
+fn test_budget_trackers() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    client.call(&inner_id, &1, &2);
+
+    let budget = e.cost_estimate().budget();
+    let trackers = budget.trackers();
+    assert_eq!(trackers.len(), ContractCostType::VARIANTS.len());
+    assert_eq!(
+        trackers.iter().map(|(_, t)| t.cpu).sum::<u64>(),
+        budget.cpu_instruction_cost()
+    );
+    assert_eq!(
+        trackers[ContractCostType::MemAlloc as usize],
+        (
+            ContractCostType::MemAlloc,
+            budget.tracker(ContractCostType::MemAlloc)
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invocation cost estimate is not available")]
+fn test_profile_without_invocation_panics() {
+    let e = Env::default();
+    e.cost_estimate().profile();
+}
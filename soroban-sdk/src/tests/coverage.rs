@@ -0,0 +1,188 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, panic_with_error, symbol_short,
+    testutils::{
+        coverage::{self, ContractCoverage, ErrorCoverage, FunctionCoverage},
+        Address as _, EnvTestConfig,
+    },
+    Address, Env, Symbol,
+};
+
+mod contract_data {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(file = "test_wasms/test_contract_data.wasm");
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Overflow = 1,
+    Unauthorized = 2,
+    Paused = 3,
+}
+
+const COUNT: Symbol = symbol_short!("count");
+
+#[contract]
+pub struct Counter;
+
+#[contractimpl]
+impl Counter {
+    pub fn __constructor(env: Env, start: u32) {
+        env.storage().instance().set(&COUNT, &start);
+    }
+
+    pub fn incr(env: Env, by: u32) -> Result<u32, Error> {
+        let count: u32 = env.storage().instance().get(&COUNT).unwrap();
+        let count = count.checked_add(by).ok_or(Error::Overflow)?;
+        env.storage().instance().set(&COUNT, &count);
+        Ok(count)
+    }
+
+    pub fn reset(env: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+        env.storage().instance().set(&COUNT, &0_u32);
+        Ok(())
+    }
+
+    pub fn pause(env: Env) {
+        panic_with_error!(&env, Error::Paused);
+    }
+}
+
+#[contract]
+pub struct Caller;
+
+#[contractimpl]
+impl Caller {
+    pub fn incr_twice(env: Env, counter: Address, by: u32) -> u32 {
+        let counter = CounterClient::new(&env, &counter);
+        counter.incr(&by);
+        counter.incr(&by)
+    }
+}
+
+fn function(name: &str, calls: u64) -> FunctionCoverage {
+    FunctionCoverage {
+        name: name.into(),
+        calls,
+    }
+}
+
+fn error(variant: &str, value: u32, returns: u64) -> ErrorCoverage {
+    ErrorCoverage {
+        error: "Error".into(),
+        variant: variant.into(),
+        value,
+        returns,
+    }
+}
+
+#[test]
+fn test_report() {
+    coverage::enable();
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    let counter_id = env.register(Counter, (1_u32,));
+    let counter = CounterClient::new(&env, &counter_id);
+    let caller = CallerClient::new(&env, &env.register(Caller, ()));
+
+    assert_eq!(counter.incr(&1), 2);
+    assert_eq!(counter.try_incr(&u32::MAX), Err(Ok(Error::Overflow)));
+    // Calls made by other contracts are included.
+    assert_eq!(caller.incr_twice(&counter_id, &2), 6);
+    assert!(caller.try_incr_twice(&counter_id, &u32::MAX).is_err());
+    assert!(counter.try_pause().is_err());
+
+    let report = coverage::report();
+    let contract = report
+        .contracts
+        .iter()
+        .find(|c| c.contract == core::any::type_name::<Counter>())
+        .unwrap();
+    assert_eq!(
+        contract,
+        &ContractCoverage {
+            contract: core::any::type_name::<Counter>().into(),
+            functions: std::vec![
+                function("__constructor", 1),
+                function("incr", 5),
+                function("pause", 1),
+                function("reset", 0),
+            ],
+            errors: std::vec![
+                error("Overflow", 1, 2),
+                error("Unauthorized", 2, 0),
+                error("Paused", 3, 1),
+            ],
+        }
+    );
+    assert_eq!(
+        contract.uncovered_functions().collect::<std::vec::Vec<_>>(),
+        [&function("reset", 0)]
+    );
+    assert_eq!(
+        contract.uncovered_errors().collect::<std::vec::Vec<_>>(),
+        [&error("Unauthorized", 2, 0)]
+    );
+    assert_eq!(
+        contract.to_string(),
+        format!(
+            "contract {}: 3 of 4 functions, 2 of 3 errors covered\n  \
+             fn __constructor: 1 call\n  \
+             fn incr: 5 calls\n  \
+             fn pause: 1 call\n  \
+             fn reset: not called\n  \
+             error Error::Overflow (1): 2 returns\n  \
+             error Error::Unauthorized (2): not returned\n  \
+             error Error::Paused (3): 1 return\n",
+            core::any::type_name::<Counter>()
+        )
+    );
+
+    let caller = report
+        .contracts
+        .iter()
+        .find(|c| c.contract == core::any::type_name::<Caller>())
+        .unwrap();
+    assert_eq!(caller.functions, [function("incr_twice", 2)]);
+}
+
+#[test]
+fn test_report_includes_deployed_wasm_contracts() {
+    coverage::enable();
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    let wasm_hash = env.deployer().upload_contract_wasm(contract_data::WASM);
+    env.mock_all_auths();
+    #[allow(deprecated)]
+    let deployed = env
+        .deployer()
+        .with_address(Address::generate(&env), [2; 32])
+        .deploy_v2(wasm_hash.clone(), ());
+    #[allow(deprecated)]
+    let registered = env.register_contract_wasm(None, contract_data::WASM);
+
+    for contract_id in [deployed, registered] {
+        let client = contract_data::Client::new(&env, &contract_id);
+        client.put(&symbol_short!("k"), &symbol_short!("v"));
+    }
+
+    let hex: std::string::String = wasm_hash
+        .to_array()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    let report = coverage::report();
+    let contract = report
+        .contracts
+        .iter()
+        .find(|c| c.contract == format!("wasm {hex}"))
+        .unwrap();
+    let names: std::vec::Vec<_> = contract.functions.iter().map(|f| &f.name[..]).collect();
+    assert_eq!(names, ["del", "get", "put"]);
+    // Other tests of the binary may call the contract too.
+    assert!(contract.functions[2].calls >= 2);
+}
@@ -1,4 +1,4 @@
-use crate::{crypto::Hash, Env};
+use crate::{crypto::Hash, BytesN, Env};
 
 #[test]
 fn test_recover_key_ecdsa_secp256k1() {
@@ -53,3 +53,23 @@ fn test_secp256k1_recover_visible_via_crypto_hazmat() {
         expected_public_key
     );
 }
+
+#[test]
+fn test_recover_key_ecdsa_secp256k1_signed_by_testutils() {
+    use crate::testutils::secp256k1::{Sign as _, SigningKey};
+
+    let env = Env::default();
+    let signing_key = SigningKey::from_bytes(&[7; 32]).unwrap();
+
+    // Sign enough digests that both recovery IDs are used.
+    for i in 0..16u8 {
+        let digest = [i; 32];
+        let signature = signing_key.sign(&digest).unwrap();
+        let public_key = env.crypto().secp256k1_recover(
+            &Hash::from_bytes(BytesN::from_array(&env, &digest)),
+            &BytesN::from_array(&env, &signature.signature),
+            signature.recovery_id,
+        );
+        assert_eq!(public_key.to_array(), signing_key.public_key());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{crypto::Hash, Env};
+use crate::{crypto::Hash, BytesN, Env};
 
 #[test]
 fn test_verify_sig_ecdsa_secp256r1() {
@@ -48,3 +48,23 @@ fn test_secp256r1_verify_visible_via_crypto_hazmat() {
     env.crypto_hazmat()
         .secp256r1_verify(&public_key, &message_digest, &signature);
 }
+
+#[test]
+fn test_verify_sig_ecdsa_secp256r1_signed_by_testutils() {
+    use crate::testutils::secp256r1::{Sign as _, SigningKey};
+
+    let env = Env::default();
+    let signing_key = SigningKey::from_bytes(&[7; 32]).unwrap();
+
+    // Sign enough digests that some signatures have a high s before
+    // normalization.
+    for i in 0..16u8 {
+        let digest = [i; 32];
+        let signature = signing_key.sign(&digest).unwrap();
+        env.crypto().secp256r1_verify(
+            &BytesN::from_array(&env, &signing_key.public_key()),
+            &Hash::from_bytes(BytesN::from_array(&env, &digest)),
+            &BytesN::from_array(&env, &signature),
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractevent, contractimpl, symbol_short, testutils::Differential, Env,
+};
+
+mod addcontract {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(file = "../target/wasm32v1-none/release/test_add_u64.wasm");
+}
+
+mod same {
+    use crate as soroban_sdk;
+    use soroban_sdk::{contract, contractimpl};
+
+    #[contract]
+    pub struct Contract;
+
+    #[contractimpl]
+    impl Contract {
+        pub fn add(a: u64, b: u64) -> u64 {
+            a + b
+        }
+    }
+}
+
+#[contractevent(topics = ["add"])]
+pub struct Added {
+    b: u64,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn add(env: Env, a: u64, b: u64) -> u64 {
+        env.storage().instance().set(&symbol_short!("last"), &a);
+        Added { b }.publish(&env);
+        a.wrapping_add(b)
+    }
+}
+
+#[test]
+fn test_same_behavior() {
+    let mut diff = Differential::new(same::Contract, addcontract::WASM, |_| ());
+
+    let r = diff.call(|env, id| addcontract::Client::new(env, id).try_add(&1, &2));
+    assert_eq!(r, Ok(Ok(3)));
+    // Overflows panic natively and trap as Wasm.
+    let r = diff.call(|env, id| addcontract::Client::new(env, id).try_add(&u64::MAX, &1));
+    assert!(r.is_err());
+
+    let costs = diff.costs();
+    assert_eq!(costs.0.len(), 2);
+    assert!(costs.0[0].wasm_cpu_insns > costs.0[0].native_cpu_insns);
+    assert!(costs.0[0].cpu_insns_gap() > 0);
+    assert!(costs.to_string().starts_with("call 0: cpu insns native "));
+}
+
+#[test]
+fn test_different_behavior() {
+    let mut diff = Differential::new(Contract, addcontract::WASM, |_| ());
+
+    let mismatch = diff
+        .try_call(|env, id| addcontract::Client::new(env, id).try_add(&u64::MAX, &1))
+        .unwrap_err();
+
+    assert_eq!(mismatch.call, 0);
+    let (native, wasm) = mismatch.result.clone().unwrap();
+    assert_eq!(native, "Ok(Ok(0))");
+    assert!(wasm.starts_with("Err("));
+    let (native_events, wasm_events) = mismatch.events.clone().unwrap();
+    assert_eq!(native_events.len(), 1);
+    assert!(wasm_events.is_empty());
+    assert_eq!(mismatch.ledger.modified.len(), 1);
+    let s = mismatch.to_string();
+    assert!(s.starts_with("call 0:\n  result:\n    native: Ok(Ok(0))\n"));
+    assert!(s.contains("  events:\n    native:\n      [add] {b: 1u64}\n    wasm:\n"));
+    assert!(s.contains("  contract data (- native, + wasm):\n"));
+}
+
+#[test]
+#[should_panic(expected = "native and wasm contracts differ")]
+fn test_call_panics_on_mismatch() {
+    let mut diff = Differential::new(Contract, addcontract::WASM, |_| ());
+
+    let _ = diff.call(|env, id| addcontract::Client::new(env, id).try_add(&u64::MAX, &1));
+}
+
+#[test]
+fn test_calls_without_invocations_have_no_costs() {
+    let mut diff = Differential::new(same::Contract, addcontract::WASM, |_| ());
+
+    diff.call(|env, id| addcontract::Client::new(env, id).add(&1, &2));
+    diff.call(|_, _| ());
+
+    let costs = diff.costs();
+    assert_eq!(costs.0.len(), 1);
+    assert_eq!(costs.0[0].call, 0);
+}
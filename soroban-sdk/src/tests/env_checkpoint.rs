@@ -0,0 +1,247 @@
+use crate::{self as soroban_sdk};
+use soroban_sdk::{
+    contract, contractevent, contractimpl,
+    testutils::{storage::Persistent as _, Address as _, Events as _, Ledger as _},
+    xdr, Address, Env, Event as _, TryFromVal,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractevent]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Set {
+    #[topic]
+    pub k: u32,
+    pub v: u32,
+}
+
+#[contractimpl]
+impl Contract {
+    pub fn set(env: Env, k: u32, v: u32) {
+        env.storage().persistent().set(&k, &v);
+        Set { k, v }.publish(&env);
+    }
+
+    pub fn set_temp(env: Env, k: u32, v: u32) {
+        env.storage().temporary().set(&k, &v);
+    }
+
+    pub fn get(env: Env, k: u32) -> Option<u32> {
+        env.storage().persistent().get(&k)
+    }
+
+    pub fn get_temp(env: Env, k: u32) -> Option<u32> {
+        env.storage().temporary().get(&k)
+    }
+
+    pub fn remove(env: Env, k: u32) {
+        env.storage().persistent().remove(&k);
+    }
+
+    pub fn extend(env: Env, k: u32, to: u32) {
+        env.storage().persistent().extend_ttl(&k, to, to);
+    }
+
+    pub fn ttl(env: Env, k: u32) -> u32 {
+        env.storage().persistent().get_ttl(&k)
+    }
+
+    pub fn auth(_env: Env, a: Address) {
+        a.require_auth();
+    }
+}
+
+#[test]
+fn test_rollback_restores_storage() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    client.set(&1, &10);
+    client.set(&2, &20);
+
+    let checkpoint = e.checkpoint();
+
+    client.set(&1, &11);
+    client.remove(&2);
+    client.set(&3, &30);
+    client.set_temp(&4, &40);
+    assert_eq!(client.get(&1), Some(11));
+    assert_eq!(client.get(&2), None);
+    assert_eq!(client.get(&3), Some(30));
+    assert_eq!(client.get_temp(&4), Some(40));
+
+    e.rollback(&checkpoint);
+
+    assert_eq!(client.get(&1), Some(10));
+    assert_eq!(client.get(&2), Some(20));
+    assert_eq!(client.get(&3), None);
+    assert_eq!(client.get_temp(&4), None);
+}
+
+#[test]
+fn test_rollback_restores_ttls_and_ledger_info() {
+    let e = Env::default();
+    e.ledger().set_sequence_number(100);
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    client.set(&1, &10);
+    let ttl = client.ttl(&1);
+
+    let checkpoint = e.checkpoint();
+
+    client.extend(&1, &5000);
+    assert_eq!(client.ttl(&1), 5000);
+    e.ledger().set_sequence_number(200);
+    e.ledger().set_timestamp(12345);
+
+    e.rollback(&checkpoint);
+
+    assert_eq!(e.ledger().sequence(), 100);
+    assert_eq!(e.ledger().timestamp(), 0);
+    assert_eq!(client.ttl(&1), ttl);
+}
+
+#[test]
+fn test_rollback_can_be_repeated() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    client.set(&1, &10);
+    let checkpoint = e.checkpoint();
+
+    for v in [11, 12, 13] {
+        assert_eq!(client.get(&1), Some(10));
+        client.set(&1, &v);
+        assert_eq!(client.get(&1), Some(v));
+        e.rollback(&checkpoint);
+    }
+
+    assert_eq!(client.get(&1), Some(10));
+}
+
+#[test]
+fn test_rollback_restores_generators() {
+    let e = Env::default();
+
+    let checkpoint = e.checkpoint();
+    let a1 = Address::generate(&e);
+    e.rollback(&checkpoint);
+    let a2 = Address::generate(&e);
+
+    assert_eq!(a1, a2);
+}
+
+#[test]
+fn test_rollback_removes_contracts_registered_after_checkpoint() {
+    let e = Env::default();
+    let checkpoint = e.checkpoint();
+
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+    client.set(&1, &10);
+
+    e.rollback(&checkpoint);
+
+    assert!(client.try_get(&1).is_err());
+
+    // The contract can be registered again at the same address, because the
+    // generators were rolled back too.
+    let contract_id_again = e.register(Contract, ());
+    assert_eq!(contract_id, contract_id_again);
+    assert_eq!(client.get(&1), None);
+}
+
+#[test]
+fn test_rollback_restores_auths_and_auth_mode() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+    let a = Address::generate(&e);
+
+    let checkpoint = e.checkpoint();
+
+    e.mock_all_auths();
+    client.auth(&a);
+    assert_eq!(e.auths().len(), 1);
+
+    e.rollback(&checkpoint);
+
+    assert_eq!(e.auths(), []);
+    assert!(client.try_auth(&a).is_err());
+}
+
+#[test]
+fn test_rollback_restores_events() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    client.set(&1, &10);
+    let checkpoint = e.checkpoint();
+    let events = e.events().all();
+    assert_eq!(
+        events,
+        [Set { k: 1, v: 10 }.to_xdr(&e, &contract_id)].as_slice()
+    );
+
+    client.set(&2, &20);
+    assert_ne!(e.events().all(), events);
+
+    e.rollback(&checkpoint);
+    assert_eq!(e.events().all(), events);
+
+    // Events of an invocation after the rollback replace the restored events.
+    client.get(&1);
+    assert_eq!(e.events().all(), [].as_slice());
+}
+
+#[test]
+fn test_rollback_restores_entries_from_snapshot_source() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+    client.set(&1, &10);
+    let contract_id_xdr = xdr::ScAddress::from(&contract_id);
+    let snapshot = e.to_snapshot();
+
+    let e = Env::from_snapshot(snapshot);
+    let contract_id = Address::try_from_val(&e, &contract_id_xdr).unwrap();
+    e.register_at(&contract_id, Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let checkpoint = e.checkpoint();
+    client.set(&1, &11);
+    assert_eq!(client.get(&1), Some(11));
+
+    e.rollback(&checkpoint);
+    assert_eq!(client.get(&1), Some(10));
+}
+
+#[test]
+fn test_scoped() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+    client.set(&1, &10);
+
+    let v = e.scoped(|| {
+        client.set(&1, &11);
+        client.get(&1)
+    });
+
+    assert_eq!(v, Some(11));
+    assert_eq!(client.get(&1), Some(10));
+}
+
+#[test]
+#[should_panic(expected = "checkpoint was created by a different Env")]
+fn test_rollback_to_checkpoint_of_other_env_panics() {
+    let e1 = Env::default();
+    let e2 = Env::default();
+    let checkpoint = e1.checkpoint();
+    e2.rollback(&checkpoint);
+}
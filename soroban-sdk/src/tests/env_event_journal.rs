@@ -0,0 +1,189 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, panic_with_error, testutils::Events as _,
+    xdr, Address, Env,
+};
+
+#[contractevent]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counted {
+    pub n: u32,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Failed = 1,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn count(env: Env, n: u32) {
+        for i in 0..n {
+            Counted { n: i }.publish(&env);
+        }
+    }
+
+    pub fn fail(env: Env) {
+        Counted { n: 99 }.publish(&env);
+        panic_with_error!(&env, Error::Failed);
+    }
+
+    pub fn fail_silently(_env: Env) {
+        panic!("failed");
+    }
+
+    pub fn call(env: Env, other: Address) {
+        let client = ContractClient::new(&env, &other);
+        client.count(&1);
+        let _ = client.try_fail();
+    }
+}
+
+fn sym(s: &str) -> xdr::ScSymbol {
+    s.try_into().unwrap()
+}
+
+#[test]
+fn test_journal_records_invocations() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &id);
+    env.enable_event_journal();
+
+    client.count(&2);
+    client.count(&0);
+    client.count(&1);
+
+    let journal = env.event_journal();
+    let entries = journal.entries();
+    assert_eq!(entries.len(), 3);
+    let first = entries[0].invocation;
+    for (i, e) in entries.iter().enumerate() {
+        assert_eq!(e.invocation, first + i as u32);
+        assert_eq!(e.contract, Some(xdr::ScAddress::from(&id)));
+        assert_eq!(e.function, Some(sym("count")));
+    }
+    assert_eq!(
+        entries
+            .iter()
+            .map(|e| e.events.len())
+            .collect::<std::vec::Vec<_>>(),
+        [2, 0, 1]
+    );
+
+    assert_eq!(
+        journal.all().decoded::<Counted>(),
+        [Counted { n: 0 }, Counted { n: 1 }, Counted { n: 0 }]
+    );
+    assert_eq!(
+        journal.invocation(first).decoded::<Counted>(),
+        [Counted { n: 0 }, Counted { n: 1 }]
+    );
+    assert_eq!(
+        journal.since_invocation(first + 1).decoded::<Counted>(),
+        [Counted { n: 0 }]
+    );
+    // Only the events of the last invocation are available from the events
+    // of the Env.
+    assert_eq!(env.events().all(), journal.invocation(first + 2));
+}
+
+#[test]
+fn test_journal_skips_failed_invocations_and_calls() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+    let other = env.register(Contract, ());
+    let client = ContractClient::new(&env, &id);
+    env.enable_event_journal();
+
+    client.count(&1);
+    assert!(client.try_fail().is_err());
+    assert!(client.try_fail_silently().is_err());
+    client.call(&other);
+
+    let journal = env.event_journal();
+    let entries = journal.entries();
+    assert_eq!(entries.len(), 2);
+    // The failed invocations are counted, but not recorded.
+    assert_eq!(entries[1].invocation, entries[0].invocation + 3);
+    assert_eq!(entries[1].function, Some(sym("call")));
+    assert_eq!(journal.invocation(entries[0].invocation + 1).events(), []);
+    // The events of the failed sub-call are not recorded.
+    assert_eq!(
+        journal.invocation(entries[1].invocation),
+        env.events().all().filter_by_contract(&other)
+    );
+    assert_eq!(
+        journal
+            .invocation(entries[1].invocation)
+            .decoded::<Counted>(),
+        [Counted { n: 0 }]
+    );
+}
+
+#[test]
+fn test_journal_records_non_contract_invocations() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+    env.enable_event_journal();
+
+    env.as_contract(&id, || Counted { n: 7 }.publish(&env));
+
+    let journal = env.event_journal();
+    let entries = journal.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].contract, None);
+    assert_eq!(entries[0].function, None);
+    assert_eq!(journal.all().decoded::<Counted>(), [Counted { n: 7 }]);
+}
+
+#[test]
+fn test_journal_since_checkpoint_and_rollback() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &id);
+    env.enable_event_journal();
+
+    client.count(&1);
+    let checkpoint = env.checkpoint();
+    client.count(&2);
+    client.count(&3);
+
+    let journal = env.event_journal();
+    assert_eq!(journal.since(&checkpoint).decoded::<Counted>().len(), 5);
+    assert_eq!(journal.all().decoded::<Counted>().len(), 6);
+
+    // Rolling back forgets the invocations made after the checkpoint, and the
+    // invocations after the rollback continue from the checkpoint.
+    env.rollback(&checkpoint);
+    assert_eq!(env.event_journal().entries().len(), 1);
+    assert_eq!(env.event_journal().since(&checkpoint).events(), []);
+    client.count(&4);
+    let journal = env.event_journal();
+    let entries = journal.entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].invocation, entries[0].invocation + 1);
+    assert_eq!(journal.since(&checkpoint).decoded::<Counted>().len(), 4);
+}
+
+#[test]
+#[should_panic(expected = "checkpoint was created by a different Env")]
+fn test_journal_since_checkpoint_of_other_env_panics() {
+    let env = Env::default();
+    env.enable_event_journal();
+    let other = Env::default();
+    env.event_journal().since(&other.checkpoint());
+}
+
+#[test]
+#[should_panic(expected = "the event journal is not enabled")]
+fn test_journal_not_enabled_panics() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+    ContractClient::new(&env, &id).count(&1);
+    env.event_journal();
+}
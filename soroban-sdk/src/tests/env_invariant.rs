@@ -0,0 +1,200 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, symbol_short,
+    testutils::{EnvTestConfig, InvariantGuard},
+    vec, Address, Env, IntoVal, Symbol,
+};
+use std::{cell::Cell, rc::Rc};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Failed = 1,
+}
+
+const TOTAL: Symbol = symbol_short!("total");
+const LEFT: Symbol = symbol_short!("left");
+const RIGHT: Symbol = symbol_short!("right");
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn add(env: Env, left: u32, right: u32) {
+        let storage = env.storage().instance();
+        storage.set(&LEFT, &(Self::left(env.clone()) + left));
+        storage.set(&RIGHT, &(Self::right(env.clone()) + right));
+        storage.set(&TOTAL, &(Self::total(env.clone()) + left + right));
+    }
+
+    pub fn add_left_only(env: Env, left: u32) {
+        let storage = env.storage().instance();
+        storage.set(&LEFT, &(Self::left(env.clone()) + left));
+    }
+
+    pub fn add_and_fail(env: Env, left: u32) -> Result<(), Error> {
+        Self::add_left_only(env, left);
+        Err(Error::Failed)
+    }
+
+    pub fn add_through(env: Env, other: Address, left: u32, right: u32) {
+        ContractClient::new(&env, &other).add(&left, &right);
+    }
+
+    pub fn left(env: Env) -> u32 {
+        env.storage().instance().get(&LEFT).unwrap_or(0)
+    }
+
+    pub fn right(env: Env) -> u32 {
+        env.storage().instance().get(&RIGHT).unwrap_or(0)
+    }
+
+    pub fn total(env: Env) -> u32 {
+        env.storage().instance().get(&TOTAL).unwrap_or(0)
+    }
+}
+
+fn setup() -> (Env, Address, Rc<Cell<u32>>, [InvariantGuard; 2]) {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    let contract_id = env.register(Contract, ());
+    let checks = Rc::new(Cell::new(0));
+
+    let c = checks.clone();
+    let counting = env.add_invariant(move |_| c.set(c.get() + 1));
+    let id = contract_id.clone();
+    let adding_up = env.add_invariant(move |env| {
+        let client = ContractClient::new(env, &id);
+        assert_eq!(
+            client.left() + client.right(),
+            client.total(),
+            "left and right must add up to total"
+        );
+    });
+
+    (env, contract_id, checks, [counting, adding_up])
+}
+
+fn panic_message(f: impl FnOnce()) -> std::string::String {
+    let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_err();
+    payload
+        .downcast_ref::<std::string::String>()
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_invariants_checked_after_each_top_level_invocation() {
+    let (env, contract_id, checks, _invariants) = setup();
+    let client = ContractClient::new(&env, &contract_id);
+
+    client.add(&1, &2);
+    assert_eq!(checks.get(), 1);
+
+    // The sub-call, and the calls made by the invariant, are not top level
+    // invocations.
+    let other = env.register(Contract, ());
+    client.add_through(&other, &3, &4);
+    assert_eq!(checks.get(), 2);
+    assert_eq!(ContractClient::new(&env, &other).total(), 7);
+    assert_eq!(checks.get(), 3);
+
+    let total: u32 = env.invoke_contract(&contract_id, &symbol_short!("total"), vec![&env]);
+    assert_eq!(total, 3);
+    assert_eq!(checks.get(), 4);
+}
+
+#[test]
+fn test_invariants_checked_after_failed_invocation() {
+    let (env, contract_id, checks, _invariants) = setup();
+    let client = ContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.try_add_and_fail(&1), Err(Ok(Error::Failed)));
+    assert_eq!(checks.get(), 1);
+
+    let res = env.try_invoke_contract::<(), Error>(
+        &contract_id,
+        &Symbol::new(&env, "add_and_fail"),
+        vec![&env, 1u32.into_val(&env)],
+    );
+    assert_eq!(res, Err(Ok(Error::Failed)));
+    assert_eq!(checks.get(), 2);
+}
+
+#[test]
+fn test_broken_invariant_names_call() {
+    let (env, contract_id, checks, _invariants) = setup();
+    let client = ContractClient::new(&env, &contract_id);
+
+    client.add(&1, &2);
+    let message = panic_message(|| client.add_left_only(&5));
+    assert_eq!(checks.get(), 2);
+    assert!(
+        message.starts_with("invariant 1 broken after call C"),
+        "{message}"
+    );
+    assert!(message.contains(".add_left_only(5u32): "), "{message}");
+    assert!(
+        message.contains("left and right must add up to total"),
+        "{message}"
+    );
+
+    // The invariant is checked again by later invocations.
+    let message = panic_message(|| {
+        env.invoke_contract::<()>(
+            &contract_id,
+            &symbol_short!("add"),
+            vec![&env, 1u32.into_val(&env), 1u32.into_val(&env)],
+        )
+    });
+    assert_eq!(checks.get(), 3);
+    assert!(
+        message.starts_with("invariant 1 broken after call "),
+        "{message}"
+    );
+    assert!(message.contains(".add(1u32, 1u32): "), "{message}");
+}
+
+#[test]
+fn test_dropped_invariant_is_removed() {
+    let (env, contract_id, checks, [counting, adding_up]) = setup();
+    let client = ContractClient::new(&env, &contract_id);
+
+    drop(counting);
+    client.add(&1, &2);
+    assert_eq!(checks.get(), 0);
+    assert_eq!(Rc::strong_count(&checks), 1);
+
+    // The invariants left keep their names.
+    let message = panic_message(|| client.add_left_only(&5));
+    assert!(
+        message.starts_with("invariant 1 broken after call C"),
+        "{message}"
+    );
+    drop(adding_up);
+}
+
+#[test]
+fn test_dropped_invariant_releases_env() {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let (e, id) = (env.clone(), contract_id.clone());
+    let invariant = env.add_invariant(move |_| {
+        let client = ContractClient::new(&e, &id);
+        assert!(client.left() <= client.total());
+    });
+    client.add(&1, &2);
+    drop(client);
+    drop(contract_id);
+    assert!(!env.host().can_finish());
+
+    // The Env captured by the invariant is released with the guard.
+    drop(invariant);
+    assert!(env.host().can_finish());
+}
@@ -0,0 +1,109 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, BytesN as _, EnvTestConfig, MuxedAddress as _},
+    Address, BytesN, Env, MuxedAddress,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn rand(env: Env) -> u64 {
+        env.prng().gen()
+    }
+}
+
+fn new_env(seed: u64) -> Env {
+    let mut env = Env::new_with_seed(seed);
+    env.set_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env
+}
+
+fn generate(env: &Env) -> (Address, MuxedAddress, BytesN<32>, BytesN<100>, u64) {
+    let contract_id = env.register(Contract, ());
+    let rand = ContractClient::new(env, &contract_id).rand();
+    (
+        Address::generate(env),
+        MuxedAddress::generate(env),
+        BytesN::random(env),
+        BytesN::random(env),
+        rand,
+    )
+}
+
+#[test]
+fn test_same_seed_generates_same_values() {
+    let env1 = new_env(1);
+    let env2 = new_env(1);
+
+    assert_eq!(env1.seed(), Some(1));
+    assert_eq!(
+        std::format!("{:?}", generate(&env1)),
+        std::format!("{:?}", generate(&env2))
+    );
+}
+
+#[test]
+fn test_different_seeds_generate_different_values() {
+    let env1 = new_env(1);
+    let env2 = new_env(2);
+
+    let (a1, m1, b1, r1, p1) = generate(&env1);
+    let (a2, m2, b2, r2, p2) = generate(&env2);
+    assert_ne!(a1.to_string().to_string(), a2.to_string().to_string());
+    assert_ne!(
+        m1.address().to_string().to_string(),
+        m2.address().to_string().to_string()
+    );
+    assert_ne!(b1.to_array(), b2.to_array());
+    assert_ne!(r1.to_array(), r2.to_array());
+    assert_ne!(p1, p2);
+}
+
+#[test]
+fn test_seeded_values_are_not_sequential() {
+    let env = new_env(1);
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    assert_ne!(a, b);
+    assert!(!a
+        .to_string()
+        .to_string()
+        .starts_with("CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"));
+}
+
+#[test]
+fn test_unseeded_env_has_no_seed() {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+
+    assert_eq!(env.seed(), None);
+}
+
+#[test]
+fn test_seed_is_kept_in_snapshot() {
+    let env1 = new_env(3);
+    Address::generate(&env1);
+    let _: BytesN<32> = BytesN::random(&env1);
+
+    let mut env2 = Env::from_snapshot(env1.to_snapshot());
+    env2.set_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+
+    assert_eq!(env2.seed(), Some(3));
+    assert_eq!(
+        Address::generate(&env1).to_string().to_string(),
+        Address::generate(&env2).to_string().to_string()
+    );
+    assert_eq!(
+        BytesN::<32>::random(&env1).to_array(),
+        BytesN::<32>::random(&env2).to_array()
+    );
+}
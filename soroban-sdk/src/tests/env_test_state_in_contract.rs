@@ -45,6 +45,11 @@ impl Contract {
         });
     }
 
+    // Uses the invariants.
+    pub fn add_invariant(env: Env) {
+        let _invariant = env.add_invariant(|_| ());
+    }
+
     // Uses the ledger snapshot.
     pub fn to_ledger_snapshot(env: Env) {
         env.to_ledger_snapshot();
@@ -106,6 +111,17 @@ fn test_set_config_in_contract() {
     client.set_config();
 }
 
+/// The invariants live in the test state, so adding an invariant inside a
+/// contract panics. Without the panic the invariant would be added to an Env
+/// that is dropped when the invocation returns, and would never be checked.
+#[test]
+#[should_panic(expected = "invariants are unavailable inside a contract function")]
+fn test_add_invariant_in_contract() {
+    let env = Env::default();
+    let client = ContractClient::new(&env, &env.register(Contract, ()));
+    client.add_invariant();
+}
+
 /// The ledger snapshot the Env was created from lives in the test state, so
 /// creating a ledger snapshot inside a contract panics.
 #[test]
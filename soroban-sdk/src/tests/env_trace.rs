@@ -0,0 +1,334 @@
+use crate as soroban_sdk;
+use expect_test::expect;
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, symbol_short,
+    testutils::trace::{Storage, StorageKey},
+    xdr::{self, ScError, ScSymbol, ScVal},
+    Address, Env, Event as _,
+};
+
+mod contract_data {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(file = "test_wasms/test_contract_data.wasm");
+}
+
+mod inner {
+    use crate as soroban_sdk;
+    use soroban_sdk::{contract, contractevent, contractimpl, Env};
+
+    #[contract]
+    pub struct Inner;
+
+    #[contractevent]
+    pub struct Stored {
+        pub v: u32,
+    }
+
+    #[contractimpl]
+    impl Inner {
+        pub fn store(env: Env, k: u32, v: u32) -> u32 {
+            env.storage().persistent().set(&k, &v);
+            Stored { v }.publish(&env);
+            v * 2
+        }
+
+        pub fn fail(_env: Env) {
+            panic!("fail")
+        }
+    }
+}
+
+#[contract]
+pub struct Outer;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Error {
+    Failed = 7,
+}
+
+#[contractevent]
+pub struct Called {}
+
+#[contractimpl]
+impl Outer {
+    pub fn call(env: Env, inner: Address, k: u32, v: u32) -> u32 {
+        let _: Option<u32> = env.storage().instance().get(&symbol_short!("count"));
+        Called {}.publish(&env);
+        inner::InnerClient::new(&env, &inner).store(&k, &v)
+    }
+
+    pub fn call_wasm(env: Env, data: Address) {
+        contract_data::Client::new(&env, &data).put(&symbol_short!("k"), &symbol_short!("w"));
+    }
+
+    pub fn call_twice(env: Env, inner: Address) {
+        let client = inner::InnerClient::new(&env, &inner);
+        client.store(&1, &2);
+        let _ = client.try_fail();
+        client.store(&3, &4);
+    }
+
+    pub fn call_fail(env: Env, inner: Address) -> bool {
+        inner::InnerClient::new(&env, &inner).try_fail().is_err()
+    }
+
+    pub fn fail(env: Env) {
+        panic_with_error!(&env, Error::Failed)
+    }
+}
+
+#[contract]
+pub struct Init;
+
+#[contractimpl]
+impl Init {
+    pub fn __constructor(env: Env, inner: Address) {
+        inner::InnerClient::new(&env, &inner).store(&1, &2);
+    }
+}
+
+fn sym(s: &str) -> ScSymbol {
+    s.try_into().unwrap()
+}
+
+#[test]
+fn test_nested_native_calls() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    assert_eq!(client.call(&inner_id, &1, &2), 4);
+
+    let trace = e.trace();
+    assert_eq!(trace.0.len(), 1);
+    let outer = &trace.0[0];
+    assert_eq!(outer.contract, xdr::ScAddress::from(&outer_id));
+    assert_eq!(outer.function, sym("call"));
+    assert_eq!(
+        outer.args,
+        [
+            ScVal::Address(xdr::ScAddress::from(&inner_id)),
+            ScVal::U32(1),
+            ScVal::U32(2)
+        ]
+    );
+    assert_eq!(outer.result, Ok(ScVal::U32(4)));
+    assert_eq!(outer.events, [Called {}.to_xdr(&e, &outer_id)]);
+    assert_eq!(
+        outer.storage,
+        Some(Storage {
+            reads: vec![StorageKey::Instance(ScVal::Symbol(sym("count")))],
+            writes: vec![],
+        })
+    );
+    assert!(outer.resources.is_some());
+
+    assert_eq!(outer.sub_frames.len(), 1);
+    let inner = &outer.sub_frames[0];
+    assert_eq!(inner.contract, xdr::ScAddress::from(&inner_id));
+    assert_eq!(inner.function, sym("store"));
+    assert_eq!(inner.args, [ScVal::U32(1), ScVal::U32(2)]);
+    assert_eq!(inner.result, Ok(ScVal::U32(4)));
+    assert_eq!(inner.events, [inner::Stored { v: 2 }.to_xdr(&e, &inner_id)]);
+    assert_eq!(
+        inner.storage,
+        Some(Storage {
+            reads: vec![],
+            writes: vec![StorageKey::Persistent(ScVal::U32(1))],
+        })
+    );
+    assert!(inner.resources.is_some());
+    assert!(
+        inner.resources.as_ref().unwrap().instructions
+            < outer.resources.as_ref().unwrap().instructions
+    );
+    assert!(inner.sub_frames.is_empty());
+
+    assert_eq!(trace.frames(), [outer, inner]);
+}
+
+#[test]
+fn test_failed_sub_call() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    assert!(client.call_fail(&inner_id));
+
+    let trace = e.trace();
+    let outer = &trace.0[0];
+    assert_eq!(outer.result, Ok(ScVal::Bool(true)));
+    assert_eq!(outer.sub_frames.len(), 1);
+    let inner = &outer.sub_frames[0];
+    assert_eq!(inner.function, sym("fail"));
+    assert_eq!(
+        inner.result,
+        Err(ScError::WasmVm(xdr::ScErrorCode::InvalidAction))
+    );
+}
+
+#[test]
+fn test_failed_root_call() {
+    let e = Env::default();
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    assert_eq!(
+        client.try_fail(),
+        Err(Ok(soroban_sdk::Error::from_contract_error(7)))
+    );
+
+    let trace = e.trace();
+    assert_eq!(trace.0.len(), 1);
+    assert_eq!(trace.0[0].function, sym("fail"));
+    assert_eq!(trace.0[0].result, Err(ScError::Contract(7)));
+}
+
+#[test]
+fn test_wasm_call() {
+    let e = Env::default();
+    let contract_id = e.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&e, &contract_id);
+
+    client.put(&symbol_short!("k"), &symbol_short!("v"));
+
+    let trace = e.trace();
+    assert_eq!(trace.0.len(), 1);
+    let frame = &trace.0[0];
+    assert_eq!(frame.function, sym("put"));
+    assert_eq!(
+        frame.args,
+        [ScVal::Symbol(sym("k")), ScVal::Symbol(sym("v"))]
+    );
+    assert_eq!(frame.result, Ok(ScVal::Void));
+    // Storage accesses are only observed for native contracts, and the
+    // storage is derived from the ledger only when footprint recording
+    // is enabled.
+    assert_eq!(frame.storage, None);
+    assert!(frame.resources.is_some());
+}
+
+#[test]
+fn test_wasm_call_storage_from_ledger() {
+    let e = Env::default();
+    e.cost_estimate().enable_footprint();
+    let data_id = e.register(contract_data::WASM, ());
+    let outer_id = e.register(Outer, ());
+    let client = contract_data::Client::new(&e, &data_id);
+
+    client.put(&symbol_short!("k"), &symbol_short!("v"));
+    assert_eq!(
+        e.trace().0[0].storage,
+        Some(Storage {
+            reads: vec![],
+            writes: vec![StorageKey::Persistent(ScVal::Symbol(sym("k")))],
+        })
+    );
+
+    // Reading the entry again after it was loaded is missed.
+    client.get(&symbol_short!("k"));
+    assert_eq!(e.trace().0[0].storage, Some(Storage::default()));
+
+    // The storage of a Wasm frame called by a native frame is derived too,
+    // while the native frame keeps its observed storage.
+    OuterClient::new(&e, &outer_id).call_wasm(&data_id);
+    let trace = e.trace();
+    let outer = &trace.0[0];
+    assert_eq!(
+        outer.storage,
+        Some(Storage {
+            reads: vec![],
+            writes: vec![],
+        })
+    );
+    assert_eq!(
+        outer.sub_frames[0].storage,
+        Some(Storage {
+            reads: vec![],
+            writes: vec![StorageKey::Persistent(ScVal::Symbol(sym("k")))],
+        })
+    );
+}
+
+#[test]
+fn test_repeated_native_calls_match_their_frames() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    client.call_twice(&inner_id);
+
+    let trace = e.trace();
+    let calls = &trace.0[0].sub_frames;
+    assert_eq!(calls.len(), 3);
+    let writes = |i: usize| calls[i].storage.as_ref().unwrap().writes.clone();
+    assert_eq!(writes(0), [StorageKey::Persistent(ScVal::U32(1))]);
+    assert_eq!(calls[1].function, sym("fail"));
+    assert!(calls[1].storage.is_some());
+    assert_eq!(writes(2), [StorageKey::Persistent(ScVal::U32(3))]);
+    // The calls, including the failed one, are metered in order.
+    assert!(calls.iter().all(|c| c.resources.is_some()));
+}
+
+#[test]
+fn test_register_traces_constructor() {
+    let e = Env::default();
+    let contract_id = e.register(inner::Inner, ());
+
+    let trace = e.trace();
+    assert_eq!(trace.0.len(), 1);
+    assert_eq!(trace.0[0].contract, xdr::ScAddress::from(&contract_id));
+    assert_eq!(trace.0[0].function, sym("__constructor"));
+    assert_eq!(trace.0[0].result, Ok(ScVal::Void));
+}
+
+#[test]
+fn test_constructor_calls_are_metered_as_calls_of_the_caller() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    e.register(Init, (&inner_id,));
+
+    // The host meters the call to store, but not the constructor it calls
+    // itself.
+    let trace = e.trace();
+    let constructor = &trace.0[0];
+    assert_eq!(constructor.function, sym("__constructor"));
+    assert!(constructor.resources.is_none());
+    let store = &constructor.sub_frames[0];
+    assert_eq!(store.function, sym("store"));
+    assert!(store.resources.is_some());
+}
+
+#[test]
+fn test_display() {
+    let e = Env::default();
+    let inner_id = e.register(inner::Inner, ());
+    let outer_id = e.register(Outer, ());
+    let client = OuterClient::new(&e, &outer_id);
+
+    client.call(&inner_id, &1, &2);
+
+    let mut trace = e.trace();
+    // Remove the resources, which change with the host, from the output.
+    fn clear_resources(frames: &mut [soroban_sdk::testutils::trace::Frame]) {
+        for f in frames {
+            f.resources = None;
+            clear_resources(&mut f.sub_frames);
+        }
+    }
+    clear_resources(&mut trace.0);
+
+    // Update the expectation by running the test with `UPDATE_EXPECT=1`.
+    expect![[r#"
+        CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4.call(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM, 1u32, 2u32) -> 4u32
+        ├─ event: topics: [called], data: {}
+        ├─ read: Instance(count)
+        └─ CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM.store(1u32, 2u32) -> 4u32
+           ├─ event: topics: [stored], data: {v: 2u32}
+           └─ write: Persistent(1u32)
+    "#]].assert_eq(&trace.to_string());
+}
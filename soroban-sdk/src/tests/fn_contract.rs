@@ -0,0 +1,177 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short,
+    testutils::EnvTestConfig,
+    vec,
+    xdr::{Limits, ReadXdr, ScSpecEntry},
+    Env, IntoVal, Symbol, Val, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Overflow = 1,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+/// The interface implemented by the closure contracts in the tests, used for
+/// its client and spec.
+#[contract]
+pub struct Counter;
+
+#[contractimpl]
+impl Counter {
+    pub fn incr(_env: Env, _by: u32) -> Result<u32, Error> {
+        unimplemented!()
+    }
+
+    pub fn get(_env: Env) -> u32 {
+        unimplemented!()
+    }
+
+    pub fn point(_env: Env) -> Point {
+        unimplemented!()
+    }
+}
+
+fn spec() -> std::vec::Vec<ScSpecEntry> {
+    [
+        &Counter::spec_xdr_incr()[..],
+        &Counter::spec_xdr_get()[..],
+        &Counter::spec_xdr_point()[..],
+        &Error::spec_xdr()[..],
+        &Point::spec_xdr()[..],
+    ]
+    .into_iter()
+    .map(|xdr| ScSpecEntry::from_xdr(xdr, Limits::none()).unwrap())
+    .collect()
+}
+
+fn env() -> Env {
+    Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    })
+}
+
+const COUNT: Symbol = symbol_short!("count");
+
+fn get(env: &Env) -> u32 {
+    env.storage().instance().get(&COUNT).unwrap_or(0)
+}
+
+fn incr(env: &Env, args: Vec<Val>) -> Result<u32, Error> {
+    let by: u32 = args.get_unchecked(0).into_val(env);
+    let count = get(env).checked_add(by).ok_or(Error::Overflow)?;
+    env.storage().instance().set(&COUNT, &count);
+    Ok(count)
+}
+
+#[test]
+fn test_closure_functions() {
+    let env = env();
+    let id = env
+        .register_fn_contract()
+        .func("incr", incr)
+        .func("get", |env, _| get(env))
+        .register();
+    let client = CounterClient::new(&env, &id);
+
+    assert_eq!(client.get(), 0);
+    assert_eq!(client.incr(&2), 2);
+    assert_eq!(client.incr(&3), 5);
+    assert_eq!(client.get(), 5);
+    assert_eq!(client.try_incr(&u32::MAX), Err(Ok(Error::Overflow)));
+    assert_eq!(client.get(), 5);
+
+    // Functions that are not added are missing.
+    assert!(client.try_point().is_err());
+}
+
+#[test]
+fn test_func_replaces_func() {
+    let env = env();
+    let id = env
+        .register_fn_contract()
+        .func("get", |_, _| 1_u32)
+        .func("get", |_, _| 2_u32)
+        .register();
+    assert_eq!(CounterClient::new(&env, &id).get(), 2);
+}
+
+#[test]
+fn test_spec_checks_calls() {
+    let env = env();
+    let id = env
+        .register_fn_contract()
+        .spec(&spec())
+        .func("incr", incr)
+        .func("get", |env, _| get(env))
+        .func("point", |_, _| Point { x: 1, y: 2 })
+        .register();
+    let client = CounterClient::new(&env, &id);
+
+    assert_eq!(client.incr(&2), 2);
+    assert_eq!(client.try_incr(&u32::MAX), Err(Ok(Error::Overflow)));
+    assert_eq!(client.point(), Point { x: 1, y: 2 });
+
+    assert_eq!(client.get(), 2);
+}
+
+#[test]
+fn test_spec_checks_args() {
+    let env = env();
+    let id = env
+        .register_fn_contract()
+        .spec(&spec())
+        .func("incr", |_, _| Ok::<_, Error>(7_u32))
+        .func("get", |_, _| 7_u32)
+        .register();
+    let client = CounterClient::new(&env, &id);
+    assert_eq!(client.incr(&1), 7);
+
+    // Args that are not the types in the spec fail the call, even though the
+    // closures ignore them.
+    let res = env.try_invoke_contract::<u32, Error>(
+        &id,
+        &symbol_short!("incr"),
+        vec![&env, symbol_short!("two").into_val(&env)],
+    );
+    assert!(res.is_err());
+    let res = env.try_invoke_contract::<u32, Error>(
+        &id,
+        &symbol_short!("get"),
+        vec![&env, 1_u32.into_val(&env)],
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_spec_checks_returned_values() {
+    let env = env();
+    let id = env
+        .register_fn_contract()
+        .spec(&spec())
+        .func("get", |_, _| -1_i32)
+        .func("point", |_, _| (1_u32, 2_u32))
+        .register();
+    let client = CounterClient::new(&env, &id);
+
+    assert!(client.try_get().is_err());
+    assert!(client.try_point().is_err());
+}
+
+#[test]
+#[should_panic(expected = "function `decr` is not in the spec")]
+fn test_spec_checks_functions() {
+    let env = env();
+    env.register_fn_contract()
+        .spec(&spec())
+        .func("decr", |_, _| ())
+        .register();
+}
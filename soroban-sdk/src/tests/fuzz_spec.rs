@@ -0,0 +1,309 @@
+use crate as soroban_sdk;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype,
+    testutils::{
+        fuzz::{Fuzzer, Step},
+        EnvTestConfig,
+    },
+    xdr::{self, Limits, ReadXdr},
+    Address, BytesN, Env, Map, String, Symbol, Val, Vec,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    Empty,
+    Circle(Point, u32),
+    Polygon(Vec<Point>),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Color {
+    Red = 1,
+    Blue = 2,
+}
+
+#[contracterror]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VaultError {
+    Insufficient = 1,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn deposit(env: Env, from: Address, amount: u32) {
+        from.require_auth();
+        let balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .instance()
+            .set(&from, &(balance + u64::from(amount)));
+    }
+
+    pub fn withdraw(env: Env, from: Address, amount: u32) -> Result<(), VaultError> {
+        from.require_auth();
+        let balance = Self::balance(env.clone(), from.clone());
+        let balance = balance
+            .checked_sub(u64::from(amount))
+            .ok_or(VaultError::Insufficient)?;
+        env.storage().instance().set(&from, &balance);
+        Ok(())
+    }
+
+    pub fn balance(env: Env, from: Address) -> u64 {
+        env.storage().instance().get(&from).unwrap_or(0)
+    }
+
+    pub fn echo_val(_env: Env, v: Val, e: soroban_sdk::Error) -> (Val, soroban_sdk::Error) {
+        (v, e)
+    }
+
+    pub fn echo(
+        _env: Env,
+        shape: Shape,
+        color: Color,
+        tags: Map<Symbol, String>,
+        data: (u128, BytesN<4>),
+    ) -> (Shape, Color, Map<Symbol, String>, (u128, BytesN<4>)) {
+        (shape, color, tags, data)
+    }
+}
+
+fn spec() -> std::vec::Vec<xdr::ScSpecEntry> {
+    [
+        &Contract::spec_xdr_deposit()[..],
+        &Contract::spec_xdr_withdraw()[..],
+        &Contract::spec_xdr_balance()[..],
+        &Contract::spec_xdr_echo()[..],
+        &Point::spec_xdr()[..],
+        &Shape::spec_xdr()[..],
+        &Color::spec_xdr()[..],
+        &VaultError::spec_xdr()[..],
+    ]
+    .iter()
+    .map(|b| xdr::ScSpecEntry::from_xdr(b, Limits::none()).unwrap())
+    .collect()
+}
+
+fn setup() -> (Env, Address) {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    let contract_id = env.register(Contract, ());
+    // Keep the contract live however far the ledger advances.
+    env.deployer()
+        .extend_ttl_for_contract_instance(contract_id.clone(), 1_000_000, 1_000_000);
+    env.deployer()
+        .extend_ttl_for_code(contract_id.clone(), 1_000_000, 1_000_000);
+    (env, contract_id)
+}
+
+fn data(seed: u64) -> std::vec::Vec<u8> {
+    let mut data = std::vec![0u8; 4096];
+    StdRng::seed_from_u64(seed).fill_bytes(&mut data);
+    data
+}
+
+fn calls(
+    steps: &[Step],
+) -> impl Iterator<
+    Item = (
+        &str,
+        &[xdr::ScVal],
+        bool,
+        &Result<xdr::ScVal, soroban_sdk::Error>,
+    ),
+> {
+    steps.iter().filter_map(|s| match s {
+        Step::Call {
+            function,
+            args,
+            auth,
+            result,
+        } => Some((function.as_str(), &args[..], *auth, result)),
+        Step::AdvanceLedger(_) => None,
+    })
+}
+
+#[test]
+fn test_run_is_deterministic() {
+    let run = || {
+        let (env, contract_id) = setup();
+        Fuzzer::new(&env, &contract_id, &spec())
+            .run_bytes(&data(1))
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<std::vec::Vec<_>>()
+    };
+
+    let steps = run();
+    assert!(!steps.is_empty());
+    assert_eq!(steps, run());
+}
+
+#[test]
+fn test_calls_every_function_with_and_without_auth() {
+    let (env, contract_id) = setup();
+    let fuzzer = Fuzzer::new(&env, &contract_id, &spec()).max_steps(64);
+
+    let mut steps = std::vec::Vec::new();
+    for seed in 0..8 {
+        steps.extend(fuzzer.run_bytes(&data(seed)));
+    }
+
+    for function in ["deposit", "withdraw", "balance", "echo"] {
+        assert!(calls(&steps).any(|(f, ..)| f == function), "{function}");
+    }
+    for (function, _, auth, result) in calls(&steps) {
+        if function == "deposit" {
+            // Deposits only fail without auth.
+            assert_eq!(result.is_ok(), auth);
+        }
+    }
+    assert!(steps.iter().any(|s| matches!(s, Step::AdvanceLedger(_))));
+}
+
+#[test]
+fn test_arguments_match_spec_types() {
+    let (env, contract_id) = setup();
+    let fuzzer = Fuzzer::new(&env, &contract_id, &spec()).max_steps(64);
+
+    let mut echoed = 0;
+    for seed in 0..8 {
+        let steps = fuzzer.run_bytes(&data(seed));
+        for (function, args, _, result) in calls(&steps) {
+            if function == "echo" {
+                // The contract converted every argument to its type, so the
+                // arguments were valid.
+                assert_eq!(
+                    result,
+                    &Ok(xdr::ScVal::Vec(Some(args.to_vec().try_into().unwrap())))
+                );
+                echoed += 1;
+            }
+        }
+    }
+    assert!(echoed > 0);
+}
+
+#[test]
+fn test_no_ledger_advance() {
+    let (env, contract_id) = setup();
+    let fuzzer = Fuzzer::new(&env, &contract_id, &spec()).max_ledger_advance(0);
+
+    let steps = fuzzer.run_bytes(&data(2));
+
+    assert!(!steps.is_empty());
+    assert!(!steps.iter().any(|s| matches!(s, Step::AdvanceLedger(_))));
+}
+
+#[test]
+fn test_seconds_per_ledger() {
+    let (env, contract_id) = setup();
+    let fuzzer = Fuzzer::new(&env, &contract_id, &spec())
+        .max_steps(64)
+        .seconds_per_ledger(7);
+
+    let timestamp = env.ledger().timestamp();
+    let mut steps = std::vec::Vec::new();
+    for seed in 0..8 {
+        steps.extend(fuzzer.run_bytes(&data(seed)));
+    }
+
+    let ledgers: u64 = steps
+        .iter()
+        .filter_map(|s| match s {
+            Step::AdvanceLedger(ledgers) => Some(u64::from(*ledgers)),
+            Step::Call { .. } => None,
+        })
+        .sum();
+    assert!(ledgers > 0);
+    assert_eq!(env.ledger().timestamp(), timestamp + 7 * ledgers);
+}
+
+#[test]
+fn test_auth_mode_restored_after_calls() {
+    let (env, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = <Address as soroban_sdk::testutils::Address>::generate(&env);
+
+    let fuzzer = Fuzzer::new(&env, &contract_id, &spec())
+        .max_steps(64)
+        // Invariants run with the auth mode set by the test.
+        .invariant("deposits are authorized", {
+            let contract_id = contract_id.clone();
+            let user = user.clone();
+            move |env| {
+                ContractClient::new(env, &contract_id)
+                    .try_deposit(&user, &0)
+                    .is_ok()
+            }
+        });
+    let steps = fuzzer.run_bytes(&data(4));
+    assert!(calls(&steps).any(|(_, _, auth, _)| !auth));
+
+    client.deposit(&user, &1);
+    assert_eq!(client.balance(&user), 1);
+}
+
+#[test]
+#[should_panic(expected = "invariant \"no deposits\" does not hold after steps:\n  0: ")]
+fn test_invariant_failure_reports_steps() {
+    let (env, contract_id) = setup();
+    let addresses = [<Address as soroban_sdk::testutils::Address>::generate(&env)];
+    let client = ContractClient::new(&env, &contract_id);
+    let user = addresses[0].clone();
+
+    let fuzzer = Fuzzer::new(&env, &contract_id, &spec())
+        .addresses(&addresses)
+        .max_ledger_advance(0)
+        .max_steps(64)
+        .invariant("no deposits", move |_| client.balance(&user) == 0);
+    for seed in 0..8 {
+        fuzzer.run_bytes(&data(seed));
+    }
+}
+
+#[test]
+fn test_val_and_error_arguments_use_prototypes() {
+    let (env, contract_id) = setup();
+    let spec = [xdr::ScSpecEntry::from_xdr(Contract::spec_xdr_echo_val(), Limits::none()).unwrap()];
+    let fuzzer = Fuzzer::new(&env, &contract_id, &spec)
+        .max_ledger_advance(0)
+        .max_steps(64);
+
+    let mut vals = std::vec::Vec::new();
+    let mut errors = std::vec::Vec::new();
+    for seed in 0..8 {
+        let steps = fuzzer.run_bytes(&data(seed));
+        for (_, args, _, result) in calls(&steps) {
+            assert_eq!(
+                result,
+                &Ok(xdr::ScVal::Vec(Some(args.to_vec().try_into().unwrap())))
+            );
+            vals.push(args[0].clone());
+            errors.push(args[1].clone());
+        }
+    }
+    // Values of all kinds are generated, not only small values.
+    assert!(vals.iter().any(|v| matches!(
+        v,
+        xdr::ScVal::Vec(_) | xdr::ScVal::Map(_) | xdr::ScVal::Bytes(_) | xdr::ScVal::I128(_)
+    )));
+    // Errors of all types are generated, not only contract errors.
+    assert!(errors
+        .iter()
+        .any(|e| !matches!(e, xdr::ScVal::Error(xdr::ScError::Contract(_)))));
+}
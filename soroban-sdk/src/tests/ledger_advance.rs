@@ -0,0 +1,262 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{storage::Persistent as _, EnvTestConfig, Ledger},
+    xdr, Address, Env,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn set(env: Env, k: u32, v: u32) {
+        env.storage().persistent().set(&k, &v);
+        env.storage().temporary().set(&k, &v);
+    }
+
+    pub fn get_persistent(env: Env, k: u32) -> Option<u32> {
+        env.storage().persistent().get(&k)
+    }
+
+    pub fn get_temporary(env: Env, k: u32) -> Option<u32> {
+        env.storage().temporary().get(&k)
+    }
+
+    pub fn extend(env: Env, k: u32, to: u32) {
+        env.storage().persistent().extend_ttl(&k, to, to);
+    }
+}
+
+fn setup() -> (Env, Address) {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 100;
+        li.timestamp = 1000;
+        li.min_persistent_entry_ttl = 50;
+        li.min_temp_entry_ttl = 10;
+        li.max_entry_ttl = 10_000;
+    });
+    let id = env.register(Contract, ());
+    // Keep the contract instance and code live for the whole test.
+    env.deployer()
+        .extend_ttl_for_contract_instance(id.clone(), 5_000, 5_000);
+    env.deployer().extend_ttl_for_code(id.clone(), 5_000, 5_000);
+    (env, id)
+}
+
+fn persistent_key(id: &Address, k: u32) -> xdr::LedgerKey {
+    xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+        contract: id.into(),
+        key: xdr::ScVal::U32(k),
+        durability: xdr::ContractDataDurability::Persistent,
+    })
+}
+
+fn panic_message(f: impl FnOnce()) -> std::string::String {
+    let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_err();
+    match err.downcast::<std::string::String>() {
+        Ok(msg) => *msg,
+        Err(err) => err.downcast::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn test_advance_moves_sequence_and_timestamp() {
+    let (env, _) = setup();
+
+    env.ledger().advance(10, 5);
+
+    assert_eq!(env.ledger().sequence(), 110);
+    assert_eq!(env.ledger().timestamp(), 1050);
+}
+
+#[test]
+fn test_advance_keeps_live_entries() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+
+    // Temporary entries live until 109, persistent entries until 149.
+    env.ledger().advance(9, 5);
+
+    assert_eq!(client.get_temporary(&1), Some(2));
+    assert_eq!(client.get_persistent(&1), Some(2));
+    assert!(env.archived_ledger_entries().is_empty());
+}
+
+#[test]
+fn test_advance_deletes_temporary_and_archives_persistent_entries() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+
+    env.ledger().advance(10, 5);
+    assert_eq!(client.get_temporary(&1), None);
+    assert_eq!(client.get_persistent(&1), Some(2));
+    assert!(env.archived_ledger_entries().is_empty());
+
+    env.ledger().advance(40, 5);
+    assert_eq!(env.archived_ledger_entries(), [persistent_key(&id, 1)]);
+
+    // Archived entries are restored automatically when accessed.
+    assert_eq!(client.get_persistent(&1), Some(2));
+    assert!(env.archived_ledger_entries().is_empty());
+    env.as_contract(&id, || {
+        assert_eq!(env.storage().persistent().get_ttl(&1u32), 49);
+    });
+}
+
+#[test]
+fn test_set_sequence_number_archives_persistent_entries() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+
+    env.ledger().set_sequence_number(200);
+
+    assert_eq!(env.archived_ledger_entries(), [persistent_key(&id, 1)]);
+}
+
+#[test]
+fn test_archived_access_fails_without_auto_restore() {
+    let (env, id) = setup();
+    env.set_auto_restore(false);
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+    env.ledger().advance(60, 5);
+
+    assert!(panic_message(|| {
+        client.get_persistent(&1);
+    })
+    .contains("accessed archived ledger entries"));
+    // The archived entry cannot be recreated either.
+    assert!(panic_message(|| client.set(&1, &3)).contains("accessed archived ledger entries"));
+    // The failed invocations are rolled back, leaving the entry archived.
+    assert_eq!(env.archived_ledger_entries(), [persistent_key(&id, 1)]);
+
+    env.ledger().restore(&persistent_key(&id, 1));
+    assert_eq!(client.get_persistent(&1), Some(2));
+}
+
+#[test]
+#[should_panic(expected = "contract invocation accessed archived ledger entries")]
+fn test_archived_contract_instance_fails_without_auto_restore() {
+    let (env, id) = setup();
+    env.set_auto_restore(false);
+    let client = ContractClient::new(&env, &id);
+    env.ledger().advance(6_000, 5);
+
+    client.get_persistent(&1);
+}
+
+#[test]
+fn test_restore() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+    env.ledger().advance(60, 5);
+
+    env.ledger().restore(&persistent_key(&id, 1));
+
+    assert!(env.archived_ledger_entries().is_empty());
+    assert_eq!(client.get_persistent(&1), Some(2));
+    env.as_contract(&id, || {
+        assert_eq!(env.storage().persistent().get_ttl(&1u32), 49);
+    });
+}
+
+#[test]
+fn test_restore_expired_entry_without_advance() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+    env.ledger().set_sequence_number(200);
+
+    env.ledger().restore(&persistent_key(&id, 1));
+
+    env.as_contract(&id, || {
+        assert_eq!(env.storage().persistent().get_ttl(&1u32), 49);
+    });
+}
+
+#[test]
+#[should_panic(expected = "ledger entry is not archived")]
+fn test_restore_live_entry_panics() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+
+    env.ledger().restore(&persistent_key(&id, 1));
+}
+
+#[test]
+#[should_panic(expected = "ledger entry is not archived")]
+fn test_restore_auto_restored_entry_panics() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+    env.ledger().advance(60, 5);
+    assert_eq!(client.get_persistent(&1), Some(2));
+
+    env.ledger().restore(&persistent_key(&id, 1));
+}
+
+#[test]
+#[should_panic(expected = "ledger entry is not archived")]
+fn test_restore_missing_entry_panics() {
+    let (env, id) = setup();
+
+    env.ledger().restore(&persistent_key(&id, 1));
+}
+
+#[test]
+fn test_extended_entries_are_not_archived() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+    client.set(&3, &4);
+    client.extend(&3, &500);
+
+    env.ledger().advance(100, 5);
+
+    assert_eq!(env.archived_ledger_entries(), [persistent_key(&id, 1)]);
+}
+
+#[test]
+fn test_rollback_restores_archived_entries() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+
+    let checkpoint = env.checkpoint();
+    env.ledger().advance(60, 5);
+    assert_eq!(env.archived_ledger_entries().len(), 1);
+    env.rollback(&checkpoint);
+
+    assert!(env.archived_ledger_entries().is_empty());
+    assert_eq!(env.ledger().sequence(), 100);
+    assert_eq!(client.get_persistent(&1), Some(2));
+}
+
+#[test]
+fn test_snapshot_entries_expire() {
+    let (env, id) = setup();
+    let client = ContractClient::new(&env, &id);
+    client.set(&1, &2);
+    let snapshot = env.to_ledger_snapshot();
+
+    // The entries of the snapshot are expired without the host loading them.
+    let env = Env::from_ledger_snapshot(snapshot);
+    env.ledger().advance(60, 5);
+    assert_eq!(env.archived_ledger_entries(), [persistent_key(&id, 1)]);
+
+    env.ledger().restore(&persistent_key(&id, 1));
+    assert!(env.archived_ledger_entries().is_empty());
+    env.as_contract(&id, || {
+        assert_eq!(env.storage().temporary().get::<_, u32>(&1u32), None);
+        assert_eq!(env.storage().persistent().get::<_, u32>(&1u32), Some(2));
+    });
+}
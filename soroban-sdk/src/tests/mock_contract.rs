@@ -0,0 +1,177 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contracterror, contractimpl,
+    testutils::{mock_contract::MockCall, Address as _, ContractClient as _, EnvTestConfig},
+    token::TokenClient,
+    xdr::{ScSpecEntry, ScSpecFunctionV0},
+    Address, Env, IntoVal, Symbol, TryFromVal,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Insufficient = 1,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn pay(env: Env, token: Address, from: Address, to: Address, amount: i128) -> i128 {
+        let token = TokenClient::new(&env, &token);
+        token.transfer(&from, &to, &amount);
+        token.balance(&to)
+    }
+}
+
+fn env() -> Env {
+    Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    })
+}
+
+#[test]
+fn test_scripted_responses_and_recorded_calls() {
+    let env = env();
+    let (token_id, token) = env.register_mock::<TokenClient>();
+    let client = ContractClient::new(&env, &env.register(Contract, ()));
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+
+    token.on("transfer").returns(());
+    token.on("balance").returns(0_i128);
+    token.on("balance").args((&b,)).returns(10_i128);
+
+    assert_eq!(client.pay(&token_id, &a, &b, &10), 10);
+    assert_eq!(client.pay(&token_id, &b, &a, &5), 0);
+
+    assert_eq!(
+        token.calls(),
+        [
+            MockCall {
+                function: Symbol::new(&env, "transfer"),
+                args: (&a, &b, 10_i128).into_val(&env),
+            },
+            MockCall {
+                function: Symbol::new(&env, "balance"),
+                args: (&b,).into_val(&env),
+            },
+            MockCall {
+                function: Symbol::new(&env, "transfer"),
+                args: (&b, &a, 5_i128).into_val(&env),
+            },
+            MockCall {
+                function: Symbol::new(&env, "balance"),
+                args: (&a,).into_val(&env),
+            },
+        ]
+    );
+    assert_eq!(
+        token.calls_to("balance"),
+        [(&b,).into_val(&env), (&a,).into_val(&env)]
+    );
+
+    token.clear_calls();
+    assert_eq!(token.calls(), []);
+}
+
+#[test]
+fn test_response_precedence() {
+    let env = env();
+    let (_, token) = env.register_mock::<TokenClient>();
+    let client = token.client();
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+
+    token.on("balance").args((&a,)).returns(1_i128);
+    token.on("balance").returns(2_i128);
+    // Responses limited to some arguments take precedence over responses for
+    // any arguments, even if scripted earlier.
+    assert_eq!(client.balance(&a), 1);
+    assert_eq!(client.balance(&b), 2);
+
+    // Responses scripted later take precedence over responses scripted
+    // earlier.
+    token.on("balance").returns(3_i128);
+    token.on("balance").args((&a,)).returns(4_i128);
+    assert_eq!(client.balance(&a), 4);
+    assert_eq!(client.balance(&b), 3);
+
+    token
+        .on("balance")
+        .matching(|args| args.len() == 1)
+        .returns(5_i128);
+    assert_eq!(client.balance(&a), 5);
+    assert_eq!(client.balance(&b), 5);
+}
+
+#[test]
+fn test_scripted_error() {
+    let env = env();
+    let (token_id, token) = env.register_mock::<TokenClient>();
+    let client = ContractClient::new(&env, &env.register(Contract, ()));
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+
+    token.on("transfer").fails(Error::Insufficient);
+    assert!(client.try_pay(&token_id, &a, &b, &10).is_err());
+
+    let res = token.client().try_transfer(&a, &b, &10);
+    let err = res.unwrap_err().unwrap();
+    assert_eq!(Error::try_from(err), Ok(Error::Insufficient));
+    assert_eq!(token.calls_to("transfer").len(), 2);
+}
+
+#[test]
+fn test_unscripted_call_fails() {
+    let env = env();
+    let (_, token) = env.register_mock::<TokenClient>();
+    let a = Address::generate(&env);
+
+    assert!(token.client().try_balance(&a).is_err());
+    // The call of the constructor when registering is not recorded.
+    let calls = token.calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].function, Symbol::new(&env, "balance"));
+    assert_eq!(
+        Address::try_from_val(&env, &calls[0].args.get_unchecked(0)),
+        Ok(a)
+    );
+}
+
+fn spec_fn(spec: &[ScSpecEntry], name: &str) -> Option<ScSpecFunctionV0> {
+    spec.iter().find_map(|entry| match entry {
+        ScSpecEntry::FunctionV0(f) if f.name.to_utf8_string_lossy() == name => Some(f.clone()),
+        _ => None,
+    })
+}
+
+#[test]
+fn test_client_spec() {
+    let spec = TokenClient::spec();
+    assert_eq!(spec_fn(&spec, "transfer").unwrap().inputs.len(), 3);
+    assert_eq!(spec_fn(&spec, "balance").unwrap().inputs.len(), 1);
+
+    let spec = ContractClient::spec();
+    assert_eq!(spec_fn(&spec, "pay").unwrap().inputs.len(), 4);
+    assert_eq!(spec.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "mock contract interface has no function `transfer_all`")]
+fn test_unknown_function_panics() {
+    let env = env();
+    let (_, token) = env.register_mock::<TokenClient>();
+
+    token.on("transfer_all").returns(());
+}
+
+#[test]
+#[should_panic(
+    expected = "mock contract function `balance` takes 1 args, but the response is limited to 2 args"
+)]
+fn test_wrong_number_of_args_panics() {
+    let env = env();
+    let (_, token) = env.register_mock::<TokenClient>();
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+
+    token.on("balance").args((&a, &b)).returns(0_i128);
+}
@@ -0,0 +1,288 @@
+use crate as soroban_sdk;
+use soroban_env_host::fees::{compute_rent_write_fee_per_1kb, RentWriteFeeConfiguration};
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{
+        cost_estimate::{MissingConfigSettings, NetworkConfig},
+        EnvTestConfig,
+    },
+    xdr::{
+        ConfigSettingContractBandwidthV0, ConfigSettingContractComputeV0,
+        ConfigSettingContractEventsV0, ConfigSettingContractExecutionLanesV0,
+        ConfigSettingContractHistoricalDataV0, ConfigSettingContractLedgerCostExtV0,
+        ConfigSettingContractLedgerCostV0, ConfigSettingEntry, ConfigSettingId, ConfigUpgradeSet,
+        LedgerEntry, LedgerEntryData, LedgerEntryExt, LedgerKey, LedgerKeyConfigSetting,
+        StateArchivalSettings, VecM,
+    },
+    Env,
+};
+
+mod contract_data {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(file = "test_wasms/test_contract_data.wasm");
+}
+
+fn env() -> Env {
+    Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    })
+}
+
+fn compute(fee_rate_per_instructions_increment: i64) -> ConfigSettingEntry {
+    ConfigSettingEntry::ContractComputeV0(ConfigSettingContractComputeV0 {
+        ledger_max_instructions: 600_000_000,
+        tx_max_instructions: 100_000_000,
+        fee_rate_per_instructions_increment,
+        tx_memory_limit: 41_943_040,
+    })
+}
+
+/// Returns the config settings of a network, with fees that differ from the
+/// Mainnet fees.
+fn settings() -> std::vec::Vec<ConfigSettingEntry> {
+    std::vec![
+        compute(25),
+        ConfigSettingEntry::ContractLedgerCostV0(ConfigSettingContractLedgerCostV0 {
+            ledger_max_disk_read_entries: 1000,
+            ledger_max_disk_read_bytes: 3_500_000,
+            ledger_max_write_ledger_entries: 250,
+            ledger_max_write_bytes: 143_360,
+            tx_max_disk_read_entries: 100,
+            tx_max_disk_read_bytes: 200_000,
+            tx_max_write_ledger_entries: 50,
+            tx_max_write_bytes: 132_096,
+            fee_disk_read_ledger_entry: 6250,
+            fee_write_ledger_entry: 10_000,
+            fee_disk_read1_kb: 1786,
+            soroban_state_target_size_bytes: 3_000_000_000,
+            rent_fee1_kb_soroban_state_size_low: -17_000,
+            rent_fee1_kb_soroban_state_size_high: 10_000,
+            soroban_state_rent_fee_growth_factor: 5000,
+        }),
+        ConfigSettingEntry::ContractLedgerCostExtV0(ConfigSettingContractLedgerCostExtV0 {
+            tx_max_footprint_entries: 200,
+            fee_write1_kb: 3500,
+        }),
+        ConfigSettingEntry::ContractHistoricalDataV0(ConfigSettingContractHistoricalDataV0 {
+            fee_historical1_kb: 16_235,
+        }),
+        ConfigSettingEntry::ContractEventsV0(ConfigSettingContractEventsV0 {
+            tx_max_contract_events_size_bytes: 16_384,
+            fee_contract_events1_kb: 10_000,
+        }),
+        ConfigSettingEntry::ContractBandwidthV0(ConfigSettingContractBandwidthV0 {
+            ledger_max_txs_size_bytes: 133_120,
+            tx_max_size_bytes: 132_096,
+            fee_tx_size1_kb: 1624,
+        }),
+        ConfigSettingEntry::ContractDataKeySizeBytes(250),
+        ConfigSettingEntry::ContractDataEntrySizeBytes(65_536),
+        ConfigSettingEntry::ContractMaxSizeBytes(131_072),
+        ConfigSettingEntry::StateArchival(StateArchivalSettings {
+            max_entry_ttl: 3_110_400,
+            min_temporary_ttl: 17_280,
+            min_persistent_ttl: 2_073_600,
+            persistent_rent_rate_denominator: 1402,
+            temp_rent_rate_denominator: 2804,
+            max_entries_to_archive: 1000,
+            live_soroban_state_size_window_sample_size: 30,
+            live_soroban_state_size_window_sample_period: 64,
+            eviction_scan_size: 100_000,
+            starting_eviction_scan_level: 7,
+        }),
+        ConfigSettingEntry::LiveSorobanStateSizeWindow(
+            std::vec![500_000_000, 700_000_000].try_into().unwrap()
+        ),
+        // Settings that are not fees or limits are ignored.
+        ConfigSettingEntry::ContractExecutionLanes(ConfigSettingContractExecutionLanesV0 {
+            ledger_max_tx_count: 100,
+        }),
+    ]
+}
+
+#[test]
+fn test_from_config_settings() {
+    let config = NetworkConfig::from_config_settings(&settings()).unwrap();
+    let fee_per_rent_1kb = compute_rent_write_fee_per_1kb(
+        600_000_000,
+        &RentWriteFeeConfiguration {
+            state_target_size_bytes: 3_000_000_000,
+            rent_fee_1kb_state_size_low: -17_000,
+            rent_fee_1kb_state_size_high: 10_000,
+            state_size_rent_fee_growth_factor: 5000,
+        },
+    );
+    assert_eq!(config.fee_per_instruction_increment, 25);
+    assert_eq!(config.fee_per_disk_read_entry, 6250);
+    assert_eq!(config.fee_per_write_entry, 10_000);
+    assert_eq!(config.fee_per_disk_read_1kb, 1786);
+    assert_eq!(config.fee_per_write_1kb, 3500);
+    assert_eq!(config.fee_per_historical_1kb, 16_235);
+    assert_eq!(config.fee_per_contract_event_1kb, 10_000);
+    assert_eq!(config.fee_per_transaction_size_1kb, 1624);
+    assert_eq!(config.fee_per_rent_1kb, fee_per_rent_1kb);
+    assert_eq!(config.persistent_rent_rate_denominator, 1402);
+    assert_eq!(config.temporary_rent_rate_denominator, 2804);
+    assert_eq!(config.limits.instructions, 100_000_000);
+    assert_eq!(config.limits.mem_bytes, 41_943_040);
+    assert_eq!(config.limits.disk_read_entries, 100);
+    assert_eq!(config.limits.write_entries, 50);
+    assert_eq!(config.limits.ledger_entries, 200);
+    assert_eq!(config.limits.disk_read_bytes, 200_000);
+    assert_eq!(config.limits.write_bytes, 132_096);
+    assert_eq!(config.limits.contract_events_size_bytes, 16_384);
+    assert_eq!(config.limits.max_contract_data_key_size_bytes, 250);
+    assert_eq!(config.limits.max_contract_data_entry_size_bytes, 65_536);
+    assert_eq!(config.limits.max_contract_code_entry_size_bytes, 131_072);
+}
+
+#[test]
+fn test_from_config_settings_missing() {
+    let settings = settings();
+    assert_eq!(
+        NetworkConfig::from_config_settings(&settings[1..10]),
+        Err(MissingConfigSettings(std::vec![
+            ConfigSettingId::ContractComputeV0,
+            ConfigSettingId::LiveSorobanStateSizeWindow,
+        ]))
+    );
+    assert_eq!(
+        NetworkConfig::from_config_settings(&settings[1..10])
+            .unwrap_err()
+            .to_string(),
+        "missing config settings: ContractComputeV0, LiveSorobanStateSizeWindow"
+    );
+}
+
+#[test]
+fn test_from_config_settings_empty_window() {
+    let mut settings = settings();
+    settings[10] = ConfigSettingEntry::LiveSorobanStateSizeWindow(VecM::default());
+    assert_eq!(
+        NetworkConfig::from_config_settings(&settings),
+        Err(MissingConfigSettings(std::vec![
+            ConfigSettingId::LiveSorobanStateSizeWindow
+        ]))
+    );
+}
+
+#[test]
+#[should_panic(expected = "LiveSorobanStateSizeWindow has no samples")]
+fn test_with_config_settings_empty_window() {
+    NetworkConfig::mainnet().with_config_settings(&[
+        ConfigSettingEntry::LiveSorobanStateSizeWindow(VecM::default()),
+    ]);
+}
+
+#[test]
+fn test_from_ledger_snapshot() {
+    let mut snapshot = LedgerSnapshot::default();
+    for setting in settings() {
+        let key = LedgerKey::ConfigSetting(LedgerKeyConfigSetting {
+            config_setting_id: setting.discriminant(),
+        });
+        let entry = LedgerEntry {
+            last_modified_ledger_seq: 0,
+            data: LedgerEntryData::ConfigSetting(setting),
+            ext: LedgerEntryExt::V0,
+        };
+        snapshot
+            .ledger_entries
+            .push((Box::new(key), (Box::new(entry), None)));
+    }
+    assert_eq!(
+        NetworkConfig::from_ledger_snapshot(&snapshot),
+        NetworkConfig::from_config_settings(&settings())
+    );
+    assert!(NetworkConfig::from_ledger_snapshot(&LedgerSnapshot::default()).is_err());
+}
+
+#[test]
+fn test_fee_uses_network_config() {
+    let env = env();
+    env.cost_estimate().enable_footprint();
+    let id = env.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&env, &id);
+    client.put(&symbol_short!("k1"), &symbol_short!("v1"));
+
+    let cost_estimate = env.cost_estimate();
+    assert_eq!(cost_estimate.network_config(), NetworkConfig::mainnet());
+    let mainnet_fee = cost_estimate.fee();
+
+    let config = NetworkConfig::from_config_settings(&settings()).unwrap();
+    cost_estimate.set_network_config(config.clone());
+    assert_eq!(env.cost_estimate().network_config(), config);
+    // The fees of the last invocation are priced with the new config.
+    let fee = env.cost_estimate().fee();
+    assert!(fee.instructions > mainnet_fee.instructions);
+    assert!(fee.write_entries > mainnet_fee.write_entries);
+    assert_ne!(fee.persistent_entry_rent, mainnet_fee.persistent_entry_rent);
+    assert_eq!(
+        env.cost_estimate().footprint().rent_fee(),
+        fee.persistent_entry_rent
+    );
+}
+
+#[test]
+fn test_what_if_config_settings() {
+    let env = env();
+    let id = env.register(contract_data::WASM, ());
+    contract_data::Client::new(&env, &id).put(&symbol_short!("k1"), &symbol_short!("v1"));
+    let mainnet_fee = env.cost_estimate().fee();
+
+    // A vote doubling the instructions fee only changes the instructions fee.
+    let mainnet = NetworkConfig::mainnet();
+    let doubled = mainnet
+        .clone()
+        .with_config_settings(&[compute(2 * mainnet.fee_per_instruction_increment)]);
+    assert_eq!(
+        doubled.fee_per_instruction_increment,
+        2 * mainnet.fee_per_instruction_increment
+    );
+    assert_eq!(doubled.fee_per_rent_1kb, mainnet.fee_per_rent_1kb);
+    env.cost_estimate().set_network_config(doubled);
+    let fee = env.cost_estimate().fee();
+    assert!(fee.instructions >= 2 * mainnet_fee.instructions - 1);
+    assert_eq!(fee.write_entries, mainnet_fee.write_entries);
+    assert_eq!(fee.persistent_entry_rent, mainnet_fee.persistent_entry_rent);
+}
+
+#[test]
+fn test_read_file() {
+    let dir =
+        std::env::temp_dir().join(format!("soroban-sdk-network-config-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let p = dir.join("upgrade.json");
+    let upgrade = ConfigUpgradeSet {
+        updated_entry: settings().try_into().unwrap(),
+    };
+    std::fs::write(&p, serde_json::to_string(&upgrade).unwrap()).unwrap();
+    assert_eq!(
+        NetworkConfig::read_file(&p).unwrap(),
+        NetworkConfig::from_config_settings(&settings()).unwrap()
+    );
+
+    let p = dir.join("entries.json");
+    std::fs::write(&p, serde_json::to_string(&settings()[..1]).unwrap()).unwrap();
+    let err = NetworkConfig::read_file(&p).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        NetworkConfig::mainnet()
+            .with_config_settings_file(&p)
+            .unwrap()
+            .fee_per_instruction_increment,
+        25
+    );
+
+    let p = dir.join("empty_window.json");
+    let entries = [ConfigSettingEntry::LiveSorobanStateSizeWindow(
+        VecM::default(),
+    )];
+    std::fs::write(&p, serde_json::to_string(&entries).unwrap()).unwrap();
+    let err = NetworkConfig::mainnet()
+        .with_config_settings_file(&p)
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,275 @@
+//! Check that the native proptest strategies generate prototypes that convert
+//! to contract values, and that failing values shrink structurally.
+
+use crate as soroban_sdk;
+use crate::crypto::bls12_381::{Bls12381Fp, Bls12381G1Affine};
+use crate::spec_shaking::SpecShakingMarker;
+use crate::testutils::arbitrary::arbitrary::{self, Arbitrary, Unstructured};
+use crate::testutils::arbitrary::SorobanArbitrary;
+use crate::testutils::proptest::{prototype, PrototypeStrategy};
+use crate::xdr::{ContractId, Hash, ScAddress, ScVal};
+use crate::{
+    contracttype, Address, Bytes, BytesN, ConversionError, Duration, Env, Error, IntoVal, Map,
+    MuxedAddress, String, Symbol, Timepoint, TryFromVal, Val, Vec, I256, U256,
+};
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestError, TestRunner};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Claim {
+    pub claimant: Address,
+    pub amounts: Vec<u32>,
+    pub kind: Kind,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    Unit,
+    Tuple(u32, Symbol),
+    Nested(Claim2),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Claim2(pub u64, pub Option<i128>);
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Level {
+    Low = 1,
+    High = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Node {
+    pub value: u32,
+    pub children: Vec<Node>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Tree {
+    Leaf(u32),
+    Branch(Vec<Tree>),
+}
+
+/// A type with a hand-written `SorobanArbitrary` implementation, whose
+/// prototype has no native strategy.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Celsius(pub i32);
+
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ArbitraryCelsius(i32);
+
+impl<'a> Arbitrary<'a> for ArbitraryCelsius {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ArbitraryCelsius(u.int_in_range(-50..=50)?))
+    }
+}
+
+impl SpecShakingMarker for Celsius {}
+
+impl TryFrom<&Celsius> for ScVal {
+    type Error = crate::xdr::Error;
+    fn try_from(v: &Celsius) -> Result<Self, crate::xdr::Error> {
+        Ok(ScVal::I32(v.0))
+    }
+}
+
+impl SorobanArbitrary for Celsius {
+    type Prototype = ArbitraryCelsius;
+}
+
+impl TryFromVal<Env, ArbitraryCelsius> for Celsius {
+    type Error = ConversionError;
+    fn try_from_val(_env: &Env, v: &ArbitraryCelsius) -> Result<Self, Self::Error> {
+        Ok(Celsius(v.0))
+    }
+}
+
+impl TryFromVal<Env, Val> for Celsius {
+    type Error = ConversionError;
+    fn try_from_val(env: &Env, v: &Val) -> Result<Self, Self::Error> {
+        Ok(Celsius(i32::try_from_val(env, v)?))
+    }
+}
+
+impl TryFromVal<Env, Celsius> for Val {
+    type Error = ConversionError;
+    fn try_from_val(env: &Env, v: &Celsius) -> Result<Self, Self::Error> {
+        Ok(v.0.into_val(env))
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reading {
+    pub at: u64,
+    pub temp: Celsius,
+}
+
+fn runner() -> TestRunner {
+    TestRunner::new(Config {
+        cases: 32,
+        max_shrink_iters: 4096,
+        failure_persistence: None,
+        ..Config::default()
+    })
+}
+
+fn run_test<T>()
+where
+    T: SorobanArbitrary,
+    T::Prototype: PrototypeStrategy,
+{
+    let env = Env::default();
+    runner()
+        .run(&prototype::<T>(), |proto| {
+            let _val: T = proto.into_val(&env);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_strategies_convert() {
+    run_test::<()>();
+    run_test::<bool>();
+    run_test::<u32>();
+    run_test::<i32>();
+    run_test::<u64>();
+    run_test::<i64>();
+    run_test::<u128>();
+    run_test::<i128>();
+    run_test::<U256>();
+    run_test::<I256>();
+    run_test::<Error>();
+    run_test::<Bytes>();
+    run_test::<BytesN<32>>();
+    run_test::<String>();
+    run_test::<Symbol>();
+    run_test::<Vec<u32>>();
+    run_test::<Map<Symbol, Option<u32>>>();
+    run_test::<Address>();
+    run_test::<MuxedAddress>();
+    run_test::<Timepoint>();
+    run_test::<Duration>();
+    run_test::<Bls12381Fp>();
+    run_test::<Bls12381G1Affine>();
+    run_test::<(u32, Address, Bytes)>();
+    run_test::<Val>();
+    run_test::<Claim>();
+    run_test::<Kind>();
+    run_test::<Claim2>();
+    run_test::<Level>();
+}
+
+#[test]
+fn test_vec_shrinks_to_shortest_with_smallest_elements() {
+    let env = Env::default();
+    let result = runner().run(&prototype::<Vec<u32>>(), |proto| {
+        let v: Vec<u32> = proto.into_val(&env);
+        prop_assert!(v.iter().all(|n| n < 100));
+        Ok(())
+    });
+    let Err(TestError::Fail(_, proto)) = result else {
+        panic!("expected failure, got {result:?}");
+    };
+    let v: Vec<u32> = proto.into_val(&env);
+    assert_eq!(v, crate::vec![&env, 100]);
+}
+
+#[test]
+fn test_contracttype_struct_shrinks_fields() {
+    let env = Env::default();
+    let result = runner().run(&prototype::<Claim>(), |proto| {
+        let claim: Claim = proto.into_val(&env);
+        prop_assert!(claim.amounts.len() < 2);
+        Ok(())
+    });
+    let Err(TestError::Fail(_, proto)) = result else {
+        panic!("expected failure, got {result:?}");
+    };
+    let claim: Claim = proto.into_val(&env);
+    assert_eq!(claim.amounts, crate::vec![&env, 0, 0]);
+    assert_eq!(claim.kind, Kind::Unit);
+    let zero: Address =
+        ScVal::Address(ScAddress::Contract(ContractId(Hash([0; 32])))).into_val(&env);
+    assert_eq!(claim.claimant, zero);
+}
+
+#[test]
+fn test_contracttype_enum_shrinks_to_earlier_variants() {
+    let env = Env::default();
+    let result = runner().run(&prototype::<Kind>(), |proto| {
+        let kind: Kind = proto.into_val(&env);
+        prop_assert_eq!(kind, Kind::Unit);
+        Ok(())
+    });
+    let Err(TestError::Fail(_, proto)) = result else {
+        panic!("expected failure, got {result:?}");
+    };
+    let kind: Kind = proto.into_val(&env);
+    assert_eq!(kind, Kind::Tuple(0, Symbol::new(&env, "")));
+}
+
+fn depth(node: &Node) -> u32 {
+    1 + node.children.iter().map(|c| depth(&c)).max().unwrap_or(0)
+}
+
+#[test]
+fn test_recursive_contracttype() {
+    run_test::<Node>();
+    run_test::<Tree>();
+
+    let env = Env::default();
+    runner()
+        .run(&prototype::<Node>(), |proto| {
+            let node: Node = proto.into_val(&env);
+            prop_assert!(depth(&node) <= 6);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_recursive_contracttype_shrinks() {
+    let env = Env::default();
+    let result = runner().run(&prototype::<Node>(), |proto| {
+        let node: Node = proto.into_val(&env);
+        prop_assert!(depth(&node) < 2);
+        Ok(())
+    });
+    let Err(TestError::Fail(_, proto)) = result else {
+        panic!("expected failure, got {result:?}");
+    };
+    let node: Node = proto.into_val(&env);
+    let leaf = Node {
+        value: 0,
+        children: Vec::new(&env),
+    };
+    assert_eq!(
+        node,
+        Node {
+            value: 0,
+            children: crate::vec![&env, leaf],
+        }
+    );
+}
+
+#[test]
+fn test_contracttype_with_hand_written_arbitrary_field() {
+    // The prototype of Celsius has no native strategy, so the field is
+    // generated from bytes with its Arbitrary impl.
+    let env = Env::default();
+    runner()
+        .run(&prototype::<Reading>(), |proto| {
+            let reading: Reading = proto.into_val(&env);
+            prop_assert!((-50..=50).contains(&reading.temp.0));
+            Ok(())
+        })
+        .unwrap();
+}
@@ -0,0 +1,121 @@
+use crate as soroban_sdk;
+use crate::env::{check_test_snapshot_file, check_test_snapshots_with};
+use soroban_sdk::{contract, contractimpl, testutils::EnvTestConfig, xdr, Env};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn set(env: Env, k: u32, v: u32) {
+        env.storage().persistent().set(&k, &v);
+    }
+}
+
+fn env() -> Env {
+    Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    })
+}
+
+fn path(dir: &tempfile::TempDir, name: &str) -> std::path::PathBuf {
+    dir.path().join(format!("{name}.json"))
+}
+
+#[test]
+fn test_check_matching_snapshot() {
+    let e = env();
+    let id = e.register(Contract, ());
+    ContractClient::new(&e, &id).set(&1, &2);
+
+    let dir = tempfile::tempdir().unwrap();
+    let p = path(&dir, "matching");
+    e.to_snapshot().write_file(&p).unwrap();
+    assert_eq!(check_test_snapshot_file(&e.to_snapshot(), &p), Ok(()));
+}
+
+#[test]
+fn test_check_missing_snapshot() {
+    let e = env();
+    let id = e.register(Contract, ());
+    ContractClient::new(&e, &id).set(&1, &2);
+
+    let dir = tempfile::tempdir().unwrap();
+    let err = check_test_snapshot_file(&e.to_snapshot(), &path(&dir, "missing")).unwrap_err();
+    assert!(err.contains("does not exist"));
+    assert!(err.contains("SOROBAN_UPDATE_SNAPSHOTS=1"));
+}
+
+#[test]
+fn test_check_empty_snapshot() {
+    let e = env();
+    let dir = tempfile::tempdir().unwrap();
+    let p = path(&dir, "empty");
+    assert_eq!(check_test_snapshot_file(&e.to_snapshot(), &p), Ok(()));
+
+    // A file left from when the snapshot was not empty is stale.
+    std::fs::write(&p, "{}").unwrap();
+    let err = check_test_snapshot_file(&e.to_snapshot(), &p).unwrap_err();
+    assert!(err.contains("test snapshot is empty"));
+    assert!(err.contains("exists, delete it"));
+}
+
+#[test]
+fn test_check_unreadable_snapshot() {
+    let e = env();
+    let id = e.register(Contract, ());
+    ContractClient::new(&e, &id).set(&1, &2);
+
+    let dir = tempfile::tempdir().unwrap();
+    let p = path(&dir, "unreadable");
+    std::fs::write(&p, "{").unwrap();
+    let err = check_test_snapshot_file(&e.to_snapshot(), &p).unwrap_err();
+    assert!(err.contains("could not be read"));
+}
+
+#[test]
+fn test_check_differing_snapshot() {
+    let e = env();
+    let id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &id);
+    client.set(&1, &2);
+
+    let dir = tempfile::tempdir().unwrap();
+    let p = path(&dir, "differing");
+    e.to_snapshot().write_file(&p).unwrap();
+    client.set(&1, &3);
+
+    let err = check_test_snapshot_file(&e.to_snapshot(), &p).unwrap_err();
+    assert!(err.contains("test snapshot differs"));
+    assert!(err.contains("SOROBAN_UPDATE_SNAPSHOTS=1"));
+    // The differences are shown with the values decoded.
+    assert!(err.contains(&format!(
+        "~ ContractData({}, persistent, 1u32)",
+        xdr::ScAddress::from(&id)
+    )));
+    assert!(err.contains("    - 2u32\n    + 3u32\n"));
+}
+
+#[test]
+fn test_check_snapshots_gating() {
+    let checked = |vars: &[(&str, &str)]| {
+        check_test_snapshots_with(|name| {
+            vars.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| std::ffi::OsString::from(v))
+        })
+    };
+    assert!(!checked(&[]));
+    assert!(checked(&[("SOROBAN_CHECK_SNAPSHOTS", "1")]));
+    assert!(!checked(&[("SOROBAN_CHECK_SNAPSHOTS", "0")]));
+    assert!(!checked(&[("SOROBAN_CHECK_SNAPSHOTS", "true")]));
+    assert!(!checked(&[
+        ("SOROBAN_CHECK_SNAPSHOTS", "1"),
+        ("SOROBAN_UPDATE_SNAPSHOTS", "1")
+    ]));
+    assert!(checked(&[
+        ("SOROBAN_CHECK_SNAPSHOTS", "1"),
+        ("SOROBAN_UPDATE_SNAPSHOTS", "0")
+    ]));
+    assert!(!checked(&[("SOROBAN_UPDATE_SNAPSHOTS", "1")]));
+}
@@ -0,0 +1,123 @@
+use crate as soroban_sdk;
+use expect_test::expect;
+use soroban_sdk::{
+    contract, contractevent, contractimpl,
+    testutils::{
+        snapshot_diff::Change, Address as _, EnvTestConfig, Ledger as _, MockAuth, MockAuthInvoke,
+    },
+    Address, Env, IntoVal,
+};
+
+#[contractevent]
+pub struct Set {
+    #[topic]
+    pub k: u32,
+    pub v: u32,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn set(env: Env, auth: Address, k: u32, v: u32) {
+        auth.require_auth();
+        env.storage().persistent().set(&k, &v);
+        Set { k, v }.publish(&env);
+    }
+
+    pub fn remove(env: Env, k: u32) {
+        env.storage().persistent().remove(&k);
+    }
+}
+
+fn env() -> Env {
+    Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    })
+}
+
+#[test]
+fn test_diff_identical_is_empty() {
+    let e = env();
+    let id = e.register(Contract, ());
+    let auth = Address::generate(&e);
+    e.mock_all_auths();
+    ContractClient::new(&e, &id).set(&auth, &1, &1);
+
+    let snapshot = e.to_snapshot();
+    let diff = snapshot.diff(&snapshot);
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn test_diff() {
+    let e = env();
+    let id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &id);
+    let auth = Address::generate(&e);
+    e.mock_all_auths();
+    client.set(&auth, &1, &10);
+    client.set(&auth, &2, &20);
+    let before = e.to_snapshot();
+
+    e.ledger().set_sequence_number(5);
+    client.remove(&1);
+    client
+        .mock_auths(&[MockAuth {
+            address: &auth,
+            invoke: &MockAuthInvoke {
+                contract: &id,
+                fn_name: "set",
+                args: (&auth, 2u32, 21u32).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .set(&auth, &2, &21);
+    let after = e.to_snapshot();
+
+    let diff = before.diff(&after);
+    assert!(!diff.is_empty());
+    // A nonce was generated for the mocked auth.
+    assert!(diff.generators.is_some());
+    assert_eq!(diff.ledger.ledger_info.len(), 1);
+    assert_eq!(diff.ledger.removed.len(), 1);
+    assert_eq!(diff.ledger.modified.len(), 1);
+    // The mocked auth added the mock contract of the address and its nonce.
+    assert_eq!(diff.ledger.added.len(), 3);
+    // Auths are recorded for every invocation, so the auths of the new
+    // invocations are added.
+    assert!(matches!(
+        diff.auth[..],
+        [
+            Change::Added(3, _),
+            Change::Added(4, _),
+            Change::Added(5, _)
+        ]
+    ));
+    // Events are recorded for the last invocation only.
+    assert!(matches!(
+        diff.events[..],
+        [Change::Removed(0, _), Change::Added(0, _)]
+    ));
+
+    expect![[r#"
+        ~ generators: {"address":2,"nonce":0,"mux_id":0} -> {"address":2,"nonce":1,"mux_id":0}
+        ~ ledger info sequence_number: 0 -> 5
+        - ContractData(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM, persistent, 1u32) = 10u32 (live until 4095)
+        + ContractData(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4, persistent, LedgerKeyContractInstance) = ContractInstance(Wasm(74beb3d1766e5455fda0dc427f938b1490e614db4e1af37a884e05f94d87a7d6), {}) (live until 4100)
+        + ContractData(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4, temporary, LedgerKeyNonce(1)) = () (live until 6312004)
+        + ContractCode(74beb3d1766e5455fda0dc427f938b1490e614db4e1af37a884e05f94d87a7d6) = wasm (86 bytes) (live until 4100)
+        ~ ContractData(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM, persistent, 2u32)
+            - 20u32
+            + 21u32
+        + auth[3]: none
+        + auth[4]: none
+        + auth[5]:
+            CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4
+                CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM.set(CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4, 2u32, 21u32)
+        - event[0]: CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM [set, 2u32] = {v: 20u32}
+        + event[0]: CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM [set, 2u32] = {v: 21u32}
+    "#]].assert_eq(&diff.to_string());
+}
@@ -0,0 +1,274 @@
+use crate as soroban_sdk;
+use soroban_env_host::InvocationResourceLimits;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{
+        cost_estimate::NetworkInvocationResourceLimits, transaction::TransactionError,
+        Address as _, EnvTestConfig,
+    },
+    xdr::{ContractDataDurability, LedgerKey, LedgerKeyContractData, ScAddress, ScVal},
+    Address, BytesN, ContractExecutable, Env,
+};
+
+mod contract_data {
+    use crate as soroban_sdk;
+    soroban_sdk::contractimport!(file = "test_wasms/test_contract_data.wasm");
+}
+
+fn env() -> Env {
+    let env = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env.mock_all_auths();
+    env
+}
+
+/// Uploads the Wasm, deploys a contract from it and puts an entry, as the
+/// steps of the transaction.
+fn upload_deploy_put(
+    tx: &soroban_sdk::testutils::Transaction,
+    deployer: &Address,
+) -> Result<Address, TransactionError> {
+    let hash = tx.step(|env| env.deployer().upload_contract_wasm(contract_data::WASM))?;
+    let id = tx.step(|env| {
+        env.deployer()
+            .with_address(deployer.clone(), BytesN::from_array(env, &[0; 32]))
+            .deploy_contract(ContractExecutable::Wasm(hash), ())
+    })?;
+    tx.step(|env| {
+        contract_data::Client::new(env, &id).put(&symbol_short!("k1"), &symbol_short!("v1"))
+    })?;
+    Ok(id)
+}
+
+#[test]
+fn test_steps_commit_together() {
+    let env = env();
+    let deployer = Address::generate(&env);
+
+    let tx = env.transaction();
+    let id = tx.run(|tx| upload_deploy_put(tx, &deployer)).unwrap();
+    let client = contract_data::Client::new(&env, &id);
+    assert_eq!(client.get(&symbol_short!("k1")), Some(symbol_short!("v1")));
+
+    // The footprint has the code uploaded, the instance deployed and the
+    // entry put, all written.
+    let footprint = tx.footprint();
+    let written = footprint.read_write_keys();
+    let data_key = |key: ScVal| {
+        LedgerKey::ContractData(LedgerKeyContractData {
+            contract: ScAddress::from(&id),
+            key,
+            durability: ContractDataDurability::Persistent,
+        })
+    };
+    assert!(written
+        .iter()
+        .any(|k| matches!(k, LedgerKey::ContractCode(_))));
+    assert!(written.contains(&data_key(ScVal::LedgerKeyContractInstance)));
+    assert!(written.contains(&data_key(symbol_short!("k1").into())));
+    assert!(footprint.rent_fee() > 0);
+
+    // The resources are the resources of the steps added up, the last of
+    // which is the put.
+    let put = env.cost_estimate().resources();
+    let resources = tx.resources();
+    assert!(resources.instructions > put.instructions);
+    assert!(resources.write_entries >= 3);
+}
+
+#[test]
+fn test_failed_step_rolls_back_all_steps() {
+    let env = env();
+    let deployer = Address::generate(&env);
+
+    let tx = env.transaction();
+    let result = tx.run(|tx| {
+        let id = upload_deploy_put(tx, &deployer)?;
+        tx.step(|env| {
+            env.deployer()
+                .with_address(deployer.clone(), BytesN::from_array(env, &[1; 32]))
+                .deploy_contract(
+                    ContractExecutable::Wasm(BytesN::from_array(env, &[2; 32])),
+                    (),
+                )
+        })?;
+        Ok(id)
+    });
+    assert!(matches!(
+        result,
+        Err(TransactionError::StepFailed { step: 3, .. })
+    ));
+
+    // The contract deployed by the transaction is rolled back with the other
+    // steps, so the transaction can deploy it again.
+    let id = env
+        .deployer()
+        .with_address(deployer.clone(), BytesN::from_array(&env, &[0; 32]))
+        .deployed_address();
+    let client = contract_data::Client::new(&env, &id);
+    assert!(client.try_get(&symbol_short!("k1")).is_err());
+    assert_eq!(
+        env.transaction().run(|tx| upload_deploy_put(tx, &deployer)),
+        Ok(id)
+    );
+}
+
+#[test]
+fn test_failure_ignored_by_the_steps_still_rolls_back() {
+    let env = env();
+    let deployer = Address::generate(&env);
+
+    let tx = env.transaction();
+    let result = tx.run(|tx| {
+        let id = upload_deploy_put(tx, &deployer)?;
+        let missing = Address::generate(&env);
+        let failed =
+            tx.step(|env| contract_data::Client::new(env, &missing).del(&symbol_short!("k1")));
+        assert!(failed.is_err());
+        // Steps after a failure are not run.
+        assert_eq!(tx.step(|_| unreachable!()), failed);
+        Ok(id)
+    });
+    assert!(matches!(
+        result,
+        Err(TransactionError::StepFailed { step: 3, .. })
+    ));
+}
+
+#[test]
+fn test_limits_are_shared_by_the_steps() {
+    let env = env();
+    let deployer = Address::generate(&env);
+    let limits = InvocationResourceLimits {
+        write_entries: 2,
+        ..InvocationResourceLimits::mainnet()
+    };
+
+    // Each step writes less than the limit, but the upload and deploy together
+    // write more.
+    let tx = env.transaction().limits(limits);
+    let result = tx.run(|tx| upload_deploy_put(tx, &deployer));
+    assert_eq!(
+        result,
+        Err(TransactionError::LimitsExceeded {
+            step: 1,
+            exceeded: std::vec!["write ledger entries: 3 > 2".into()],
+        })
+    );
+    assert_eq!(
+        env.transaction()
+            .disable_limits()
+            .run(|tx| upload_deploy_put(tx, &deployer))
+            .map(|_| ()),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_entries_accessed_by_several_steps_count_once() {
+    let env = env();
+    let id = env.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&env, &id);
+    client.put(&symbol_short!("k1"), &symbol_short!("v1"));
+
+    let tx = env.transaction();
+    tx.run(|tx| tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("v2"))))
+        .unwrap();
+    let r = tx.resources();
+    let entries = r.disk_read_entries + r.memory_read_entries + r.write_entries;
+    let limits = InvocationResourceLimits {
+        ledger_entries: entries,
+        ..InvocationResourceLimits::mainnet()
+    };
+
+    // The steps access the same entries, which fit the limit once, although
+    // the entries of the steps added up do not.
+    let tx = env.transaction().limits(limits);
+    let result = tx.run(|tx| {
+        tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("v3")))?;
+        tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("v4")))
+    });
+    assert_eq!(result, Ok(()));
+    let r = tx.resources();
+    assert!(r.disk_read_entries + r.memory_read_entries + r.write_entries > entries);
+}
+
+#[test]
+fn test_entries_written_by_several_steps_count_once() {
+    let env = env();
+    let id = env.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&env, &id);
+    client.put(&symbol_short!("k1"), &symbol_short!("v1"));
+
+    let tx = env.transaction();
+    tx.run(|tx| tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("v2"))))
+        .unwrap();
+    let r = tx.resources();
+    let limits = InvocationResourceLimits {
+        write_entries: r.write_entries,
+        write_bytes: r.write_bytes,
+        ..InvocationResourceLimits::mainnet()
+    };
+
+    // The steps write the same entry, with values of the same size, which
+    // fits the limits once, although the writes of the steps added up do not.
+    let tx = env.transaction().limits(limits.clone());
+    let result = tx.run(|tx| {
+        tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("v3")))?;
+        tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("v4")))
+    });
+    assert_eq!(result, Ok(()));
+    assert!(tx.resources().write_entries > r.write_entries);
+    assert!(tx.resources().write_bytes > r.write_bytes);
+
+    // A larger value written by the last step exceeds the write bytes.
+    let tx = env.transaction().limits(limits);
+    let result = tx.run(|tx| {
+        tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("v5")))?;
+        tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("longer")))
+    });
+    assert!(matches!(
+        result,
+        Err(TransactionError::LimitsExceeded { step: 1, ref exceeded })
+            if exceeded.len() == 1 && exceeded[0].starts_with("write bytes: ")
+    ));
+}
+
+#[test]
+fn test_step_without_invocation_adds_no_resources() {
+    let env = env();
+    let id = env.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&env, &id);
+
+    let tx = env.transaction();
+    tx.run(|tx| {
+        tx.step(|_| client.put(&symbol_short!("k1"), &symbol_short!("v1")))?;
+        let resources = tx.resources();
+        tx.step(|_| ())?;
+        assert_eq!(tx.resources(), resources);
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_step_without_invocation_adds_no_footprint() {
+    let env = env();
+    let id = env.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&env, &id);
+    client.put(&symbol_short!("k1"), &symbol_short!("v1"));
+
+    // The trace of the invocation made before the transaction is not of the
+    // step.
+    let tx = env.transaction();
+    tx.run(|tx| tx.step(|_| ())).unwrap();
+    assert_eq!(tx.footprint(), Default::default());
+}
+
+#[test]
+#[should_panic(expected = "transaction steps can only be run inside Transaction::run")]
+fn test_step_outside_run() {
+    let env = env();
+    let _ = env.transaction().step(|_| ());
+}
@@ -5,23 +5,50 @@
 
 pub mod arbitrary;
 
+pub mod fuzz;
+
 pub mod proptest;
 
+pub mod state_machine;
+
 mod sign;
 use std::{fmt::Debug, rc::Rc};
 
-pub use sign::ed25519;
+pub use sign::{ed25519, secp256k1, secp256r1};
 
 mod mock_auth;
+pub(crate) use mock_auth::mock_auth_entry;
 pub use mock_auth::{
-    AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthContract, MockAuthInvoke,
+    AuthMismatch, AuthorizedFunction, AuthorizedInvocation, ExpectedAuths, ExpectedInvocation,
+    MockAuth, MockAuthContract, MockAuthInvoke,
 };
 use soroban_env_host::{TryFromVal, TryIntoVal};
 
+mod keypair;
+pub use keypair::Keypair;
+
 pub mod storage;
 
 pub mod cost_estimate;
 
+pub mod coverage;
+
+pub mod trace;
+
+pub mod snapshot_diff;
+
+pub mod differential;
+pub use differential::Differential;
+
+pub mod mock_contract;
+pub use mock_contract::MockContract;
+
+pub mod fn_contract;
+pub use fn_contract::FnContract;
+
+pub mod transaction;
+pub use transaction::Transaction;
+
 use crate::{xdr, ConstructorArgs, Env, Val, Vec};
 use soroban_ledger_snapshot::LedgerSnapshot;
 
@@ -72,6 +99,56 @@ impl<'w> Register for &'w [u8] {
     }
 }
 
+/// A client for calling a contract, implemented by the clients generated by
+/// [`contractimpl`][crate::contractimpl],
+/// [`contractclient`][crate::contractclient] and
+/// [`contractimport`][crate::contractimport].
+pub trait ContractClient {
+    /// Create a client for calling the contract at the address.
+    fn new(env: &Env, address: &crate::Address) -> Self;
+
+    /// Returns the spec of the functions the client calls.
+    ///
+    /// Functions that start with `__`, which cannot be called with a client,
+    /// are not included. Argument and result types that have no spec type are
+    /// given as `Val`.
+    fn spec() -> std::vec::Vec<xdr::ScSpecEntry> {
+        Self::client_spec().entries()
+    }
+
+    #[doc(hidden)]
+    fn client_spec() -> &'static ClientSpec;
+}
+
+/// The spec of the functions of a client, registered by the code generated
+/// for each block of the client's functions.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct ClientSpec(std::sync::Mutex<std::vec::Vec<&'static [u8]>>);
+
+impl ClientSpec {
+    pub const fn new() -> Self {
+        Self(std::sync::Mutex::new(std::vec::Vec::new()))
+    }
+
+    pub fn register(&self, spec_xdr: &'static [u8]) {
+        self.0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(spec_xdr);
+    }
+
+    pub fn entries(&self) -> std::vec::Vec<xdr::ScSpecEntry> {
+        use xdr::ReadXdr as _;
+        self.0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .map(|spec_xdr| xdr::ScSpecEntry::from_xdr(spec_xdr, xdr::Limits::none()).unwrap())
+            .collect()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Snapshot {
@@ -204,6 +281,16 @@ pub struct Generators {
     // generator is omitted from snapshots when unused.
     #[serde(default, skip_serializing_if = "is_zero")]
     wasm_hash: u64,
+    // Generated keypairs are less common than the other generated values, so
+    // the generator is omitted from snapshots when unused.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    secret_key: u64,
+    // The seed and random generator are only set for Envs created with
+    // [`Env::new_with_seed`], so are omitted from snapshots when unused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    random: u64,
 }
 
 fn is_zero(v: &u64) -> bool {
@@ -217,6 +304,9 @@ impl Default for Generators {
             nonce: 0,
             mux_id: 0,
             wasm_hash: 0,
+            secret_key: 0,
+            seed: None,
+            random: 0,
         }
     }
 }
@@ -251,8 +341,58 @@ impl Generators {
 }
 
 impl Generators {
+    /// Create generators that derive every generated value from the seed.
+    pub(crate) fn with_seed(seed: u64) -> Generators {
+        Generators {
+            seed: Some(seed),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the seed the generators were created with, if any.
+    pub(crate) fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Derive 32 bytes for the n-th value of a kind from the seed.
+    fn derive(seed: u64, kind: &str, n: u64) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        hasher.update(seed.to_be_bytes());
+        hasher.update(kind.as_bytes());
+        hasher.update(n.to_be_bytes());
+        hasher.finalize().into()
+    }
+
+    /// Returns the seed of the host's base PRNG. Envs without a seed use the
+    /// zero seed.
+    pub(crate) fn prng_seed(&self) -> [u8; 32] {
+        match self.seed {
+            Some(seed) => Self::derive(seed, "prng", 0),
+            None => [0; 32],
+        }
+    }
+
+    /// Fill the buffer with random bytes, derived from the seed if the
+    /// generators have one, otherwise from the thread's random generator.
+    pub(crate) fn fill_random(&mut self, buf: &mut [u8]) {
+        let Some(seed) = self.seed else {
+            use rand::RngCore;
+            rand::thread_rng().fill_bytes(buf);
+            return;
+        };
+        for chunk in buf.chunks_mut(32) {
+            self.random = self.random.checked_add(1).unwrap();
+            let b = Self::derive(seed, "random", self.random);
+            chunk.copy_from_slice(&b[..chunk.len()]);
+        }
+    }
+
     pub fn address(&mut self) -> [u8; 32] {
         self.address = self.address.checked_add(1).unwrap();
+        if let Some(seed) = self.seed {
+            return Self::derive(seed, "address", self.address);
+        }
         let b: [u8; 8] = self.address.to_be_bytes();
         [
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, b[0], b[1],
@@ -260,18 +400,39 @@ impl Generators {
         ]
     }
 
+    /// Returns the secret key of a generated keypair.
+    ///
+    /// The secret keys are derived from their own stream, separate from the
+    /// generated addresses, so that a secret key is never a value the test
+    /// can observe elsewhere. Envs without a seed use the zero seed.
+    pub(crate) fn secret_key(&mut self) -> [u8; 32] {
+        self.secret_key = self.secret_key.checked_add(1).unwrap();
+        Self::derive(self.seed.unwrap_or(0), "secret_key", self.secret_key)
+    }
+
     pub fn nonce(&mut self) -> i64 {
         self.nonce = self.nonce.checked_add(1).unwrap();
+        if let Some(seed) = self.seed {
+            let b = Self::derive(seed, "nonce", self.nonce as u64);
+            return i64::from_be_bytes(b[..8].try_into().unwrap());
+        }
         self.nonce
     }
 
     pub fn mux_id(&mut self) -> u64 {
         self.mux_id = self.mux_id.checked_add(1).unwrap();
+        if let Some(seed) = self.seed {
+            let b = Self::derive(seed, "mux_id", self.mux_id);
+            return u64::from_be_bytes(b[..8].try_into().unwrap());
+        }
         self.mux_id
     }
 
     pub fn wasm_hash(&mut self) -> [u8; 32] {
         self.wasm_hash = self.wasm_hash.checked_add(1).unwrap();
+        if let Some(seed) = self.seed {
+            return Self::derive(seed, "wasm_hash", self.wasm_hash);
+        }
         let b: [u8; 8] = self.wasm_hash.to_be_bytes();
         [
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, b[0], b[1],
@@ -280,6 +441,56 @@ impl Generators {
     }
 }
 
+pub(crate) type EntryWithLiveUntil = (Rc<xdr::LedgerEntry>, Option<u32>);
+
+/// The archival state of an [Env]'s ledger.
+///
+/// See [`Env::archived_ledger_entries`] and [`Env::set_auto_restore`].
+pub(crate) struct Archival {
+    /// Whether archived entries are restored automatically when an invocation
+    /// accesses them.
+    pub(crate) auto_restore: bool,
+    /// The keys of the archived entries, and the ledger sequence number they
+    /// were found at. Keys of entries restored since are removed.
+    pub(crate) keys: Option<(u32, std::collections::BTreeSet<Rc<xdr::LedgerKey>>)>,
+    /// The keys of the entries of the snapshot the Env was created from that
+    /// expire, by their live until ledger. Indexed the first time entries
+    /// expire, since the snapshot does not change.
+    pub(crate) snapshot: Option<std::collections::BTreeMap<u32, std::vec::Vec<Rc<xdr::LedgerKey>>>>,
+}
+
+impl Default for Archival {
+    fn default() -> Self {
+        Self {
+            auto_restore: true,
+            keys: None,
+            snapshot: None,
+        }
+    }
+}
+
+/// A checkpoint of the state of an [Env], created with [`Env::checkpoint`] and
+/// restored with [`Env::rollback`].
+///
+/// The checkpoint captures the ledger entries and their TTLs, the ledger info,
+/// the generators, the authorization mode, the recorded authorizations, the
+/// events of the last invocation, and the position in the event journal.
+///
+/// A checkpoint can be rolled back to any number of times, which is useful for
+/// exploring multiple branches of a test from a common, possibly expensive,
+/// setup.
+#[derive(Clone)]
+pub struct Checkpoint {
+    pub(crate) env: std::rc::Weak<core::cell::RefCell<Generators>>,
+    pub(crate) entries: std::vec::Vec<(Rc<xdr::LedgerKey>, Option<EntryWithLiveUntil>)>,
+    pub(crate) ledger_info: LedgerInfo,
+    pub(crate) generators: Generators,
+    pub(crate) auth_manager: crate::env::internal::auth::AuthorizationManager,
+    pub(crate) auth_snapshot: AuthSnapshot,
+    pub(crate) events: crate::env::internal::events::Events,
+    pub(crate) invocations: u32,
+}
+
 #[doc(hidden)]
 pub type ContractFunctionF = dyn Send + Sync + Fn(Env, &[Val]) -> Val;
 #[doc(hidden)]
@@ -344,6 +555,42 @@ pub trait Ledger {
     fn with_mut<F>(&self, f: F)
     where
         F: FnMut(&mut LedgerInfo);
+
+    /// Advances the ledger by a number of ledgers, increasing the sequence
+    /// number by `ledgers` and the timestamp by `ledgers *
+    /// seconds_per_ledger`.
+    ///
+    /// Ledger entries that have a live until ledger before the new sequence
+    /// number expire:
+    ///  - Expired persistent entries, contract data and contract code, are
+    ///    archived. Archived entries stay in the ledger with their expired live
+    ///    until ledger, and are returned by
+    ///    [`Env::archived_ledger_entries`] until they are restored, either
+    ///    explicitly with [`restore`][Ledger::restore], or automatically when
+    ///    accessed unless disabled with [`Env::set_auto_restore`].
+    ///  - Expired temporary entries are deleted.
+    ///
+    /// Setting the sequence number directly with
+    /// [`set_sequence_number`][Ledger::set_sequence_number] archives expired
+    /// persistent entries in the same way, but leaves expired temporary entries
+    /// in the ledger, where they behave as if they do not exist.
+    ///
+    /// ### Panics
+    ///
+    /// If the sequence number or timestamp overflow.
+    fn advance(&self, ledgers: u32, seconds_per_ledger: u64);
+
+    /// Restores an archived ledger entry, mirroring the RestoreFootprint
+    /// operation.
+    ///
+    /// The entry becomes live until the current ledger plus the minimum
+    /// persistent entry TTL, including the current ledger.
+    ///
+    /// ### Panics
+    ///
+    /// If the entry is not archived, i.e. it is live, does not exist, or is not
+    /// a persistent entry.
+    fn restore(&self, key: &xdr::LedgerKey);
 }
 
 pub mod budget {
@@ -445,6 +692,20 @@ pub mod budget {
             self.0.get_tracker(cost_type).unwrap()
         }
 
+        /// Get the cost trackers of all the cost types, in the order of
+        /// [`ContractCostType::VARIANTS`].
+        ///
+        /// The trackers are global totals. Use
+        /// [`CostEstimate::profile`][crate::testutils::cost_estimate::CostEstimate::profile]
+        /// to attribute the costs to the contract functions that incurred
+        /// them.
+        pub fn trackers(&self) -> std::vec::Vec<(ContractCostType, CostTracker)> {
+            ContractCostType::VARIANTS
+                .iter()
+                .map(|t| (*t, self.tracker(*t)))
+                .collect()
+        }
+
         /// Print the budget costs and inputs to stdout.
         pub fn print(&self) {
             println!("{}", self.0);
@@ -483,6 +744,34 @@ impl ContractEvents {
             .collect();
         Self::new(&self.env, filtered_events)
     }
+
+    /// Returns the events that decode as the event type `T`, in the order
+    /// they were published. Events of other types are skipped.
+    ///
+    /// Events emitted by any contract are included. Use
+    /// [`filter_by_contract`][Self::filter_by_contract] first to only
+    /// include the events of one contract.
+    pub fn decoded<T: Event>(&self) -> std::vec::Vec<T> {
+        self.events
+            .iter()
+            .filter_map(|e| T::decode(&self.env, e).ok())
+            .collect()
+    }
+
+    /// Returns true if the event was published by any contract.
+    ///
+    /// The event is compared in its encoded form, so the comparison follows
+    /// the topics and data format of the event type.
+    pub fn contains(&self, event: &impl crate::Event) -> bool {
+        let Ok(data) = xdr::ScVal::try_from_val(&self.env, &event.data(&self.env)) else {
+            return false;
+        };
+        let body = xdr::ContractEventBody::V0(xdr::ContractEventV0 {
+            topics: event.topics(&self.env).into(),
+            data,
+        });
+        self.events.iter().any(|e| e.body == body)
+    }
 }
 
 impl Eq for ContractEvents {}
@@ -549,6 +838,270 @@ impl Debug for ContractEvents {
     }
 }
 
+/// The events published by a top level invocation, recorded in the
+/// [`EventJournal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// The index of the invocation among all top level invocations of the
+    /// Env, starting at zero.
+    ///
+    /// All invocations are counted, including invocations that failed,
+    /// invocations made before the journal was enabled, and the calls of
+    /// contract constructors made when registering contracts.
+    pub invocation: u32,
+    /// The contract called by the invocation, or `None` if the invocation was
+    /// not a contract call, such as [`Env::as_contract`].
+    pub contract: Option<xdr::ScAddress>,
+    /// The function called by the invocation, or `None` if the invocation was
+    /// not a contract call.
+    pub function: Option<xdr::ScSymbol>,
+    /// The contract events published by the invocation, excluding events of
+    /// sub-calls that failed.
+    pub events: std::vec::Vec<xdr::ContractEvent>,
+}
+
+#[derive(Default)]
+pub(crate) struct Journal {
+    pub(crate) enabled: bool,
+    pub(crate) invocations: u32,
+    entries: std::vec::Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// Records the events of the top level invocation that just finished.
+    ///
+    /// The events are only retrieved when the journal is enabled, because
+    /// retrieving them is charged to the shadow budget of the host.
+    pub(crate) fn record(
+        &mut self,
+        events: impl FnOnce() -> std::vec::Vec<crate::env::internal::events::HostEvent>,
+    ) {
+        let invocation = self.invocations;
+        self.invocations += 1;
+        if !self.enabled {
+            return;
+        }
+        let events = events();
+        let (contract, function) = match trace::root_call(&events) {
+            Some((_, _, false)) => return,
+            Some((contract, function, true)) => (Some(contract), Some(function)),
+            None => (None, None),
+        };
+        let events = events
+            .iter()
+            .filter(|e| {
+                !e.failed_call
+                    && e.event.type_ == xdr::ContractEventType::Contract
+                    && e.event.contract_id.is_some()
+            })
+            .map(|e| e.event.clone())
+            .collect();
+        self.entries.push(JournalEntry {
+            invocation,
+            contract,
+            function,
+            events,
+        });
+    }
+
+    /// Forgets the invocations from the invocation index given onwards.
+    pub(crate) fn truncate(&mut self, invocations: u32) {
+        self.invocations = invocations;
+        self.entries.retain(|e| e.invocation < invocations);
+    }
+}
+
+type Invariant = Rc<dyn Fn(&Env)>;
+
+/// The invariants added with [`Env::add_invariant`], with the order they were
+/// added in.
+#[derive(Default)]
+pub(crate) struct Invariants {
+    checks: std::vec::Vec<(u32, Invariant)>,
+    added: u32,
+    running: bool,
+}
+
+/// Guard for an invariant added with [`Env::add_invariant`], that removes the
+/// invariant when dropped.
+///
+/// The guard does not keep the Env alive, so the invariant, and the clients
+/// and addresses it captured, are dropped with the guard, and the Env is
+/// dropped once the test drops it too.
+#[must_use = "the invariant is removed when the guard is dropped"]
+pub struct InvariantGuard {
+    invariants: std::rc::Weak<core::cell::RefCell<Invariants>>,
+    id: u32,
+}
+
+impl Drop for InvariantGuard {
+    fn drop(&mut self) {
+        if let Some(invariants) = self.invariants.upgrade() {
+            let removed = {
+                let mut invariants = invariants.borrow_mut();
+                let i = invariants.checks.iter().position(|(id, _)| *id == self.id);
+                i.map(|i| invariants.checks.remove(i))
+            };
+            // The invariant may hold the last Env other than the test's, so it
+            // is dropped after the invariants are released.
+            drop(removed);
+        }
+    }
+}
+
+impl Invariants {
+    pub(crate) fn add(
+        invariants: &Rc<core::cell::RefCell<Self>>,
+        check: Invariant,
+    ) -> InvariantGuard {
+        let mut i = invariants.borrow_mut();
+        let id = i.added;
+        i.added += 1;
+        i.checks.push((id, check));
+        InvariantGuard {
+            invariants: Rc::downgrade(invariants),
+            id,
+        }
+    }
+
+    /// Runs the invariants after a top level invocation, panicking with the
+    /// description of the call if any of them panics.
+    ///
+    /// Invocations made by the invariants themselves do not run the
+    /// invariants again.
+    pub(crate) fn check(
+        invariants: &core::cell::RefCell<Self>,
+        env: &Env,
+        call: impl FnOnce() -> std::string::String,
+    ) {
+        let checks = {
+            let mut invariants = invariants.borrow_mut();
+            if invariants.running || invariants.checks.is_empty() {
+                return;
+            }
+            invariants.running = true;
+            invariants.checks.clone()
+        };
+        let failure = checks.iter().find_map(|(i, check)| {
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| check(env)))
+                .err()
+                .map(|payload| (i, payload))
+        });
+        invariants.borrow_mut().running = false;
+        if let Some((i, payload)) = failure {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| std::string::String::from(*s))
+                .or_else(|| payload.downcast_ref::<std::string::String>().cloned())
+                .unwrap_or_default();
+            panic!("invariant {i} broken after call {}: {message}", call());
+        }
+    }
+}
+
+/// Describes a contract call for [`Invariants::check`], as
+/// `contract.function(args..)`.
+pub(crate) fn describe_call(
+    env: &Env,
+    contract: &crate::Address,
+    func: &crate::Symbol,
+    args: &Vec<Val>,
+) -> std::string::String {
+    use soroban_ledger_snapshot::DisplayScVal;
+    let display = |v: &Val| match xdr::ScVal::try_from_val(env, v) {
+        Ok(v) => DisplayScVal(&v).to_string(),
+        Err(_) => std::string::String::from("?"),
+    };
+    let args = args
+        .iter()
+        .map(|a| display(&a))
+        .collect::<std::vec::Vec<_>>()
+        .join(", ");
+    std::format!(
+        "{}.{}({args})",
+        display(contract.as_val()),
+        func.to_string()
+    )
+}
+
+/// The events of every successful top level invocation of an [Env] since
+/// [`Env::enable_event_journal`] was called.
+///
+/// Returned by [`Env::event_journal`].
+#[derive(Clone)]
+pub struct EventJournal {
+    env: Env,
+    owner: std::rc::Weak<core::cell::RefCell<Generators>>,
+    entries: std::vec::Vec<JournalEntry>,
+}
+
+impl EventJournal {
+    pub(crate) fn new(
+        env: &Env,
+        owner: std::rc::Weak<core::cell::RefCell<Generators>>,
+        journal: &Journal,
+    ) -> Self {
+        EventJournal {
+            env: env.clone(),
+            owner,
+            entries: journal.entries.clone(),
+        }
+    }
+
+    /// Returns the recorded invocations, in the order they were made.
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Returns the events of all recorded invocations.
+    pub fn all(&self) -> ContractEvents {
+        self.events_where(|_| true)
+    }
+
+    /// Returns the events of the invocation with the index given.
+    ///
+    /// No events are returned if the invocation failed or was made before the
+    /// journal was enabled.
+    pub fn invocation(&self, invocation: u32) -> ContractEvents {
+        self.events_where(|e| e.invocation == invocation)
+    }
+
+    /// Returns the events of the invocation with the index given and all
+    /// invocations after it.
+    pub fn since_invocation(&self, invocation: u32) -> ContractEvents {
+        self.events_where(|e| e.invocation >= invocation)
+    }
+
+    /// Returns the events of the invocations made after the checkpoint was
+    /// created.
+    ///
+    /// ### Panics
+    ///
+    /// If the checkpoint was created by a different Env.
+    pub fn since(&self, checkpoint: &Checkpoint) -> ContractEvents {
+        if !checkpoint.env.ptr_eq(&self.owner) {
+            panic!("checkpoint was created by a different Env");
+        }
+        self.since_invocation(checkpoint.invocations)
+    }
+
+    fn events_where(&self, f: impl Fn(&JournalEntry) -> bool) -> ContractEvents {
+        let events = self
+            .entries
+            .iter()
+            .filter(|e| f(e))
+            .flat_map(|e| e.events.iter().cloned())
+            .collect();
+        ContractEvents::new(&self.env, events)
+    }
+}
+
+impl Debug for EventJournal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.entries)
+    }
+}
+
 /// Test utilities for [`Events`][crate::events::Events].
 pub trait Events {
     /// Returns all contract events that have been published by the last contract
@@ -563,6 +1116,89 @@ pub trait Events {
     fn all(&self) -> ContractEvents;
 }
 
+/// Test utilities for [`Event`][crate::Event].
+///
+/// Implemented for event types defined with the
+/// [`contractevent`][crate::contractevent] macro, except for event types with
+/// fields that are references.
+pub trait Event: crate::Event + Sized {
+    /// Decodes an event of this type from its XDR form.
+    ///
+    /// Returns an error if the event is not a contract event, its prefix
+    /// topics differ from the prefix topics of this type, or its topics or
+    /// data do not convert into the fields of this type.
+    fn decode(env: &Env, event: &xdr::ContractEvent) -> Result<Self, xdr::Error>;
+}
+
+/// Decodes the topics and data of an event into the values of the fields in
+/// the topics and the fields in the data, in the order of the names given.
+///
+/// Used by the [`contractevent`][crate::contractevent] macro to implement
+/// [`Event::decode`].
+#[doc(hidden)]
+pub fn decode_event(
+    env: &Env,
+    event: &xdr::ContractEvent,
+    prefix_topics: &[&str],
+    topic_count: usize,
+    data_format: xdr::ScSpecEventDataFormat,
+    data_names: &[&str],
+) -> Result<(std::vec::Vec<Val>, std::vec::Vec<Val>), xdr::Error> {
+    let to_val = |v: &xdr::ScVal| Val::try_from_val(env, v).map_err(|_| xdr::Error::Invalid);
+    if event.type_ != xdr::ContractEventType::Contract {
+        return Err(xdr::Error::Invalid);
+    }
+    let xdr::ContractEventBody::V0(body) = &event.body;
+
+    let topics = body.topics.as_slice();
+    if topics.len() != prefix_topics.len() + topic_count {
+        return Err(xdr::Error::Invalid);
+    }
+    for (topic, prefix) in topics.iter().zip(prefix_topics) {
+        match topic {
+            xdr::ScVal::Symbol(s) if s.as_vec() == prefix.as_bytes() => {}
+            _ => return Err(xdr::Error::Invalid),
+        }
+    }
+    let topics = topics[prefix_topics.len()..]
+        .iter()
+        .map(to_val)
+        .collect::<Result<_, _>>()?;
+
+    let data = match (data_format, &body.data) {
+        (xdr::ScSpecEventDataFormat::SingleValue, xdr::ScVal::Void) if data_names.is_empty() => {
+            std::vec::Vec::new()
+        }
+        (xdr::ScSpecEventDataFormat::SingleValue, v) if data_names.len() == 1 => {
+            std::vec![to_val(v)?]
+        }
+        (xdr::ScSpecEventDataFormat::Vec, xdr::ScVal::Vec(Some(v)))
+            if v.len() == data_names.len() =>
+        {
+            v.iter().map(to_val).collect::<Result<_, _>>()?
+        }
+        (xdr::ScSpecEventDataFormat::Map, xdr::ScVal::Map(Some(m))) => {
+            let is_key = |k: &xdr::ScVal, n: &str| matches!(k, xdr::ScVal::Symbol(s) if s.as_vec() == n.as_bytes());
+            if m.iter()
+                .any(|e| !data_names.iter().any(|n| is_key(&e.key, n)))
+            {
+                return Err(xdr::Error::Invalid);
+            }
+            // Fields missing from the map are void, which is how sparse maps
+            // encode fields that are none.
+            data_names
+                .iter()
+                .map(|n| match m.iter().find(|e| is_key(&e.key, n)) {
+                    Some(e) => to_val(&e.val),
+                    None => Ok(Val::VOID.to_val()),
+                })
+                .collect::<Result<_, _>>()?
+        }
+        _ => return Err(xdr::Error::Invalid),
+    };
+    Ok((topics, data))
+}
+
 /// Test utilities for [`Logs`][crate::logs::Logs].
 pub trait Logs {
     /// Returns all diagnostic events that have been logged.
@@ -581,11 +1217,13 @@ pub trait BytesN<const N: usize> {
 
 /// Generates an array of N random bytes.
 ///
+/// The bytes are derived from the seed of Envs created with
+/// [`Env::new_with_seed`], so are the same each time a test runs.
+///
 /// The value returned is not cryptographically secure.
-pub(crate) fn random<const N: usize>() -> [u8; N] {
-    use rand::RngCore;
+pub(crate) fn random<const N: usize>(env: &Env) -> [u8; N] {
     let mut arr = [0u8; N];
-    rand::thread_rng().fill_bytes(&mut arr);
+    env.with_generator(|mut g| g.fill_random(&mut arr));
     arr
 }
 
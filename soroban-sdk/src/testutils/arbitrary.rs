@@ -170,6 +170,58 @@
 //!     // fuzz the program based on the input
 //! });
 //! ```
+//!
+//!
+//! ## Constraining the fields of a custom contract type
+//!
+//! The prototypes derived for contract types generate any value of each
+//! field. When a contract rejects most of those values, such as amounts
+//! outside of a limit, the fields can be constrained with the `arbitrary`
+//! attribute, so that fuzzers spend their time on values the contract accepts:
+//!
+//! - `#[arbitrary(range = a..=b)]` generates an integer field in the inclusive
+//!   range.
+//! - `#[arbitrary(len = a..=b)]` generates a [`Vec`], [`Map`], [`Bytes`],
+//!   [`String`] or [`Symbol`] field with a length in the inclusive range, see
+//!   [`ArbitraryLen`].
+//! - `#[arbitrary(with = f)]` generates the field with a function, or closure,
+//!   `f: fn(&mut Unstructured) -> arbitrary::Result<Prototype>`, that returns
+//!   a prototype of the field.
+//!
+//! The attributes also constrain the [`proptest`] strategies derived for
+//! contract types.
+//!
+//! [`String`]: crate::String
+//!
+//! ```
+//! # macro_rules! fuzz_target {
+//! #     (|$data:ident: $dty: ty| $body:block) => { };
+//! # }
+//! use soroban_sdk::{contracttype, Address, Env, IntoVal, Vec};
+//! use soroban_sdk::testutils::arbitrary::arbitrary::{Result, Unstructured};
+//! use soroban_sdk::testutils::arbitrary::SorobanArbitrary;
+//!
+//! #[contracttype]
+//! pub struct Deposit {
+//!     pub from: Address,
+//!     #[arbitrary(range = 1..=1_000_000)]
+//!     pub amount: i128,
+//!     #[arbitrary(len = 1..=10)]
+//!     pub memos: Vec<u32>,
+//!     #[arbitrary(with = even)]
+//!     pub ledger: u32,
+//! }
+//!
+//! fn even(u: &mut Unstructured) -> Result<u32> {
+//!     Ok(u.int_in_range(0..=u32::MAX / 2)? * 2)
+//! }
+//!
+//! fuzz_target!(|input: <Deposit as SorobanArbitrary>::Prototype| {
+//!     let env = Env::default();
+//!     let deposit: Deposit = input.into_val(&env);
+//!     // fuzz the program based on the input
+//! });
+//! ```
 
 /// A reexport of the `arbitrary` crate.
 ///
@@ -231,6 +283,28 @@ mod api {
         // `SorobanArbitrary`, but it makes the purpose clear.
         type Prototype: for<'a> Arbitrary<'a>;
     }
+
+    /// A prototype of a collection that can be generated with a length in a
+    /// range.
+    ///
+    /// Used by the `#[arbitrary(len = ...)]` attribute on the fields of
+    /// [`contracttype`][crate::contracttype] types. Implemented for the
+    /// prototypes of [`Vec`][crate::Vec], [`Map`][crate::Map],
+    /// [`Bytes`][crate::Bytes], [`String`][crate::String] and
+    /// [`Symbol`][crate::Symbol].
+    pub trait ArbitraryLen: Sized {
+        /// Generates the prototype with a length in the range.
+        fn arbitrary_len(
+            u: &mut arbitrary::Unstructured<'_>,
+            len: core::ops::RangeInclusive<usize>,
+        ) -> arbitrary::Result<Self>;
+
+        /// Returns a strategy that generates the prototype with a length in
+        /// the range.
+        fn strategy_len(
+            len: core::ops::RangeInclusive<usize>,
+        ) -> proptest::strategy::BoxedStrategy<Self>;
+    }
 }
 
 /// Implementations of `soroban_sdk::testutils::arbitrary::api` for Rust scalar types.
@@ -279,6 +353,25 @@ mod scalars {
     impl SorobanArbitrary for i128 {
         type Prototype = i128;
     }
+
+    mod strategies {
+        use crate::testutils::proptest::PrototypeStrategy;
+        use proptest::prelude::*;
+
+        macro_rules! impl_strategy {
+            ($($ty: ty),+) => {
+                $(
+                    impl PrototypeStrategy for $ty {
+                        fn strategy() -> BoxedStrategy<Self> {
+                            any::<$ty>().boxed()
+                        }
+                    }
+                )+
+            };
+        }
+
+        impl_strategy!((), bool, u32, i32, u64, i64, u128, i128);
+    }
 }
 
 /// Implementations of `soroban_sdk::testutils::arbitrary::api` for Soroban types that do not
@@ -301,6 +394,32 @@ mod simple {
     impl SorobanArbitrary for Error {
         type Prototype = Error;
     }
+
+    mod strategies {
+        use super::Error;
+        use crate::testutils::proptest::PrototypeStrategy;
+        use crate::xdr::{ScErrorCode, ScErrorType};
+        use proptest::prelude::*;
+        use proptest::sample::select;
+
+        impl PrototypeStrategy for Error {
+            fn strategy() -> BoxedStrategy<Self> {
+                let non_contract_types = ScErrorType::VARIANTS
+                    .into_iter()
+                    .filter(|t| *t != ScErrorType::Contract)
+                    .collect::<std::vec::Vec<_>>();
+                prop_oneof![
+                    any::<u32>().prop_map(Error::from_contract_error),
+                    (
+                        select(non_contract_types),
+                        select(ScErrorCode::VARIANTS.to_vec())
+                    )
+                        .prop_map(|(t, c)| Error::from_type_and_code(t, c)),
+                ]
+                .boxed()
+            }
+        }
+    }
 }
 
 /// Implementations of `soroban_sdk::testutils::arbitrary::api` for Soroban types that do
@@ -343,7 +462,7 @@ mod objects {
     //////////////////////////////////
 
     #[derive(Arbitrary, Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
-    pub struct ArbitraryOption<T>(Option<T>);
+    pub struct ArbitraryOption<T>(pub(crate) Option<T>);
 
     impl<T> SorobanArbitrary for Option<T>
     where
@@ -472,6 +591,8 @@ mod objects {
 
     //////////////////////////////////
 
+    const SYMBOL_CHARS: &str = "_abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
     #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
     pub struct ArbitrarySymbol {
         s: RustString,
@@ -479,8 +600,7 @@ mod objects {
 
     impl<'a> Arbitrary<'a> for ArbitrarySymbol {
         fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<ArbitrarySymbol> {
-            let valid_chars = "_abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
-            let valid_chars = valid_chars.as_bytes();
+            let valid_chars = SYMBOL_CHARS.as_bytes();
             let mut chars = vec![];
             let len = u.int_in_range(0..=32)?;
             for _ in 0..len {
@@ -937,6 +1057,276 @@ mod objects {
             Ok(Bn254Fr::from_bytes(BytesN::from_array(env, &v.bytes)))
         }
     }
+
+    //////////////////////////////////
+
+    mod strategies {
+        use super::*;
+        use crate::testutils::proptest::{element_strategy, PrototypeStrategy, LEN};
+        use arbitrary::Arbitrary;
+        use core::ops::RangeInclusive;
+        use proptest::collection::vec;
+        use proptest::prelude::*;
+
+        impl<T: PrototypeStrategy> PrototypeStrategy for ArbitraryOption<T> {
+            fn strategy() -> BoxedStrategy<Self> {
+                match element_strategy(T::strategy) {
+                    Some(strategy) => proptest::option::of(strategy)
+                        .prop_map(ArbitraryOption)
+                        .boxed(),
+                    None => Just(ArbitraryOption(None)).boxed(),
+                }
+            }
+        }
+
+        impl PrototypeStrategy for ArbitraryU256 {
+            fn strategy() -> BoxedStrategy<Self> {
+                any::<(u64, u64, u64, u64)>()
+                    .prop_map(|parts| ArbitraryU256 { parts })
+                    .boxed()
+            }
+        }
+
+        impl PrototypeStrategy for ArbitraryI256 {
+            fn strategy() -> BoxedStrategy<Self> {
+                any::<(i64, u64, u64, u64)>()
+                    .prop_map(|parts| ArbitraryI256 { parts })
+                    .boxed()
+            }
+        }
+
+        impl PrototypeStrategy for ArbitraryBytes {
+            fn strategy() -> BoxedStrategy<Self> {
+                vec(any::<u8>(), LEN)
+                    .prop_map(|vec| ArbitraryBytes { vec })
+                    .boxed()
+            }
+        }
+
+        impl PrototypeStrategy for ArbitraryString {
+            fn strategy() -> BoxedStrategy<Self> {
+                vec(any::<char>(), LEN)
+                    .prop_map(|chars| ArbitraryString {
+                        inner: chars.into_iter().collect(),
+                    })
+                    .boxed()
+            }
+        }
+
+        impl<const N: usize> PrototypeStrategy for ArbitraryBytesN<N> {
+            fn strategy() -> BoxedStrategy<Self> {
+                any::<[u8; N]>()
+                    .prop_map(|array| ArbitraryBytesN { array })
+                    .boxed()
+            }
+        }
+
+        impl PrototypeStrategy for ArbitrarySymbol {
+            fn strategy() -> BoxedStrategy<Self> {
+                "[_a-zA-Z0-9]{0,32}"
+                    .prop_map(|s| ArbitrarySymbol { s })
+                    .boxed()
+            }
+        }
+
+        impl<T: PrototypeStrategy> PrototypeStrategy for ArbitraryVec<T> {
+            fn strategy() -> BoxedStrategy<Self> {
+                vec_strategy(LEN)
+            }
+        }
+
+        impl<K: PrototypeStrategy, V: PrototypeStrategy> PrototypeStrategy for ArbitraryMap<K, V> {
+            fn strategy() -> BoxedStrategy<Self> {
+                map_strategy(LEN)
+            }
+        }
+
+        fn vec_strategy<T: PrototypeStrategy>(
+            len: RangeInclusive<usize>,
+        ) -> BoxedStrategy<ArbitraryVec<T>> {
+            match element_strategy(T::strategy) {
+                Some(strategy) => vec(strategy, len).prop_map(ArbitraryVec::Good).boxed(),
+                None => Just(ArbitraryVec::Good(RustVec::new())).boxed(),
+            }
+        }
+
+        fn map_strategy<K: PrototypeStrategy, V: PrototypeStrategy>(
+            len: RangeInclusive<usize>,
+        ) -> BoxedStrategy<ArbitraryMap<K, V>> {
+            match element_strategy(|| (K::strategy(), V::strategy()).boxed()) {
+                Some(strategy) => vec(strategy, len).prop_map(ArbitraryMap::Good).boxed(),
+                None => Just(ArbitraryMap::Good(RustVec::new())).boxed(),
+            }
+        }
+
+        impl PrototypeStrategy for ArbitraryAddress {
+            fn strategy() -> BoxedStrategy<Self> {
+                any::<[u8; 32]>()
+                    .prop_map(|inner| ArbitraryAddress { inner })
+                    .boxed()
+            }
+        }
+
+        impl PrototypeStrategy for ArbitraryMuxedAddress {
+            fn strategy() -> BoxedStrategy<Self> {
+                prop_oneof![
+                    ArbitraryAddress::strategy().prop_map(ArbitraryMuxedAddress::Address),
+                    any::<([u8; 32], u64)>()
+                        .prop_map(|(ed25519, id)| ArbitraryMuxedAddress::Muxed { ed25519, id }),
+                ]
+                .boxed()
+            }
+        }
+
+        impl PrototypeStrategy for ArbitraryTimepoint {
+            fn strategy() -> BoxedStrategy<Self> {
+                any::<u64>()
+                    .prop_map(|inner| ArbitraryTimepoint { inner })
+                    .boxed()
+            }
+        }
+
+        impl PrototypeStrategy for ArbitraryDuration {
+            fn strategy() -> BoxedStrategy<Self> {
+                any::<u64>()
+                    .prop_map(|inner| ArbitraryDuration { inner })
+                    .boxed()
+            }
+        }
+
+        macro_rules! impl_bytes_strategy {
+            ($($name: ident, $size: expr;)+) => {
+                $(
+                    impl PrototypeStrategy for $name {
+                        fn strategy() -> BoxedStrategy<Self> {
+                            any::<[u8; $size]>()
+                                .prop_map(|bytes| $name { bytes })
+                                .boxed()
+                        }
+                    }
+                )+
+            };
+        }
+
+        impl<T> ArbitraryLen for ArbitraryVec<T>
+        where
+            T: for<'a> Arbitrary<'a> + PrototypeStrategy,
+        {
+            fn arbitrary_len(
+                u: &mut Unstructured<'_>,
+                len: RangeInclusive<usize>,
+            ) -> ArbitraryResult<Self> {
+                let len = u.int_in_range(len)?;
+                let vec = (0..len)
+                    .map(|_| T::arbitrary(u))
+                    .collect::<ArbitraryResult<_>>()?;
+                Ok(ArbitraryVec::Good(vec))
+            }
+
+            fn strategy_len(len: RangeInclusive<usize>) -> BoxedStrategy<Self> {
+                vec_strategy(len)
+            }
+        }
+
+        impl<K, V> ArbitraryLen for ArbitraryMap<K, V>
+        where
+            K: for<'a> Arbitrary<'a> + PrototypeStrategy,
+            V: for<'a> Arbitrary<'a> + PrototypeStrategy,
+        {
+            fn arbitrary_len(
+                u: &mut Unstructured<'_>,
+                len: RangeInclusive<usize>,
+            ) -> ArbitraryResult<Self> {
+                let len = u.int_in_range(len)?;
+                let vec = (0..len)
+                    .map(|_| Ok((K::arbitrary(u)?, V::arbitrary(u)?)))
+                    .collect::<ArbitraryResult<_>>()?;
+                Ok(ArbitraryMap::Good(vec))
+            }
+
+            fn strategy_len(len: RangeInclusive<usize>) -> BoxedStrategy<Self> {
+                map_strategy(len)
+            }
+        }
+
+        impl ArbitraryLen for ArbitraryBytes {
+            fn arbitrary_len(
+                u: &mut Unstructured<'_>,
+                len: RangeInclusive<usize>,
+            ) -> ArbitraryResult<Self> {
+                let len = u.int_in_range(len)?;
+                let vec = (0..len)
+                    .map(|_| u8::arbitrary(u))
+                    .collect::<ArbitraryResult<_>>()?;
+                Ok(ArbitraryBytes { vec })
+            }
+
+            fn strategy_len(len: RangeInclusive<usize>) -> BoxedStrategy<Self> {
+                vec(any::<u8>(), len)
+                    .prop_map(|vec| ArbitraryBytes { vec })
+                    .boxed()
+            }
+        }
+
+        impl ArbitraryLen for ArbitraryString {
+            fn arbitrary_len(
+                u: &mut Unstructured<'_>,
+                len: RangeInclusive<usize>,
+            ) -> ArbitraryResult<Self> {
+                let len = u.int_in_range(len)?;
+                let inner = (0..len)
+                    .map(|_| char::arbitrary(u))
+                    .collect::<ArbitraryResult<_>>()?;
+                Ok(ArbitraryString { inner })
+            }
+
+            fn strategy_len(len: RangeInclusive<usize>) -> BoxedStrategy<Self> {
+                vec(any::<char>(), len)
+                    .prop_map(|chars| ArbitraryString {
+                        inner: chars.into_iter().collect(),
+                    })
+                    .boxed()
+            }
+        }
+
+        /// Symbols are at most 32 characters, so lengths are limited to 32.
+        impl ArbitraryLen for ArbitrarySymbol {
+            fn arbitrary_len(
+                u: &mut Unstructured<'_>,
+                len: RangeInclusive<usize>,
+            ) -> ArbitraryResult<Self> {
+                let len = u.int_in_range(*len.start()..=(*len.end()).min(32))?;
+                let s = (0..len)
+                    .map(|_| u.choose(SYMBOL_CHARS.as_bytes()).map(|c| char::from(*c)))
+                    .collect::<ArbitraryResult<_>>()?;
+                Ok(ArbitrarySymbol { s })
+            }
+
+            fn strategy_len(len: RangeInclusive<usize>) -> BoxedStrategy<Self> {
+                let chars = SYMBOL_CHARS.chars().collect::<RustVec<_>>();
+                vec(
+                    proptest::sample::select(chars),
+                    *len.start()..=(*len.end()).min(32),
+                )
+                .prop_map(|chars| ArbitrarySymbol {
+                    s: chars.into_iter().collect(),
+                })
+                .boxed()
+            }
+        }
+
+        impl_bytes_strategy! {
+            ArbitraryBls12381Fp, FP_SERIALIZED_SIZE;
+            ArbitraryBls12381Fp2, FP2_SERIALIZED_SIZE;
+            ArbitraryBls12381G1Affine, G1_SERIALIZED_SIZE;
+            ArbitraryBls12381G2Affine, G2_SERIALIZED_SIZE;
+            ArbitraryBls12381Fr, 32;
+            ArbitraryBn254G1Affine, BN254_G1_SERIALIZED_SIZE;
+            ArbitraryBn254G2Affine, BN254_G2_SERIALIZED_SIZE;
+            ArbitraryBn254Fp, BN254_FP_SERIALIZED_SIZE;
+            ArbitraryBn254Fr, 32;
+        }
+    }
 }
 
 /// Implementations of `soroban_sdk::testutils::arbitrary::api` for tuples of Soroban types.
@@ -944,9 +1334,11 @@ mod objects {
 /// The implementation is similar to objects, but macroized.
 mod tuples {
     use super::api::*;
+    use crate::testutils::proptest::PrototypeStrategy;
     use crate::ConversionError;
     use crate::{Env, IntoVal, TryFromVal, TryIntoVal, Val};
     use arbitrary::Arbitrary;
+    use proptest::strategy::{BoxedStrategy, Strategy};
 
     macro_rules! impl_tuple {
         ($name: ident, $($ty: ident),+ ) => {
@@ -972,6 +1364,15 @@ mod tuples {
                     )*))
                 }
             }
+
+            impl<$($ty: PrototypeStrategy,)*> PrototypeStrategy for $name<$($ty,)*> {
+                #[allow(non_snake_case)] // naming values T1, etc.
+                fn strategy() -> BoxedStrategy<Self> {
+                    ($($ty::strategy(),)*)
+                        .prop_map(|($($ty,)*)| $name { $($ty,)* })
+                        .boxed()
+                }
+            }
         }
     }
 
@@ -1465,6 +1866,139 @@ mod composite {
             })
         }
     }
+
+    mod strategies {
+        use super::*;
+        use crate::testutils::proptest::PrototypeStrategy;
+        use proptest::prelude::*;
+
+        type Proto<T> = <T as SorobanArbitrary>::Prototype;
+
+        impl PrototypeStrategy for ArbitraryVal {
+            fn strategy() -> BoxedStrategy<Self> {
+                let leaf = prop_oneof![
+                    Just(ArbitraryVal::Void),
+                    any::<bool>().prop_map(ArbitraryVal::Bool),
+                    Error::strategy().prop_map(ArbitraryVal::Error),
+                    any::<u32>().prop_map(ArbitraryVal::U32),
+                    any::<i32>().prop_map(ArbitraryVal::I32),
+                    any::<u64>().prop_map(ArbitraryVal::U64),
+                    any::<i64>().prop_map(ArbitraryVal::I64),
+                    any::<u128>().prop_map(ArbitraryVal::U128),
+                    any::<i128>().prop_map(ArbitraryVal::I128),
+                    ArbitraryU256::strategy().prop_map(ArbitraryVal::U256),
+                    ArbitraryI256::strategy().prop_map(ArbitraryVal::I256),
+                    ArbitraryBytes::strategy().prop_map(ArbitraryVal::Bytes),
+                    ArbitraryString::strategy().prop_map(ArbitraryVal::String),
+                    ArbitrarySymbol::strategy().prop_map(ArbitraryVal::Symbol),
+                    ArbitraryAddress::strategy().prop_map(ArbitraryVal::Address),
+                    ArbitraryTimepoint::strategy().prop_map(ArbitraryVal::Timepoint),
+                    ArbitraryDuration::strategy().prop_map(ArbitraryVal::Duration),
+                ];
+                // Vals nest inside vecs, maps and options of vals, so limit
+                // the depth of the nesting.
+                leaf.prop_recursive(3, 64, 8, |val| {
+                    prop_oneof![
+                        val_vec(val.clone()).prop_map(ArbitraryVal::Vec),
+                        val_map(val.clone()).prop_map(ArbitraryVal::Map),
+                        val_option(val).prop_map(ArbitraryVal::Option),
+                    ]
+                })
+                .boxed()
+            }
+        }
+
+        fn val_vec(val: BoxedStrategy<ArbitraryVal>) -> BoxedStrategy<ArbitraryValVec> {
+            prop_oneof![
+                Proto::<Vec<()>>::strategy().prop_map(ArbitraryValVec::Void),
+                Proto::<Vec<bool>>::strategy().prop_map(ArbitraryValVec::Bool),
+                Proto::<Vec<Error>>::strategy().prop_map(ArbitraryValVec::Error),
+                Proto::<Vec<u32>>::strategy().prop_map(ArbitraryValVec::U32),
+                Proto::<Vec<i32>>::strategy().prop_map(ArbitraryValVec::I32),
+                Proto::<Vec<u64>>::strategy().prop_map(ArbitraryValVec::U64),
+                Proto::<Vec<i64>>::strategy().prop_map(ArbitraryValVec::I64),
+                Proto::<Vec<u128>>::strategy().prop_map(ArbitraryValVec::U128),
+                Proto::<Vec<i128>>::strategy().prop_map(ArbitraryValVec::I128),
+                Proto::<Vec<U256>>::strategy().prop_map(ArbitraryValVec::U256),
+                Proto::<Vec<I256>>::strategy().prop_map(ArbitraryValVec::I256),
+                Proto::<Vec<Bytes>>::strategy().prop_map(ArbitraryValVec::Bytes),
+                Proto::<Vec<BytesN<32>>>::strategy().prop_map(ArbitraryValVec::BytesN),
+                Proto::<Vec<String>>::strategy().prop_map(ArbitraryValVec::String),
+                Proto::<Vec<Symbol>>::strategy().prop_map(ArbitraryValVec::Symbol),
+                Proto::<Vec<Vec<u32>>>::strategy().prop_map(ArbitraryValVec::Vec),
+                Proto::<Vec<Map<u32, u32>>>::strategy().prop_map(ArbitraryValVec::Map),
+                Proto::<Vec<Address>>::strategy().prop_map(ArbitraryValVec::Address),
+                Proto::<Vec<Timepoint>>::strategy().prop_map(ArbitraryValVec::Timepoint),
+                Proto::<Vec<Duration>>::strategy().prop_map(ArbitraryValVec::Duration),
+                proptest::collection::vec(val, crate::testutils::proptest::LEN)
+                    .prop_map(|vals| ArbitraryValVec::Val(ArbitraryVec::Good(vals))),
+            ]
+            .boxed()
+        }
+
+        fn val_map(val: BoxedStrategy<ArbitraryVal>) -> BoxedStrategy<ArbitraryValMap> {
+            prop_oneof![
+                Proto::<Map<(), ()>>::strategy().prop_map(ArbitraryValMap::VoidToVoid),
+                Proto::<Map<bool, bool>>::strategy().prop_map(ArbitraryValMap::BoolToBool),
+                Proto::<Map<Error, Error>>::strategy().prop_map(ArbitraryValMap::ErrorToError),
+                Proto::<Map<u32, u32>>::strategy().prop_map(ArbitraryValMap::U32ToU32),
+                Proto::<Map<i32, i32>>::strategy().prop_map(ArbitraryValMap::I32ToI32),
+                Proto::<Map<u64, u64>>::strategy().prop_map(ArbitraryValMap::U64ToU64),
+                Proto::<Map<i64, i64>>::strategy().prop_map(ArbitraryValMap::I64ToI64),
+                Proto::<Map<u128, u128>>::strategy().prop_map(ArbitraryValMap::U128ToU128),
+                Proto::<Map<i128, i128>>::strategy().prop_map(ArbitraryValMap::I128ToI128),
+                Proto::<Map<U256, U256>>::strategy().prop_map(ArbitraryValMap::U256ToU256),
+                Proto::<Map<I256, I256>>::strategy().prop_map(ArbitraryValMap::I256ToI256),
+                Proto::<Map<Bytes, Bytes>>::strategy().prop_map(ArbitraryValMap::BytesToBytes),
+                Proto::<Map<BytesN<32>, BytesN<32>>>::strategy()
+                    .prop_map(ArbitraryValMap::BytesNToBytesN),
+                Proto::<Map<String, String>>::strategy().prop_map(ArbitraryValMap::StringToString),
+                Proto::<Map<Symbol, Symbol>>::strategy().prop_map(ArbitraryValMap::SymbolToSymbol),
+                Proto::<Map<Vec<u32>, Vec<u32>>>::strategy().prop_map(ArbitraryValMap::VecToVec),
+                Proto::<Map<Map<u32, u32>, Map<u32, u32>>>::strategy()
+                    .prop_map(ArbitraryValMap::MapToMap),
+                Proto::<Map<Address, Address>>::strategy()
+                    .prop_map(ArbitraryValMap::AddressToAddress),
+                Proto::<Map<Timepoint, Timepoint>>::strategy()
+                    .prop_map(ArbitraryValMap::TimepointToTimepoint),
+                Proto::<Map<Duration, Duration>>::strategy()
+                    .prop_map(ArbitraryValMap::DurationToDuration),
+                proptest::collection::vec((val.clone(), val), crate::testutils::proptest::LEN)
+                    .prop_map(|pairs| ArbitraryValMap::ValToVal(ArbitraryMap::Good(pairs))),
+                Proto::<Map<Option<u32>, Option<u32>>>::strategy()
+                    .prop_map(ArbitraryValMap::OptionToOption),
+            ]
+            .boxed()
+        }
+
+        fn val_option(val: BoxedStrategy<ArbitraryVal>) -> BoxedStrategy<ArbitraryValOption> {
+            prop_oneof![
+                Proto::<Option<()>>::strategy().prop_map(ArbitraryValOption::Void),
+                Proto::<Option<bool>>::strategy().prop_map(ArbitraryValOption::Bool),
+                Proto::<Option<Error>>::strategy().prop_map(ArbitraryValOption::Error),
+                Proto::<Option<u32>>::strategy().prop_map(ArbitraryValOption::U32),
+                Proto::<Option<i32>>::strategy().prop_map(ArbitraryValOption::I32),
+                Proto::<Option<u64>>::strategy().prop_map(ArbitraryValOption::U64),
+                Proto::<Option<i64>>::strategy().prop_map(ArbitraryValOption::I64),
+                Proto::<Option<u128>>::strategy().prop_map(ArbitraryValOption::U128),
+                Proto::<Option<i128>>::strategy().prop_map(ArbitraryValOption::I128),
+                Proto::<Option<U256>>::strategy().prop_map(ArbitraryValOption::U256),
+                Proto::<Option<I256>>::strategy().prop_map(ArbitraryValOption::I256),
+                Proto::<Option<Bytes>>::strategy().prop_map(ArbitraryValOption::Bytes),
+                Proto::<Option<BytesN<32>>>::strategy().prop_map(ArbitraryValOption::BytesN),
+                Proto::<Option<String>>::strategy().prop_map(ArbitraryValOption::String),
+                Proto::<Option<Symbol>>::strategy().prop_map(ArbitraryValOption::Symbol),
+                Proto::<Option<Vec<u32>>>::strategy().prop_map(ArbitraryValOption::Vec),
+                Proto::<Option<Map<u32, u32>>>::strategy().prop_map(ArbitraryValOption::Map),
+                Proto::<Option<Address>>::strategy().prop_map(ArbitraryValOption::Address),
+                Proto::<Option<Timepoint>>::strategy().prop_map(ArbitraryValOption::Timepoint),
+                Proto::<Option<Duration>>::strategy().prop_map(ArbitraryValOption::Duration),
+                proptest::option::of(val)
+                    .prop_map(|val| ArbitraryValOption::Val(Box::new(ArbitraryOption(val)))),
+            ]
+            .boxed()
+        }
+    }
 }
 
 /// Additional tools for writing fuzz tests.
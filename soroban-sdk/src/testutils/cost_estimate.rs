@@ -1,8 +1,24 @@
+use core::fmt::{self, Write as _};
+use std::{collections::BTreeMap, rc::Rc};
+
 use soroban_env_host::{
-    fees::FeeConfiguration, FeeEstimate, InvocationResourceLimits, InvocationResources,
+    budget::Budget as HostBudget,
+    e2e_invoke::entry_size_for_rent,
+    fees::{
+        compute_rent_fee, compute_rent_write_fee_per_1kb, FeeConfiguration, LedgerEntryRentChange,
+        RentFeeConfiguration, RentWriteFeeConfiguration,
+    },
+    storage::EntryWithLiveUntil,
+    FeeEstimate, InvocationResourceLimits, InvocationResources,
 };
 
-use crate::{testutils::budget::Budget, Env};
+use soroban_ledger_snapshot::LedgerSnapshot;
+
+use crate::{
+    testutils::{budget::Budget, trace},
+    xdr::{self, LedgerEntry, LedgerKey, WriteXdr},
+    Env,
+};
 
 pub struct CostEstimate {
     env: Env,
@@ -36,14 +52,8 @@ impl CostEstimate {
     /// Estimates the fee for the last invocation's resources, i.e. the
     /// resources returned by `resources()`.
     ///
-    /// The fees are computed using a snapshot of the Stellar Mainnet fees made
-    /// on 2026-07-10. Because the fees are hardcoded rather than pulled
-    /// dynamically, they may drift from the live network over time; the current
-    /// values can be checked via `stellar network settings --network mainnet`
-    /// or on Stellar Lab: <https://lab.stellar.org/network-limits>. The one
-    /// exception is the per-1KB storage rent rate, which is a deliberate
-    /// conservative overestimate rather than the snapshot value, so storage
-    /// rent estimates may be higher than the live network charges.
+    /// The fees are computed using the network config set with
+    /// `set_network_config()`, which by default is [`NetworkConfig::mainnet`].
     ///
     /// Take the return value with a grain of salt as both the resource estimate
     /// and the fee rates may be imprecise.
@@ -53,30 +63,12 @@ impl CostEstimate {
     /// VM instantiation and execution, as well as Wasm reads/rent bumps will be
     /// missed.
     pub fn fee(&self) -> FeeEstimate {
-        // This is a snapshot of the Stellar Mainnet fees as of 2026-07-10.
-        // Refresh it with the values from `stellar network settings --network
-        // mainnet` (or <https://lab.stellar.org/network-limits>) when it drifts.
-        let pubnet_fee_config = FeeConfiguration {
-            fee_per_instruction_increment: 7,
-            fee_per_disk_read_entry: 1563,
-            fee_per_write_entry: 2500,
-            fee_per_disk_read_1kb: 447,
-            fee_per_write_1kb: 875,
-            fee_per_historical_1kb: 4059,
-            fee_per_contract_event_1kb: 5000,
-            fee_per_transaction_size_1kb: 406,
-        };
-        let pubnet_persistent_rent_rate_denominator = 1215;
-        let pubnet_temp_rent_rate_denominator = 2430;
-        // This is a bit higher than the current network fee, it's an
-        // overestimate for the sake of providing a bit more conservative
-        // results in case if the state grows.
-        let fee_per_rent_1kb = 12000;
+        let config = self.env.network_config();
         self.resources().estimate_fees(
-            &pubnet_fee_config,
-            fee_per_rent_1kb,
-            pubnet_persistent_rent_rate_denominator,
-            pubnet_temp_rent_rate_denominator,
+            &config.fee_configuration(),
+            config.fee_per_rent_1kb,
+            config.persistent_rent_rate_denominator,
+            config.temporary_rent_rate_denominator,
         )
     }
 
@@ -97,6 +89,166 @@ impl CostEstimate {
         Budget::new(self.env.host().budget_cloned())
     }
 
+    /// Returns the profile of the costs of the last top level contract
+    /// invocation, attributed to each contract function called during the
+    /// invocation.
+    ///
+    /// Unlike `budget()`, which reports the costs of the whole invocation,
+    /// the profile shows which of the calls made in a multi-contract flow the
+    /// costs were incurred in. The profile can be exported as JSON with
+    /// [`Profile::to_json`], or as folded stacks that flamegraph tools can
+    /// read with [`Profile::to_folded_cpu_insns`].
+    pub fn profile(&self) -> Profile {
+        // Panic with an explanation if the invocation was not metered.
+        self.resources();
+        let trace = self.env.trace();
+        Profile(trace.0.iter().map(ProfileFrame::new).collect())
+    }
+
+    /// Returns the ledger footprint of the last top level contract
+    /// invocation, with the size of each entry in the footprint and the rent
+    /// fees for the entries that were created, grew, or had their TTL
+    /// extended.
+    ///
+    /// The rent fees are computed using the same network config as `fee()`,
+    /// and break down its rent fees by ledger entry.
+    ///
+    /// The footprint is only available after footprint recording is enabled
+    /// with `enable_footprint()`, for invocations made with a contract client
+    /// or [`Env::invoke_contract`]. It is reconstructed from the
+    /// ledger entries before and after the invocation rather than recorded by
+    /// the host, so take it with a grain of salt:
+    ///
+    /// - Entries that are created, modified or deleted are read-write, as are
+    ///   entries written by natively registered contracts.
+    /// - Entries loaded for the first time, entries whose TTL is extended,
+    ///   the instances and Wasm code of the contracts called, and entries
+    ///   read by natively registered contracts are read-only.
+    /// - Entries that a Wasm contract reads again after an earlier invocation
+    ///   loaded them, entries written with the value they already had, and
+    ///   entries the built-in contracts read, such as accounts, are missed.
+    ///
+    /// ### Panics
+    ///
+    /// If footprint recording was not enabled before the last invocation, or
+    /// the last invocation was not made with a contract client or
+    /// [`Env::invoke_contract`].
+    pub fn footprint(&self) -> Footprint {
+        self.try_footprint().unwrap_or_else(|| panic!("Invocation footprint is not available. Make sure footprint recording is enabled with `enable_footprint()` and this is called after a contract function is invoked with a contract client or `Env::invoke_contract`."))
+    }
+
+    /// Enables recording the footprints of the top level invocations, see
+    /// `footprint()`.
+    ///
+    /// Recording captures all the ledger entries before every invocation made
+    /// with a contract client, so it is disabled by default to keep tests with
+    /// large ledger states fast.
+    pub fn enable_footprint(&self) {
+        self.env.set_footprint_recording(true);
+    }
+
+    /// Disables recording the footprints of the top level invocations.
+    pub fn disable_footprint(&self) {
+        self.env.set_footprint_recording(false);
+    }
+
+    /// Returns the footprint of the last top level invocation, or `None` if
+    /// the ledger entries were not captured before it.
+    pub(crate) fn try_footprint(&self) -> Option<Footprint> {
+        let before = self.env.last_invocation_ledger_state()?;
+        Some(self.footprint_since(&before, true))
+    }
+
+    /// Returns the footprint of the invocations made since the ledger entries
+    /// were captured.
+    ///
+    /// The storage accessed by the frames of the trace is included only if
+    /// `invoked`, since the trace is of the last invocation, which otherwise
+    /// was made before the entries were captured.
+    pub(crate) fn footprint_since(&self, before: &LedgerState, invoked: bool) -> Footprint {
+        let after: BTreeMap<_, _> = self
+            .env
+            .host()
+            .get_stored_entries()
+            .unwrap()
+            .into_iter()
+            .collect();
+        let old = |k: &Rc<LedgerKey>| before.entry(&self.env, k);
+
+        let mut keys = before.accessed(&self.env, &after);
+        let trace = self.env.trace();
+        let frames = if invoked { trace.frames() } else { Vec::new() };
+        for frame in frames {
+            let instance = contract_data_key(
+                &frame.contract,
+                xdr::ScVal::LedgerKeyContractInstance,
+                xdr::ContractDataDurability::Persistent,
+            );
+            if let Some(Some((entry, _))) = after.get(&instance) {
+                if let Some(code) = wasm_code_key(entry) {
+                    keys.entry(Rc::new(code)).or_insert(false);
+                }
+            }
+            keys.entry(Rc::new(instance)).or_insert(false);
+            let Some(storage) = &frame.storage else {
+                continue;
+            };
+            let accesses = storage.reads.iter().map(|k| (k, false));
+            for (k, write) in accesses.chain(storage.writes.iter().map(|k| (k, true))) {
+                let key = Rc::new(match k {
+                    trace::StorageKey::Persistent(k) => contract_data_key(
+                        &frame.contract,
+                        k.clone(),
+                        xdr::ContractDataDurability::Persistent,
+                    ),
+                    trace::StorageKey::Temporary(k) => contract_data_key(
+                        &frame.contract,
+                        k.clone(),
+                        xdr::ContractDataDurability::Temporary,
+                    ),
+                    trace::StorageKey::Instance(_) => contract_data_key(
+                        &frame.contract,
+                        xdr::ScVal::LedgerKeyContractInstance,
+                        xdr::ContractDataDurability::Persistent,
+                    ),
+                });
+                *keys.entry(key).or_insert(write) |= write;
+            }
+        }
+
+        let rent_fee_config = self.env.network_config().rent_fee_configuration();
+        let mut footprint = Footprint::default();
+        for (k, write) in keys {
+            let new = after.get(&k).cloned().flatten();
+            let entry = FootprintEntry::new(&k, old(&k), new, &rent_fee_config, before.ledger);
+            if write {
+                footprint.read_write.push(entry);
+            } else {
+                footprint.read_only.push(entry);
+            }
+        }
+        footprint
+    }
+
+    /// Returns the network config that fees are computed with.
+    pub fn network_config(&self) -> NetworkConfig {
+        self.env.network_config()
+    }
+
+    /// Sets the network config that fees are computed with, and enforces its
+    /// resource limits as with `enforce_resource_limits()`.
+    ///
+    /// The config can be loaded from the config settings of a real network,
+    /// such as in a ledger snapshot taken from Mainnet or Testnet, with
+    /// [`NetworkConfig::from_ledger_snapshot`] or [`NetworkConfig::read_file`].
+    /// The settings proposed for a protocol upgrade vote can be applied to a
+    /// config with [`NetworkConfig::with_config_settings`] to see how they
+    /// would change the fees.
+    pub fn set_network_config(&self, config: NetworkConfig) {
+        self.enforce_resource_limits(config.limits.clone());
+        self.env.set_network_config(config);
+    }
+
     /// Enforces custom resource limits for contract invocations in tests.
     ///
     /// When limit enforcement is enabled, for every contract invocation the
@@ -130,6 +282,268 @@ impl CostEstimate {
     }
 }
 
+/// The ledger footprint of an invocation, see [`CostEstimate::footprint`].
+///
+/// The entries are ordered by key.
+///
+/// The footprint is reconstructed rather than recorded by the host, and it
+/// misses the entries that a Wasm contract reads again after an earlier
+/// invocation loaded them. For example, a token balance that a Wasm contract
+/// only reads is in the footprint of the first invocation that reads it, but
+/// not of the later invocations, while the network would include it in the
+/// footprint of every transaction that reads it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Footprint {
+    /// Entries the invocation only reads, or only extends the TTL of.
+    pub read_only: std::vec::Vec<FootprintEntry>,
+    /// Entries the invocation creates, modifies or deletes.
+    pub read_write: std::vec::Vec<FootprintEntry>,
+}
+
+impl Footprint {
+    /// Returns the keys of the read-only entries.
+    pub fn read_only_keys(&self) -> std::vec::Vec<LedgerKey> {
+        self.read_only.iter().map(|e| e.key.clone()).collect()
+    }
+
+    /// Returns the keys of the read-write entries.
+    pub fn read_write_keys(&self) -> std::vec::Vec<LedgerKey> {
+        self.read_write.iter().map(|e| e.key.clone()).collect()
+    }
+
+    /// Returns the total size of the entries in bytes, after the invocation.
+    pub fn size_bytes(&self) -> u32 {
+        self.read_only
+            .iter()
+            .chain(&self.read_write)
+            .map(|e| e.new_size_bytes)
+            .sum()
+    }
+
+    /// Returns the total rent fee of the entries.
+    pub fn rent_fee(&self) -> i64 {
+        self.read_only
+            .iter()
+            .chain(&self.read_write)
+            .map(|e| e.rent_fee)
+            .sum()
+    }
+}
+
+/// A ledger entry in a [`Footprint`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FootprintEntry {
+    pub key: LedgerKey,
+    /// Size of the entry as XDR before the invocation, or 0 if the entry did
+    /// not exist.
+    pub old_size_bytes: u32,
+    /// Size of the entry as XDR after the invocation, or 0 if the entry does
+    /// not exist.
+    pub new_size_bytes: u32,
+    /// Ledger the entry was live until before the invocation, `None` if the
+    /// entry did not exist or has no TTL.
+    pub old_live_until_ledger: Option<u32>,
+    /// Ledger the entry is live until after the invocation, `None` if the
+    /// entry does not exist or has no TTL.
+    pub new_live_until_ledger: Option<u32>,
+    /// Rent fee for creating the entry, growing it, or extending its TTL,
+    /// including the fee for writing its TTL. 0 if the invocation did none of
+    /// those.
+    pub rent_fee: i64,
+}
+
+impl FootprintEntry {
+    fn new(
+        key: &LedgerKey,
+        old: Option<EntryWithLiveUntil>,
+        new: Option<EntryWithLiveUntil>,
+        rent_fee_config: &RentFeeConfiguration,
+        ledger: u32,
+    ) -> Self {
+        let size = |e: &Option<EntryWithLiveUntil>| {
+            e.as_ref().map_or(0, |(e, _)| {
+                e.to_xdr(xdr::Limits::none()).unwrap().len() as u32
+            })
+        };
+        // The rent is charged for the size of the entry including the memory
+        // the Wasm module of contract code takes when instantiated.
+        let rent_size = |e: &Option<EntryWithLiveUntil>| {
+            e.as_ref().map_or(0, |(entry, _)| {
+                entry_size_for_rent(&HostBudget::default(), entry, size(e)).unwrap()
+            })
+        };
+        let old_live_until_ledger = old.as_ref().and_then(|e| e.1);
+        let new_live_until_ledger = new.as_ref().and_then(|e| e.1);
+        let rent_fee = match (new_live_until_ledger, key) {
+            (
+                Some(new_live_until_ledger),
+                LedgerKey::ContractData(_) | LedgerKey::ContractCode(_),
+            ) => {
+                let change = LedgerEntryRentChange {
+                    is_persistent: !matches!(
+                        key,
+                        LedgerKey::ContractData(xdr::LedgerKeyContractData {
+                            durability: xdr::ContractDataDurability::Temporary,
+                            ..
+                        })
+                    ),
+                    is_code_entry: matches!(key, LedgerKey::ContractCode(_)),
+                    old_size_bytes: rent_size(&old),
+                    new_size_bytes: rent_size(&new),
+                    old_live_until_ledger: old_live_until_ledger.unwrap_or(0),
+                    new_live_until_ledger,
+                };
+                // Entries that neither grow nor are extended pay no rent.
+                if change.old_live_until_ledger >= change.new_live_until_ledger
+                    && change.old_size_bytes >= change.new_size_bytes
+                {
+                    0
+                } else {
+                    compute_rent_fee(&[change], rent_fee_config, ledger)
+                }
+            }
+            _ => 0,
+        };
+        FootprintEntry {
+            key: key.clone(),
+            old_size_bytes: size(&old),
+            new_size_bytes: size(&new),
+            old_live_until_ledger,
+            new_live_until_ledger,
+            rent_fee,
+        }
+    }
+}
+
+fn contract_data_key(
+    contract: &xdr::ScAddress,
+    key: xdr::ScVal,
+    durability: xdr::ContractDataDurability,
+) -> LedgerKey {
+    LedgerKey::ContractData(xdr::LedgerKeyContractData {
+        contract: contract.clone(),
+        key,
+        durability,
+    })
+}
+
+/// Returns the key of the Wasm code of a contract instance entry, if the
+/// contract is a Wasm contract.
+fn wasm_code_key(instance: &LedgerEntry) -> Option<LedgerKey> {
+    match &instance.data {
+        xdr::LedgerEntryData::ContractData(xdr::ContractDataEntry {
+            val:
+                xdr::ScVal::ContractInstance(xdr::ScContractInstance {
+                    executable: xdr::ContractExecutable::Wasm(hash),
+                    ..
+                }),
+            ..
+        }) => Some(LedgerKey::ContractCode(xdr::LedgerKeyContractCode {
+            hash: hash.clone(),
+        })),
+        _ => None,
+    }
+}
+
+/// The ledger entries before an invocation, for computing its footprint.
+pub(crate) struct LedgerState {
+    ledger: u32,
+    entries: BTreeMap<Rc<LedgerKey>, Option<EntryWithLiveUntil>>,
+}
+
+/// Records the ledger entries before the top level invocations made by the
+/// contract clients.
+///
+/// The entries are captured before the invocation starts, because the host
+/// resets the budget only then, so that decoding the entries is not metered
+/// as part of the invocation.
+#[derive(Default)]
+pub(crate) struct FootprintRecorder {
+    enabled: bool,
+    pending: Option<Rc<LedgerState>>,
+    last: Option<Rc<LedgerState>>,
+}
+
+impl LedgerState {
+    /// Captures the ledger entries, or returns `None` if they cannot be
+    /// decoded within the budget left.
+    pub(crate) fn capture(env: &Env) -> Option<Rc<Self>> {
+        let entries = env.host().get_stored_entries().ok()?;
+        Some(Rc::new(LedgerState {
+            ledger: env.ledger().sequence(),
+            entries: entries.into_iter().collect(),
+        }))
+    }
+
+    /// Returns the entry before the invocation.
+    pub(crate) fn entry(&self, env: &Env, k: &Rc<LedgerKey>) -> Option<EntryWithLiveUntil> {
+        match self.entries.get(k) {
+            Some(v) => v.clone(),
+            // Entries that were not loaded before the invocation were loaded
+            // from the snapshot source during it.
+            None => env.snapshot_source_entry(k),
+        }
+    }
+
+    /// Returns the keys of the entries accessed since the state was captured,
+    /// as far as the entries before and after tell, and whether they were
+    /// written.
+    ///
+    /// Entries whose value changed are written, entries that were loaded for
+    /// the first time or only had their TTL changed are read.
+    pub(crate) fn accessed(
+        &self,
+        env: &Env,
+        after: &BTreeMap<Rc<LedgerKey>, Option<EntryWithLiveUntil>>,
+    ) -> BTreeMap<Rc<LedgerKey>, bool> {
+        let mut keys = BTreeMap::new();
+        for (k, new) in after {
+            let loaded = !self.entries.contains_key(k);
+            let old = self.entry(env, k);
+            if old.as_ref().map(|e| &e.0) != new.as_ref().map(|e| &e.0) {
+                keys.insert(k.clone(), true);
+            } else if loaded || old.map(|e| e.1) != new.as_ref().map(|e| e.1) {
+                keys.insert(k.clone(), false);
+            }
+        }
+        keys
+    }
+}
+
+impl FootprintRecorder {
+    pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.pending = None;
+            self.last = None;
+        }
+    }
+
+    /// Captures the ledger entries before an invocation is made, if recording
+    /// is enabled.
+    pub(crate) fn capture(&mut self, env: &Env) {
+        if self.enabled {
+            self.pending = LedgerState::capture(env);
+        }
+    }
+
+    /// Called when a top level invocation starts. The invocation has the
+    /// entries captured just before it, if any.
+    pub(crate) fn start(&mut self) {
+        self.last = self.pending.take();
+    }
+
+    /// Called when a top level invocation finishes. Entries captured before
+    /// calls made inside the invocation are discarded.
+    pub(crate) fn finish(&mut self) {
+        self.pending = None;
+    }
+
+    pub(crate) fn last(&self) -> Option<Rc<LedgerState>> {
+        self.last.clone()
+    }
+}
+
 /// Predefined network invocation resource limits.
 pub trait NetworkInvocationResourceLimits {
     fn mainnet() -> Self;
@@ -159,3 +573,503 @@ impl NetworkInvocationResourceLimits for InvocationResourceLimits {
         }
     }
 }
+
+/// The fee rates, rent rates and resource limits of a Soroban network, that
+/// [`CostEstimate`] computes fees with.
+///
+/// See [`CostEstimate::set_network_config`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkConfig {
+    /// Fee per 10,000 instructions.
+    pub fee_per_instruction_increment: i64,
+    /// Fee per ledger entry read from disk.
+    pub fee_per_disk_read_entry: i64,
+    /// Fee per ledger entry written.
+    pub fee_per_write_entry: i64,
+    /// Fee per 1KB read from disk.
+    pub fee_per_disk_read_1kb: i64,
+    /// Fee per 1KB written.
+    pub fee_per_write_1kb: i64,
+    /// Fee per 1KB of transaction history.
+    pub fee_per_historical_1kb: i64,
+    /// Fee per 1KB of contract events.
+    pub fee_per_contract_event_1kb: i64,
+    /// Fee per 1KB of transaction size.
+    pub fee_per_transaction_size_1kb: i64,
+    /// Fee per 1KB of ledger space rented, which the network computes from
+    /// the size of the Soroban state.
+    pub fee_per_rent_1kb: i64,
+    /// 1KB of persistent entries pays `fee_per_rent_1kb` for every
+    /// `persistent_rent_rate_denominator` ledgers it is rented for.
+    pub persistent_rent_rate_denominator: i64,
+    /// 1KB of temporary entries pays `fee_per_rent_1kb` for every
+    /// `temporary_rent_rate_denominator` ledgers it is rented for.
+    pub temporary_rent_rate_denominator: i64,
+    /// Resource limits of a transaction.
+    pub limits: InvocationResourceLimits,
+}
+
+/// The config settings [`NetworkConfig`] needs that are missing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingConfigSettings(pub std::vec::Vec<xdr::ConfigSettingId>);
+
+impl fmt::Display for MissingConfigSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: std::vec::Vec<_> = self.0.iter().map(|id| id.name()).collect();
+        write!(f, "missing config settings: {}", names.join(", "))
+    }
+}
+
+impl std::error::Error for MissingConfigSettings {}
+
+/// The config settings that a [`NetworkConfig`] is made of.
+const CONFIG_SETTINGS: [xdr::ConfigSettingId; 11] = [
+    xdr::ConfigSettingId::ContractComputeV0,
+    xdr::ConfigSettingId::ContractLedgerCostV0,
+    xdr::ConfigSettingId::ContractLedgerCostExtV0,
+    xdr::ConfigSettingId::ContractHistoricalDataV0,
+    xdr::ConfigSettingId::ContractEventsV0,
+    xdr::ConfigSettingId::ContractBandwidthV0,
+    xdr::ConfigSettingId::ContractDataKeySizeBytes,
+    xdr::ConfigSettingId::ContractDataEntrySizeBytes,
+    xdr::ConfigSettingId::ContractMaxSizeBytes,
+    xdr::ConfigSettingId::StateArchival,
+    xdr::ConfigSettingId::LiveSorobanStateSizeWindow,
+];
+
+/// The contents of a file a [`NetworkConfig`] is read from.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum ConfigSettingsFile {
+    UpgradeSet(xdr::ConfigUpgradeSet),
+    Entries(std::vec::Vec<xdr::ConfigSettingEntry>),
+    LedgerSnapshot(LedgerSnapshot),
+}
+
+const EMPTY_WINDOW: &str =
+    "LiveSorobanStateSizeWindow has no samples to compute the rent rate from";
+
+/// Returns true if the setting is a `LiveSorobanStateSizeWindow` with no
+/// samples.
+fn is_empty_window(setting: &xdr::ConfigSettingEntry) -> bool {
+    matches!(setting, xdr::ConfigSettingEntry::LiveSorobanStateSizeWindow(w) if w.is_empty())
+}
+
+impl NetworkConfig {
+    /// Returns the config of Stellar Mainnet.
+    ///
+    /// The fees are a snapshot of the Stellar Mainnet fees made on
+    /// 2026-07-10, and the limits are [`InvocationResourceLimits::mainnet`].
+    /// Because the fees are hardcoded rather than pulled dynamically, they may
+    /// drift from the live network over time; the current values can be
+    /// checked via `stellar network settings --network mainnet` or on Stellar
+    /// Lab: <https://lab.stellar.org/network-limits>. The one exception is the
+    /// per-1KB storage rent rate, which is a deliberate conservative
+    /// overestimate rather than the snapshot value, so storage rent estimates
+    /// may be higher than the live network charges.
+    ///
+    /// Load the config from a ledger snapshot with
+    /// [`NetworkConfig::from_ledger_snapshot`] for the current values.
+    pub fn mainnet() -> Self {
+        // This is a snapshot of the Stellar Mainnet fees as of 2026-07-10.
+        // Refresh it with the values from `stellar network settings --network
+        // mainnet` (or <https://lab.stellar.org/network-limits>) when it drifts.
+        NetworkConfig {
+            fee_per_instruction_increment: 7,
+            fee_per_disk_read_entry: 1563,
+            fee_per_write_entry: 2500,
+            fee_per_disk_read_1kb: 447,
+            fee_per_write_1kb: 875,
+            fee_per_historical_1kb: 4059,
+            fee_per_contract_event_1kb: 5000,
+            fee_per_transaction_size_1kb: 406,
+            // This is a bit higher than the current network fee, it's an
+            // overestimate for the sake of providing a bit more conservative
+            // results in case if the state grows.
+            fee_per_rent_1kb: 12000,
+            persistent_rent_rate_denominator: 1215,
+            temporary_rent_rate_denominator: 2430,
+            limits: InvocationResourceLimits::mainnet(),
+        }
+    }
+
+    /// Returns the config made of the config settings of a network.
+    ///
+    /// The settings are the `ConfigSetting` ledger entries of the network,
+    /// which define its fees and limits. Settings that are not fees or limits,
+    /// such as the cost model parameters, are ignored.
+    ///
+    /// Returns an error listing the settings that are missing if any of the
+    /// settings the config is made of are not given. A
+    /// `LiveSorobanStateSizeWindow` with no samples is missing, since the
+    /// rent rate cannot be computed from it.
+    pub fn from_config_settings(
+        settings: &[xdr::ConfigSettingEntry],
+    ) -> Result<Self, MissingConfigSettings> {
+        let missing: std::vec::Vec<_> = CONFIG_SETTINGS
+            .into_iter()
+            .filter(|id| {
+                !settings
+                    .iter()
+                    .any(|s| s.discriminant() == *id && !is_empty_window(s))
+            })
+            .collect();
+        if !missing.is_empty() {
+            return Err(MissingConfigSettings(missing));
+        }
+        Ok(Self::mainnet().with_config_settings(settings))
+    }
+
+    /// Returns the config made of the config settings in the ledger snapshot,
+    /// such as a snapshot of Mainnet or Testnet.
+    ///
+    /// See [`NetworkConfig::from_config_settings`].
+    pub fn from_ledger_snapshot(snapshot: &LedgerSnapshot) -> Result<Self, MissingConfigSettings> {
+        let settings: std::vec::Vec<_> = snapshot
+            .ledger_entries
+            .iter()
+            .filter_map(|(_, (entry, _))| match &entry.data {
+                xdr::LedgerEntryData::ConfigSetting(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect();
+        Self::from_config_settings(&settings)
+    }
+
+    /// Read the config from a JSON file.
+    ///
+    /// The file contains the config settings either as a JSON array of
+    /// `ConfigSettingEntry`, as a `ConfigUpgradeSet`, or as the ledger
+    /// entries of a [`LedgerSnapshot`], with the XDR types in the JSON
+    /// representation of the `stellar-xdr` crate.
+    ///
+    /// See [`NetworkConfig::from_config_settings`].
+    pub fn read_file(p: impl AsRef<std::path::Path>) -> Result<Self, std::io::Error> {
+        let settings = Self::read_config_settings_file(p)?;
+        Self::from_config_settings(&settings)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Returns the config with the config settings applied, such as the
+    /// settings of a `ConfigUpgradeSet` proposed for a protocol upgrade vote.
+    ///
+    /// Settings that are not fees or limits are ignored. The rent rate per
+    /// 1KB is computed from the rent fee settings in `ContractLedgerCostV0`
+    /// and the size of the Soroban state in `LiveSorobanStateSizeWindow`, and
+    /// is only changed when both are applied.
+    ///
+    /// ### Panics
+    ///
+    /// If a `LiveSorobanStateSizeWindow` with no samples is applied.
+    pub fn with_config_settings(mut self, settings: &[xdr::ConfigSettingEntry]) -> Self {
+        use xdr::ConfigSettingEntry as S;
+        let mut rent_write_fee_config = None;
+        let mut soroban_state_size_bytes = None;
+        for setting in settings {
+            match setting {
+                S::ContractComputeV0(s) => {
+                    self.fee_per_instruction_increment = s.fee_rate_per_instructions_increment;
+                    self.limits.instructions = s.tx_max_instructions;
+                    self.limits.mem_bytes = s.tx_memory_limit.into();
+                }
+                S::ContractLedgerCostV0(s) => {
+                    self.fee_per_disk_read_entry = s.fee_disk_read_ledger_entry;
+                    self.fee_per_write_entry = s.fee_write_ledger_entry;
+                    self.fee_per_disk_read_1kb = s.fee_disk_read1_kb;
+                    self.limits.disk_read_entries = s.tx_max_disk_read_entries;
+                    self.limits.disk_read_bytes = s.tx_max_disk_read_bytes;
+                    self.limits.write_entries = s.tx_max_write_ledger_entries;
+                    self.limits.write_bytes = s.tx_max_write_bytes;
+                    rent_write_fee_config = Some(RentWriteFeeConfiguration {
+                        state_target_size_bytes: s.soroban_state_target_size_bytes,
+                        rent_fee_1kb_state_size_low: s.rent_fee1_kb_soroban_state_size_low,
+                        rent_fee_1kb_state_size_high: s.rent_fee1_kb_soroban_state_size_high,
+                        state_size_rent_fee_growth_factor: s.soroban_state_rent_fee_growth_factor,
+                    });
+                }
+                S::ContractLedgerCostExtV0(s) => {
+                    self.fee_per_write_1kb = s.fee_write1_kb;
+                    self.limits.ledger_entries = s.tx_max_footprint_entries;
+                }
+                S::ContractHistoricalDataV0(s) => {
+                    self.fee_per_historical_1kb = s.fee_historical1_kb;
+                }
+                S::ContractEventsV0(s) => {
+                    self.fee_per_contract_event_1kb = s.fee_contract_events1_kb;
+                    self.limits.contract_events_size_bytes = s.tx_max_contract_events_size_bytes;
+                }
+                S::ContractBandwidthV0(s) => {
+                    self.fee_per_transaction_size_1kb = s.fee_tx_size1_kb;
+                }
+                S::ContractDataKeySizeBytes(n) => {
+                    self.limits.max_contract_data_key_size_bytes = *n;
+                }
+                S::ContractDataEntrySizeBytes(n) => {
+                    self.limits.max_contract_data_entry_size_bytes = *n;
+                }
+                S::ContractMaxSizeBytes(n) => {
+                    self.limits.max_contract_code_entry_size_bytes = *n;
+                }
+                S::StateArchival(s) => {
+                    self.persistent_rent_rate_denominator = s.persistent_rent_rate_denominator;
+                    self.temporary_rent_rate_denominator = s.temp_rent_rate_denominator;
+                }
+                S::LiveSorobanStateSizeWindow(window) => {
+                    if window.is_empty() {
+                        panic!("{EMPTY_WINDOW}");
+                    }
+                    // The network rents the ledger space at the rate for the
+                    // average size of the state over the window.
+                    let sum: u64 = window.iter().sum();
+                    soroban_state_size_bytes = Some((sum / window.len() as u64) as i64);
+                }
+                _ => {}
+            }
+        }
+        if let (Some(config), Some(size)) = (rent_write_fee_config, soroban_state_size_bytes) {
+            self.fee_per_rent_1kb = compute_rent_write_fee_per_1kb(size, &config);
+        }
+        self
+    }
+
+    /// Returns the config with the config settings in the JSON file applied.
+    ///
+    /// See [`NetworkConfig::read_file`] for the contents of the file, and
+    /// [`NetworkConfig::with_config_settings`]. Returns an error rather than
+    /// panicking if the file has a `LiveSorobanStateSizeWindow` with no
+    /// samples.
+    pub fn with_config_settings_file(
+        self,
+        p: impl AsRef<std::path::Path>,
+    ) -> Result<Self, std::io::Error> {
+        let settings = Self::read_config_settings_file(p)?;
+        if settings.iter().any(is_empty_window) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                EMPTY_WINDOW,
+            ));
+        }
+        Ok(self.with_config_settings(&settings))
+    }
+
+    fn read_config_settings_file(
+        p: impl AsRef<std::path::Path>,
+    ) -> Result<std::vec::Vec<xdr::ConfigSettingEntry>, std::io::Error> {
+        let reader = std::io::BufReader::new(std::fs::File::open(p)?);
+        Ok(match serde_json::from_reader(reader)? {
+            ConfigSettingsFile::UpgradeSet(set) => set.updated_entry.into(),
+            ConfigSettingsFile::Entries(entries) => entries,
+            ConfigSettingsFile::LedgerSnapshot(snapshot) => snapshot
+                .ledger_entries
+                .into_iter()
+                .filter_map(|(_, (entry, _))| match entry.data {
+                    xdr::LedgerEntryData::ConfigSetting(s) => Some(s),
+                    _ => None,
+                })
+                .collect(),
+        })
+    }
+
+    pub(crate) fn fee_configuration(&self) -> FeeConfiguration {
+        FeeConfiguration {
+            fee_per_instruction_increment: self.fee_per_instruction_increment,
+            fee_per_disk_read_entry: self.fee_per_disk_read_entry,
+            fee_per_write_entry: self.fee_per_write_entry,
+            fee_per_disk_read_1kb: self.fee_per_disk_read_1kb,
+            fee_per_write_1kb: self.fee_per_write_1kb,
+            fee_per_historical_1kb: self.fee_per_historical_1kb,
+            fee_per_contract_event_1kb: self.fee_per_contract_event_1kb,
+            fee_per_transaction_size_1kb: self.fee_per_transaction_size_1kb,
+        }
+    }
+
+    pub(crate) fn rent_fee_configuration(&self) -> RentFeeConfiguration {
+        RentFeeConfiguration {
+            fee_per_write_1kb: self.fee_per_write_1kb,
+            fee_per_rent_1kb: self.fee_per_rent_1kb,
+            fee_per_write_entry: self.fee_per_write_entry,
+            persistent_rent_rate_denominator: self.persistent_rent_rate_denominator,
+            temporary_rent_rate_denominator: self.temporary_rent_rate_denominator,
+        }
+    }
+}
+
+/// The costs of an invocation, attributed to the contract frames of the
+/// invocation.
+///
+/// Contains one [`ProfileFrame`] for each contract call made at the top level
+/// of the invocation, with the calls made inside a contract nested in the
+/// frame of their caller.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile(pub std::vec::Vec<ProfileFrame>);
+
+/// The costs of a contract frame in a [`Profile`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileFrame {
+    /// Address of the contract called.
+    pub contract: xdr::ScAddress,
+    /// Name of the function called.
+    pub function: xdr::ScSymbol,
+    /// CPU instructions used by the frame, including its sub-frames.
+    pub cpu_insns: u64,
+    /// Memory bytes used by the frame, including its sub-frames.
+    pub mem_bytes: u64,
+    /// CPU instructions used by the frame itself, excluding its sub-frames.
+    pub self_cpu_insns: u64,
+    /// Memory bytes used by the frame itself, excluding its sub-frames.
+    pub self_mem_bytes: u64,
+    /// Frames of the calls made by the frame, in order.
+    pub sub_frames: std::vec::Vec<ProfileFrame>,
+}
+
+/// The costs of all the calls of a contract function in a [`Profile`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionProfile {
+    /// Address of the contract.
+    pub contract: xdr::ScAddress,
+    /// Name of the function.
+    pub function: xdr::ScSymbol,
+    /// Number of times the function was called.
+    pub calls: u32,
+    /// CPU instructions used by the calls, excluding their sub-frames.
+    pub self_cpu_insns: u64,
+    /// Memory bytes used by the calls, excluding their sub-frames.
+    pub self_mem_bytes: u64,
+}
+
+impl ProfileFrame {
+    fn new(frame: &trace::Frame) -> Self {
+        let cpu = |f: &trace::Frame| f.resources.as_ref().map_or(0, |r| r.instructions as u64);
+        let mem = |f: &trace::Frame| f.resources.as_ref().map_or(0, |r| r.mem_bytes as u64);
+        let cpu_insns = cpu(frame);
+        let mem_bytes = mem(frame);
+        ProfileFrame {
+            contract: frame.contract.clone(),
+            function: frame.function.clone(),
+            cpu_insns,
+            mem_bytes,
+            self_cpu_insns: cpu_insns.saturating_sub(frame.sub_frames.iter().map(cpu).sum()),
+            self_mem_bytes: mem_bytes.saturating_sub(frame.sub_frames.iter().map(mem).sum()),
+            sub_frames: frame.sub_frames.iter().map(ProfileFrame::new).collect(),
+        }
+    }
+
+    fn name(&self) -> std::string::String {
+        format!(
+            "{}.{}",
+            self.contract,
+            self.function.0.to_utf8_string_lossy()
+        )
+    }
+
+    fn to_json_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "contract": self.contract.to_string(),
+            "function": self.function.0.to_utf8_string_lossy(),
+            "cpu_insns": self.cpu_insns,
+            "mem_bytes": self.mem_bytes,
+            "self_cpu_insns": self.self_cpu_insns,
+            "self_mem_bytes": self.self_mem_bytes,
+            "sub_frames": self.sub_frames.iter().map(Self::to_json_value).collect::<std::vec::Vec<_>>(),
+        })
+    }
+
+    fn write_folded(
+        &self,
+        out: &mut std::string::String,
+        stack: &str,
+        self_value: fn(&ProfileFrame) -> u64,
+    ) {
+        let stack = if stack.is_empty() {
+            self.name()
+        } else {
+            format!("{stack};{}", self.name())
+        };
+        let v = self_value(self);
+        if v > 0 {
+            let _ = writeln!(out, "{stack} {v}");
+        }
+        for sub in &self.sub_frames {
+            sub.write_folded(out, &stack, self_value);
+        }
+    }
+}
+
+impl Profile {
+    /// Returns the frames of the profile in depth-first order.
+    pub fn frames(&self) -> std::vec::Vec<&ProfileFrame> {
+        fn collect<'a>(frames: &'a [ProfileFrame], out: &mut std::vec::Vec<&'a ProfileFrame>) {
+            for frame in frames {
+                out.push(frame);
+                collect(&frame.sub_frames, out);
+            }
+        }
+        let mut out = std::vec::Vec::new();
+        collect(&self.0, &mut out);
+        out
+    }
+
+    /// Returns the costs of each contract function called during the
+    /// invocation, summed over all its calls, with the functions that used
+    /// the most CPU instructions first.
+    pub fn by_function(&self) -> std::vec::Vec<FunctionProfile> {
+        let mut functions = std::vec::Vec::<FunctionProfile>::new();
+        for frame in self.frames() {
+            let f = match functions
+                .iter_mut()
+                .find(|f| f.contract == frame.contract && f.function == frame.function)
+            {
+                Some(f) => f,
+                None => {
+                    functions.push(FunctionProfile {
+                        contract: frame.contract.clone(),
+                        function: frame.function.clone(),
+                        calls: 0,
+                        self_cpu_insns: 0,
+                        self_mem_bytes: 0,
+                    });
+                    functions.last_mut().unwrap()
+                }
+            };
+            f.calls += 1;
+            f.self_cpu_insns += frame.self_cpu_insns;
+            f.self_mem_bytes += frame.self_mem_bytes;
+        }
+        functions.sort_by(|a, b| b.self_cpu_insns.cmp(&a.self_cpu_insns));
+        functions
+    }
+
+    /// Returns the profile as JSON.
+    ///
+    /// The JSON is an object with a `frames` array, where each frame has the
+    /// fields of [`ProfileFrame`], with the contract as a strkey.
+    pub fn to_json(&self) -> std::string::String {
+        let frames: std::vec::Vec<_> = self.0.iter().map(ProfileFrame::to_json_value).collect();
+        serde_json::to_string_pretty(&serde_json::json!({ "frames": frames })).unwrap()
+    }
+
+    /// Returns the CPU instructions of the profile as folded stacks, the text
+    /// format read by flamegraph tools such as `inferno-flamegraph` and
+    /// `flamegraph.pl`.
+    ///
+    /// Each line is a stack of `contract.function` frames separated by `;`,
+    /// and the CPU instructions used by the last frame itself, e.g.
+    /// `CA..FCT4.call;CA..D2KM.store 2000`.
+    pub fn to_folded_cpu_insns(&self) -> std::string::String {
+        let mut out = std::string::String::new();
+        for frame in &self.0 {
+            frame.write_folded(&mut out, "", |f| f.self_cpu_insns);
+        }
+        out
+    }
+
+    /// Returns the memory bytes of the profile as folded stacks, in the same
+    /// format as [`Profile::to_folded_cpu_insns`].
+    pub fn to_folded_mem_bytes(&self) -> std::string::String {
+        let mut out = std::string::String::new();
+        for frame in &self.0 {
+            frame.write_folded(&mut out, "", |f| f.self_mem_bytes);
+        }
+        out
+    }
+}
@@ -0,0 +1,524 @@
+//! Coverage of the functions and errors in the specs of contracts by the tests
+//! of a test binary.
+//!
+//! When collecting is enabled, with the `SOROBAN_COVERAGE` environment
+//! variable set to `1` or with [`enable`], the calls made to registered
+//! contracts are collected, and [`report`] returns a [`Report`] listing for
+//! each contract the functions in its spec and how many times each was
+//! called, and the variants of the [`contracterror`][crate::contracterror]
+//! types in its spec and how many times each was returned. Functions that
+//! were not called and errors that were not returned are listed as
+//! uncovered.
+//!
+//! Calls made by contracts to other contracts are included, as are calls that
+//! fail. The spec of a natively registered contract is the spec of the
+//! functions of its [`contractimpl`][crate::contractimpl] blocks, and the
+//! [`contracterror`][crate::contracterror] types those functions return, or
+//! that are defined in the same module as the contract. The spec of a Wasm
+//! contract is the spec in its Wasm.
+//!
+//! The contracts included are the contracts registered with
+//! [`Env::register`][crate::Env::register] and
+//! [`Env::register_at`][crate::Env::register_at], and the Wasm contracts
+//! deployed by the test with the [`Deployer`][crate::deploy::Deployer], when
+//! the Wasm was uploaded with the deployer in the same test binary. Contracts
+//! deployed by contracts, contracts deployed with an executable reference,
+//! and contracts loaded from a ledger snapshot are not included.
+//!
+//! The report is not printed automatically. Print it, or assert on it, at the
+//! end of a test that exercises the contracts, or hold the guard returned by
+//! [`print_at_drop`] while they are exercised:
+//!
+//! ```ignore
+//! #[test]
+//! fn test_coverage() {
+//!     let _coverage = coverage::print_at_drop();
+//!     // ... call the contracts ...
+//!     for contract in &coverage::report().contracts {
+//!         assert_eq!(contract.uncovered_functions().count(), 0, "{contract}");
+//!     }
+//! }
+//! ```
+//!
+//! ```text
+//! $ cargo test test_coverage -- --nocapture
+//! ...
+//! contract my_contract::Contract: 2 of 3 functions, 1 of 2 errors covered
+//!   fn deposit: 4 calls
+//!   fn withdraw: 2 calls
+//!   fn close: not called
+//!   error Error::Insufficient (1): 1 return
+//!   error Error::Unauthorized (2): not returned
+//! ```
+//!
+//! The report covers the calls made by all the tests of the test binary that
+//! have run so far, so a report that covers the whole binary needs the tests
+//! to run in one thread, e.g. with `--test-threads=1` and a test that runs
+//! last, or a test binary with `harness = false`.
+use core::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+use crate::{
+    env::internal::events::HostEvent,
+    testutils::trace,
+    xdr::{self, Limits, ReadXdr},
+};
+
+/// The code of a registered contract.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Code {
+    /// A natively registered contract, identified by the name of its type.
+    Native(&'static str),
+    /// A Wasm contract, identified by the hash of its Wasm.
+    Wasm([u8; 32]),
+}
+
+/// The spec of the functions of a natively registered contract.
+#[derive(Default)]
+struct NativeSpec {
+    /// The modules of the contractimpl blocks of the contract.
+    modules: BTreeSet<&'static str>,
+    functions: std::vec::Vec<&'static [u8]>,
+}
+
+/// The calls made to a function.
+#[derive(Default)]
+struct Calls {
+    count: u64,
+    /// The number of calls that failed with each contract error code.
+    errors: BTreeMap<u32, u64>,
+}
+
+#[derive(Default)]
+struct State {
+    native_specs: BTreeMap<&'static str, NativeSpec>,
+    /// The spec of every contracterror type, with the module it is in.
+    error_specs: std::vec::Vec<(&'static str, &'static [u8])>,
+    wasm_specs: BTreeMap<[u8; 32], std::vec::Vec<xdr::ScSpecEntry>>,
+    registered: BTreeSet<Code>,
+    calls: BTreeMap<Code, BTreeMap<std::string::String, Calls>>,
+}
+
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    static STATE: OnceLock<Mutex<State>> = OnceLock::new();
+    let mut state = STATE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    f(&mut state)
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Returns true if the `SOROBAN_COVERAGE` environment variable is set to `1`.
+fn enabled_by_env() -> bool {
+    static ENABLED_BY_ENV: OnceLock<bool> = OnceLock::new();
+    *ENABLED_BY_ENV.get_or_init(|| std::env::var_os("SOROBAN_COVERAGE").is_some_and(|v| v == "1"))
+}
+
+/// Collect the calls made to registered contracts from now on, without the
+/// `SOROBAN_COVERAGE` environment variable being set.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub(crate) fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed) || enabled_by_env()
+}
+
+// The specs are registered by reference, and only parsed when a report is
+// made, so that registering them costs little when collecting is disabled.
+
+#[doc(hidden)]
+pub fn register_function_spec(
+    contract: &'static str,
+    module: &'static str,
+    spec_xdr: &'static [u8],
+) {
+    with_state(|s| {
+        let spec = s.native_specs.entry(contract).or_default();
+        spec.modules.insert(module);
+        spec.functions.push(spec_xdr);
+    });
+}
+
+#[doc(hidden)]
+pub fn register_error_spec(module: &'static str, spec_xdr: &'static [u8]) {
+    with_state(|s| s.error_specs.push((module, spec_xdr)));
+}
+
+/// Reads the spec of the Wasm, unless it has been read already.
+pub(crate) fn register_wasm(hash: [u8; 32], wasm: &[u8]) {
+    with_state(|s| {
+        s.wasm_specs
+            .entry(hash)
+            .or_insert_with(|| soroban_spec::read::from_wasm(wasm).unwrap_or_default());
+    });
+}
+
+/// A call made to a contract.
+struct Call {
+    contract: xdr::ScAddress,
+    function: std::string::String,
+    error: Option<u32>,
+}
+
+/// The contracts registered with an [`Env`][crate::Env], and the calls made to
+/// them.
+#[derive(Default)]
+pub(crate) struct Contracts {
+    codes: BTreeMap<xdr::ScAddress, Code>,
+    /// Calls made in the last invocation to contracts that were not
+    /// registered, such as the call of the constructor of a contract being
+    /// registered.
+    unregistered: std::vec::Vec<Call>,
+}
+
+impl Contracts {
+    /// Associates the contract at the address with its code, including any
+    /// calls made to it while it was being registered.
+    pub(crate) fn register(&mut self, contract: xdr::ScAddress, code: Code) {
+        let calls = core::mem::take(&mut self.unregistered);
+        with_state(|s| {
+            s.registered.insert(code.clone());
+            for call in calls.iter().filter(|c| c.contract == contract) {
+                s.record(&code, call);
+            }
+        });
+        self.codes.insert(contract, code);
+    }
+
+    /// Records the calls made by the invocation that is finishing.
+    pub(crate) fn record(&mut self, events: &[HostEvent]) {
+        fn calls(frames: &[trace::Frame], out: &mut std::vec::Vec<Call>) {
+            for frame in frames {
+                out.push(Call {
+                    contract: frame.contract.clone(),
+                    function: frame.function.0.to_utf8_string_lossy(),
+                    error: match frame.result {
+                        Err(xdr::ScError::Contract(code)) => Some(code),
+                        _ => None,
+                    },
+                });
+                calls(&frame.sub_frames, out);
+            }
+        }
+        let mut all = std::vec::Vec::new();
+        calls(&trace::finishing_frames(events), &mut all);
+        let (registered, unregistered): (std::vec::Vec<_>, _) = all
+            .into_iter()
+            .partition(|c| self.codes.contains_key(&c.contract));
+        self.unregistered = unregistered;
+        with_state(|s| {
+            for call in registered {
+                s.record(&self.codes[&call.contract], &call);
+            }
+        });
+    }
+}
+
+impl State {
+    fn record(&mut self, code: &Code, call: &Call) {
+        let calls = self
+            .calls
+            .entry(code.clone())
+            .or_default()
+            .entry(call.function.clone())
+            .or_default();
+        calls.count += 1;
+        if let Some(error) = call.error {
+            *calls.errors.entry(error).or_default() += 1;
+        }
+    }
+
+    /// Returns the spec of the natively registered contract.
+    fn native_spec(&self, spec: &NativeSpec) -> std::vec::Vec<xdr::ScSpecEntry> {
+        let parse = |xdr: &[u8]| xdr::ScSpecEntry::from_xdr(xdr, Limits::none()).ok();
+        let mut entries: std::vec::Vec<_> =
+            spec.functions.iter().filter_map(|f| parse(f)).collect();
+        let errors: std::vec::Vec<_> = self
+            .error_specs
+            .iter()
+            .filter_map(|(module, xdr)| match parse(xdr)? {
+                xdr::ScSpecEntry::UdtErrorEnumV0(e) => Some((*module, e)),
+                _ => None,
+            })
+            .collect();
+
+        // The error types returned by the functions are resolved by name,
+        // preferring the types in the modules of the contract, then in the
+        // crates of the contract, then the only type with the name.
+        let crate_of = |module: &str| module.split("::").next().unwrap_or_default().to_owned();
+        let crates: BTreeSet<_> = spec.modules.iter().map(|m| crate_of(m)).collect();
+        let mut names: BTreeSet<std::string::String> = errors
+            .iter()
+            .filter(|(module, _)| spec.modules.contains(module))
+            .map(|(_, e)| e.name.to_utf8_string_lossy())
+            .collect();
+        names.extend(entries.iter().filter_map(|e| match e {
+            xdr::ScSpecEntry::FunctionV0(f) => match f.outputs.first()? {
+                xdr::ScSpecTypeDef::Result(r) => match &*r.error_type {
+                    xdr::ScSpecTypeDef::Udt(u) => Some(u.name.to_utf8_string_lossy()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }));
+        for name in names {
+            let candidates: std::vec::Vec<_> = errors
+                .iter()
+                .filter(|(_, e)| e.name.to_utf8_string_lossy() == name)
+                .collect();
+            let in_modules = candidates.iter().find(|(m, _)| spec.modules.contains(m));
+            let in_crates = || {
+                let mut c = candidates
+                    .iter()
+                    .filter(|(m, _)| crates.contains(&crate_of(m)));
+                c.next().filter(|_| c.next().is_none())
+            };
+            let only = || Some(&candidates).filter(|c| c.len() == 1).map(|c| &c[0]);
+            if let Some((_, e)) = in_modules.or_else(in_crates).or_else(only) {
+                entries.push(xdr::ScSpecEntry::UdtErrorEnumV0(e.clone()));
+            }
+        }
+        entries
+    }
+
+    fn report(&self) -> Report {
+        let mut contracts = std::vec::Vec::new();
+        for code in &self.registered {
+            let (contract, spec) = match code {
+                Code::Native(name) => match self.native_specs.get(name) {
+                    Some(spec) => ((*name).to_owned(), self.native_spec(spec)),
+                    None => continue,
+                },
+                Code::Wasm(hash) => match self.wasm_specs.get(hash) {
+                    Some(spec) if !spec.is_empty() => {
+                        let hex: std::string::String =
+                            hash.iter().map(|b| format!("{b:02x}")).collect();
+                        (format!("wasm {hex}"), spec.clone())
+                    }
+                    _ => continue,
+                },
+            };
+            let no_calls = BTreeMap::new();
+            let calls = self.calls.get(code).unwrap_or(&no_calls);
+            contracts.push(ContractCoverage::new(contract, &spec, calls));
+        }
+        Report { contracts }
+    }
+}
+
+/// The coverage of the specs of the contracts registered by the tests.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The contracts, ordered by name for natively registered contracts and
+    /// by Wasm hash for Wasm contracts.
+    pub contracts: std::vec::Vec<ContractCoverage>,
+}
+
+/// The coverage of the spec of a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractCoverage {
+    /// The name of the type of the contract for a natively registered
+    /// contract, or the hash of the Wasm for a Wasm contract.
+    pub contract: std::string::String,
+    /// The functions in the spec, ordered by name.
+    pub functions: std::vec::Vec<FunctionCoverage>,
+    /// The variants of the error types in the spec, ordered by type and
+    /// value.
+    pub errors: std::vec::Vec<ErrorCoverage>,
+}
+
+/// The coverage of a function in the spec of a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionCoverage {
+    /// Name of the function.
+    pub name: std::string::String,
+    /// Number of calls made to the function, including calls that failed.
+    pub calls: u64,
+}
+
+/// The coverage of a variant of an error type in the spec of a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorCoverage {
+    /// Name of the error type.
+    pub error: std::string::String,
+    /// Name of the variant.
+    pub variant: std::string::String,
+    /// Value of the variant.
+    pub value: u32,
+    /// Number of calls to the contract that failed with the variant.
+    pub returns: u64,
+}
+
+impl ContractCoverage {
+    fn new(
+        contract: std::string::String,
+        spec: &[xdr::ScSpecEntry],
+        calls: &BTreeMap<std::string::String, Calls>,
+    ) -> Self {
+        let mut functions = BTreeMap::new();
+        let mut errors = BTreeMap::new();
+        for entry in spec {
+            match entry {
+                xdr::ScSpecEntry::FunctionV0(f) => {
+                    functions.insert(f.name.to_utf8_string_lossy(), f);
+                }
+                xdr::ScSpecEntry::UdtErrorEnumV0(e) => {
+                    errors.insert(e.name.to_utf8_string_lossy(), e);
+                }
+                _ => {}
+            }
+        }
+
+        let mut returns = BTreeMap::<(std::string::String, u32), u64>::new();
+        for (name, calls) in calls {
+            // An error code is attributed to the error type the function
+            // returns, or else to the first error type with the code.
+            let returned = functions.get(name).and_then(|f| match f.outputs.first()? {
+                xdr::ScSpecTypeDef::Result(r) => match &*r.error_type {
+                    xdr::ScSpecTypeDef::Udt(u) => Some(u.name.to_utf8_string_lossy()),
+                    _ => None,
+                },
+                _ => None,
+            });
+            for (value, count) in &calls.errors {
+                let has_value =
+                    |e: &&xdr::ScSpecUdtErrorEnumV0| e.cases.iter().any(|c| c.value == *value);
+                let error = returned
+                    .as_ref()
+                    .filter(|name| errors.get(*name).is_some_and(&has_value))
+                    .cloned()
+                    .or_else(|| {
+                        errors
+                            .iter()
+                            .find(|(_, e)| has_value(e))
+                            .map(|(name, _)| name.clone())
+                    });
+                if let Some(error) = error {
+                    *returns.entry((error, *value)).or_default() += count;
+                }
+            }
+        }
+
+        Self {
+            contract,
+            functions: functions
+                .into_keys()
+                .map(|name| FunctionCoverage {
+                    calls: calls.get(&name).map_or(0, |c| c.count),
+                    name,
+                })
+                .collect(),
+            errors: errors
+                .into_iter()
+                .flat_map(|(error, e)| {
+                    let mut cases = e.cases.to_vec();
+                    cases.sort_by_key(|c| c.value);
+                    let returns = &returns;
+                    cases.into_iter().map(move |c| ErrorCoverage {
+                        returns: returns
+                            .get(&(error.clone(), c.value))
+                            .copied()
+                            .unwrap_or_default(),
+                        error: error.clone(),
+                        variant: c.name.to_utf8_string_lossy(),
+                        value: c.value,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the functions in the spec that were not called.
+    pub fn uncovered_functions(&self) -> impl Iterator<Item = &FunctionCoverage> {
+        self.functions.iter().filter(|f| f.calls == 0)
+    }
+
+    /// Returns the variants of the error types in the spec that were not
+    /// returned.
+    pub fn uncovered_errors(&self) -> impl Iterator<Item = &ErrorCoverage> {
+        self.errors.iter().filter(|e| e.returns == 0)
+    }
+}
+
+/// Returns the coverage of the specs of the contracts registered so far.
+///
+/// Only contracts registered while collecting is enabled, with the
+/// `SOROBAN_COVERAGE` environment variable or [`enable`], are included.
+pub fn report() -> Report {
+    with_state(|s| s.report())
+}
+
+/// Enables collecting, and returns a guard that prints the [`Report`] to
+/// stderr when it is dropped.
+///
+/// The test harness captures the output of a passing test unless it is run
+/// with `--nocapture`.
+pub fn print_at_drop() -> PrintAtDrop {
+    enable();
+    PrintAtDrop(())
+}
+
+/// Prints the [`Report`] to stderr when dropped, see [`print_at_drop`].
+#[must_use]
+pub struct PrintAtDrop(());
+
+impl Drop for PrintAtDrop {
+    fn drop(&mut self) {
+        let report = report();
+        if !report.contracts.is_empty() {
+            eprint!("\n{report}");
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for contract in &self.contracts {
+            write!(f, "{contract}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ContractCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "contract {}: {} of {} functions, {} of {} errors covered",
+            self.contract,
+            self.functions.len() - self.uncovered_functions().count(),
+            self.functions.len(),
+            self.errors.len() - self.uncovered_errors().count(),
+            self.errors.len(),
+        )?;
+        let plural = |n: u64| if n == 1 { "" } else { "s" };
+        for function in &self.functions {
+            match function.calls {
+                0 => writeln!(f, "  fn {}: not called", function.name)?,
+                n => writeln!(f, "  fn {}: {n} call{}", function.name, plural(n))?,
+            }
+        }
+        for error in &self.errors {
+            write!(
+                f,
+                "  error {}::{} ({}): ",
+                error.error, error.variant, error.value
+            )?;
+            match error.returns {
+                0 => writeln!(f, "not returned")?,
+                n => writeln!(f, "{n} return{}", plural(n))?,
+            }
+        }
+        Ok(())
+    }
+}
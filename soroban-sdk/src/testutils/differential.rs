@@ -0,0 +1,363 @@
+//! Differential testing of a contract registered natively and from its Wasm.
+
+use core::fmt;
+use std::rc::Rc;
+
+use soroban_ledger_snapshot::{DisplayScVal, LedgerSnapshot, LedgerSnapshotDiff};
+
+use crate::{
+    testutils::{EnvTestConfig, Register},
+    xdr, Address, ConstructorArgs, Env,
+};
+
+/// A harness that runs the same calls against a contract registered natively
+/// and registered from its compiled Wasm, and asserts that they behave the
+/// same.
+///
+/// The contract is registered in two Envs, at the same address, so that the
+/// calls made in each Env are identical. After every call the harness
+/// compares the value returned by the call, the events published by the
+/// invocations of the call, and the contract data in the ledger of each Env.
+///
+/// Native builds can hide differences that only appear when the contract runs
+/// as Wasm, such as differences in overflow checks or allocation behaviour.
+/// The costs of native execution are also underestimated, so the harness
+/// records the CPU instructions and memory of the last invocation of each call
+/// in each Env, see [`Differential::costs`].
+///
+/// ### Examples
+/// ```
+/// use soroban_sdk::{contract, contractimpl, testutils::Differential};
+///
+/// mod wasm {
+///     soroban_sdk::contractimport!(file = "doctest_fixtures/contract.wasm");
+/// }
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn add(a: u64, b: u64) -> u64 {
+///         a + b
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # fn main() {
+///     let mut diff = Differential::new(Contract, wasm::WASM, |_| ());
+///     let r = diff.call(|env, id| wasm::Client::new(env, id).try_add(&1, &2));
+///     assert_eq!(r, Ok(Ok(3)));
+///     // Overflows panic natively and trap as Wasm, and both fail the call.
+///     let r = diff.call(|env, id| wasm::Client::new(env, id).try_add(&u64::MAX, &1));
+///     assert!(r.is_err());
+///     std::println!("{}", diff.costs());
+/// }
+/// ```
+pub struct Differential {
+    native: Env,
+    wasm: Env,
+    native_id: Address,
+    wasm_id: Address,
+    calls: usize,
+    costs: std::vec::Vec<CallCosts>,
+}
+
+impl Differential {
+    /// Registers the contract natively in one Env, and the Wasm of the
+    /// contract in another Env at the same address.
+    ///
+    /// The constructor arguments are created for each Env by the function.
+    pub fn new<C, A>(contract: C, wasm: &[u8], constructor_args: impl Fn(&Env) -> A) -> Self
+    where
+        C: Register,
+        A: ConstructorArgs,
+    {
+        let config = || EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        };
+        let native = Env::new_with_config(config());
+        let wasm_env = Env::new_with_config(config());
+        native.enable_event_journal();
+        wasm_env.enable_event_journal();
+
+        let native_id = native.register(contract, constructor_args(&native));
+        let wasm_id = Address::from_str(&wasm_env, &native_id.to_string().to_string());
+        wasm_env.register_at(&wasm_id, wasm, constructor_args(&wasm_env));
+        // Native and Wasm contracts are registered with different TTLs, so
+        // give the Wasm contract instance the TTL of the native one.
+        let key = Rc::new(xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+            contract: (&native_id).into(),
+            key: xdr::ScVal::LedgerKeyContractInstance,
+            durability: xdr::ContractDataDurability::Persistent,
+        }));
+        let (_, live_until) = native.host().get_ledger_entry(&key).unwrap().unwrap();
+        let (entry, _) = wasm_env.host().get_ledger_entry(&key).unwrap().unwrap();
+        wasm_env
+            .host()
+            .add_ledger_entry(&key, &entry, live_until)
+            .unwrap();
+        // Keep the generators of both Envs the same, so that the values the
+        // calls generate are the same in both.
+        let generators = native.with_generator(|g| g.clone());
+        wasm_env.with_generator(|mut g| *g = generators);
+
+        Differential {
+            native,
+            wasm: wasm_env,
+            native_id,
+            wasm_id,
+            calls: 0,
+            costs: std::vec::Vec::new(),
+        }
+    }
+
+    /// Returns the Env the contract is registered natively in.
+    pub fn native(&self) -> &Env {
+        &self.native
+    }
+
+    /// Returns the Env the Wasm of the contract is registered in.
+    pub fn wasm(&self) -> &Env {
+        &self.wasm
+    }
+
+    /// Runs the function with each Env and the address of the contract in it,
+    /// such as to set up the ledger or authorization of both Envs.
+    pub fn setup(&self, f: impl Fn(&Env, &Address)) {
+        f(&self.native, &self.native_id);
+        f(&self.wasm, &self.wasm_id);
+    }
+
+    /// Runs the call with each Env and the address of the contract in it, and
+    /// returns the value returned by the native call.
+    ///
+    /// Use the `try_` functions of contract clients to compare errors as well
+    /// as values.
+    ///
+    /// ### Panics
+    ///
+    /// If the values returned, the events published, or the contract data
+    /// differ between the native contract and the Wasm contract.
+    pub fn call<T>(&mut self, f: impl Fn(&Env, &Address) -> T) -> T
+    where
+        T: fmt::Debug + PartialEq,
+    {
+        match self.try_call(f) {
+            Ok(v) => v,
+            Err(e) => panic!("native and wasm contracts differ:\n{e}"),
+        }
+    }
+
+    /// Runs the call with each Env and the address of the contract in it, and
+    /// returns the value returned by the native call, or the differences
+    /// between the native contract and the Wasm contract.
+    pub fn try_call<T>(&mut self, f: impl Fn(&Env, &Address) -> T) -> Result<T, Box<Mismatch>>
+    where
+        T: fmt::Debug + PartialEq,
+    {
+        let call = self.calls;
+        self.calls += 1;
+
+        let native_invocations = self.native.event_journal().entries().len();
+        let wasm_invocations = self.wasm.event_journal().entries().len();
+        let native_count = self.native.invocation_count();
+        let wasm_count = self.wasm.invocation_count();
+        let native_result = f(&self.native, &self.native_id);
+        let wasm_result = f(&self.wasm, &self.wasm_id);
+
+        // The resources of the last invocation are stale if the call made no
+        // invocation. Failed invocations are counted, though the journal has
+        // no entries for them.
+        let native_resources = self
+            .native
+            .host()
+            .get_last_invocation_resources()
+            .filter(|_| self.native.invocation_count() != native_count);
+        let wasm_resources = self
+            .wasm
+            .host()
+            .get_last_invocation_resources()
+            .filter(|_| self.wasm.invocation_count() != wasm_count);
+        if let (Some(native), Some(wasm)) = (native_resources, wasm_resources) {
+            self.costs.push(CallCosts {
+                call,
+                native_cpu_insns: native.instructions,
+                native_mem_bytes: native.mem_bytes,
+                wasm_cpu_insns: wasm.instructions,
+                wasm_mem_bytes: wasm.mem_bytes,
+            });
+        }
+
+        let native_events = events_since(&self.native, native_invocations);
+        let wasm_events = events_since(&self.wasm, wasm_invocations);
+        let mismatch = Mismatch {
+            call,
+            result: (native_result != wasm_result)
+                .then(|| (format!("{native_result:?}"), format!("{wasm_result:?}"))),
+            events: (native_events != wasm_events).then_some((native_events, wasm_events)),
+            ledger: contract_data(&self.native).diff(&contract_data(&self.wasm)),
+        };
+        if mismatch.is_empty() {
+            Ok(native_result)
+        } else {
+            Err(Box::new(mismatch))
+        }
+    }
+
+    /// Returns the CPU instructions and memory of the last invocation of each
+    /// call made, natively and as Wasm.
+    ///
+    /// Calls that made no invocations are not included.
+    pub fn costs(&self) -> Costs {
+        Costs(self.costs.clone())
+    }
+}
+
+/// Returns the events of the invocations recorded in the event journal after
+/// the number of invocations given.
+fn events_since(env: &Env, invocations: usize) -> std::vec::Vec<xdr::ContractEvent> {
+    env.event_journal().entries()[invocations..]
+        .iter()
+        .flat_map(|e| e.events.iter().cloned())
+        .collect()
+}
+
+/// Returns a snapshot of the ledger with only the contract data entries, and
+/// with the executable of contract instances removed, because the executables
+/// of the native and Wasm contracts always differ.
+fn contract_data(env: &Env) -> LedgerSnapshot {
+    let snapshot = env.to_ledger_snapshot();
+    let entries = snapshot
+        .entries()
+        .into_iter()
+        .filter(|(k, _)| matches!(***k, xdr::LedgerKey::ContractData(_)))
+        .map(|(k, (entry, live_until))| {
+            let mut entry = entry.clone();
+            if let xdr::LedgerEntryData::ContractData(xdr::ContractDataEntry {
+                val: xdr::ScVal::ContractInstance(instance),
+                ..
+            }) = &mut entry.data
+            {
+                instance.executable = xdr::ContractExecutable::Wasm(xdr::Hash([0; 32]));
+            }
+            (k.clone(), entry, *live_until)
+        })
+        .collect::<std::vec::Vec<_>>();
+    let mut normalized = snapshot.clone();
+    normalized.set_entries(entries.iter().map(|(k, e, l)| (k, (e, *l))));
+    normalized
+}
+
+/// The differences between the native contract and the Wasm contract after a
+/// call, returned by [`Differential::try_call`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The index of the call, starting at zero.
+    pub call: usize,
+    /// The native and Wasm values returned, if they differ.
+    pub result: Option<(std::string::String, std::string::String)>,
+    /// The native and Wasm events published, if they differ.
+    pub events: Option<(
+        std::vec::Vec<xdr::ContractEvent>,
+        std::vec::Vec<xdr::ContractEvent>,
+    )>,
+    /// The changes from the native contract data to the Wasm contract data.
+    pub ledger: LedgerSnapshotDiff,
+}
+
+impl Mismatch {
+    /// Returns true if there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.result.is_none() && self.events.is_none() && self.ledger.is_empty()
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "call {}:", self.call)?;
+        if let Some((native, wasm)) = &self.result {
+            writeln!(f, "  result:")?;
+            writeln!(f, "    native: {native}")?;
+            writeln!(f, "    wasm:   {wasm}")?;
+        }
+        if let Some((native, wasm)) = &self.events {
+            writeln!(f, "  events:")?;
+            for (name, events) in [("native", native), ("wasm", wasm)] {
+                writeln!(f, "    {name}:")?;
+                for e in events {
+                    let xdr::ContractEventBody::V0(body) = &e.body;
+                    let topics = body
+                        .topics
+                        .iter()
+                        .map(|t| DisplayScVal(t).to_string())
+                        .collect::<std::vec::Vec<_>>()
+                        .join(", ");
+                    writeln!(f, "      [{topics}] {}", DisplayScVal(&body.data))?;
+                }
+            }
+        }
+        if !self.ledger.is_empty() {
+            writeln!(f, "  contract data (- native, + wasm):")?;
+            for line in self.ledger.to_string().lines() {
+                writeln!(f, "    {line}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The CPU instructions and memory of the last invocation of a call, natively
+/// and as Wasm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallCosts {
+    /// The index of the call, starting at zero.
+    pub call: usize,
+    pub native_cpu_insns: i64,
+    pub native_mem_bytes: i64,
+    pub wasm_cpu_insns: i64,
+    pub wasm_mem_bytes: i64,
+}
+
+impl CallCosts {
+    /// Returns the CPU instructions of the Wasm invocation less the CPU
+    /// instructions of the native invocation.
+    pub fn cpu_insns_gap(&self) -> i64 {
+        self.wasm_cpu_insns - self.native_cpu_insns
+    }
+
+    /// Returns the memory bytes of the Wasm invocation less the memory bytes
+    /// of the native invocation.
+    pub fn mem_bytes_gap(&self) -> i64 {
+        self.wasm_mem_bytes - self.native_mem_bytes
+    }
+}
+
+/// The costs of the calls made with a [`Differential`], returned by
+/// [`Differential::costs`].
+///
+/// The [`Display`][fmt::Display] form lists the costs of each call, one per
+/// line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Costs(pub std::vec::Vec<CallCosts>);
+
+impl fmt::Display for Costs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in &self.0 {
+            writeln!(
+                f,
+                "call {}: cpu insns native {} wasm {} gap {:+}, mem bytes native {} wasm {} gap {:+}",
+                c.call,
+                c.native_cpu_insns,
+                c.wasm_cpu_insns,
+                c.cpu_insns_gap(),
+                c.native_mem_bytes,
+                c.wasm_mem_bytes,
+                c.mem_bytes_gap(),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+//! Contracts registered from closures, for test doubles that are too small to
+//! be worth a [`contract`][crate::contract] and
+//! [`contractimpl`][crate::contractimpl].
+//!
+//! [`Env::register_fn_contract`] returns a [`FnContract`] builder. Each
+//! function of the contract is a closure that is given the [`Env`] and the
+//! arguments of the call, and returns any value that converts into a [`Val`],
+//! including a `Result` with a [`contracterror`][crate::contracterror] error.
+//!
+//! The builder can optionally be given the spec of the interface the contract
+//! implements, such as the spec in the Wasm of a contract imported with
+//! [`contractimport`][crate::contractimport]. With a spec, registering checks
+//! that every function is in the spec, and every call checks the arguments
+//! and the value returned against the types in the spec, so that clients of
+//! the interface call the contract the same way they would call a contract
+//! implementing the interface.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::{
+//!     contracterror, token::TokenClient, Address, Env, testutils::Address as _,
+//! };
+//!
+//! #[contracterror]
+//! #[derive(Copy, Clone, Debug, PartialEq)]
+//! pub enum Error {
+//!     Frozen = 1,
+//! }
+//!
+//! #[test]
+//! fn test() {
+//! # }
+//! # fn main() {
+//!     let env = Env::default();
+//!     let token_id = env
+//!         .register_fn_contract()
+//!         .func("balance", |_, _| 100_i128)
+//!         .func("transfer", |_, _| Err::<(), _>(Error::Frozen))
+//!         .register();
+//!
+//!     let token = TokenClient::new(&env, &token_id);
+//!     let (a, b) = (Address::generate(&env), Address::generate(&env));
+//!     assert_eq!(token.balance(&a), 100);
+//!     assert!(token.try_transfer(&a, &b, &1).is_err());
+//! }
+//! ```
+use core::fmt;
+use std::{collections::BTreeMap, rc::Rc};
+
+use crate::{testutils::ContractFunctionSet, xdr, Address, Env, IntoVal, TryFromVal, Val, Vec};
+
+type Func = Rc<dyn Fn(&Env, Vec<Val>) -> Val>;
+
+/// A builder of a contract registered from closures.
+///
+/// Returned by [`Env::register_fn_contract`]. See the [module docs][self] for
+/// an example.
+#[must_use = "the contract is only registered by calling `register`"]
+pub struct FnContract {
+    env: Env,
+    funcs: BTreeMap<std::string::String, Func>,
+    spec: Option<std::vec::Vec<xdr::ScSpecEntry>>,
+}
+
+impl fmt::Debug for FnContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnContract")
+            .field("funcs", &self.funcs.keys().collect::<std::vec::Vec<_>>())
+            .field("spec", &self.spec.is_some())
+            .finish()
+    }
+}
+
+impl FnContract {
+    pub(crate) fn new(env: &Env) -> Self {
+        Self {
+            env: env.clone(),
+            funcs: BTreeMap::new(),
+            spec: None,
+        }
+    }
+
+    /// Add a function to the contract.
+    ///
+    /// The closure is called with the arguments of every call of the
+    /// function. Adding a function with the same name as a function already
+    /// added replaces it.
+    pub fn func<R, F>(mut self, name: &str, f: F) -> Self
+    where
+        R: IntoVal<Env, Val>,
+        F: Fn(&Env, Vec<Val>) -> R + 'static,
+    {
+        let func: Func = Rc::new(move |env, args| f(env, args).into_val(env));
+        self.funcs.insert(name.into(), func);
+        self
+    }
+
+    /// Check the functions of the contract against the spec entries.
+    pub fn spec(mut self, spec: &[xdr::ScSpecEntry]) -> Self {
+        self.spec = Some(spec.to_vec());
+        self
+    }
+
+    /// Check the functions of the contract against the spec in the Wasm of a
+    /// contract.
+    ///
+    /// The Wasm is only read for its spec.
+    ///
+    /// ### Panics
+    ///
+    /// If the Wasm does not contain a valid spec.
+    pub fn spec_from_wasm(self, wasm: &[u8]) -> Self {
+        let spec = soroban_spec::read::from_wasm(wasm).unwrap();
+        self.spec(&spec)
+    }
+
+    /// Register the contract, returning its address.
+    ///
+    /// ### Panics
+    ///
+    /// If a spec is given and a function of the contract is not in the spec.
+    pub fn register(self) -> Address {
+        if let Some(spec) = &self.spec {
+            for name in self.funcs.keys() {
+                if function(spec, name).is_none() {
+                    panic!("function `{name}` is not in the spec");
+                }
+            }
+        }
+        let env = self.env.clone();
+        env.register(
+            FnFunctionSet {
+                funcs: self.funcs,
+                spec: self.spec,
+            },
+            (),
+        )
+    }
+}
+
+struct FnFunctionSet {
+    funcs: BTreeMap<std::string::String, Func>,
+    spec: Option<std::vec::Vec<xdr::ScSpecEntry>>,
+}
+
+impl ContractFunctionSet for FnFunctionSet {
+    fn call(&self, func: &str, env: Env, args: &[Val]) -> Option<Val> {
+        let f = self.funcs.get(func)?;
+        let Some(spec) = &self.spec else {
+            return Some(f(&env, Vec::from_slice(&env, args)));
+        };
+        let function = function(spec, func).unwrap();
+        if args.len() != function.inputs.len() {
+            panic!(
+                "function `{func}` called with {} args, the spec has {} inputs",
+                args.len(),
+                function.inputs.len()
+            );
+        }
+        for (input, arg) in function.inputs.iter().zip(args) {
+            if !conforms(&to_sc_val(&env, arg), &input.type_, spec) {
+                panic!(
+                    "arg `{}` of function `{func}` is not a {:?}",
+                    input.name.to_utf8_string_lossy(),
+                    input.type_
+                );
+            }
+        }
+        let rv = f(&env, Vec::from_slice(&env, args));
+        let output = function
+            .outputs
+            .first()
+            .unwrap_or(&xdr::ScSpecTypeDef::Void);
+        // Errors are returned by failing the call, not as a value of the output
+        // type, so they conform to any output type.
+        let rv_sc_val = to_sc_val(&env, &rv);
+        if !matches!(rv_sc_val, xdr::ScVal::Error(_)) && !conforms(&rv_sc_val, output, spec) {
+            panic!("value returned by function `{func}` is not a {output:?}");
+        }
+        Some(rv)
+    }
+}
+
+fn to_sc_val(env: &Env, v: &Val) -> xdr::ScVal {
+    xdr::ScVal::try_from_val(env, v).unwrap()
+}
+
+fn function<'a>(spec: &'a [xdr::ScSpecEntry], name: &str) -> Option<&'a xdr::ScSpecFunctionV0> {
+    spec.iter().find_map(|e| match e {
+        xdr::ScSpecEntry::FunctionV0(f) if f.name.to_utf8_string_lossy() == name => Some(f),
+        _ => None,
+    })
+}
+
+/// Returns true if the value is a value of the type in the spec.
+fn conforms(v: &xdr::ScVal, ty: &xdr::ScSpecTypeDef, spec: &[xdr::ScSpecEntry]) -> bool {
+    use xdr::{ScSpecTypeDef as T, ScVal as V};
+    match (ty, v) {
+        (T::Val, _)
+        | (T::Bool, V::Bool(_))
+        | (T::Void, V::Void)
+        | (T::Error, V::Error(_))
+        | (T::U32, V::U32(_))
+        | (T::I32, V::I32(_))
+        | (T::U64, V::U64(_))
+        | (T::I64, V::I64(_))
+        | (T::Timepoint, V::Timepoint(_))
+        | (T::Duration, V::Duration(_))
+        | (T::U128, V::U128(_))
+        | (T::I128, V::I128(_))
+        | (T::U256, V::U256(_))
+        | (T::I256, V::I256(_))
+        | (T::Bytes, V::Bytes(_))
+        | (T::String, V::String(_))
+        | (T::Symbol, V::Symbol(_))
+        | (T::MuxedAddress, V::Address(_)) => true,
+        (T::Address, V::Address(a)) => {
+            matches!(a, xdr::ScAddress::Account(_) | xdr::ScAddress::Contract(_))
+        }
+        (T::BytesN(n), V::Bytes(b)) => b.len() == n.n as usize,
+        (T::Option(_), V::Void) => true,
+        (T::Option(o), v) => conforms(v, &o.value_type, spec),
+        (T::Result(r), V::Error(_)) => conforms(v, &r.error_type, spec),
+        (T::Result(r), v) => conforms(v, &r.ok_type, spec),
+        (T::Vec(t), V::Vec(Some(vs))) => vs.iter().all(|v| conforms(v, &t.element_type, spec)),
+        (T::Map(t), V::Map(Some(m))) => m
+            .iter()
+            .all(|e| conforms(&e.key, &t.key_type, spec) && conforms(&e.val, &t.value_type, spec)),
+        (T::Tuple(t), V::Vec(Some(vs))) => {
+            vs.len() == t.value_types.len()
+                && vs
+                    .iter()
+                    .zip(t.value_types.iter())
+                    .all(|(v, t)| conforms(v, t, spec))
+        }
+        (T::Udt(udt), v) => conforms_udt(v, &udt.name.to_utf8_string_lossy(), spec),
+        _ => false,
+    }
+}
+
+/// Returns true if the value is a value of the user defined type in the spec.
+fn conforms_udt(v: &xdr::ScVal, name: &str, spec: &[xdr::ScSpecEntry]) -> bool {
+    use xdr::{ScSpecEntry as E, ScVal as V};
+    let entry = spec.iter().find(|e| match e {
+        E::UdtStructV0(s) => s.name.to_utf8_string_lossy() == name,
+        E::UdtUnionV0(s) => s.name.to_utf8_string_lossy() == name,
+        E::UdtEnumV0(s) => s.name.to_utf8_string_lossy() == name,
+        E::UdtErrorEnumV0(s) => s.name.to_utf8_string_lossy() == name,
+        _ => false,
+    });
+    match (entry, v) {
+        (Some(E::UdtStructV0(s)), V::Vec(Some(vs))) => {
+            let is_tuple = s
+                .fields
+                .iter()
+                .all(|f| f.name.as_slice().iter().all(u8::is_ascii_digit));
+            is_tuple
+                && vs.len() == s.fields.len()
+                && vs
+                    .iter()
+                    .zip(s.fields.iter())
+                    .all(|(v, f)| conforms(v, &f.type_, spec))
+        }
+        (Some(E::UdtStructV0(s)), V::Map(Some(m))) => {
+            m.len() == s.fields.len()
+                && s.fields.iter().all(|f| {
+                    m.iter().any(|e| {
+                        matches!(&e.key, V::Symbol(k) if k.as_slice() == f.name.as_slice())
+                            && conforms(&e.val, &f.type_, spec)
+                    })
+                })
+        }
+        (Some(E::UdtUnionV0(u)), V::Vec(Some(vs))) => {
+            let Some((V::Symbol(case), values)) = vs.split_first() else {
+                return false;
+            };
+            u.cases.iter().any(|c| match c {
+                xdr::ScSpecUdtUnionCaseV0::VoidV0(c) => {
+                    c.name.as_slice() == case.as_slice() && values.is_empty()
+                }
+                xdr::ScSpecUdtUnionCaseV0::TupleV0(c) => {
+                    c.name.as_slice() == case.as_slice()
+                        && values.len() == c.type_.len()
+                        && values
+                            .iter()
+                            .zip(c.type_.iter())
+                            .all(|(v, t)| conforms(v, t, spec))
+                }
+            })
+        }
+        (Some(E::UdtEnumV0(e)), V::U32(n)) => e.cases.iter().any(|c| c.value == *n),
+        (Some(E::UdtErrorEnumV0(e)), V::Error(xdr::ScError::Contract(n))) => {
+            e.cases.iter().any(|c| c.value == *n)
+        }
+        _ => false,
+    }
+}
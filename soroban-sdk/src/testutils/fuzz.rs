@@ -0,0 +1,508 @@
+//! Spec-driven fuzzing of the functions of a contract.
+//!
+//! A [`Fuzzer`] reads the functions of a contract and the types of their
+//! inputs from the contract's spec, and turns fuzzer input into a sequence of
+//! steps: calls of the contract's functions with arbitrary arguments, with
+//! authorization mocked or not, and advances of the ledger. After every step
+//! the invariants registered with the fuzzer are checked.
+//!
+//! No input struct or dispatch needs to be written for each function, so a
+//! fuzz target for a contract can be as small as:
+//!
+//! ```ignore
+//! #![no_main]
+//!
+//! use libfuzzer_sys::fuzz_target;
+//! use soroban_sdk::{testutils::fuzz::Fuzzer, Env};
+//!
+//! fuzz_target!(|data: &[u8]| {
+//!     let env = Env::default();
+//!     let contract_id = env.register(Contract, ());
+//!     let client = ContractClient::new(&env, &contract_id);
+//!
+//!     Fuzzer::from_wasm(&env, &contract_id, WASM)
+//!         .invariant("supply is never negative", move |_| client.supply() >= 0)
+//!         .run_bytes(data);
+//! });
+//! ```
+//!
+//! The arguments are generated from the types in the spec, so they are always
+//! valid inputs of the functions. Arguments of type [`Val`] and [`Error`] are
+//! generated from the prototypes of their
+//! [`SorobanArbitrary`][crate::testutils::arbitrary::SorobanArbitrary]
+//! impls. The other arguments are generated differently to `SorobanArbitrary`:
+//!
+//! - Addresses are picked from a small pool of addresses, so that calls
+//!   interact with each other, for example a transfer to an address and a
+//!   transfer from the same address.
+//! - Vecs, maps, bytes, strings and symbols have at most 8 elements, and
+//!   values are nested at most 3 levels deep.
+//! - Strings are made of arbitrary bytes, which are not necessarily UTF-8.
+//! - User defined types are generated from their definitions in the spec,
+//!   because the fuzzer does not know their Rust types. The
+//!   `#[arbitrary(range = ..)]`, `#[arbitrary(len = ..)]` and
+//!   `#[arbitrary(with = ..)]` attributes of their fields, and hand-written
+//!   `SorobanArbitrary` impls, are not applied.
+
+use core::fmt;
+
+use arbitrary::{Result, Unstructured};
+use soroban_ledger_snapshot::DisplayScVal;
+
+use crate::{
+    testutils::{arbitrary::SorobanArbitrary, Address as _, Ledger as _},
+    xdr, Address, Env, Error, Symbol, TryFromVal, Val, Vec,
+};
+
+/// The number of addresses in the default pool of addresses.
+const DEFAULT_ADDRESSES: usize = 3;
+
+/// The maximum depth of nested values generated for arguments.
+const MAX_DEPTH: usize = 3;
+
+/// The maximum number of elements generated in vecs, maps, bytes and strings.
+const MAX_LEN: usize = 8;
+
+type Invariant = std::boxed::Box<dyn Fn(&Env) -> bool>;
+
+/// Generates and runs sequences of calls to the functions of a contract, and
+/// checks invariants after every step.
+///
+/// See the [module docs][self] for an example.
+pub struct Fuzzer {
+    env: Env,
+    contract_id: Address,
+    functions: std::vec::Vec<xdr::ScSpecFunctionV0>,
+    types: std::vec::Vec<xdr::ScSpecEntry>,
+    addresses: std::vec::Vec<Address>,
+    invariants: std::vec::Vec<(std::string::String, Invariant)>,
+    max_steps: usize,
+    max_ledger_advance: u32,
+    seconds_per_ledger: u64,
+}
+
+impl Fuzzer {
+    /// Create a fuzzer for the contract using the spec entries.
+    ///
+    /// The contract's constructor and `__check_auth` are not called.
+    pub fn new(env: &Env, contract_id: &Address, spec: &[xdr::ScSpecEntry]) -> Self {
+        let functions = spec
+            .iter()
+            .filter_map(|e| match e {
+                xdr::ScSpecEntry::FunctionV0(f)
+                    if !f.name.to_utf8_string_lossy().starts_with("__") =>
+                {
+                    Some(f.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        let addresses = (0..DEFAULT_ADDRESSES)
+            .map(|_| Address::generate(env))
+            .collect();
+        Fuzzer {
+            env: env.clone(),
+            contract_id: contract_id.clone(),
+            functions,
+            types: spec.to_vec(),
+            addresses,
+            invariants: std::vec::Vec::new(),
+            max_steps: 32,
+            max_ledger_advance: 100,
+            seconds_per_ledger: 5,
+        }
+    }
+
+    /// Create a fuzzer for the contract using the spec in the contract's
+    /// Wasm.
+    ///
+    /// The Wasm is only read for its spec, the contract registered may be a
+    /// native contract.
+    ///
+    /// ### Panics
+    ///
+    /// If the Wasm does not contain a valid spec.
+    pub fn from_wasm(env: &Env, contract_id: &Address, wasm: &[u8]) -> Self {
+        let spec = soroban_spec::read::from_wasm(wasm).unwrap();
+        Self::new(env, contract_id, &spec)
+    }
+
+    /// Set the pool of addresses that address arguments are picked from.
+    ///
+    /// By default the pool contains a few generated addresses.
+    pub fn addresses(mut self, addresses: &[Address]) -> Self {
+        self.addresses = addresses.to_vec();
+        self
+    }
+
+    /// Set the maximum number of steps in a sequence. Defaults to 32.
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Set the maximum number of ledgers a step advances the ledger by.
+    /// Defaults to 100. Set to zero to never advance the ledger.
+    pub fn max_ledger_advance(mut self, ledgers: u32) -> Self {
+        self.max_ledger_advance = ledgers;
+        self
+    }
+
+    /// Set the number of seconds the ledger timestamp advances by for each
+    /// ledger the ledger is advanced by. Defaults to 5.
+    pub fn seconds_per_ledger(mut self, seconds: u64) -> Self {
+        self.seconds_per_ledger = seconds;
+        self
+    }
+
+    /// Register an invariant that must hold after every step.
+    pub fn invariant(mut self, name: &str, f: impl Fn(&Env) -> bool + 'static) -> Self {
+        self.invariants.push((name.into(), std::boxed::Box::new(f)));
+        self
+    }
+
+    /// Generate and run a sequence of steps from the fuzzer input, returning
+    /// the steps run.
+    ///
+    /// ### Panics
+    ///
+    /// If an invariant does not hold after a step. The panic message lists
+    /// the steps run.
+    pub fn run_bytes(&self, data: &[u8]) -> std::vec::Vec<Step> {
+        self.run(&mut Unstructured::new(data)).unwrap_or_default()
+    }
+
+    /// Generate and run a sequence of steps from the unstructured data,
+    /// returning the steps run.
+    ///
+    /// ### Panics
+    ///
+    /// If an invariant does not hold after a step. The panic message lists
+    /// the steps run.
+    pub fn run(&self, u: &mut Unstructured) -> Result<std::vec::Vec<Step>> {
+        let mut steps = std::vec::Vec::new();
+        self.check_invariants(&steps);
+        u.arbitrary_loop(None, Some(self.max_steps as u32), |u| {
+            let step = self.step(u)?;
+            steps.push(step);
+            self.check_invariants(&steps);
+            Ok(core::ops::ControlFlow::Continue(()))
+        })?;
+        Ok(steps)
+    }
+
+    fn check_invariants(&self, steps: &[Step]) {
+        for (name, f) in &self.invariants {
+            if !f(&self.env) {
+                let mut msg = std::format!("invariant {name:?} does not hold after steps:");
+                for (i, step) in steps.iter().enumerate() {
+                    msg.push_str(&std::format!("\n  {i}: {step}"));
+                }
+                panic!("{msg}");
+            }
+        }
+    }
+
+    fn step(&self, u: &mut Unstructured) -> Result<Step> {
+        if self.functions.is_empty() || (self.max_ledger_advance > 0 && u.ratio(1, 8)?) {
+            let ledgers = u.int_in_range(1..=self.max_ledger_advance.max(1))?;
+            self.env.ledger().advance(ledgers, self.seconds_per_ledger);
+            return Ok(Step::AdvanceLedger(ledgers));
+        }
+
+        let f = u.choose(&self.functions)?;
+        let args = f
+            .inputs
+            .iter()
+            .map(|i| self.arbitrary(u, &i.type_, MAX_DEPTH))
+            .collect::<Result<std::vec::Vec<_>>>()?;
+        let auth = u.arbitrary::<bool>()?;
+        let function = f.name.to_utf8_string_lossy();
+
+        // The auth mode of the call is only for the call, the invariants and
+        // the test code after the run see the auth mode set by the test.
+        let prev_auth_manager = self.env.host().snapshot_auth_manager().unwrap();
+        if auth {
+            self.env.mock_all_auths();
+        } else {
+            self.env.set_auths(&[]);
+        }
+        let mut vals = Vec::<Val>::new(&self.env);
+        for a in &args {
+            vals.push_back(Val::try_from_val(&self.env, a).unwrap());
+        }
+        let result = self.env.try_invoke_contract::<Val, Error>(
+            &self.contract_id,
+            &Symbol::new(&self.env, &function),
+            vals,
+        );
+        self.env.host().set_auth_manager(prev_auth_manager).unwrap();
+        let result = match result {
+            Ok(Ok(v)) => Ok(xdr::ScVal::try_from_val(&self.env, &v).unwrap()),
+            Ok(Err(_)) => unreachable!("a val always converts to a val"),
+            Err(Ok(e)) => Err(e),
+            Err(Err(_)) => unreachable!("an error always converts to an error"),
+        };
+        Ok(Step::Call {
+            function,
+            args,
+            auth,
+            result,
+        })
+    }
+
+    /// Generate an arbitrary value of the spec type.
+    fn arbitrary(
+        &self,
+        u: &mut Unstructured,
+        t: &xdr::ScSpecTypeDef,
+        depth: usize,
+    ) -> Result<xdr::ScVal> {
+        use xdr::ScSpecTypeDef as T;
+        let len = |u: &mut Unstructured| -> Result<usize> {
+            if depth == 0 {
+                Ok(0)
+            } else {
+                u.int_in_range(0..=MAX_LEN)
+            }
+        };
+        Ok(match t {
+            T::Val => self.prototype::<Val>(u)?,
+            T::Bool => xdr::ScVal::Bool(u.arbitrary()?),
+            T::Void => xdr::ScVal::Void,
+            T::Error => self.prototype::<Error>(u)?,
+            T::U32 => xdr::ScVal::U32(u.arbitrary()?),
+            T::I32 => xdr::ScVal::I32(u.arbitrary()?),
+            T::U64 => xdr::ScVal::U64(u.arbitrary()?),
+            T::I64 => xdr::ScVal::I64(u.arbitrary()?),
+            T::Timepoint => xdr::ScVal::Timepoint(xdr::TimePoint(u.arbitrary()?)),
+            T::Duration => xdr::ScVal::Duration(xdr::Duration(u.arbitrary()?)),
+            T::U128 => xdr::ScVal::U128(xdr::UInt128Parts {
+                hi: u.arbitrary()?,
+                lo: u.arbitrary()?,
+            }),
+            T::I128 => xdr::ScVal::I128(xdr::Int128Parts {
+                hi: u.arbitrary()?,
+                lo: u.arbitrary()?,
+            }),
+            T::U256 => xdr::ScVal::U256(xdr::UInt256Parts {
+                hi_hi: u.arbitrary()?,
+                hi_lo: u.arbitrary()?,
+                lo_hi: u.arbitrary()?,
+                lo_lo: u.arbitrary()?,
+            }),
+            T::I256 => xdr::ScVal::I256(xdr::Int256Parts {
+                hi_hi: u.arbitrary()?,
+                hi_lo: u.arbitrary()?,
+                lo_hi: u.arbitrary()?,
+                lo_lo: u.arbitrary()?,
+            }),
+            T::Bytes => {
+                let n = len(u)?;
+                let b = u.bytes(n)?.to_vec();
+                xdr::ScVal::Bytes(b.try_into().unwrap())
+            }
+            T::BytesN(b) => {
+                let b = u.bytes(b.n as usize)?.to_vec();
+                xdr::ScVal::Bytes(b.try_into().unwrap())
+            }
+            T::String => {
+                let n = len(u)?;
+                let s = u.bytes(n)?.to_vec();
+                xdr::ScVal::String(xdr::ScString(s.try_into().unwrap()))
+            }
+            T::Symbol => {
+                const CHARS: &[u8] =
+                    b"_0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+                let n = len(u)?;
+                let s = (0..n)
+                    .map(|_| u.choose(CHARS).copied())
+                    .collect::<Result<std::vec::Vec<u8>>>()?;
+                xdr::ScVal::Symbol(xdr::ScSymbol(s.try_into().unwrap()))
+            }
+            T::Address => xdr::ScVal::Address(self.address(u)?),
+            T::MuxedAddress => {
+                let address = self.address(u)?;
+                match address {
+                    xdr::ScAddress::Account(xdr::AccountId(
+                        xdr::PublicKey::PublicKeyTypeEd25519(ed25519),
+                    )) if u.arbitrary()? => xdr::ScVal::Address(xdr::ScAddress::MuxedAccount(
+                        xdr::MuxedEd25519Account {
+                            id: u.arbitrary()?,
+                            ed25519,
+                        },
+                    )),
+                    address => xdr::ScVal::Address(address),
+                }
+            }
+            T::Option(o) => {
+                if depth > 0 && u.arbitrary()? {
+                    self.arbitrary(u, &o.value_type, depth - 1)?
+                } else {
+                    xdr::ScVal::Void
+                }
+            }
+            T::Result(r) => {
+                // Results are only returned by functions, an argument is the ok
+                // value.
+                self.arbitrary(u, &r.ok_type, depth)?
+            }
+            T::Vec(v) => {
+                let n = len(u)?;
+                let elements = (0..n)
+                    .map(|_| self.arbitrary(u, &v.element_type, depth.saturating_sub(1)))
+                    .collect::<Result<std::vec::Vec<_>>>()?;
+                xdr::ScVal::Vec(Some(elements.try_into().unwrap()))
+            }
+            T::Map(m) => {
+                let n = len(u)?;
+                let mut entries = std::collections::BTreeMap::new();
+                for _ in 0..n {
+                    let k = self.arbitrary(u, &m.key_type, depth.saturating_sub(1))?;
+                    let v = self.arbitrary(u, &m.value_type, depth.saturating_sub(1))?;
+                    entries.insert(k, v);
+                }
+                xdr::ScVal::Map(Some(
+                    xdr::ScMap::sorted_from_pairs(entries.into_iter()).unwrap(),
+                ))
+            }
+            T::Tuple(t) => {
+                let elements = t
+                    .value_types
+                    .iter()
+                    .map(|t| self.arbitrary(u, t, depth.saturating_sub(1)))
+                    .collect::<Result<std::vec::Vec<_>>>()?;
+                xdr::ScVal::Vec(Some(elements.try_into().unwrap()))
+            }
+            T::Udt(udt) => self.arbitrary_udt(u, &udt.name.to_utf8_string_lossy(), depth)?,
+        })
+    }
+
+    /// Generate an arbitrary value of the SDK type from its
+    /// [`SorobanArbitrary`] prototype.
+    fn prototype<T: SorobanArbitrary>(&self, u: &mut Unstructured) -> Result<xdr::ScVal> {
+        let prototype: T::Prototype = u.arbitrary()?;
+        let v = <T as TryFromVal<Env, T::Prototype>>::try_from_val(&self.env, &prototype)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        let v: Val = v.into_val(&self.env);
+        xdr::ScVal::try_from_val(&self.env, &v).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    /// Generate an arbitrary value of the user defined type in the spec.
+    fn arbitrary_udt(&self, u: &mut Unstructured, name: &str, depth: usize) -> Result<xdr::ScVal> {
+        let entry = self
+            .types
+            .iter()
+            .find(|e| match e {
+                xdr::ScSpecEntry::UdtStructV0(s) => s.name.to_utf8_string_lossy() == name,
+                xdr::ScSpecEntry::UdtUnionV0(s) => s.name.to_utf8_string_lossy() == name,
+                xdr::ScSpecEntry::UdtEnumV0(s) => s.name.to_utf8_string_lossy() == name,
+                xdr::ScSpecEntry::UdtErrorEnumV0(s) => s.name.to_utf8_string_lossy() == name,
+                _ => false,
+            })
+            .unwrap_or_else(|| panic!("type {name} is not in the spec"));
+        let depth = depth.saturating_sub(1);
+        Ok(match entry {
+            xdr::ScSpecEntry::UdtStructV0(s) => {
+                let is_tuple = s
+                    .fields
+                    .iter()
+                    .all(|f| f.name.as_slice().iter().all(u8::is_ascii_digit));
+                if is_tuple && !s.fields.is_empty() {
+                    let elements = s
+                        .fields
+                        .iter()
+                        .map(|f| self.arbitrary(u, &f.type_, depth))
+                        .collect::<Result<std::vec::Vec<_>>>()?;
+                    xdr::ScVal::Vec(Some(elements.try_into().unwrap()))
+                } else {
+                    let mut entries = std::vec::Vec::new();
+                    for f in s.fields.iter() {
+                        let k = xdr::ScVal::Symbol(xdr::ScSymbol(
+                            f.name.as_slice().to_vec().try_into().unwrap(),
+                        ));
+                        entries.push((k, self.arbitrary(u, &f.type_, depth)?));
+                    }
+                    xdr::ScVal::Map(Some(
+                        xdr::ScMap::sorted_from_pairs(entries.into_iter()).unwrap(),
+                    ))
+                }
+            }
+            xdr::ScSpecEntry::UdtUnionV0(s) => {
+                let (name, types) = match u.choose(&s.cases)? {
+                    xdr::ScSpecUdtUnionCaseV0::VoidV0(c) => (&c.name, &[][..]),
+                    xdr::ScSpecUdtUnionCaseV0::TupleV0(c) => (&c.name, &c.type_[..]),
+                };
+                let mut elements = std::vec![xdr::ScVal::Symbol(xdr::ScSymbol(
+                    name.as_slice().to_vec().try_into().unwrap()
+                ))];
+                for t in types {
+                    elements.push(self.arbitrary(u, t, depth)?);
+                }
+                xdr::ScVal::Vec(Some(elements.try_into().unwrap()))
+            }
+            xdr::ScSpecEntry::UdtEnumV0(s) => xdr::ScVal::U32(u.choose(&s.cases)?.value),
+            xdr::ScSpecEntry::UdtErrorEnumV0(s) => {
+                xdr::ScVal::Error(xdr::ScError::Contract(u.choose(&s.cases)?.value))
+            }
+            _ => unreachable!(),
+        })
+    }
+
+    fn address(&self, u: &mut Unstructured) -> Result<xdr::ScAddress> {
+        let address = if self.addresses.is_empty() {
+            &self.contract_id
+        } else {
+            u.choose(&self.addresses)?
+        };
+        Ok(address.into())
+    }
+}
+
+/// A step of a sequence run by a [`Fuzzer`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    /// A call of a function of the contract.
+    Call {
+        function: std::string::String,
+        args: std::vec::Vec<xdr::ScVal>,
+        /// Whether all authorizations were mocked for the call. If not, the
+        /// call was made with no authorizations.
+        auth: bool,
+        result: core::result::Result<xdr::ScVal, Error>,
+    },
+    /// An advance of the ledger by the number of ledgers, with
+    /// [`Ledger::advance`][crate::testutils::Ledger::advance] and the
+    /// fuzzer's [seconds per ledger][Fuzzer::seconds_per_ledger].
+    AdvanceLedger(u32),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Call {
+                function,
+                args,
+                auth,
+                result,
+            } => {
+                write!(f, "{function}(")?;
+                for (i, a) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", DisplayScVal(a))?;
+                }
+                write!(f, ")")?;
+                if !auth {
+                    write!(f, " without auth")?;
+                }
+                match result {
+                    Ok(v) => write!(f, " -> {}", DisplayScVal(v)),
+                    Err(e) => write!(f, " -> {e:?}"),
+                }
+            }
+            Step::AdvanceLedger(ledgers) => write!(f, "advance ledger by {ledgers}"),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(any(test, feature = "testutils"))]
+
+use ed25519_dalek::{Signer, SigningKey};
+use sha2::{Digest, Sha256};
+
+use crate::{testutils::Ledger as _, xdr, xdr::Limits, xdr::WriteXdr, Address, Env, TryIntoVal};
+
+/// An ed25519 keypair of a Stellar account that signs authorizations with real
+/// signatures.
+///
+/// Creating a keypair adds the account's entry to the ledger, so that the host
+/// can verify the account's signatures in the same way as on the network.
+///
+/// Sign authorizations with [`sign_auth`][Keypair::sign_auth], or set signed
+/// authorizations for a set of keypairs with [`Env::sign_auths`].
+///
+/// ### Examples
+/// ```
+/// use soroban_sdk::{contract, contractimpl, Env, Address, testutils::{Keypair, MockAuthInvoke}, IntoVal};
+///
+/// #[contract]
+/// pub struct HelloContract;
+///
+/// #[contractimpl]
+/// impl HelloContract {
+///     pub fn hello(env: Env, from: Address) {
+///         from.require_auth();
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # fn main() {
+///     let env = Env::default();
+///     let contract_id = env.register(HelloContract, ());
+///     let client = HelloContractClient::new(&env, &contract_id);
+///
+///     let keypair = Keypair::generate(&env);
+///     let addr = keypair.address();
+///     env.sign_auths(&[(
+///         &keypair,
+///         &MockAuthInvoke {
+///             contract: &contract_id,
+///             fn_name: "hello",
+///             args: (&addr,).into_val(&env),
+///             sub_invokes: &[],
+///         },
+///     )]);
+///     client.hello(&addr);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Keypair {
+    env: Env,
+    signing_key: SigningKey,
+}
+
+impl Keypair {
+    /// Generates a keypair and adds its account to the ledger.
+    ///
+    /// The secret key is derived from the Env's generators, so the same
+    /// keypairs are generated each time a test runs.
+    pub fn generate(env: &Env) -> Self {
+        let secret = env.with_generator(|mut g| g.secret_key());
+        Self::from_signing_key(env, SigningKey::from_bytes(&secret))
+    }
+
+    /// Creates a keypair from a signing key, adding its account to the ledger
+    /// if the account does not exist.
+    pub fn from_signing_key(env: &Env, signing_key: SigningKey) -> Self {
+        let keypair = Self {
+            env: env.clone(),
+            signing_key,
+        };
+        env.create_account_entry(&keypair.account_id());
+        keypair
+    }
+
+    /// Returns the signing key.
+    pub fn signing_key(&self) -> &SigningKey {
+        &self.signing_key
+    }
+
+    /// Returns the ed25519 public key.
+    pub fn public_key(&self) -> [u8; 32] {
+        self.signing_key.verifying_key().to_bytes()
+    }
+
+    /// Returns the address of the account.
+    pub fn address(&self) -> Address {
+        xdr::ScAddress::Account(self.account_id())
+            .try_into_val(&self.env)
+            .unwrap()
+    }
+
+    fn account_id(&self) -> xdr::AccountId {
+        xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
+            self.public_key(),
+        )))
+    }
+
+    /// Signs an authorization of the invocation by the account.
+    ///
+    /// The authorization uses a nonce from the Env's generators, and expires
+    /// at the maximum ledger that an entry created in the current ledger can
+    /// live until. The signature is over the hash of the authorization
+    /// preimage for the current network ID, and is verified by the host in
+    /// the same way as on the network.
+    pub fn sign_auth(
+        &self,
+        invocation: impl Into<xdr::SorobanAuthorizedInvocation>,
+    ) -> xdr::SorobanAuthorizationEntry {
+        let signature_expiration_ledger = self.env.ledger().max_live_until_ledger();
+        self.sign_auth_with_expiration(invocation, signature_expiration_ledger)
+    }
+
+    /// Signs an authorization of the invocation by the account that expires
+    /// after the ledger.
+    ///
+    /// See [`sign_auth`][Keypair::sign_auth].
+    pub fn sign_auth_with_expiration(
+        &self,
+        invocation: impl Into<xdr::SorobanAuthorizedInvocation>,
+        signature_expiration_ledger: u32,
+    ) -> xdr::SorobanAuthorizationEntry {
+        let invocation = invocation.into();
+        let nonce = self.env.with_generator(|mut g| g.nonce());
+        let preimage =
+            xdr::HashIdPreimage::SorobanAuthorization(xdr::HashIdPreimageSorobanAuthorization {
+                network_id: xdr::Hash(self.env.ledger().get().network_id),
+                nonce,
+                signature_expiration_ledger,
+                invocation: invocation.clone(),
+            });
+        let payload = Sha256::digest(preimage.to_xdr(Limits::none()).unwrap());
+        let signature = self.signing_key.sign(&payload).to_bytes();
+        xdr::SorobanAuthorizationEntry {
+            root_invocation: invocation,
+            credentials: xdr::SorobanCredentials::Address(xdr::SorobanAddressCredentials {
+                address: xdr::ScAddress::Account(self.account_id()),
+                nonce,
+                signature_expiration_ledger,
+                signature: self.signature_val(&signature),
+            }),
+        }
+    }
+
+    /// Returns the signature in the form that the host verifies for accounts,
+    /// a vector of maps of the public key and signature.
+    fn signature_val(&self, signature: &[u8; 64]) -> xdr::ScVal {
+        let map = xdr::ScMap::sorted_from([
+            (
+                xdr::ScVal::Symbol("public_key".try_into().unwrap()),
+                xdr::ScVal::Bytes(self.public_key().to_vec().try_into().unwrap()),
+            ),
+            (
+                xdr::ScVal::Symbol("signature".try_into().unwrap()),
+                xdr::ScVal::Bytes(signature.to_vec().try_into().unwrap()),
+            ),
+        ])
+        .unwrap();
+        xdr::ScVal::Vec(Some(
+            std::vec![xdr::ScVal::Map(Some(map))].try_into().unwrap(),
+        ))
+    }
+}
+
+impl core::fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Keypair")
+            .field("address", &self.address())
+            .finish_non_exhaustive()
+    }
+}
@@ -1,5 +1,7 @@
 #![cfg(any(test, feature = "testutils"))]
 
+use soroban_ledger_snapshot::DisplayScVal;
+
 use crate::{contract, contractimpl, xdr, Address, Env, Symbol, TryFromVal, Val, Vec};
 
 #[doc(hidden)]
@@ -28,18 +30,27 @@ pub struct MockAuthInvoke<'a> {
 
 impl<'a> From<&MockAuth<'a>> for xdr::SorobanAuthorizationEntry {
     fn from(value: &MockAuth) -> Self {
-        let env = value.address.env();
-        let curr_ledger = env.ledger().sequence();
-        let max_entry_ttl = env.storage().max_ttl();
-        Self {
-            root_invocation: value.invoke.into(),
-            credentials: xdr::SorobanCredentials::Address(xdr::SorobanAddressCredentials {
-                address: value.address.try_into().unwrap(),
-                nonce: env.with_generator(|mut g| g.nonce()),
-                signature_expiration_ledger: curr_ledger + max_entry_ttl,
-                signature: xdr::ScVal::Void,
-            }),
-        }
+        mock_auth_entry(value.address, value.invoke.into())
+    }
+}
+
+/// Returns an authorization entry of the invocation by the address, with a
+/// generated nonce and no signature, that is accepted by [`MockAuthContract`].
+pub(crate) fn mock_auth_entry(
+    address: &Address,
+    root_invocation: xdr::SorobanAuthorizedInvocation,
+) -> xdr::SorobanAuthorizationEntry {
+    let env = address.env();
+    let curr_ledger = env.ledger().sequence();
+    let max_entry_ttl = env.storage().max_ttl();
+    xdr::SorobanAuthorizationEntry {
+        root_invocation,
+        credentials: xdr::SorobanCredentials::Address(xdr::SorobanAddressCredentials {
+            address: address.into(),
+            nonce: env.with_generator(|mut g| g.nonce()),
+            signature_expiration_ledger: curr_ledger + max_entry_ttl,
+            signature: xdr::ScVal::Void,
+        }),
     }
 }
 
@@ -131,6 +142,26 @@ impl AuthorizedFunction {
     }
 }
 
+impl From<&AuthorizedFunction> for xdr::SorobanAuthorizedFunction {
+    fn from(value: &AuthorizedFunction) -> Self {
+        match value {
+            AuthorizedFunction::Contract((contract, fn_name, args)) => {
+                Self::ContractFn(xdr::InvokeContractArgs {
+                    contract_address: contract.into(),
+                    function_name: fn_name.to_string().as_str().try_into().unwrap(),
+                    args: args.clone().into(),
+                })
+            }
+            AuthorizedFunction::CreateContractHostFn(create_contract) => {
+                Self::CreateContractHostFn(create_contract.clone())
+            }
+            AuthorizedFunction::CreateContractV2HostFn(create_contract) => {
+                Self::CreateContractV2HostFn(create_contract.clone())
+            }
+        }
+    }
+}
+
 impl AuthorizedInvocation {
     pub fn from_xdr(env: &Env, v: &xdr::SorobanAuthorizedInvocation) -> Self {
         Self {
@@ -143,3 +174,418 @@ impl AuthorizedInvocation {
         }
     }
 }
+
+/// Converts a recorded authorized invocation, such as one returned by
+/// [`Env::auths`], into the invocation of an authorization entry.
+///
+/// The invocation can be signed with
+/// [`Keypair::sign_auth`][crate::testutils::Keypair::sign_auth] to replay the
+/// authorization of an account with a real signature.
+impl From<&AuthorizedInvocation> for xdr::SorobanAuthorizedInvocation {
+    fn from(value: &AuthorizedInvocation) -> Self {
+        Self {
+            function: (&value.function).into(),
+            sub_invocations: value
+                .sub_invocations
+                .iter()
+                .map(Into::<_>::into)
+                .collect::<std::vec::Vec<_>>()
+                .try_into()
+                .unwrap(),
+        }
+    }
+}
+
+impl From<AuthorizedInvocation> for xdr::SorobanAuthorizedInvocation {
+    fn from(value: AuthorizedInvocation) -> Self {
+        (&value).into()
+    }
+}
+
+/// The expected authorizations of addresses, for asserting that the
+/// authorizations recorded by [`Env::auths`] match.
+///
+/// Expected authorizations are matched in order. Expected invocations can
+/// ignore their arguments and allow extra sub-invocations, so that a test only
+/// asserts on the parts of the authorization tree that it is concerned with.
+/// An expected invocation with arguments is matched with the next recorded
+/// invocation of the function with the same arguments, if there is one.
+/// When the authorizations do not match, the error displays the recorded tree
+/// with the differences marked.
+///
+/// ### Examples
+/// ```
+/// use soroban_sdk::{contract, contractimpl, Env, Address, testutils::{Address as _, ExpectedAuths, ExpectedInvocation}, IntoVal};
+///
+/// #[contract]
+/// pub struct HelloContract;
+///
+/// #[contractimpl]
+/// impl HelloContract {
+///     pub fn hello(_env: Env, from: Address, _n: u32) {
+///         from.require_auth();
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # fn main() {
+///     let env = Env::default();
+///     env.mock_all_auths();
+///     let contract_id = env.register(HelloContract, ());
+///     let client = HelloContractClient::new(&env, &contract_id);
+///
+///     let addr = Address::generate(&env);
+///     client.hello(&addr, &1);
+///
+///     ExpectedAuths::new()
+///         .auth(
+///             &addr,
+///             ExpectedInvocation::contract(&contract_id, "hello")
+///                 .args((&addr, 1u32).into_val(&env)),
+///         )
+///         .assert_matches(&env.auths());
+///
+///     // Arguments are not compared unless they are specified.
+///     ExpectedAuths::new()
+///         .auth(&addr, ExpectedInvocation::contract(&contract_id, "hello"))
+///         .assert_matches(&env.auths());
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ExpectedAuths {
+    auths: std::vec::Vec<(Address, ExpectedInvocation)>,
+    allow_extra_auths: bool,
+}
+
+/// An expected node of an authorized invocation tree, see [`ExpectedAuths`].
+#[derive(Clone, Debug)]
+pub struct ExpectedInvocation {
+    function: ExpectedFunction,
+    args: Option<Vec<Val>>,
+    sub_invocations: std::vec::Vec<ExpectedInvocation>,
+    allow_extra_sub_invocations: bool,
+}
+
+#[derive(Clone, Debug)]
+enum ExpectedFunction {
+    Contract(Address, std::string::String),
+    CreateContract,
+}
+
+/// The difference between expected and recorded authorizations, returned by
+/// [`ExpectedAuths::matches`].
+///
+/// The [`Display`][core::fmt::Display] form is the recorded authorization
+/// tree, with the lines of missing expected invocations prefixed with `-`,
+/// unexpected recorded invocations prefixed with `+`, and invocations with
+/// arguments that differ listed as both.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthMismatch {
+    lines: std::vec::Vec<std::string::String>,
+}
+
+impl ExpectedAuths {
+    /// Creates an empty set of expected authorizations, that matches only
+    /// when no authorizations are recorded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an expected authorization of an invocation tree by the address.
+    pub fn auth(mut self, address: &Address, invocation: ExpectedInvocation) -> Self {
+        self.auths.push((address.clone(), invocation));
+        self
+    }
+
+    /// Allows recorded authorizations that are not expected, before, between
+    /// or after the expected authorizations.
+    pub fn allow_extra_auths(mut self) -> Self {
+        self.allow_extra_auths = true;
+        self
+    }
+
+    /// Returns an error describing the differences if the recorded
+    /// authorizations do not match.
+    pub fn matches(&self, auths: &[(Address, AuthorizedInvocation)]) -> Result<(), AuthMismatch> {
+        let mut lines = std::vec::Vec::new();
+        let mut matched = true;
+        let mut next = 0;
+        for (address, expected) in &self.auths {
+            let found = find_candidate(&auths[next..], |(a, actual), with_args| {
+                a == address && expected.is_invocation(&actual.function, with_args)
+            });
+            match found {
+                Some(i) => {
+                    for (a, actual) in &auths[next..next + i] {
+                        matched &= self.allow_extra_auths;
+                        push_unexpected(&mut lines, a, actual, self.allow_extra_auths);
+                    }
+                    let (a, actual) = &auths[next + i];
+                    lines.push(std::format!("  {}", display_address(a)));
+                    matched &= expected.diff(&mut lines, 1, actual);
+                    next += i + 1;
+                }
+                None => {
+                    matched = false;
+                    lines.push(std::format!("- {}", display_address(address)));
+                    expected.push_missing(&mut lines, 1);
+                }
+            }
+        }
+        for (a, actual) in &auths[next..] {
+            matched &= self.allow_extra_auths;
+            push_unexpected(&mut lines, a, actual, self.allow_extra_auths);
+        }
+        if matched {
+            Ok(())
+        } else {
+            Err(AuthMismatch { lines })
+        }
+    }
+
+    /// Asserts that the recorded authorizations match.
+    ///
+    /// ### Panics
+    ///
+    /// If the recorded authorizations do not match, with the differences in
+    /// the panic message.
+    pub fn assert_matches(&self, auths: &[(Address, AuthorizedInvocation)]) {
+        if let Err(e) = self.matches(auths) {
+            panic!("recorded auths do not match the expected auths:\n{e}");
+        }
+    }
+}
+
+impl ExpectedInvocation {
+    /// Creates an expected invocation of the contract function, that matches
+    /// any arguments and no sub-invocations.
+    pub fn contract(contract: &Address, fn_name: &str) -> Self {
+        Self::new(ExpectedFunction::Contract(contract.clone(), fn_name.into()))
+    }
+
+    /// Creates an expected invocation of a create contract host function, that
+    /// matches any arguments and no sub-invocations.
+    pub fn create_contract() -> Self {
+        Self::new(ExpectedFunction::CreateContract)
+    }
+
+    fn new(function: ExpectedFunction) -> Self {
+        Self {
+            function,
+            args: None,
+            sub_invocations: std::vec::Vec::new(),
+            allow_extra_sub_invocations: false,
+        }
+    }
+
+    /// Sets the arguments that the invocation must be authorized with.
+    pub fn args(mut self, args: Vec<Val>) -> Self {
+        self.args = Some(args);
+        self
+    }
+
+    /// Adds an expected sub-invocation. Sub-invocations are matched in order.
+    pub fn sub_invocation(mut self, sub_invocation: ExpectedInvocation) -> Self {
+        self.sub_invocations.push(sub_invocation);
+        self
+    }
+
+    /// Allows recorded sub-invocations that are not expected, before, between
+    /// or after the expected sub-invocations.
+    pub fn allow_extra_sub_invocations(mut self) -> Self {
+        self.allow_extra_sub_invocations = true;
+        self
+    }
+
+    /// Adds the lines of the recorded invocation and its sub-invocations,
+    /// marking the differences, and returns true if they match.
+    fn diff(
+        &self,
+        lines: &mut std::vec::Vec<std::string::String>,
+        depth: usize,
+        actual: &AuthorizedInvocation,
+    ) -> bool {
+        let indent = "    ".repeat(depth);
+        let mut matched = true;
+        match (&self.args, &actual.function) {
+            (Some(args), AuthorizedFunction::Contract((_, _, actual_args)))
+                if args != actual_args =>
+            {
+                matched = false;
+                lines.push(std::format!("- {indent}{}", self.display()));
+                lines.push(std::format!(
+                    "+ {indent}{}",
+                    display_function(&actual.function)
+                ));
+            }
+            _ => lines.push(std::format!(
+                "  {indent}{}",
+                display_function(&actual.function)
+            )),
+        }
+        let mut next = 0;
+        for expected in &self.sub_invocations {
+            let found = find_candidate(&actual.sub_invocations[next..], |a, with_args| {
+                expected.is_invocation(&a.function, with_args)
+            });
+            match found {
+                Some(i) => {
+                    for a in &actual.sub_invocations[next..next + i] {
+                        matched &= self.allow_extra_sub_invocations;
+                        push_unexpected_invocation(
+                            lines,
+                            depth + 1,
+                            a,
+                            self.allow_extra_sub_invocations,
+                        );
+                    }
+                    matched &= expected.diff(lines, depth + 1, &actual.sub_invocations[next + i]);
+                    next += i + 1;
+                }
+                None => {
+                    matched = false;
+                    expected.push_missing(lines, depth + 1);
+                }
+            }
+        }
+        for a in &actual.sub_invocations[next..] {
+            matched &= self.allow_extra_sub_invocations;
+            push_unexpected_invocation(lines, depth + 1, a, self.allow_extra_sub_invocations);
+        }
+        matched
+    }
+
+    /// Returns true if the function is the expected function, and when
+    /// `with_args`, is authorized with the expected arguments if specified.
+    fn is_invocation(&self, function: &AuthorizedFunction, with_args: bool) -> bool {
+        self.function.is_function(function)
+            && match (&self.args, function) {
+                (Some(args), AuthorizedFunction::Contract((_, _, actual_args))) if with_args => {
+                    args == actual_args
+                }
+                _ => true,
+            }
+    }
+
+    fn push_missing(&self, lines: &mut std::vec::Vec<std::string::String>, depth: usize) {
+        lines.push(std::format!("- {}{}", "    ".repeat(depth), self.display()));
+        for sub in &self.sub_invocations {
+            sub.push_missing(lines, depth + 1);
+        }
+    }
+
+    fn display(&self) -> std::string::String {
+        match &self.function {
+            ExpectedFunction::Contract(contract, fn_name) => std::format!(
+                "{}.{fn_name}({})",
+                display_address(contract),
+                match &self.args {
+                    Some(args) => display_args(args),
+                    None => "..".into(),
+                }
+            ),
+            ExpectedFunction::CreateContract => "create_contract(..)".into(),
+        }
+    }
+}
+
+impl ExpectedFunction {
+    fn is_function(&self, function: &AuthorizedFunction) -> bool {
+        match (self, function) {
+            (Self::Contract(contract, fn_name), AuthorizedFunction::Contract((c, f, _))) => {
+                contract == c && Symbol::new(c.env(), fn_name) == *f
+            }
+            (
+                Self::CreateContract,
+                AuthorizedFunction::CreateContractHostFn(_)
+                | AuthorizedFunction::CreateContractV2HostFn(_),
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the position of the recorded item that an expected invocation is
+/// matched with: the first item that is the expected invocation with the
+/// expected arguments, or if there is none, the first item that is the expected
+/// function, so that the differences in arguments are reported.
+fn find_candidate<T>(items: &[T], is_invocation: impl Fn(&T, bool) -> bool) -> Option<usize> {
+    items
+        .iter()
+        .position(|item| is_invocation(item, true))
+        .or_else(|| items.iter().position(|item| is_invocation(item, false)))
+}
+
+/// Adds the lines of a recorded authorization that is not expected. Allowed
+/// extra authorizations are listed without a marker.
+fn push_unexpected(
+    lines: &mut std::vec::Vec<std::string::String>,
+    address: &Address,
+    invocation: &AuthorizedInvocation,
+    allowed: bool,
+) {
+    let marker = if allowed { ' ' } else { '+' };
+    lines.push(std::format!("{marker} {}", display_address(address)));
+    push_unexpected_invocation(lines, 1, invocation, allowed);
+}
+
+fn push_unexpected_invocation(
+    lines: &mut std::vec::Vec<std::string::String>,
+    depth: usize,
+    invocation: &AuthorizedInvocation,
+    allowed: bool,
+) {
+    let marker = if allowed { ' ' } else { '+' };
+    lines.push(std::format!(
+        "{marker} {}{}",
+        "    ".repeat(depth),
+        display_function(&invocation.function)
+    ));
+    for sub in &invocation.sub_invocations {
+        push_unexpected_invocation(lines, depth + 1, sub, allowed);
+    }
+}
+
+fn display_address(address: &Address) -> std::string::String {
+    xdr::ScAddress::from(address).to_string()
+}
+
+fn display_args(args: &Vec<Val>) -> std::string::String {
+    args.iter()
+        .map(|v| {
+            let v = xdr::ScVal::try_from_val(args.env(), &v).unwrap();
+            DisplayScVal(&v).to_string()
+        })
+        .collect::<std::vec::Vec<_>>()
+        .join(", ")
+}
+
+fn display_function(function: &AuthorizedFunction) -> std::string::String {
+    match function {
+        AuthorizedFunction::Contract((contract, fn_name, args)) => std::format!(
+            "{}.{}({})",
+            display_address(contract),
+            fn_name.to_string(),
+            display_args(args)
+        ),
+        AuthorizedFunction::CreateContractHostFn(args) => {
+            std::format!("create_contract({:?})", args.executable)
+        }
+        AuthorizedFunction::CreateContractV2HostFn(args) => {
+            std::format!("create_contract({:?})", args.executable)
+        }
+    }
+}
+
+impl core::fmt::Display for AuthMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AuthMismatch {}
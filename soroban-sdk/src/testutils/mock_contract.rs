@@ -0,0 +1,349 @@
+//! Mock contracts with scripted responses, for testing a contract in
+//! isolation from the contracts it calls.
+//!
+//! [`Env::register_mock`] registers a contract that implements no functions of
+//! its own. The [`MockContract`] handle it returns scripts the value returned,
+//! or the error failed with, by each function, and records the calls made to
+//! the contract.
+//!
+//! Responses are scripted per function, optionally limited to the calls with
+//! specific arguments, or arguments matching a predicate. When several
+//! responses match a call, responses limited to some arguments take
+//! precedence over responses for any arguments, and responses scripted later
+//! take precedence over responses scripted earlier. A call that no response
+//! matches fails.
+//!
+//! Responses are checked against the spec of the interface when scripted:
+//! scripting a response for a function the interface does not have, or
+//! limiting it to a number of arguments the function does not take, panics.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::{
+//!     contract, contractclient, contracterror, contractimpl, Address, Env,
+//!     testutils::Address as _,
+//! };
+//!
+//! #[contractclient(name = "OracleClient")]
+//! pub trait Oracle {
+//!     fn price(env: Env, asset: Address) -> i128;
+//! }
+//!
+//! #[contracterror]
+//! #[derive(Copy, Clone, Debug, PartialEq)]
+//! pub enum OracleError {
+//!     Stale = 1,
+//! }
+//!
+//! #[contract]
+//! pub struct Vault;
+//!
+//! #[contractimpl]
+//! impl Vault {
+//!     pub fn value(env: Env, oracle: Address, asset: Address, amount: i128) -> i128 {
+//!         OracleClient::new(&env, &oracle).price(&asset) * amount
+//!     }
+//! }
+//!
+//! #[test]
+//! fn test() {
+//! # }
+//! # fn main() {
+//!     let env = Env::default();
+//!     let (oracle_id, oracle) = env.register_mock::<OracleClient>();
+//!     let vault = VaultClient::new(&env, &env.register(Vault, ()));
+//!     let (usdc, xlm) = (Address::generate(&env), Address::generate(&env));
+//!
+//!     oracle.on("price").returns(1_i128);
+//!     oracle.on("price").args((&xlm,)).returns(3_i128);
+//!     assert_eq!(vault.value(&oracle_id, &usdc, &10), 10);
+//!     assert_eq!(vault.value(&oracle_id, &xlm, &10), 30);
+//!     assert_eq!(oracle.calls_to("price").len(), 2);
+//!
+//!     oracle.on("price").fails(OracleError::Stale);
+//!     assert!(vault.try_value(&oracle_id, &usdc, &10).is_err());
+//! }
+//! ```
+use core::{cell::RefCell, fmt, marker::PhantomData};
+use std::rc::Rc;
+
+use crate::{
+    testutils::{ContractClient, ContractFunctionSet},
+    xdr::ScSpecEntry,
+    Address, Env, Error, IntoVal, Symbol, Val, Vec,
+};
+
+/// A call made to a mock contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockCall {
+    /// The function called.
+    pub function: Symbol,
+    /// The arguments the function was called with.
+    pub args: Vec<Val>,
+}
+
+type Matcher = Rc<dyn Fn(&Vec<Val>) -> bool>;
+
+#[derive(Clone)]
+enum Pattern {
+    Any,
+    Args(Vec<Val>),
+    Matching(Matcher),
+}
+
+impl Pattern {
+    fn matches(&self, args: &Vec<Val>) -> bool {
+        match self {
+            Pattern::Any => true,
+            Pattern::Args(expected) => expected == args,
+            Pattern::Matching(f) => f(args),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Response {
+    Returns(Val),
+    Fails(Error),
+}
+
+struct Rule {
+    function: std::string::String,
+    pattern: Pattern,
+    response: Response,
+}
+
+#[derive(Default)]
+struct State {
+    rules: std::vec::Vec<Rule>,
+    calls: std::vec::Vec<MockCall>,
+}
+
+impl State {
+    /// Returns the response of the most specific, most recently scripted,
+    /// rule that matches the call.
+    fn response(&self, function: &str, args: &Vec<Val>) -> Option<Response> {
+        let mut rules = self
+            .rules
+            .iter()
+            .rev()
+            .filter(|r| r.function == function && r.pattern.matches(args));
+        let first = rules.next()?;
+        if !matches!(first.pattern, Pattern::Any) {
+            return Some(first.response);
+        }
+        Some(
+            rules
+                .find(|r| !matches!(r.pattern, Pattern::Any))
+                .unwrap_or(first)
+                .response,
+        )
+    }
+}
+
+/// The functions of a mock contract, dispatching calls to the scripted
+/// responses.
+struct MockFunctionSet(Rc<RefCell<State>>);
+
+impl ContractFunctionSet for MockFunctionSet {
+    fn call(&self, func: &str, env: Env, args: &[Val]) -> Option<Val> {
+        let args = Vec::from_slice(&env, args);
+        let response = (*self.0).borrow().response(func, &args);
+        // Contracts without a constructor are registered by calling a missing
+        // constructor, which is not a call to record.
+        if func == "__constructor" && response.is_none() {
+            return None;
+        }
+        (*self.0).borrow_mut().calls.push(MockCall {
+            function: Symbol::new(&env, func),
+            args: args.clone(),
+        });
+        match response {
+            Some(Response::Returns(v)) => Some(v),
+            Some(Response::Fails(e)) => Some(e.to_val()),
+            None => panic!("no response is scripted for the call to mock contract function `{func}` with args {args:?}"),
+        }
+    }
+}
+
+/// A handle to a mock contract registered with [`Env::register_mock`].
+///
+/// The type parameter is the client of the interface the mock contract
+/// stands in for. See the [module docs][self] for an example.
+pub struct MockContract<C> {
+    env: Env,
+    address: Address,
+    state: Rc<RefCell<State>>,
+    _client: PhantomData<fn() -> C>,
+}
+
+impl<C> Clone for MockContract<C> {
+    fn clone(&self) -> Self {
+        Self {
+            env: self.env.clone(),
+            address: self.address.clone(),
+            state: self.state.clone(),
+            _client: PhantomData,
+        }
+    }
+}
+
+impl<C> fmt::Debug for MockContract<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockContract")
+            .field("address", &self.address)
+            .field("calls", &(*self.state).borrow().calls)
+            .finish()
+    }
+}
+
+impl<C: ContractClient> MockContract<C> {
+    pub(crate) fn register(env: &Env) -> Self {
+        let state = Rc::new(RefCell::new(State::default()));
+        let address = env.register(MockFunctionSet(state.clone()), ());
+        Self {
+            env: env.clone(),
+            address,
+            state,
+            _client: PhantomData,
+        }
+    }
+
+    /// Returns the address of the mock contract.
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// Returns a client for calling the mock contract.
+    pub fn client(&self) -> C {
+        C::new(&self.env, &self.address)
+    }
+
+    /// Script the response of a function of the mock contract.
+    ///
+    /// The response applies to calls with any arguments, unless limited with
+    /// [`MockFunction::args`] or [`MockFunction::matching`].
+    ///
+    /// ### Panics
+    ///
+    /// If the interface has no function with the name.
+    pub fn on(&self, function: &str) -> MockFunction {
+        let functions = C::spec()
+            .into_iter()
+            .filter_map(|entry| match entry {
+                ScSpecEntry::FunctionV0(f) => Some(f),
+                _ => None,
+            })
+            .collect::<std::vec::Vec<_>>();
+        let Some(f) = functions
+            .iter()
+            .find(|f| f.name.to_utf8_string_lossy() == function)
+        else {
+            let names = functions
+                .iter()
+                .map(|f| f.name.to_utf8_string_lossy())
+                .collect::<std::vec::Vec<_>>();
+            panic!("mock contract interface has no function `{function}`, the functions are: {names:?}");
+        };
+        MockFunction {
+            env: self.env.clone(),
+            state: self.state.clone(),
+            function: function.into(),
+            arity: f.inputs.len(),
+            pattern: Pattern::Any,
+        }
+    }
+
+    /// Returns the calls made to the mock contract, in the order they were
+    /// made.
+    ///
+    /// Calls that failed are included, including calls that failed because
+    /// no response was scripted for them.
+    pub fn calls(&self) -> std::vec::Vec<MockCall> {
+        (*self.state).borrow().calls.clone()
+    }
+
+    /// Returns the arguments of the calls made to a function of the mock
+    /// contract, in the order they were made.
+    pub fn calls_to(&self, function: &str) -> std::vec::Vec<Vec<Val>> {
+        let function = Symbol::new(&self.env, function);
+        (*self.state)
+            .borrow()
+            .calls
+            .iter()
+            .filter(|c| c.function == function)
+            .map(|c| c.args.clone())
+            .collect()
+    }
+
+    /// Forget the calls recorded so far.
+    pub fn clear_calls(&self) {
+        (*self.state).borrow_mut().calls.clear();
+    }
+}
+
+/// The response of a function of a mock contract, being scripted.
+///
+/// Returned by [`MockContract::on`]. The response is scripted by calling
+/// [`returns`][MockFunction::returns] or [`fails`][MockFunction::fails].
+#[must_use = "the response is only scripted by calling `returns` or `fails`"]
+pub struct MockFunction {
+    env: Env,
+    state: Rc<RefCell<State>>,
+    function: std::string::String,
+    arity: usize,
+    pattern: Pattern,
+}
+
+impl MockFunction {
+    /// Limit the response to calls with the arguments.
+    ///
+    /// ### Panics
+    ///
+    /// If the function does not take the number of arguments.
+    pub fn args(self, args: impl IntoVal<Env, Vec<Val>>) -> Self {
+        let args = args.into_val(&self.env);
+        if args.len() as usize != self.arity {
+            panic!(
+                "mock contract function `{}` takes {} args, but the response is limited to {} args",
+                self.function,
+                self.arity,
+                args.len()
+            );
+        }
+        Self {
+            pattern: Pattern::Args(args),
+            ..self
+        }
+    }
+
+    /// Limit the response to calls with arguments the predicate returns true
+    /// for.
+    pub fn matching(self, f: impl Fn(&Vec<Val>) -> bool + 'static) -> Self {
+        Self {
+            pattern: Pattern::Matching(Rc::new(f)),
+            ..self
+        }
+    }
+
+    /// Respond to the calls by returning the value.
+    pub fn returns(self, v: impl IntoVal<Env, Val>) {
+        let v = v.into_val(&self.env);
+        self.script(Response::Returns(v));
+    }
+
+    /// Respond to the calls by failing with the error, such as an error of a
+    /// [`contracterror`][crate::contracterror] type.
+    pub fn fails(self, e: impl Into<Error>) {
+        self.script(Response::Fails(e.into()));
+    }
+
+    fn script(self, response: Response) {
+        (*self.state).borrow_mut().rules.push(Rule {
+            function: self.function,
+            pattern: self.pattern,
+            response,
+        });
+    }
+}
@@ -28,7 +28,8 @@
 //! truncating the generated bytes and building a new value from them rather
 //! than by reducing the value itself. The input `proptest` reports still fails
 //! the property, but it is generally a different input rather than a smaller
-//! version of the one that failed first.
+//! version of the one that failed first. For shrinking that reduces the value
+//! itself, use the native strategies [described below](#native-strategies).
 //!
 //! Property testing a contract this way needs both crates as dev-dependencies:
 //!
@@ -95,3 +96,262 @@
 //! }
 //! # fn main() { }
 //! ```
+//!
+//!
+//! ## Native strategies
+//!
+//! Every prototype of the SDK types, and every prototype derived for a
+//! [`contracttype`], also implements [`PrototypeStrategy`], which builds a
+//! `proptest` [`Strategy`] directly from the strategies of its parts rather
+//! than from bytes. When a property fails, these strategies shrink the value
+//! structurally: vecs, maps and bytes get shorter, integers get closer to
+//! zero, options become `None`, and enums move to variants declared earlier.
+//!
+//! The [`prototype`] function returns the strategy for a contract type. With
+//! it, property testing a contract only needs `proptest` as a
+//! dev-dependency.
+//!
+//! [`contracttype`]: crate::contracttype
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use soroban_sdk::testutils::proptest::prototype;
+//! use soroban_sdk::{contracttype, Address, Env, IntoVal, Vec};
+//!
+//! #[contracttype]
+//! pub struct Claim {
+//!     pub claimant: Address,
+//!     pub amounts: Vec<i128>,
+//! }
+//!
+//! proptest! {
+//!     #[test]
+//!     fn test_claim(claim_proto in prototype::<Claim>()) {
+//!         let env = Env::default();
+//!         let claim: Claim = claim_proto.into_val(&env);
+//!         // call the contract with `claim`
+//!     }
+//! }
+//! # fn main() { }
+//! ```
+//!
+//! Unlike the prototypes generated through `arb`, vecs and maps generated by
+//! these strategies always contain elements of their declared types.
+//!
+//! A [`contracttype`] that contains itself, such as a tree whose nodes hold a
+//! `Vec` of child nodes, is generated to a limited depth, below which the vecs,
+//! maps and options that hold the nested values are empty.
+//!
+//! A field of a [`contracttype`] whose type has a hand-written
+//! [`SorobanArbitrary`] implementation, with a prototype that does not
+//! implement [`PrototypeStrategy`], is generated from random bytes with the
+//! prototype's [`Arbitrary`] implementation, and so does not shrink
+//! structurally.
+//!
+//! [`SorobanArbitrary`]: crate::testutils::arbitrary::SorobanArbitrary
+
+use core::any::{Any, TypeId};
+use core::cell::{Cell, RefCell};
+use core::fmt;
+use core::marker::PhantomData;
+use std::{collections::BTreeMap, rc::Rc};
+
+use ::proptest::strategy::{BoxedStrategy, Just, Strategy};
+
+use crate::testutils::arbitrary::SorobanArbitrary;
+
+/// A reexport of the `proptest` crate.
+///
+/// Used by the `contracttype` macro to derive [`PrototypeStrategy`].
+pub use ::proptest;
+
+/// The range of lengths of the vecs, maps, bytes and strings that the
+/// strategies generate.
+pub(crate) const LEN: core::ops::RangeInclusive<usize> = 0..=16;
+
+/// The maximum depth that a contract type that contains itself is generated
+/// to.
+const DEPTH: u32 = 4;
+
+/// A contract type whose strategy is being built.
+struct Nested {
+    /// The strategy of the values of the type nested in it, or `None` if the
+    /// nested values are at the maximum depth.
+    inner: Option<Rc<dyn Any>>,
+    /// Whether the type was found nested in itself.
+    recursed: bool,
+}
+
+std::thread_local! {
+    /// The contract types whose strategies are being built.
+    static NESTED: RefCell<BTreeMap<TypeId, Nested>> = const { RefCell::new(BTreeMap::new()) };
+
+    /// Whether a strategy was built for a value at the maximum depth, that the
+    /// collection holding the value must not generate.
+    static EXHAUSTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A prototype of a contract type that a `proptest` [`Strategy`] can generate
+/// and shrink structurally.
+///
+/// Implemented for the prototypes of the SDK types, and derived for the
+/// prototypes of [`contracttype`][crate::contracttype] types.
+///
+/// [`Strategy`]: ::proptest::strategy::Strategy
+pub trait PrototypeStrategy: Clone + fmt::Debug + Sized + 'static {
+    /// Returns a strategy that generates the prototype.
+    fn strategy() -> BoxedStrategy<Self>;
+}
+
+/// Returns a strategy that generates values with a function of
+/// [`Unstructured`][arbitrary::Unstructured] data, such as a function given
+/// to the `#[arbitrary(with = ...)]` attribute on a field of a
+/// [`contracttype`][crate::contracttype].
+///
+/// The values are generated from random bytes, so they shrink no better than
+/// values generated through `arb`.
+pub fn strategy_from_fn<T, F>(f: F) -> BoxedStrategy<T>
+where
+    T: fmt::Debug + 'static,
+    F: Fn(&mut arbitrary::Unstructured<'_>) -> arbitrary::Result<T> + 'static,
+{
+    ::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), 0..=1024)
+        .prop_filter_map("not enough data to generate a value", move |data| {
+            f(&mut arbitrary::Unstructured::new(&data)).ok()
+        })
+        .boxed()
+}
+
+/// The prototype of a field of a contract type, that has a strategy with
+/// [`FieldStrategy`] if the prototype implements [`PrototypeStrategy`], and
+/// otherwise with [`FieldStrategyFallback`].
+///
+/// Used by the `contracttype` macro to derive [`PrototypeStrategy`].
+#[doc(hidden)]
+pub struct Field<T>(PhantomData<T>);
+
+impl<T> Field<T> {
+    pub fn new() -> Self {
+        Field(PhantomData)
+    }
+}
+
+impl<T> Default for Field<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the strategy of a field whose prototype implements
+/// [`PrototypeStrategy`].
+#[doc(hidden)]
+pub trait FieldStrategy<T> {
+    fn field_strategy(&self) -> BoxedStrategy<T>;
+}
+
+impl<T: PrototypeStrategy> FieldStrategy<T> for Field<T> {
+    fn field_strategy(&self) -> BoxedStrategy<T> {
+        T::strategy()
+    }
+}
+
+/// Returns the strategy of a field whose prototype does not implement
+/// [`PrototypeStrategy`], that generates the prototype from bytes.
+///
+/// Implemented for a reference to [`Field`], so that [`FieldStrategy`] is
+/// preferred when both apply.
+#[doc(hidden)]
+pub trait FieldStrategyFallback<T> {
+    fn field_strategy(&self) -> BoxedStrategy<T>;
+}
+
+impl<T> FieldStrategyFallback<T> for &Field<T>
+where
+    T: for<'a> arbitrary::Arbitrary<'a> + fmt::Debug + 'static,
+{
+    fn field_strategy(&self) -> BoxedStrategy<T> {
+        strategy_from_fn(|u| T::arbitrary(u))
+    }
+}
+
+/// Returns the strategy that `strategy` builds for the prototype of a
+/// contract type, generating the prototypes nested in themselves to a
+/// limited depth.
+///
+/// Used by the `contracttype` macro to derive [`PrototypeStrategy`].
+#[doc(hidden)]
+pub fn recursive_strategy<T: PrototypeStrategy>(
+    strategy: fn() -> BoxedStrategy<T>,
+) -> BoxedStrategy<T> {
+    let id = TypeId::of::<T>();
+    let nested = NESTED.with(|n| {
+        n.borrow_mut().get_mut(&id).map(|nested| {
+            nested.recursed = true;
+            nested.inner.clone()
+        })
+    });
+    match nested {
+        Some(Some(inner)) => {
+            return inner
+                .downcast_ref::<BoxedStrategy<T>>()
+                .expect("strategy of the nested type")
+                .clone();
+        }
+        Some(None) => {
+            EXHAUSTED.with(|e| e.set(true));
+            return Just(())
+                .prop_filter_map("nested at the maximum depth", |()| None)
+                .boxed();
+        }
+        None => {}
+    }
+    let (leaf, recursed) = build_nested(id, None, strategy);
+    if !recursed {
+        return leaf;
+    }
+    leaf.prop_recursive(DEPTH, 32, 4, move |inner| {
+        build_nested(id, Some(Rc::new(inner)), strategy).0
+    })
+    .boxed()
+}
+
+/// Builds the strategy of a contract type with the strategy of the values of
+/// the type nested in it, returning whether the type is nested in itself.
+fn build_nested<T>(
+    id: TypeId,
+    inner: Option<Rc<dyn Any>>,
+    strategy: fn() -> BoxedStrategy<T>,
+) -> (BoxedStrategy<T>, bool) {
+    let nested = Nested {
+        inner,
+        recursed: false,
+    };
+    NESTED.with(|n| n.borrow_mut().insert(id, nested));
+    let strategy = strategy();
+    let nested = NESTED
+        .with(|n| n.borrow_mut().remove(&id))
+        .expect("nested type");
+    (strategy, nested.recursed)
+}
+
+/// Returns the strategy of the elements of a vec, map or option, or `None` if
+/// the elements are contract types nested in themselves at the maximum depth,
+/// and the vec, map or option must be empty.
+pub(crate) fn element_strategy<T>(
+    strategy: impl FnOnce() -> BoxedStrategy<T>,
+) -> Option<BoxedStrategy<T>> {
+    let outer = EXHAUSTED.with(|e| e.replace(false));
+    let strategy = strategy();
+    let exhausted = EXHAUSTED.with(|e| e.replace(outer));
+    (!exhausted).then_some(strategy)
+}
+
+/// Returns a strategy that generates prototypes of the contract type `T`,
+/// that can be converted to `T` with `into_val`.
+pub fn prototype<T>() -> BoxedStrategy<T::Prototype>
+where
+    T: SorobanArbitrary,
+    T::Prototype: PrototypeStrategy,
+{
+    T::Prototype::strategy()
+}
@@ -9,8 +9,14 @@ pub trait Sign<MSG> {
     fn sign(&self, m: MSG) -> Result<Self::Signature, Self::Error>;
 }
 
-// TODO: Add a Verify interface and ed25519 implementation to counter the Sign
-// interface.
+/// Verify implementations verify signatures of types that can be represented as
+/// the MSG.
+pub trait Verify<MSG> {
+    type Signature;
+    type Error;
+    /// Verify checks that the signature is a valid signature of MSGs.
+    fn verify(&self, m: MSG, s: &Self::Signature) -> Result<(), Self::Error>;
+}
 
 pub mod ed25519 {
     use crate::xdr;
@@ -55,7 +61,7 @@ pub mod ed25519 {
         }
     }
 
-    pub use super::Sign;
+    pub use super::{Sign, Verify};
 
     impl<S, M> Sign<M> for S
     where
@@ -73,9 +79,29 @@ pub mod ed25519 {
         }
     }
 
+    impl<V, M> Verify<M> for V
+    where
+        V: ed25519_dalek::Verifier<ed25519_dalek::Signature>,
+        M: TryInto<xdr::ScVal>,
+        <M as TryInto<xdr::ScVal>>::Error: std::error::Error,
+    {
+        type Error = Error<<M as TryInto<xdr::ScVal>>::Error>;
+        type Signature = [u8; 64];
+        fn verify(&self, m: M, s: &Self::Signature) -> Result<(), Self::Error> {
+            let mut buf = Vec::<u8>::new();
+            let val: xdr::ScVal = m.try_into().map_err(Self::Error::ConversionError)?;
+            val.write_xdr(&mut Limited::new(&mut buf, Limits::none()))?;
+            Ok(ed25519_dalek::Verifier::verify(
+                self,
+                &buf,
+                &ed25519_dalek::Signature::from_bytes(s),
+            )?)
+        }
+    }
+
     #[cfg(test)]
     mod test {
-        use super::Sign;
+        use super::{Sign, Verify};
         use ed25519_dalek::SigningKey;
 
         #[test]
@@ -93,5 +119,357 @@ pub mod ed25519 {
                 "a9b9dfac10bc1e5c8bc565e9515e5d086e3264b71bf4daf2c7340e1d10fae86e2563fa1d639ff153559a9710dfa270a9462fe87faa0e18a7a54a8a1a6151e909",
             );
         }
+
+        #[test]
+        fn verify() {
+            let sk = SigningKey::from_bytes(&[1; 32]);
+            let vk = sk.verifying_key();
+            let sig = sk.sign(128i64).unwrap();
+            assert!(vk.verify(128i64, &sig).is_ok());
+            assert!(vk.verify(129i64, &sig).is_err());
+        }
+    }
+}
+
+pub mod secp256r1 {
+    //! Signs and verifies 32-byte message digests with ECDSA secp256r1 (P-256),
+    //! producing the signatures that
+    //! [`Crypto::secp256r1_verify`][crate::crypto::Crypto::secp256r1_verify]
+    //! accepts.
+
+    use p256::ecdsa::signature::hazmat::{PrehashSigner, PrehashVerifier};
+
+    pub use super::{Sign, Verify};
+
+    #[derive(Debug)]
+    pub enum Error {
+        EcdsaError(p256::ecdsa::Error),
+        /// The signature's `s` is not normalized to the low form, which the
+        /// host rejects.
+        HighS,
+    }
+
+    impl std::error::Error for Error {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                Self::EcdsaError(e) => e.source(),
+                Self::HighS => None,
+            }
+        }
+    }
+
+    impl std::fmt::Display for Error {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+                Self::EcdsaError(e) => write!(f, "{}", e),
+                Self::HighS => write!(f, "signature 's' is not normalized to the low form"),
+            }
+        }
+    }
+
+    impl From<p256::ecdsa::Error> for Error {
+        fn from(e: p256::ecdsa::Error) -> Self {
+            Error::EcdsaError(e)
+        }
+    }
+
+    /// A secp256r1 signing key.
+    #[derive(Clone, Debug)]
+    pub struct SigningKey(p256::ecdsa::SigningKey);
+
+    impl SigningKey {
+        /// Creates a signing key from a 32-byte secret scalar.
+        pub fn from_bytes(secret: &[u8; 32]) -> Result<Self, Error> {
+            Ok(Self(p256::ecdsa::SigningKey::from_slice(secret)?))
+        }
+
+        /// Generates a random signing key.
+        pub fn random() -> Self {
+            Self(p256::ecdsa::SigningKey::random(&mut rand::thread_rng()))
+        }
+
+        /// Returns the verifying key.
+        pub fn verifying_key(&self) -> VerifyingKey {
+            VerifyingKey(*self.0.verifying_key())
+        }
+
+        /// Returns the SEC-1 uncompressed public key, in the form that
+        /// `Crypto::secp256r1_verify` accepts.
+        pub fn public_key(&self) -> [u8; 65] {
+            self.verifying_key().public_key()
+        }
+    }
+
+    impl From<p256::ecdsa::SigningKey> for SigningKey {
+        fn from(k: p256::ecdsa::SigningKey) -> Self {
+            Self(k)
+        }
+    }
+
+    /// A secp256r1 verifying key.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct VerifyingKey(p256::ecdsa::VerifyingKey);
+
+    impl VerifyingKey {
+        /// Creates a verifying key from a SEC-1 encoded public key.
+        pub fn from_sec1_bytes(public_key: &[u8]) -> Result<Self, Error> {
+            Ok(Self(p256::ecdsa::VerifyingKey::from_sec1_bytes(
+                public_key,
+            )?))
+        }
+
+        /// Returns the SEC-1 uncompressed public key, in the form that
+        /// `Crypto::secp256r1_verify` accepts.
+        pub fn public_key(&self) -> [u8; 65] {
+            self.0
+                .to_encoded_point(false)
+                .as_bytes()
+                .try_into()
+                .unwrap()
+        }
+    }
+
+    impl From<p256::ecdsa::VerifyingKey> for VerifyingKey {
+        fn from(k: p256::ecdsa::VerifyingKey) -> Self {
+            Self(k)
+        }
+    }
+
+    impl Sign<&[u8; 32]> for SigningKey {
+        type Error = Error;
+        /// The `r` and `s` of the signature, with `s` normalized to the low
+        /// form.
+        type Signature = [u8; 64];
+        fn sign(&self, digest: &[u8; 32]) -> Result<Self::Signature, Self::Error> {
+            let sig: p256::ecdsa::Signature = self.0.sign_prehash(digest)?;
+            let sig = sig.normalize_s().unwrap_or(sig);
+            Ok(sig.to_bytes().into())
+        }
+    }
+
+    impl Verify<&[u8; 32]> for VerifyingKey {
+        type Error = Error;
+        type Signature = [u8; 64];
+        fn verify(&self, digest: &[u8; 32], s: &Self::Signature) -> Result<(), Self::Error> {
+            let sig = p256::ecdsa::Signature::from_slice(s)?;
+            if sig.normalize_s().is_some() {
+                return Err(Error::HighS);
+            }
+            Ok(self.0.verify_prehash(digest, &sig)?)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{Error, Sign, SigningKey, Verify};
+
+        #[test]
+        fn sign_and_verify() {
+            let sk = SigningKey::from_bytes(&[1; 32]).unwrap();
+            let vk = sk.verifying_key();
+            let digest = [2; 32];
+            let sig = sk.sign(&digest).unwrap();
+            assert!(vk.verify(&digest, &sig).is_ok());
+            assert!(vk.verify(&[3; 32], &sig).is_err());
+            assert_eq!(sk.public_key()[0], 4);
+        }
+
+        #[test]
+        fn verify_rejects_high_s() {
+            let sk = SigningKey::from_bytes(&[1; 32]).unwrap();
+            let digest = [2; 32];
+            let sig = sk.sign(&digest).unwrap();
+            let sig = p256::ecdsa::Signature::from_slice(&sig).unwrap();
+            let (r, s) = sig.split_scalars();
+            let high = p256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+            assert!(matches!(
+                sk.verifying_key().verify(&digest, &high.to_bytes().into()),
+                Err(Error::HighS)
+            ));
+        }
+    }
+}
+
+pub mod secp256k1 {
+    //! Signs and verifies 32-byte message digests with ECDSA secp256k1,
+    //! producing the signatures and recovery IDs that
+    //! [`Crypto::secp256k1_recover`][crate::crypto::Crypto::secp256k1_recover]
+    //! accepts.
+
+    use k256::ecdsa::RecoveryId;
+
+    pub use super::{Sign, Verify};
+
+    #[derive(Debug)]
+    pub enum Error {
+        EcdsaError(k256::ecdsa::Error),
+        /// The signature's `s` is not normalized to the low form, which the
+        /// host rejects.
+        HighS,
+        /// The public key recovered from the signature is not the verifying
+        /// key.
+        RecoveredKeyMismatch,
+    }
+
+    impl std::error::Error for Error {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                Self::EcdsaError(e) => e.source(),
+                Self::HighS | Self::RecoveredKeyMismatch => None,
+            }
+        }
+    }
+
+    impl std::fmt::Display for Error {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+                Self::EcdsaError(e) => write!(f, "{}", e),
+                Self::HighS => write!(f, "signature 's' is not normalized to the low form"),
+                Self::RecoveredKeyMismatch => {
+                    write!(f, "recovered public key does not match the verifying key")
+                }
+            }
+        }
+    }
+
+    impl From<k256::ecdsa::Error> for Error {
+        fn from(e: k256::ecdsa::Error) -> Self {
+            Error::EcdsaError(e)
+        }
+    }
+
+    /// A signature with the recovery ID of the public key that produced it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Signature {
+        /// The `r` and `s` of the signature, with `s` normalized to the low
+        /// form.
+        pub signature: [u8; 64],
+        /// The recovery ID, from 0 to 3.
+        pub recovery_id: u32,
+    }
+
+    /// A secp256k1 signing key.
+    #[derive(Clone, Debug)]
+    pub struct SigningKey(k256::ecdsa::SigningKey);
+
+    impl SigningKey {
+        /// Creates a signing key from a 32-byte secret scalar.
+        pub fn from_bytes(secret: &[u8; 32]) -> Result<Self, Error> {
+            Ok(Self(k256::ecdsa::SigningKey::from_slice(secret)?))
+        }
+
+        /// Generates a random signing key.
+        pub fn random() -> Self {
+            Self(k256::ecdsa::SigningKey::random(&mut rand::thread_rng()))
+        }
+
+        /// Returns the verifying key.
+        pub fn verifying_key(&self) -> VerifyingKey {
+            VerifyingKey(*self.0.verifying_key())
+        }
+
+        /// Returns the SEC-1 uncompressed public key, in the form that
+        /// `Crypto::secp256k1_recover` returns.
+        pub fn public_key(&self) -> [u8; 65] {
+            self.verifying_key().public_key()
+        }
+    }
+
+    impl From<k256::ecdsa::SigningKey> for SigningKey {
+        fn from(k: k256::ecdsa::SigningKey) -> Self {
+            Self(k)
+        }
+    }
+
+    /// A secp256k1 verifying key.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct VerifyingKey(k256::ecdsa::VerifyingKey);
+
+    impl VerifyingKey {
+        /// Creates a verifying key from a SEC-1 encoded public key.
+        pub fn from_sec1_bytes(public_key: &[u8]) -> Result<Self, Error> {
+            Ok(Self(k256::ecdsa::VerifyingKey::from_sec1_bytes(
+                public_key,
+            )?))
+        }
+
+        /// Returns the SEC-1 uncompressed public key, in the form that
+        /// `Crypto::secp256k1_recover` returns.
+        pub fn public_key(&self) -> [u8; 65] {
+            self.0
+                .to_encoded_point(false)
+                .as_bytes()
+                .try_into()
+                .unwrap()
+        }
+    }
+
+    impl From<k256::ecdsa::VerifyingKey> for VerifyingKey {
+        fn from(k: k256::ecdsa::VerifyingKey) -> Self {
+            Self(k)
+        }
+    }
+
+    impl Sign<&[u8; 32]> for SigningKey {
+        type Error = Error;
+        type Signature = Signature;
+        fn sign(&self, digest: &[u8; 32]) -> Result<Self::Signature, Self::Error> {
+            let (sig, recovery_id) = self.0.sign_prehash_recoverable(digest)?;
+            // Normalizing s negates the point, flipping the parity of its y
+            // coordinate that the recovery ID records.
+            let (sig, recovery_id) = match sig.normalize_s() {
+                Some(sig) => (
+                    sig,
+                    RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced()),
+                ),
+                None => (sig, recovery_id),
+            };
+            Ok(Signature {
+                signature: sig.to_bytes().into(),
+                recovery_id: recovery_id.to_byte().into(),
+            })
+        }
+    }
+
+    impl Verify<&[u8; 32]> for VerifyingKey {
+        type Error = Error;
+        type Signature = Signature;
+        /// Verifies the signature by recovering the public key from it, in the
+        /// same way as `Crypto::secp256k1_recover`.
+        fn verify(&self, digest: &[u8; 32], s: &Self::Signature) -> Result<(), Self::Error> {
+            let sig = k256::ecdsa::Signature::from_slice(&s.signature)?;
+            if sig.normalize_s().is_some() {
+                return Err(Error::HighS);
+            }
+            let recovery_id = u8::try_from(s.recovery_id)
+                .ok()
+                .and_then(RecoveryId::from_byte)
+                .ok_or_else(k256::ecdsa::Error::new)?;
+            let recovered =
+                k256::ecdsa::VerifyingKey::recover_from_prehash(digest, &sig, recovery_id)?;
+            if recovered != self.0 {
+                return Err(Error::RecoveredKeyMismatch);
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{Error, Sign, SigningKey, Verify};
+
+        #[test]
+        fn sign_and_verify() {
+            let sk = SigningKey::from_bytes(&[1; 32]).unwrap();
+            let vk = sk.verifying_key();
+            let digest = [2; 32];
+            let sig = sk.sign(&digest).unwrap();
+            assert!(vk.verify(&digest, &sig).is_ok());
+            assert!(matches!(
+                vk.verify(&[3; 32], &sig),
+                Err(Error::RecoveredKeyMismatch)
+            ));
+            assert_eq!(sk.public_key()[0], 4);
+        }
     }
 }
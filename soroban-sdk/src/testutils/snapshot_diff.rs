@@ -0,0 +1,180 @@
+//! Diffs between test snapshots.
+
+use core::fmt;
+
+use soroban_ledger_snapshot::{DisplayScVal, LedgerSnapshotDiff};
+
+use crate::xdr;
+
+use super::{EventSnapshot, Generators, Snapshot};
+
+/// The changes between two [`Snapshot`]s, returned by [`Snapshot::diff`].
+///
+/// The [`Display`][fmt::Display] form lists the changes one per line, with
+/// the keys and values of contract data, the topics and data of events, and
+/// the arguments of authorized invocations decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    /// The generators before and after, if they changed.
+    pub generators: Option<(Generators, Generators)>,
+    /// The changes to the ledger.
+    pub ledger: LedgerSnapshotDiff,
+    /// The authorizations of invocations that were removed or added.
+    pub auth:
+        std::vec::Vec<Change<std::vec::Vec<(xdr::ScAddress, xdr::SorobanAuthorizedInvocation)>>>,
+    /// The events that were removed or added.
+    pub events: std::vec::Vec<Change<EventSnapshot>>,
+}
+
+/// An item that was removed from, or added to, a list in a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change<T> {
+    /// An item of the original snapshot, at the index in the original list,
+    /// that is not in the other snapshot.
+    Removed(usize, T),
+    /// An item of the other snapshot, at the index in the other list, that is
+    /// not in the original snapshot.
+    Added(usize, T),
+}
+
+impl Snapshot {
+    /// Returns the changes from this snapshot to the other snapshot.
+    ///
+    /// Ledger entries are matched by their key. The lists of authorizations
+    /// and events are compared in order, and items that are in both lists in
+    /// the same relative order are not reported as changes.
+    pub fn diff(&self, other: &Snapshot) -> SnapshotDiff {
+        SnapshotDiff {
+            generators: (self.generators != other.generators)
+                .then(|| (self.generators.clone(), other.generators.clone())),
+            ledger: self.ledger.diff(&other.ledger),
+            auth: diff_seq(&self.auth.0, &other.auth.0),
+            events: diff_seq(&self.events.0, &other.events.0),
+        }
+    }
+}
+
+impl SnapshotDiff {
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.generators.is_none()
+            && self.ledger.is_empty()
+            && self.auth.is_empty()
+            && self.events.is_empty()
+    }
+}
+
+/// Returns the items that must be removed from `a` and added to `b` to turn
+/// `a` into `b`, keeping the longest common subsequence of both unchanged.
+fn diff_seq<T: PartialEq + Clone>(a: &[T], b: &[T]) -> std::vec::Vec<Change<T>> {
+    // lcs[i][j] is the length of the longest common subsequence of a[i..] and
+    // b[j..].
+    let mut lcs = std::vec![std::vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut changes = std::vec::Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            i += 1;
+            j += 1;
+        } else if j == b.len() || (i < a.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            changes.push(Change::Removed(i, a[i].clone()));
+            i += 1;
+        } else {
+            changes.push(Change::Added(j, b[j].clone()));
+            j += 1;
+        }
+    }
+    changes
+}
+
+impl fmt::Display for SnapshotDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((before, after)) = &self.generators {
+            writeln!(
+                f,
+                "~ generators: {} -> {}",
+                serde_json::to_string(before).map_err(|_| fmt::Error)?,
+                serde_json::to_string(after).map_err(|_| fmt::Error)?
+            )?;
+        }
+        write!(f, "{}", self.ledger)?;
+        for change in &self.auth {
+            let (sign, index, auths) = match change {
+                Change::Removed(i, v) => ('-', i, v),
+                Change::Added(i, v) => ('+', i, v),
+            };
+            if auths.is_empty() {
+                writeln!(f, "{sign} auth[{index}]: none")?;
+            } else {
+                writeln!(f, "{sign} auth[{index}]:")?;
+            }
+            for (address, invocation) in auths {
+                writeln!(f, "    {address}")?;
+                fmt_invocation(f, invocation, 2)?;
+            }
+        }
+        for change in &self.events {
+            let (sign, index, event) = match change {
+                Change::Removed(i, v) => ('-', i, v),
+                Change::Added(i, v) => ('+', i, v),
+            };
+            write!(f, "{sign} event[{index}]: ")?;
+            if let Some(contract_id) = &event.event.contract_id {
+                write!(f, "{} ", xdr::ScAddress::Contract(contract_id.clone()))?;
+            }
+            let xdr::ContractEventBody::V0(body) = &event.event.body;
+            write!(f, "[")?;
+            for (i, topic) in body.topics.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", DisplayScVal(topic))?;
+            }
+            write!(f, "] = {}", DisplayScVal(&body.data))?;
+            if event.failed_call {
+                write!(f, " (failed call)")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn fmt_invocation(
+    f: &mut fmt::Formatter<'_>,
+    invocation: &xdr::SorobanAuthorizedInvocation,
+    depth: usize,
+) -> fmt::Result {
+    let indent = "    ".repeat(depth);
+    match &invocation.function {
+        xdr::SorobanAuthorizedFunction::ContractFn(c) => {
+            write!(
+                f,
+                "{indent}{}.{}(",
+                c.contract_address,
+                c.function_name.0.to_utf8_string_lossy()
+            )?;
+            for (i, arg) in c.args.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", DisplayScVal(arg))?;
+            }
+            writeln!(f, ")")?;
+        }
+        function => writeln!(f, "{indent}{function:?}")?,
+    }
+    for sub in invocation.sub_invocations.iter() {
+        fmt_invocation(f, sub, depth + 1)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,327 @@
+//! Model-based state machine testing of contracts.
+//!
+//! A state machine test checks a contract against a reference model: a plain
+//! Rust value that describes what the state of the contract should be. The
+//! test defines the transitions that can be made, such as the calls of a
+//! token's `mint`, `transfer` and `burn`, how each transition changes the
+//! model, and how each transition is executed against the contract.
+//!
+//! The [`Runner`] generates sequences of transitions, executes each sequence
+//! against a new [`Env`], and after every step checks that the contract agrees
+//! with the model. When a sequence fails, the runner shrinks it by removing
+//! steps until no step can be removed without the sequence passing, and
+//! reports the smallest failing sequence it found. Unlike values generated
+//! through the [`proptest`][crate::testutils::proptest] bridge, the reported
+//! sequence is always a subsequence of the sequence that failed first.
+//!
+//! ### Examples
+//! ```
+//! use soroban_sdk::{
+//!     contract, contractimpl,
+//!     testutils::{
+//!         arbitrary::arbitrary::{Result, Unstructured},
+//!         state_machine::{Runner, StateMachine},
+//!     },
+//!     Env,
+//! };
+//!
+//! #[contract]
+//! pub struct Counter;
+//!
+//! #[contractimpl]
+//! impl Counter {
+//!     pub fn add(env: Env, n: u32) -> u32 {
+//!         let count: u32 = env.storage().instance().get(&()).unwrap_or(0);
+//!         env.storage().instance().set(&(), &(count + n));
+//!         count + n
+//!     }
+//!
+//!     pub fn get(env: Env) -> u32 {
+//!         env.storage().instance().get(&()).unwrap_or(0)
+//!     }
+//! }
+//!
+//! #[derive(Clone, Debug)]
+//! enum Transition {
+//!     Add(u32),
+//! }
+//!
+//! struct CounterTest;
+//!
+//! impl StateMachine for CounterTest {
+//!     type Model = u32;
+//!     type Transition = Transition;
+//!     type System = CounterClient<'static>;
+//!
+//!     fn init(env: &Env) -> (Self::System, Self::Model) {
+//!         let contract_id = env.register(Counter, ());
+//!         (CounterClient::new(env, &contract_id), 0)
+//!     }
+//!
+//!     fn transition(_model: &u32, u: &mut Unstructured) -> Result<Transition> {
+//!         Ok(Transition::Add(u.int_in_range(0..=100)?))
+//!     }
+//!
+//!     fn precondition(model: &u32, t: &Transition) -> bool {
+//!         let Transition::Add(n) = t;
+//!         model.checked_add(*n).is_some()
+//!     }
+//!
+//!     fn apply(model: &mut u32, t: &Transition) {
+//!         let Transition::Add(n) = t;
+//!         *model += n;
+//!     }
+//!
+//!     fn execute(_env: &Env, client: &mut CounterClient<'static>, model: &u32, t: &Transition) {
+//!         let Transition::Add(n) = t;
+//!         assert_eq!(client.add(n), model + n);
+//!     }
+//!
+//!     fn check(_env: &Env, client: &CounterClient<'static>, model: &u32) {
+//!         assert_eq!(client.get(), *model);
+//!     }
+//! }
+//!
+//! #[test]
+//! fn test() {
+//! # }
+//! # fn main() {
+//!     Runner::new().run::<CounterTest>();
+//! }
+//! ```
+
+use core::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use arbitrary::Unstructured;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+use crate::{testutils::EnvTestConfig, Env};
+
+/// A reference model of a contract, and the transitions that change it.
+///
+/// See the [module docs][self] for an example.
+pub trait StateMachine {
+    /// The reference model of the state of the contract.
+    type Model: Clone + fmt::Debug;
+    /// A transition of the state, such as a call of a contract function with
+    /// its arguments.
+    type Transition: Clone + fmt::Debug;
+    /// The system under test, such as the clients of the contracts
+    /// registered in the [Env].
+    type System;
+
+    /// Sets up the system under test in the Env, and returns it with the
+    /// model of its initial state.
+    fn init(env: &Env) -> (Self::System, Self::Model);
+
+    /// Generates a transition that can be made from the state of the model.
+    fn transition(model: &Self::Model, u: &mut Unstructured)
+        -> arbitrary::Result<Self::Transition>;
+
+    /// Returns whether the transition can be made from the state of the
+    /// model.
+    ///
+    /// Shrinking removes steps from a sequence, which can make the steps
+    /// after them invalid, such as a transfer of a balance that is no longer
+    /// minted. Sequences with a step that fails its precondition are not run.
+    fn precondition(_model: &Self::Model, _transition: &Self::Transition) -> bool {
+        true
+    }
+
+    /// Applies the transition to the model.
+    fn apply(model: &mut Self::Model, transition: &Self::Transition);
+
+    /// Executes the transition against the system under test.
+    ///
+    /// The model is the state before the transition is applied, and can be
+    /// used to assert the values the contract returns.
+    fn execute(
+        env: &Env,
+        system: &mut Self::System,
+        model: &Self::Model,
+        transition: &Self::Transition,
+    );
+
+    /// Checks that the system under test agrees with the model, panicking if
+    /// it does not.
+    ///
+    /// Called after the system is set up and after every transition.
+    fn check(env: &Env, system: &Self::System, model: &Self::Model);
+}
+
+/// Runs state machine tests, generating sequences of transitions and
+/// shrinking the sequences that fail.
+#[derive(Debug, Clone)]
+pub struct Runner {
+    cases: u32,
+    max_steps: usize,
+    seed: u64,
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runner {
+    /// Create a runner that runs 64 sequences of up to 32 steps each.
+    pub fn new() -> Self {
+        Runner {
+            cases: 64,
+            max_steps: 32,
+            seed: 0,
+        }
+    }
+
+    /// Set the number of sequences to run.
+    pub fn cases(mut self, cases: u32) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Set the maximum number of steps in a sequence.
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Set the seed that the sequences are generated from. The sequences
+    /// generated for a seed are the same each time the test runs.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Generate and run the sequences.
+    ///
+    /// ### Panics
+    ///
+    /// If a sequence fails, with the smallest failing sequence found by
+    /// shrinking, and the failure of that sequence.
+    pub fn run<M: StateMachine>(&self) {
+        for case in 0..self.cases {
+            let seed = self.seed.wrapping_add(u64::from(case));
+            let steps = self.generate::<M>(seed);
+            let Err(failure) = replay::<M>(seed, &steps) else {
+                continue;
+            };
+            let (steps, failure) = shrink::<M>(seed, steps, failure);
+            let mut msg = std::format!(
+                "state machine test failed (case seed {seed}), minimal failing sequence of {} steps:",
+                steps.len()
+            );
+            for (i, t) in steps.iter().enumerate() {
+                msg.push_str(&std::format!("\n  {i}: {t:?}"));
+            }
+            msg.push_str(&std::format!("\nfailure: {failure}"));
+            panic!("{msg}");
+        }
+    }
+
+    /// Generate a sequence of transitions from the seed, applying each to a
+    /// model so that every transition is generated from the state the
+    /// previous transitions led to.
+    fn generate<M: StateMachine>(&self, seed: u64) -> std::vec::Vec<M::Transition> {
+        let env = new_env(seed);
+        let (_, mut model) = M::init(&env);
+        let mut data = std::vec![0u8; 64 * 1024];
+        StdRng::seed_from_u64(seed).fill_bytes(&mut data);
+        let mut u = Unstructured::new(&data);
+        let mut steps = std::vec::Vec::new();
+        // Transitions that fail their precondition are skipped, so bound the
+        // attempts in case most transitions generated fail it.
+        for _ in 0..self.max_steps * 4 {
+            if steps.len() >= self.max_steps || u.is_empty() {
+                break;
+            }
+            let Ok(t) = M::transition(&model, &mut u) else {
+                break;
+            };
+            if !M::precondition(&model, &t) {
+                continue;
+            }
+            M::apply(&mut model, &t);
+            steps.push(t);
+        }
+        steps
+    }
+}
+
+/// The outcome of replaying a sequence that did not pass.
+enum Failure {
+    /// A step failed its precondition, so the sequence could not be run.
+    Invalid,
+    /// The sequence failed with the panic message.
+    Failed(std::string::String),
+}
+
+/// Replays the steps against a new Env created with the seed.
+fn replay<M: StateMachine>(seed: u64, steps: &[M::Transition]) -> Result<(), std::string::String> {
+    match try_replay::<M>(seed, steps) {
+        Ok(()) | Err(Failure::Invalid) => Ok(()),
+        Err(Failure::Failed(msg)) => Err(msg),
+    }
+}
+
+fn try_replay<M: StateMachine>(seed: u64, steps: &[M::Transition]) -> Result<(), Failure> {
+    let env = new_env(seed);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let (mut system, mut model) = M::init(&env);
+        M::check(&env, &system, &model);
+        for t in steps {
+            if !M::precondition(&model, t) {
+                return Err(Failure::Invalid);
+            }
+            M::execute(&env, &mut system, &model, t);
+            M::apply(&mut model, t);
+            M::check(&env, &system, &model);
+        }
+        Ok(())
+    }));
+    match result {
+        Ok(r) => r,
+        Err(payload) => Err(Failure::Failed(
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| std::string::String::from(*s))
+                .or_else(|| payload.downcast_ref::<std::string::String>().cloned())
+                .unwrap_or_default(),
+        )),
+    }
+}
+
+/// Shrinks the failing sequence by removing chunks of steps, halving the size
+/// of the chunks until single steps cannot be removed.
+fn shrink<M: StateMachine>(
+    seed: u64,
+    mut steps: std::vec::Vec<M::Transition>,
+    mut failure: std::string::String,
+) -> (std::vec::Vec<M::Transition>, std::string::String) {
+    let mut chunk = steps.len().div_ceil(2);
+    while chunk > 0 {
+        let mut start = 0;
+        while start < steps.len() {
+            let mut candidate = steps.clone();
+            candidate.drain(start..(start + chunk).min(steps.len()));
+            match try_replay::<M>(seed, &candidate) {
+                Err(Failure::Failed(msg)) => {
+                    steps = candidate;
+                    failure = msg;
+                }
+                Ok(()) | Err(Failure::Invalid) => start += chunk,
+            }
+        }
+        chunk /= 2;
+    }
+    (steps, failure)
+}
+
+fn new_env(seed: u64) -> Env {
+    let mut env = Env::new_with_seed(seed);
+    env.set_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env
+}
@@ -0,0 +1,683 @@
+//! Call tree tracing of contract invocations.
+//!
+//! Use [`Env::trace`] to get the [`Trace`] of the last invocation.
+//!
+//! The trace is assembled after the invocation from the diagnostic events
+//! that the host emits for every contract call and return, the resources the
+//! host meters for each call, and the storage accesses and costs the SDK
+//! observes in natively registered contracts. The storage of the other
+//! contracts is derived from the ledger entries before and after the
+//! invocation, when footprint recording is enabled.
+
+use core::{cell::RefCell, fmt};
+use soroban_ledger_snapshot::DisplayScVal;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+};
+
+use crate::{
+    env::internal::{events::HostEvent, InvocationResources, StorageType},
+    testutils::cost_estimate::LedgerState,
+    xdr, Env, TryFromVal, Val,
+};
+
+/// The call tree of an invocation.
+///
+/// Contains one [`Frame`] for each contract call made at the top level of the
+/// invocation, which is usually exactly one. Calls made inside a contract are
+/// nested in the frame of their caller.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trace(pub std::vec::Vec<Frame>);
+
+/// A contract frame in a [`Trace`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// Address of the contract called.
+    pub contract: xdr::ScAddress,
+    /// Name of the function called.
+    pub function: xdr::ScSymbol,
+    /// Arguments the function was called with.
+    ///
+    /// For contracts that are not registered natively, the arguments are
+    /// decoded from the host's diagnostic events, which do not distinguish a
+    /// single vector argument from multiple arguments. In that case the
+    /// elements of the vector are listed as the arguments.
+    pub args: std::vec::Vec<xdr::ScVal>,
+    /// Value returned by the function, or the error the call failed with.
+    pub result: Result<xdr::ScVal, xdr::ScError>,
+    /// Contract and system events emitted by the frame.
+    ///
+    /// Events emitted by a frame that failed, or that was rolled back because
+    /// a caller failed, are included.
+    pub events: std::vec::Vec<xdr::ContractEvent>,
+    /// Storage read and written by the frame.
+    ///
+    /// For natively registered contracts, the SDK observes the storage
+    /// accesses of each frame.
+    ///
+    /// The storage accesses of Wasm and built-in contracts are not observable,
+    /// and are instead derived from the ledger entries before and after the
+    /// invocation, when footprint recording is enabled with
+    /// [`CostEstimate::enable_footprint`](crate::testutils::cost_estimate::CostEstimate::enable_footprint).
+    /// The keys are then ordered by key rather than by access, and all the
+    /// accesses to the storage of a contract are attributed to its first
+    /// frame. Only the keys whose values changed are written, and the keys a
+    /// frame reads are missed if an earlier invocation loaded them, as are
+    /// the instance storage keys it only reads. `None` if footprint recording
+    /// is disabled.
+    pub storage: Option<Storage>,
+    /// Resources used by the frame, including its sub-frames.
+    ///
+    /// `None` if the host did not meter the frame, such as the frames of
+    /// constructors and of `__check_auth` functions, which the host calls
+    /// itself and meters as part of their caller.
+    pub resources: Option<InvocationResources>,
+    /// Frames of the calls made by the frame, in order.
+    pub sub_frames: std::vec::Vec<Frame>,
+}
+
+/// Storage keys accessed by a [`Frame`], in order of first access for
+/// natively registered contracts, see [`Frame::storage`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Storage {
+    pub reads: std::vec::Vec<StorageKey>,
+    pub writes: std::vec::Vec<StorageKey>,
+}
+
+/// A key in one of the storage types of a contract.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StorageKey {
+    Persistent(xdr::ScVal),
+    Temporary(xdr::ScVal),
+    Instance(xdr::ScVal),
+}
+
+impl Trace {
+    /// Returns the frames of the trace in depth-first order.
+    pub fn frames(&self) -> std::vec::Vec<&Frame> {
+        fn collect<'a>(frames: &'a [Frame], out: &mut std::vec::Vec<&'a Frame>) {
+            for frame in frames {
+                out.push(frame);
+                collect(&frame.sub_frames, out);
+            }
+        }
+        let mut out = std::vec::Vec::new();
+        collect(&self.0, &mut out);
+        out
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for frame in &self.0 {
+            frame.fmt_tree(f, "", "")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tree(f, "", "")
+    }
+}
+
+impl Frame {
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>, first: &str, rest: &str) -> fmt::Result {
+        write!(
+            f,
+            "{first}{}.{}(",
+            self.contract,
+            self.function.0.to_utf8_string_lossy()
+        )?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", DisplayScVal(arg))?;
+        }
+        write!(f, ")")?;
+        match &self.result {
+            Ok(v) => write!(f, " -> {}", DisplayScVal(v))?,
+            Err(e) => write!(f, " -> Err({e:?})")?,
+        }
+        if let Some(r) = &self.resources {
+            write!(
+                f,
+                " [instructions: {}, mem_bytes: {}]",
+                r.instructions, r.mem_bytes
+            )?;
+        }
+        writeln!(f)?;
+
+        let mut lines = std::vec::Vec::<std::string::String>::new();
+        for e in &self.events {
+            let xdr::ContractEventBody::V0(body) = &e.body;
+            let topics = xdr::ScVal::Vec(Some(xdr::ScVec(body.topics.clone())));
+            lines.push(format!(
+                "event: topics: {}, data: {}",
+                DisplayScVal(&topics),
+                DisplayScVal(&body.data)
+            ));
+        }
+        if let Some(storage) = &self.storage {
+            for k in &storage.reads {
+                lines.push(format!("read: {k}"));
+            }
+            for k in &storage.writes {
+                lines.push(format!("write: {k}"));
+            }
+        }
+        let count = lines.len() + self.sub_frames.len();
+        let mut i = 0;
+        for line in lines {
+            i += 1;
+            let branch = if i == count { "└─ " } else { "├─ " };
+            writeln!(f, "{rest}{branch}{line}")?;
+        }
+        for sub in &self.sub_frames {
+            i += 1;
+            let (branch, indent) = if i == count {
+                ("└─ ", "   ")
+            } else {
+                ("├─ ", "│  ")
+            };
+            sub.fmt_tree(f, &format!("{rest}{branch}"), &format!("{rest}{indent}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for StorageKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageKey::Persistent(k) => write!(f, "Persistent({})", DisplayScVal(k)),
+            StorageKey::Temporary(k) => write!(f, "Temporary({})", DisplayScVal(k)),
+            StorageKey::Instance(k) => write!(f, "Instance({})", DisplayScVal(k)),
+        }
+    }
+}
+
+/// Records the frames of natively registered contracts, and the storage they
+/// access, during an invocation.
+#[derive(Default)]
+pub(crate) struct Recorder {
+    frames: std::vec::Vec<NativeFrame>,
+    stack: std::vec::Vec<usize>,
+}
+
+struct NativeFrame {
+    contract: Val,
+    function: std::string::String,
+    args: std::vec::Vec<Val>,
+    reads: std::vec::Vec<(StorageType, Val)>,
+    writes: std::vec::Vec<(StorageType, Val)>,
+}
+
+thread_local! {
+    // The recorders of the native frames currently executing, innermost last.
+    // Storage accesses are attributed to the innermost frame.
+    static ACTIVE: RefCell<std::vec::Vec<Rc<RefCell<Recorder>>>> = const { RefCell::new(std::vec::Vec::new()) };
+}
+
+impl Recorder {
+    /// Clears the frames recorded for the previous invocation.
+    pub(crate) fn clear(&mut self) {
+        self.frames.clear();
+        self.stack.clear();
+    }
+}
+
+/// Guard for a native frame that is being recorded, that ends the frame when
+/// dropped, including when the contract function panics.
+pub(crate) struct NativeFrameGuard;
+
+impl NativeFrameGuard {
+    pub(crate) fn enter(
+        recorder: &Rc<RefCell<Recorder>>,
+        contract: Val,
+        function: &str,
+        args: &[Val],
+    ) -> Self {
+        let mut r = recorder.borrow_mut();
+        let i = r.frames.len();
+        r.frames.push(NativeFrame {
+            contract,
+            function: function.into(),
+            args: args.to_vec(),
+            reads: std::vec::Vec::new(),
+            writes: std::vec::Vec::new(),
+        });
+        r.stack.push(i);
+        ACTIVE.with_borrow_mut(|a| a.push(recorder.clone()));
+        NativeFrameGuard
+    }
+}
+
+impl Drop for NativeFrameGuard {
+    fn drop(&mut self) {
+        if let Some(recorder) = ACTIVE.with_borrow_mut(|a| a.pop()) {
+            recorder.borrow_mut().stack.pop();
+        }
+    }
+}
+
+/// Records a storage access by the innermost native frame, if any.
+pub(crate) fn record_storage_access(storage_type: StorageType, key: Val, write: bool) {
+    ACTIVE.with_borrow(|a| {
+        if let Some(recorder) = a.last() {
+            let mut r = recorder.borrow_mut();
+            if let Some(&i) = r.stack.last() {
+                let frame = &mut r.frames[i];
+                if write {
+                    frame.writes.push((storage_type, key));
+                } else {
+                    frame.reads.push((storage_type, key));
+                }
+            }
+        }
+    });
+}
+
+impl Trace {
+    pub(crate) fn new(env: &Env, events: &[HostEvent], recorder: &Recorder) -> Trace {
+        let mut frames = build_frames(events);
+        if let Some(root) = metered_invocations(env) {
+            // The root of the metered invocations is the top level frame when
+            // the invocation is a contract call, or some other entry point
+            // (e.g. contract creation, whose constructor is not metered)
+            // whose sub-calls are the calls of the top level frames.
+            match frames.as_mut_slice() {
+                [root_frame] if is_metered(root_frame) => attach_resources(root_frame, &root),
+                frames => attach_sub_resources(frames, &root.sub_invocations),
+            }
+        }
+        let to_scval = |v: &Val| xdr::ScVal::try_from_val(env, v).unwrap();
+        let mut native: std::vec::Vec<_> = recorder
+            .frames
+            .iter()
+            .map(|n| {
+                Some(NativeRecord {
+                    contract: to_scval(&n.contract),
+                    args: n.args.iter().map(to_scval).collect(),
+                    frame: n,
+                })
+            })
+            .collect();
+        attach_native(env, &mut frames, &mut native);
+        if let Some(before) = env.last_invocation_ledger_state() {
+            attach_ledger_storage(env, &mut frames, &before);
+        }
+        Trace(frames)
+    }
+}
+
+/// Returns the contract and function called by the top level invocation that
+/// is finishing, and whether the call succeeded, or `None` if the invocation
+/// was not a contract call.
+///
+/// The host emits the `fn_return` event of a top level call only after the
+/// invocation has finished, so a call that has not returned yet is not
+/// necessarily a failed call. Instead, when a call fails the host marks all the
+/// events emitted within it as failed, and there is always at least the error
+/// event. Events of the call itself, including the `fn_call` events of its
+/// sub-calls, are only marked if the call itself fails.
+pub(crate) fn root_call(events: &[HostEvent]) -> Option<(xdr::ScAddress, xdr::ScSymbol, bool)> {
+    let i = events.iter().position(|e| {
+        let xdr::ContractEventBody::V0(body) = &e.event.body;
+        e.event.type_ == xdr::ContractEventType::Diagnostic
+            && e.event.contract_id.is_none()
+            && matches!(body.topics.first(), Some(xdr::ScVal::Symbol(s)) if s.as_vec() == b"fn_call")
+    })?;
+    let xdr::ContractEventBody::V0(body) = &events[i].event.body;
+    let [_, xdr::ScVal::Bytes(id), xdr::ScVal::Symbol(function)] = body.topics.as_slice() else {
+        return None;
+    };
+    let id = <[u8; 32]>::try_from(id.as_slice()).ok()?;
+    let within = &events[i + 1..];
+    let ok = within.is_empty() || within.iter().any(|e| !e.failed_call);
+    Some((
+        xdr::ScAddress::Contract(xdr::ContractId(xdr::Hash(id))),
+        function.clone(),
+        ok,
+    ))
+}
+
+/// An invocation metered by the host.
+struct Metered {
+    resources: InvocationResources,
+    sub_invocations: std::vec::Vec<Metered>,
+}
+
+/// Returns the tree of invocations metered by the host for the last
+/// invocation.
+fn metered_invocations(env: &Env) -> Option<Metered> {
+    // The host does not export the type of its metered invocation tree, so
+    // the tree is flattened depth-first with the type inferred, then rebuilt.
+    let root = env.host().get_detailed_last_invocation_resources()?;
+    let mut flat = std::vec::Vec::new();
+    let mut stack = std::vec![(0usize, &root)];
+    while let Some((depth, node)) = stack.pop() {
+        flat.push((
+            depth,
+            Metered {
+                resources: node.resources.clone().into(),
+                sub_invocations: std::vec::Vec::new(),
+            },
+        ));
+        for sub in node.sub_call_resources.iter().rev() {
+            stack.push((depth + 1, sub));
+        }
+    }
+    let mut flat = flat.into_iter().peekable();
+    let (_, mut root) = flat.next()?;
+    fn rebuild(
+        parent: &mut Metered,
+        depth: usize,
+        flat: &mut core::iter::Peekable<impl Iterator<Item = (usize, Metered)>>,
+    ) {
+        while let Some((_, mut node)) = flat.next_if(|(d, _)| *d == depth + 1) {
+            rebuild(&mut node, depth + 1, flat);
+            parent.sub_invocations.push(node);
+        }
+    }
+    rebuild(&mut root, 0, &mut flat);
+    Some(root)
+}
+
+/// Returns the frames of the calls made by the invocation that is finishing,
+/// from its events.
+///
+/// The frames have the results of the calls, except for the results of the
+/// calls made at the top level that succeeded, which have not returned yet and
+/// are void.
+pub(crate) fn finishing_frames(events: &[HostEvent]) -> std::vec::Vec<Frame> {
+    let mut frames = build_frames(events);
+    if let (Some((_, _, true)), Some(root)) = (root_call(events), frames.last_mut()) {
+        root.result = Ok(xdr::ScVal::Void);
+    }
+    frames
+}
+
+fn build_frames(events: &[HostEvent]) -> std::vec::Vec<Frame> {
+    let mut roots = std::vec::Vec::<Frame>::new();
+    let mut stack = std::vec::Vec::<Frame>::new();
+    let mut last_error: Option<xdr::ScError> = None;
+
+    // Completes the frame on the top of the stack, attaching it to its caller.
+    let finish = |stack: &mut std::vec::Vec<Frame>,
+                  roots: &mut std::vec::Vec<Frame>,
+                  result: Result<xdr::ScVal, xdr::ScError>| {
+        if let Some(mut frame) = stack.pop() {
+            frame.result = result;
+            match stack.last_mut() {
+                Some(caller) => caller.sub_frames.push(frame),
+                None => roots.push(frame),
+            }
+        }
+    };
+    let failed = |last_error: &Option<xdr::ScError>| {
+        Err(last_error
+            .clone()
+            .unwrap_or(xdr::ScError::Context(xdr::ScErrorCode::InternalError)))
+    };
+
+    for e in events {
+        let event = &e.event;
+        let xdr::ContractEventBody::V0(body) = &event.body;
+        let topics = body.topics.as_slice();
+        match event.type_ {
+            xdr::ContractEventType::Diagnostic => match topics {
+                [xdr::ScVal::Symbol(s), xdr::ScVal::Bytes(id), xdr::ScVal::Symbol(function)]
+                    if s.as_vec() == b"fn_call" =>
+                {
+                    let Ok(id) = <[u8; 32]>::try_from(id.as_slice()) else {
+                        continue;
+                    };
+                    // The event is emitted by the caller, so any frames above
+                    // the caller have ended without returning, i.e. failed.
+                    let caller = event.contract_id.clone().map(xdr::ScAddress::Contract);
+                    while let Some(top) = stack.last() {
+                        if Some(&top.contract) == caller.as_ref() {
+                            break;
+                        }
+                        let result = failed(&last_error);
+                        finish(&mut stack, &mut roots, result);
+                    }
+                    stack.push(Frame {
+                        contract: xdr::ScAddress::Contract(xdr::ContractId(xdr::Hash(id))),
+                        function: function.clone(),
+                        args: event_args(&body.data),
+                        result: Ok(xdr::ScVal::Void),
+                        events: std::vec::Vec::new(),
+                        storage: None,
+                        resources: None,
+                        sub_frames: std::vec::Vec::new(),
+                    });
+                }
+                [xdr::ScVal::Symbol(s), xdr::ScVal::Symbol(function)]
+                    if s.as_vec() == b"fn_return" =>
+                {
+                    let callee = event.contract_id.clone().map(xdr::ScAddress::Contract);
+                    let Some(i) = stack.iter().rposition(|f| {
+                        Some(&f.contract) == callee.as_ref() && &f.function == function
+                    }) else {
+                        continue;
+                    };
+                    while stack.len() > i + 1 {
+                        let result = failed(&last_error);
+                        finish(&mut stack, &mut roots, result);
+                    }
+                    finish(&mut stack, &mut roots, Ok(body.data.clone()));
+                }
+                [xdr::ScVal::Symbol(s), xdr::ScVal::Error(error)] if s.as_vec() == b"error" => {
+                    // The event is emitted by the contract running when the
+                    // error occurs, so any frames above it have failed.
+                    let emitter = event.contract_id.clone().map(xdr::ScAddress::Contract);
+                    if let Some(i) = stack
+                        .iter()
+                        .rposition(|f| Some(&f.contract) == emitter.as_ref())
+                    {
+                        while stack.len() > i + 1 {
+                            let result = failed(&last_error);
+                            finish(&mut stack, &mut roots, result);
+                        }
+                    }
+                    last_error = Some(error.clone());
+                }
+                _ => {}
+            },
+            xdr::ContractEventType::Contract | xdr::ContractEventType::System => {
+                if let Some(top) = stack.last_mut() {
+                    top.events.push(event.clone());
+                }
+            }
+        }
+    }
+    while !stack.is_empty() {
+        let result = failed(&last_error);
+        finish(&mut stack, &mut roots, result);
+    }
+    roots
+}
+
+/// Returns whether the host meters the call of the frame.
+///
+/// The host meters the calls that contracts make, but not the calls it makes
+/// itself to constructors and to `__check_auth` functions, whose sub-calls
+/// it meters as sub-calls of the caller.
+fn is_metered(frame: &Frame) -> bool {
+    !matches!(
+        frame.function.0.as_slice(),
+        b"__constructor" | b"__check_auth"
+    )
+}
+
+fn attach_resources(frame: &mut Frame, node: &Metered) {
+    frame.resources = Some(node.resources.clone());
+    attach_sub_resources(&mut frame.sub_frames, &node.sub_invocations);
+}
+
+/// Attaches the resources of the metered sub-calls of an invocation to the
+/// frames of the calls, which are in the same order. If the number of calls
+/// differs, the frames are not the calls metered, and none are attached.
+fn attach_sub_resources(frames: &mut [Frame], nodes: &[Metered]) {
+    fn collect<'a>(frames: &'a mut [Frame], out: &mut std::vec::Vec<&'a mut Frame>) {
+        for frame in frames {
+            if is_metered(frame) {
+                out.push(frame);
+            } else {
+                collect(&mut frame.sub_frames, out);
+            }
+        }
+    }
+    let mut metered = std::vec::Vec::new();
+    collect(frames, &mut metered);
+    if metered.len() == nodes.len() {
+        for (frame, node) in metered.into_iter().zip(nodes) {
+            attach_resources(frame, node);
+        }
+    }
+}
+
+/// Returns the arguments of a call from the data of its `fn_call` event.
+fn event_args(data: &xdr::ScVal) -> std::vec::Vec<xdr::ScVal> {
+    match data {
+        xdr::ScVal::Void => std::vec::Vec::new(),
+        xdr::ScVal::Vec(Some(v)) => v.to_vec(),
+        v => std::vec![v.clone()],
+    }
+}
+
+/// Returns the arguments of a call as they are decoded from its `fn_call`
+/// event, where the host lists multiple arguments as a vector, and a single
+/// argument as itself.
+fn as_event_args(args: &[xdr::ScVal]) -> std::vec::Vec<xdr::ScVal> {
+    match args {
+        [] => std::vec::Vec::new(),
+        [arg] => event_args(arg),
+        args => args.to_vec(),
+    }
+}
+
+/// A native frame recorded during the invocation, with its contract and
+/// arguments converted for matching it to a [`Frame`].
+struct NativeRecord<'a> {
+    contract: xdr::ScVal,
+    args: std::vec::Vec<xdr::ScVal>,
+    frame: &'a NativeFrame,
+}
+
+/// Attaches the records of the native frames to the frames they are of.
+///
+/// Each frame takes the first record that is not taken yet of the same
+/// contract, function and arguments, so that frames of calls the SDK does not
+/// observe, or records of calls the host emits no events for, do not shift
+/// the records onto other frames.
+fn attach_native(env: &Env, frames: &mut [Frame], native: &mut [Option<NativeRecord>]) {
+    let to_scval = |v: &Val| xdr::ScVal::try_from_val(env, v).unwrap();
+    for frame in frames {
+        let contract = xdr::ScVal::Address(frame.contract.clone());
+        let record = native.iter_mut().find(|n| {
+            n.as_ref().is_some_and(|n| {
+                n.frame.function.as_bytes() == frame.function.0.as_vec()
+                    && n.contract == contract
+                    && as_event_args(&n.args) == frame.args
+            })
+        });
+        if let Some(NativeRecord { args, frame: n, .. }) = record.and_then(Option::take) {
+            let to_keys = |accesses: &[(StorageType, Val)]| {
+                let mut keys = std::vec::Vec::<StorageKey>::new();
+                for (t, k) in accesses {
+                    let k = to_scval(k);
+                    let k = match t {
+                        StorageType::Persistent => StorageKey::Persistent(k),
+                        StorageType::Temporary => StorageKey::Temporary(k),
+                        StorageType::Instance => StorageKey::Instance(k),
+                    };
+                    if !keys.contains(&k) {
+                        keys.push(k);
+                    }
+                }
+                keys
+            };
+            frame.args = args;
+            frame.storage = Some(Storage {
+                reads: to_keys(&n.reads),
+                writes: to_keys(&n.writes),
+            });
+        }
+        attach_native(env, &mut frame.sub_frames, native);
+    }
+}
+
+/// Attaches the storage of the contracts that are not registered natively,
+/// derived from the ledger entries before and after the invocation, to their
+/// frames. All the accesses to the storage of a contract are attributed to
+/// its first frame.
+fn attach_ledger_storage(env: &Env, frames: &mut [Frame], before: &LedgerState) {
+    let Ok(after) = env.host().get_stored_entries() else {
+        return;
+    };
+    let after: BTreeMap<_, _> = after.into_iter().collect();
+    let mut storages = BTreeMap::<xdr::ScAddress, Storage>::new();
+    for (k, write) in before.accessed(env, &after) {
+        let xdr::LedgerKey::ContractData(data) = &*k else {
+            continue;
+        };
+        let storage = storages.entry(data.contract.clone()).or_default();
+        let key = match (&data.key, data.durability) {
+            (xdr::ScVal::LedgerKeyContractInstance, _) => {
+                // The instance is read by every call, so only the keys of the
+                // instance storage that changed are recorded.
+                let old = instance_storage(before.entry(env, &k).as_ref().map(|e| &*e.0));
+                let new = instance_storage(after.get(&k).and_then(|e| e.as_ref()).map(|e| &*e.0));
+                let keys: BTreeSet<_> = old.keys().chain(new.keys()).collect();
+                for key in keys {
+                    if old.get(key) != new.get(key) {
+                        storage.writes.push(StorageKey::Instance(key.clone()));
+                    }
+                }
+                continue;
+            }
+            (key, xdr::ContractDataDurability::Persistent) => StorageKey::Persistent(key.clone()),
+            (key, xdr::ContractDataDurability::Temporary) => StorageKey::Temporary(key.clone()),
+        };
+        if write {
+            storage.writes.push(key);
+        } else {
+            storage.reads.push(key);
+        }
+    }
+
+    fn attach(frames: &mut [Frame], storages: &mut BTreeMap<xdr::ScAddress, Storage>) {
+        for frame in frames {
+            // Frames of natively registered contracts have their storage
+            // accesses already.
+            if frame.storage.is_none() {
+                frame.storage = Some(storages.remove(&frame.contract).unwrap_or_default());
+            }
+            attach(&mut frame.sub_frames, storages);
+        }
+    }
+    attach(frames, &mut storages);
+}
+
+/// Returns the instance storage of a contract instance entry.
+fn instance_storage(entry: Option<&xdr::LedgerEntry>) -> BTreeMap<xdr::ScVal, xdr::ScVal> {
+    match entry.map(|e| &e.data) {
+        Some(xdr::LedgerEntryData::ContractData(xdr::ContractDataEntry {
+            val:
+                xdr::ScVal::ContractInstance(xdr::ScContractInstance {
+                    storage: Some(storage),
+                    ..
+                }),
+            ..
+        })) => storage
+            .iter()
+            .map(|e| (e.key.clone(), e.val.clone()))
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
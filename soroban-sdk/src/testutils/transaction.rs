@@ -0,0 +1,489 @@
+//! Simulated transactions made of several host function invocations, for
+//! testing the multi-step flows that are submitted together, such as
+//! uploading Wasm, deploying a contract from it and initializing the contract.
+//!
+//! [`Env::transaction`] returns a [`Transaction`]. Each step of the
+//! transaction is a closure passed to [`Transaction::step`] that makes one top
+//! level invocation, such as a call with a contract client, or an upload or
+//! deploy with the [`Deployer`][crate::deploy::Deployer].
+//!
+//! The steps share one set of resource limits and one footprint. The ledger
+//! entries, disk reads and writes are counted from the footprint, so that an
+//! entry accessed by several steps counts once, and the other resources of the
+//! steps are added up. When a step fails,
+//! or the steps exceed the limits, the changes made by all the steps of the
+//! transaction are rolled back.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::{contract, contractimpl, Env, Symbol};
+//!
+//! #[contract]
+//! pub struct Counter;
+//!
+//! #[contractimpl]
+//! impl Counter {
+//!     pub fn incr(env: Env, max: u32) -> u32 {
+//!         let key = Symbol::new(&env, "count");
+//!         let count = env.storage().instance().get(&key).unwrap_or(0) + 1;
+//!         assert!(count <= max);
+//!         env.storage().instance().set(&key, &count);
+//!         count
+//!     }
+//! }
+//!
+//! #[test]
+//! fn test() {
+//! # }
+//! # fn main() {
+//!     let env = Env::default();
+//!     let client = CounterClient::new(&env, &env.register(Counter, ()));
+//!
+//!     let tx = env.transaction();
+//!     let result = tx.run(|tx| {
+//!         tx.step(|_| client.incr(&2))?;
+//!         tx.step(|_| client.incr(&2))
+//!     });
+//!     assert_eq!(result, Ok(2));
+//!     assert_eq!(tx.footprint().read_write.len(), 1);
+//!
+//!     // The second step fails, so the first is rolled back too.
+//!     let result = env.transaction().run(|tx| {
+//!         tx.step(|_| client.incr(&3))?;
+//!         tx.step(|_| client.incr(&3))
+//!     });
+//!     assert!(result.is_err());
+//!     assert_eq!(client.incr(&3), 3);
+//! }
+//! ```
+use core::{cell::RefCell, fmt};
+use std::{
+    collections::BTreeMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use crate::{
+    env::internal::{InvocationResourceLimits, InvocationResources},
+    testutils::cost_estimate::{Footprint, FootprintEntry, LedgerState},
+    xdr, Env,
+};
+
+/// The reason a [`Transaction`] failed and was rolled back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionError {
+    /// A step panicked, such as when a contract function called with a
+    /// contract client failed.
+    StepFailed {
+        /// Index of the step, starting at 0.
+        step: usize,
+        /// Message the step panicked with.
+        message: std::string::String,
+    },
+    /// The resources of the steps up to and including a step exceeded the
+    /// limits of the transaction.
+    LimitsExceeded {
+        /// Index of the step, starting at 0.
+        step: usize,
+        /// The limits exceeded, with the resources used and the limit, such
+        /// as `instructions: 500000000 > 400000000`.
+        exceeded: std::vec::Vec<std::string::String>,
+    },
+}
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransactionError::StepFailed { step, message } => {
+                write!(f, "step {step} failed: {message}")
+            }
+            TransactionError::LimitsExceeded { step, exceeded } => write!(
+                f,
+                "step {step} exceeded the transaction resource limits: {}",
+                exceeded.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TransactionError {}
+
+#[derive(Default)]
+struct State {
+    running: bool,
+    steps: usize,
+    failure: Option<TransactionError>,
+    resources: InvocationResources,
+    footprint: BTreeMap<xdr::LedgerKey, Entry>,
+    /// The most ledger entries, disk reads and writes of any one step, that
+    /// the footprint may miss entries of.
+    step_max: FootprintResources,
+}
+
+/// An entry in the footprint of the transaction.
+struct Entry {
+    entry: FootprintEntry,
+    write: bool,
+    /// Whether the entry is read from disk, because it is not contract data or
+    /// code, or was archived and is restored by the first step accessing it.
+    disk_read: bool,
+}
+
+/// The resources that are counted from the footprint of the transaction.
+#[derive(Clone, Copy, Default)]
+struct FootprintResources {
+    ledger_entries: u32,
+    disk_read_entries: u32,
+    disk_read_bytes: u32,
+    write_entries: u32,
+    write_bytes: u32,
+}
+
+impl FootprintResources {
+    fn of_footprint(footprint: &BTreeMap<xdr::LedgerKey, Entry>) -> Self {
+        let disk_reads = footprint.values().filter(|e| e.disk_read);
+        // Entries written by several steps are written once, with their size
+        // after the last step.
+        let writes = footprint.values().filter(|e| e.write);
+        FootprintResources {
+            ledger_entries: footprint.len() as u32,
+            disk_read_entries: disk_reads.clone().count() as u32,
+            disk_read_bytes: disk_reads
+                .map(|e| e.entry.old_size_bytes)
+                .fold(0, u32::saturating_add),
+            write_entries: writes.clone().count() as u32,
+            write_bytes: writes
+                .map(|e| e.entry.new_size_bytes)
+                .fold(0, u32::saturating_add),
+        }
+    }
+
+    fn of_step(r: &InvocationResources) -> Self {
+        FootprintResources {
+            ledger_entries: r
+                .disk_read_entries
+                .saturating_add(r.memory_read_entries)
+                .saturating_add(r.write_entries),
+            disk_read_entries: r.disk_read_entries,
+            disk_read_bytes: r.disk_read_bytes,
+            write_entries: r.write_entries,
+            write_bytes: r.write_bytes,
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        FootprintResources {
+            ledger_entries: self.ledger_entries.max(other.ledger_entries),
+            disk_read_entries: self.disk_read_entries.max(other.disk_read_entries),
+            disk_read_bytes: self.disk_read_bytes.max(other.disk_read_bytes),
+            write_entries: self.write_entries.max(other.write_entries),
+            write_bytes: self.write_bytes.max(other.write_bytes),
+        }
+    }
+}
+
+/// A simulated transaction made of several host function invocations.
+///
+/// Returned by [`Env::transaction`]. See the [module docs][self] for an
+/// example.
+pub struct Transaction {
+    env: Env,
+    limits: Option<InvocationResourceLimits>,
+    state: RefCell<State>,
+}
+
+impl fmt::Debug for Transaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct("Transaction")
+            .field("limits", &self.limits)
+            .field("steps", &state.steps)
+            .field("failure", &state.failure)
+            .finish()
+    }
+}
+
+impl Transaction {
+    pub(crate) fn new(env: &Env) -> Self {
+        Self {
+            env: env.clone(),
+            limits: Some(env.network_config().limits),
+            state: RefCell::new(State::default()),
+        }
+    }
+
+    /// Check the resources of the steps against the limits, instead of the
+    /// limits of the network config set with
+    /// [`CostEstimate::set_network_config`][crate::testutils::cost_estimate::CostEstimate::set_network_config].
+    ///
+    /// Each step is also checked on its own against the limits enforced with
+    /// [`CostEstimate::enforce_resource_limits`][crate::testutils::cost_estimate::CostEstimate::enforce_resource_limits].
+    pub fn limits(self, limits: InvocationResourceLimits) -> Self {
+        Self {
+            limits: Some(limits),
+            ..self
+        }
+    }
+
+    /// Do not check the resources of the steps against any limits.
+    pub fn disable_limits(self) -> Self {
+        Self {
+            limits: None,
+            ..self
+        }
+    }
+
+    /// Run the steps of the transaction.
+    ///
+    /// The function runs the steps with [`Transaction::step`]. If any step
+    /// fails, or the steps exceed the limits, everything the function did is
+    /// rolled back and the failure is returned, even if the function ignores
+    /// the failure. Steps run after a failure are not run and fail with the
+    /// same error.
+    ///
+    /// The state is rolled back the same way as with [`Env::rollback`].
+    pub fn run<T>(
+        &self,
+        f: impl FnOnce(&Self) -> Result<T, TransactionError>,
+    ) -> Result<T, TransactionError> {
+        *self.state.borrow_mut() = State {
+            running: true,
+            ..State::default()
+        };
+        let checkpoint = self.env.checkpoint();
+        let result = f(self);
+        let mut state = self.state.borrow_mut();
+        state.running = false;
+        let result = match state.failure.clone() {
+            Some(e) => Err(e),
+            None => result,
+        };
+        if result.is_err() {
+            self.env.rollback(&checkpoint);
+        }
+        result
+    }
+
+    /// Run a step of the transaction.
+    ///
+    /// The function is expected to make one top level invocation, whose
+    /// resources and footprint are added to the transaction's. A step that
+    /// makes no invocation adds nothing.
+    ///
+    /// ### Panics
+    ///
+    /// If called outside of [`Transaction::run`].
+    pub fn step<T>(&self, f: impl FnOnce(&Env) -> T) -> Result<T, TransactionError> {
+        let step = {
+            let mut state = self.state.borrow_mut();
+            if !state.running {
+                panic!("transaction steps can only be run inside Transaction::run");
+            }
+            if let Some(e) = &state.failure {
+                return Err(e.clone());
+            }
+            state.steps += 1;
+            state.steps - 1
+        };
+        let before = LedgerState::capture(&self.env);
+        let invocations = self.env.invocation_count();
+        let last_resources = self.env.host().get_detailed_last_invocation_resources();
+        let t = catch_unwind(AssertUnwindSafe(|| f(&self.env))).map_err(|payload| {
+            TransactionError::StepFailed {
+                step,
+                message: payload
+                    .downcast_ref::<&str>()
+                    .map(|s| std::string::String::from(*s))
+                    .or_else(|| payload.downcast_ref::<std::string::String>().cloned())
+                    .unwrap_or_default(),
+            }
+        });
+        let mut state = self.state.borrow_mut();
+        let result = t.and_then(|t| {
+            // The resources of the last invocation are stale if the step made
+            // no invocation. Wasm uploads are not counted as invocations,
+            // since they call no contract, so are told apart by their
+            // resources.
+            let invoked = self.env.invocation_count() != invocations
+                || self.env.host().get_detailed_last_invocation_resources() != last_resources;
+            if let Some(resources) = self
+                .env
+                .host()
+                .get_last_invocation_resources()
+                .filter(|_| invoked)
+            {
+                state.resources = add(&state.resources, &resources);
+                state.step_max = state.step_max.max(FootprintResources::of_step(&resources));
+            }
+            if let Some(before) = before {
+                let footprint = self.env.cost_estimate().footprint_since(&before, invoked);
+                merge(
+                    &mut state.footprint,
+                    footprint,
+                    self.env.ledger().sequence(),
+                );
+            }
+            let footprint = FootprintResources::of_footprint(&state.footprint).max(state.step_max);
+            let exceeded = self
+                .limits
+                .as_ref()
+                .map(|limits| exceeded(&state.resources, &footprint, limits))
+                .unwrap_or_default();
+            if exceeded.is_empty() {
+                Ok(t)
+            } else {
+                Err(TransactionError::LimitsExceeded { step, exceeded })
+            }
+        });
+        if let Err(e) = &result {
+            state.failure = Some(e.clone());
+        }
+        result
+    }
+
+    /// Returns the resources of the steps run, added up.
+    ///
+    /// The ledger entries, disk reads and writes are added up too, so an entry
+    /// accessed by several steps is counted once for each step. The limits
+    /// are checked against the entries of the [footprint][Self::footprint]
+    /// instead.
+    pub fn resources(&self) -> InvocationResources {
+        self.state.borrow().resources.clone()
+    }
+
+    /// Returns the footprint of the steps run.
+    ///
+    /// Entries that any step writes are read-write, and the other entries are
+    /// read-only. The sizes and TTLs of an entry are from before the first
+    /// step that accesses it and after the last, and its rent fee is the rent
+    /// fees of the steps added up.
+    ///
+    /// See [`CostEstimate::footprint`][crate::testutils::cost_estimate::CostEstimate::footprint]
+    /// for how the footprint of each step is observed.
+    pub fn footprint(&self) -> Footprint {
+        let mut footprint = Footprint::default();
+        for e in self.state.borrow().footprint.values() {
+            if e.write {
+                footprint.read_write.push(e.entry.clone());
+            } else {
+                footprint.read_only.push(e.entry.clone());
+            }
+        }
+        footprint
+    }
+}
+
+fn merge(entries: &mut BTreeMap<xdr::LedgerKey, Entry>, footprint: Footprint, ledger: u32) {
+    let read_only = footprint.read_only.into_iter().map(|e| (e, false));
+    for (entry, write) in read_only.chain(footprint.read_write.into_iter().map(|e| (e, true))) {
+        match entries.get_mut(&entry.key) {
+            Some(e) => {
+                e.entry.new_size_bytes = entry.new_size_bytes;
+                e.entry.new_live_until_ledger = entry.new_live_until_ledger;
+                e.entry.rent_fee = e.entry.rent_fee.saturating_add(entry.rent_fee);
+                e.write |= write;
+            }
+            None => {
+                let disk_read = is_disk_read(&entry, ledger);
+                entries.insert(
+                    entry.key.clone(),
+                    Entry {
+                        entry,
+                        write,
+                        disk_read,
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns whether the entry is read from disk, the same way as the host
+/// decides.
+fn is_disk_read(entry: &FootprintEntry, ledger: u32) -> bool {
+    match &entry.key {
+        xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+            durability: xdr::ContractDataDurability::Temporary,
+            ..
+        }) => false,
+        xdr::LedgerKey::ContractData(_) | xdr::LedgerKey::ContractCode(_) => entry
+            .old_live_until_ledger
+            .is_some_and(|live_until| live_until < ledger),
+        _ => true,
+    }
+}
+
+fn add(a: &InvocationResources, b: &InvocationResources) -> InvocationResources {
+    InvocationResources {
+        instructions: a.instructions.saturating_add(b.instructions),
+        mem_bytes: a.mem_bytes.saturating_add(b.mem_bytes),
+        disk_read_entries: a.disk_read_entries.saturating_add(b.disk_read_entries),
+        memory_read_entries: a.memory_read_entries.saturating_add(b.memory_read_entries),
+        write_entries: a.write_entries.saturating_add(b.write_entries),
+        disk_read_bytes: a.disk_read_bytes.saturating_add(b.disk_read_bytes),
+        write_bytes: a.write_bytes.saturating_add(b.write_bytes),
+        contract_events_size_bytes: a
+            .contract_events_size_bytes
+            .saturating_add(b.contract_events_size_bytes),
+        persistent_rent_ledger_bytes: a
+            .persistent_rent_ledger_bytes
+            .saturating_add(b.persistent_rent_ledger_bytes),
+        persistent_entry_rent_bumps: a
+            .persistent_entry_rent_bumps
+            .saturating_add(b.persistent_entry_rent_bumps),
+        temporary_rent_ledger_bytes: a
+            .temporary_rent_ledger_bytes
+            .saturating_add(b.temporary_rent_ledger_bytes),
+        temporary_entry_rent_bumps: a
+            .temporary_entry_rent_bumps
+            .saturating_add(b.temporary_entry_rent_bumps),
+    }
+}
+
+/// Returns the limits the resources exceed, described the same way as the
+/// host describes the limits an invocation exceeds.
+fn exceeded(
+    r: &InvocationResources,
+    footprint: &FootprintResources,
+    limits: &InvocationResourceLimits,
+) -> std::vec::Vec<std::string::String> {
+    let checks: [(&str, i64, i64); 8] = [
+        ("instructions", r.instructions, limits.instructions),
+        ("memory bytes", r.mem_bytes, limits.mem_bytes),
+        (
+            "total footprint ledger entries",
+            footprint.ledger_entries.into(),
+            limits.ledger_entries.into(),
+        ),
+        (
+            "disk read ledger entries",
+            footprint.disk_read_entries.into(),
+            limits.disk_read_entries.into(),
+        ),
+        (
+            "disk read bytes",
+            footprint.disk_read_bytes.into(),
+            limits.disk_read_bytes.into(),
+        ),
+        (
+            "write ledger entries",
+            footprint.write_entries.into(),
+            limits.write_entries.into(),
+        ),
+        (
+            "write bytes",
+            footprint.write_bytes.into(),
+            limits.write_bytes.into(),
+        ),
+        (
+            "contract events size bytes",
+            r.contract_events_size_bytes.into(),
+            limits.contract_events_size_bytes.into(),
+        ),
+    ];
+    checks
+        .into_iter()
+        .filter(|(_, used, limit)| used > limit)
+        .map(|(name, used, limit)| format!("{name}: {used} > {limit}"))
+        .collect()
+}
@@ -4,6 +4,7 @@ fn compile_fails() {
     t.compile_fail("tests/compile_fails/contractevent_sparse_arg_errors.rs");
     t.compile_fail("tests/compile_fails/contracttrait_cfg_errors.rs");
     t.compile_fail("tests/compile_fails/contracttrait_without_trait.rs");
+    t.compile_fail("tests/compile_fails/contracttype_arbitrary_attr_errors.rs");
     t.compile_fail("tests/compile_fails/contracttype_lib_removed.rs");
     t.compile_fail("tests/compile_fails/export_arg_errors.rs");
 }
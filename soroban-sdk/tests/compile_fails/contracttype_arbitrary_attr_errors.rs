@@ -0,0 +1,17 @@
+// The `arbitrary` attribute on the fields of a contract type accepts one of
+// `range`, `len` or `with`.
+use soroban_sdk::{contracttype, Vec};
+
+#[contracttype]
+pub struct Unknown {
+    #[arbitrary(max = 10)]
+    pub a: u32,
+}
+
+#[contracttype]
+pub struct Conflicting {
+    #[arbitrary(range = 0..=10, len = 1..=2)]
+    pub a: Vec<u32>,
+}
+
+fn main() {}
@@ -61,6 +61,35 @@ impl soroban_sdk::SpecShakingMarker for Error {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __Error__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __Error__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_account",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x05Error\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x04Fail\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for Error {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -180,6 +209,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -436,6 +474,11 @@ fn __Contract__CustomAccountInterface__d465b6861ce11142d9f64c1622e1ad88ae003d910
             #[allow(deprecated)]
             &__Contract____check_auth__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_account",
+            &const { <Contract>::spec_xdr___check_auth() },
+        );
     }
 }
 mod test {
@@ -461,6 +504,16 @@ mod test {
         #[doc(hidden)]
         allow_non_root_auth: bool,
     }
+    impl<'a> soroban_sdk::testutils::ContractClient for TestContractClient<'a> {
+        fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+            Self::new(env, address)
+        }
+        fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+            static SPEC: soroban_sdk::testutils::ClientSpec =
+                soroban_sdk::testutils::ClientSpec::new();
+            &SPEC
+        }
+    }
     impl<'a> TestContractClient<'a> {
         pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
             Self {
@@ -21,6 +21,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -244,6 +253,35 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__7e9e5ac30f2216fd0fd6f5faed316f2d5983361a4203c3330cfa46ef65bb4767_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__7e9e5ac30f2216fd0fd6f5faed316f2d5983361a4203c3330cfa46ef65bb4767_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x03add\0\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\n\0\0\0\0\0\0\0\x01b\0\0\0\0\0\0\n\0\0\0\x01\0\0\0\n",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -335,6 +373,11 @@ fn __Contract____7e9e5ac30f2216fd0fd6f5faed316f2d5983361a4203c3330cfa46ef65bb476
             #[allow(deprecated)]
             &__Contract__add__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_add_u128",
+            &const { <Contract>::spec_xdr_add() },
+        );
     }
 }
 mod test {
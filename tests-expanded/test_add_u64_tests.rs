@@ -21,6 +21,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -166,6 +175,35 @@ impl soroban_sdk::SpecShakingMarker for Error {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __Error__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __Error__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_add_u64",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x05Error\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x08Overflow\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for Error {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -299,6 +337,35 @@ impl soroban_sdk::SpecShakingMarker for MyError {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __MyError__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __MyError__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_add_u64",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x07MyError\0\0\0\0\x01\0\0\0\0\0\0\0\x08Overflow\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for MyError {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -710,6 +777,43 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__6ecf4b81a1826b186b96027a980a40b74ef0e4056b0b7fa44cfd522125765f33_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__6ecf4b81a1826b186b96027a980a40b74ef0e4056b0b7fa44cfd522125765f33_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x03add\0\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01b\0\0\0\0\0\0\x06\0\0\0\x01\0\0\0\x06",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08safe_add\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01b\0\0\0\0\0\0\x06\0\0\0\x01\0\0\x03\xe9\0\0\0\x06\0\0\0\x03",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0csafe_add_two\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01b\0\0\0\0\0\0\x06\0\0\0\x01\0\0\x03\xe9\0\0\0\x06\0\0\x07\xd0\0\0\0\x07MyError\0",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -923,16 +1027,31 @@ fn __Contract____6ecf4b81a1826b186b96027a980a40b74ef0e4056b0b7fa44cfd522125765f3
             #[allow(deprecated)]
             &__Contract__add__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_add_u64",
+            &const { <Contract>::spec_xdr_add() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "safe_add",
             #[allow(deprecated)]
             &__Contract__safe_add__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_add_u64",
+            &const { <Contract>::spec_xdr_safe_add() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "safe_add_two",
             #[allow(deprecated)]
             &__Contract__safe_add_two__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_add_u64",
+            &const { <Contract>::spec_xdr_safe_add_two() },
+        );
     }
 }
 mod test {
@@ -33,6 +33,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -956,6 +965,61 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__43ee96751c899ff0e1a286858793b710aeacd1f5d72c118904d14312012a0923_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__43ee96751c899ff0e1a286858793b710aeacd1f5d72c118904d14312012a0923_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x07set_val\0\0\0\0\x01\0\0\0\0\0\0\0\x05input\0\0\0\0\0\0\x06\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x07get_val\0\0\0\0\0\0\0\0\x01\0\0\0\x06");
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x04both\0\0\0\x01\0\0\0\0\0\0\0\x05input\0\0\0\0\0\0\x06\0\0\0\x01\0\0\0\x06",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x07wrapped\0\0\0\0\x01\0\0\0\0\0\0\0\x05input\0\0\0\0\0\x03\xea\0\0\0\x06\0\0\0\x01\0\0\x03\xe9\0\0\0\x06\0\0\0\x03",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0edouble_wrapped\0\0\0\0\0\x01\0\0\0\0\0\0\0\x05input\0\0\0\0\0\x03\xe8\0\0\x03\xea\0\0\0\x06\0\0\0\x01\0\0\x03\xe9\0\0\x03\xea\0\0\0\x06\0\0\0\x03",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x06valval\0\0\0\0\0\x01\0\0\0\0\0\0\0\x05input\0\0\0\0\0\0\x06\0\0\0\x01\0\0\x03\xe8\0\0\0\x06",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x05tuple\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x05input\0\0\0\0\0\0\x06\0\0\0\x01\0\0\x03\xed\0\0\0\x02\0\0\0\x06\0\0\0\x06",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x06valref\0\0\0\0\0\x01\0\0\0\0\0\0\0\x05input\0\0\0\0\0\0\x06\0\0\0\x01\0\0\0\x06",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -1398,41 +1462,81 @@ fn __Contract__AssociatedType__43ee96751c899ff0e1a286858793b710aeacd1f5d72c11890
             #[allow(deprecated)]
             &__Contract__set_val__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_type_chained",
+            &const { <Contract>::spec_xdr_set_val() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "get_val",
             #[allow(deprecated)]
             &__Contract__get_val__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_type_chained",
+            &const { <Contract>::spec_xdr_get_val() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "both",
             #[allow(deprecated)]
             &__Contract__both__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_type_chained",
+            &const { <Contract>::spec_xdr_both() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "wrapped",
             #[allow(deprecated)]
             &__Contract__wrapped__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_type_chained",
+            &const { <Contract>::spec_xdr_wrapped() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "double_wrapped",
             #[allow(deprecated)]
             &__Contract__double_wrapped__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_type_chained",
+            &const { <Contract>::spec_xdr_double_wrapped() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "valval",
             #[allow(deprecated)]
             &__Contract__valval__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_type_chained",
+            &const { <Contract>::spec_xdr_valval() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "tuple",
             #[allow(deprecated)]
             &__Contract__tuple__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_type_chained",
+            &const { <Contract>::spec_xdr_tuple() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "valref",
             #[allow(deprecated)]
             &__Contract__valref__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_type_chained",
+            &const { <Contract>::spec_xdr_valref() },
+        );
     }
 }
 mod test {
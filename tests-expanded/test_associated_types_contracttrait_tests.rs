@@ -21,6 +21,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -159,6 +168,15 @@ pub struct TraitClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for TraitClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> TraitClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -323,6 +341,33 @@ impl<'a> TraitClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __TraitClient__2706c619fe73f0cf112473c6ee02e66c04e1c01c110b0c37b88d8eb509630c9f_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __TraitClient__2706c619fe73f0cf112473c6ee02e66c04e1c01c110b0c37b88d8eb509630c9f_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <TraitClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x04exec\0\0\0\0\0\0\0\x01\0\0\0\x10");
+    }
+}
 ///TraitArgs is a type for building arg lists for functions defined in "Trait".
 pub struct TraitArgs;
 impl TraitArgs {
@@ -483,6 +528,33 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__2706c619fe73f0cf112473c6ee02e66c04e1c01c110b0c37b88d8eb509630c9f_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__2706c619fe73f0cf112473c6ee02e66c04e1c01c110b0c37b88d8eb509630c9f_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x04exec\0\0\0\0\0\0\0\x01\0\0\0\x10");
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -518,6 +590,11 @@ fn __Contract__Trait__2706c619fe73f0cf112473c6ee02e66c04e1c01c110b0c37b88d8eb509
             #[allow(deprecated)]
             &__Contract__exec__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_types_contracttrait",
+            &const { <Contract>::spec_xdr_exec() },
+        );
     }
 }
 #[doc(hidden)]
@@ -660,6 +737,33 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__b85cb430838ad2be2940af63cd34aab7c962ea8738ef61b7759fbac5a916bc1c_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__b85cb430838ad2be2940af63cd34aab7c962ea8738ef61b7759fbac5a916bc1c_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x05exec2\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x10");
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -732,6 +836,11 @@ fn __Contract__TraitWithoutContractTrait__b85cb430838ad2be2940af63cd34aab7c962ea
             #[allow(deprecated)]
             &__Contract__exec2__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_associated_types_contracttrait",
+            &const { <Contract>::spec_xdr_exec2() },
+        );
     }
 }
 mod test {
@@ -813,6 +922,16 @@ mod test_with_wasm {
             #[doc(hidden)]
             allow_non_root_auth: bool,
         }
+        impl<'a> soroban_sdk::testutils::ContractClient for Client<'a> {
+            fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+                Self::new(env, address)
+            }
+            fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+                static SPEC: soroban_sdk::testutils::ClientSpec =
+                    soroban_sdk::testutils::ClientSpec::new();
+                &SPEC
+            }
+        }
         impl<'a> Client<'a> {
             pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
                 Self {
@@ -1071,6 +1190,35 @@ mod test_with_wasm {
                 res
             }
         }
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        #[allow(unused)]
+        fn __Client__63842da933ab3611b9860480a0bd8b7c0de39e8f6f301c9ed52dd54191e8c014_spec_ctor() {
+            #[allow(unsafe_code)]
+            {
+                #[link_section = ".init_array"]
+                #[used]
+                #[allow(non_upper_case_globals, non_snake_case)]
+                #[doc(hidden)]
+                static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                    #[link_section = ".text.startup"]
+                    #[allow(non_snake_case)]
+                    extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                        unsafe {
+                            __Client__63842da933ab3611b9860480a0bd8b7c0de39e8f6f301c9ed52dd54191e8c014_spec_ctor();
+                        };
+                        core::default::Default::default()
+                    }
+                    f
+                };
+            }
+            {
+                <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                    .register(b"\0\0\0\0\0\0\0\0\0\0\0\x04exec\0\0\0\0\0\0\0\x01\0\0\0\x10");
+                <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                    .register(b"\0\0\0\0\0\0\0\0\0\0\0\x05exec2\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x10");
+            }
+        }
         ///Args is a type for building arg lists for functions defined in "Contract".
         pub struct Args;
         impl Args {
@@ -1557,6 +1705,45 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutableRef {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::String as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryContractExecutableRef {
+                                owner: field_0,
+                                tag: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct ContractContext {
             pub args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -2120,6 +2307,59 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Vec<
+                                            soroban_sdk::Val,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <soroban_sdk::Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryContractContext {
+                                args: field_0,
+                                contract: field_1,
+                                fn_name: field_2,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct SubContractInvocation {
             pub context: ContractContext,
@@ -2611,6 +2851,47 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitrarySubContractInvocation {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Vec<
+                                                InvokerContractAuthEntry,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitrarySubContractInvocation {
+                                context: field_0,
+                                sub_invocations: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct CreateContractHostFnContext {
             pub executable: ContractExecutable,
@@ -3101,6 +3382,47 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryCreateContractHostFnContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::BytesN<
+                                                32,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryCreateContractHostFnContext {
+                                executable: field_0,
+                                salt: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct CreateContractWithConstructorHostFnContext {
             pub constructor_args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -3713,6 +4035,63 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy
+                for ArbitraryCreateContractWithConstructorHostFnContext
+            {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Vec<
+                                            soroban_sdk::Val,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <soroban_sdk::BytesN<
+                                                    32,
+                                                > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryCreateContractWithConstructorHostFnContext {
+                                constructor_args: field_0,
+                                executable: field_1,
+                                salt: field_2,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum ContractExecutable {
             Wasm(soroban_sdk::BytesN<32>),
@@ -4331,6 +4710,60 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutable {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <soroban_sdk::BytesN<
+                                                            32,
+                                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContractExecutable::Wasm(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <ContractExecutableRef as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContractExecutable::ExternalRef(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum Context {
             Contract(ContractContext),
@@ -5063,6 +5496,77 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::Contract(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::CreateContractHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::CreateContractWithCtorHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum InvokerContractAuthEntry {
             Contract(SubContractInvocation),
@@ -5844,6 +6348,79 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryInvokerContractAuthEntry {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <SubContractInvocation as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::Contract(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractWithCtorHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum Executable {
             Wasm(soroban_sdk::BytesN<32>),
@@ -6427,6 +7004,53 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryExecutable {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <soroban_sdk::BytesN<
+                                                            32,
+                                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryExecutable::Wasm(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            |()| ArbitraryExecutable::StellarAsset,
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            |()| ArbitraryExecutable::Account,
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
     }
     extern crate test;
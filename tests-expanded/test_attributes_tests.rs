@@ -381,6 +381,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryAttributeType {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryAttributeType { value: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct AttributeEvent {
     topic: u32,
@@ -424,6 +447,30 @@ impl AttributeEvent {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for AttributeEvent {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["attribute_event"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["value"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            topic: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            value: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub struct Contract;
 ///ContractArgs is a type for building arg lists for functions defined in "Contract".
 pub struct ContractArgs;
@@ -440,6 +487,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -594,6 +650,15 @@ pub struct AttributeTraitClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for AttributeTraitClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> AttributeTraitClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -1173,6 +1238,52 @@ impl<'a> AttributeTraitClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __AttributeTraitClient__6b37338f3334793661a157573f91046168ff8e5c0899abf1b9b102765941ac50_spec_ctor(
+) {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __AttributeTraitClient__6b37338f3334793661a157573f91046168ff8e5c0899abf1b9b102765941ac50_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <AttributeTraitClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x0etrait_override\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        <AttributeTraitClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\rtrait_default\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        <AttributeTraitClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x19trait_default_stacked_cfg\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+            );
+        <AttributeTraitClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x1atrait_override_stacked_cfg\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+        <AttributeTraitClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x1atrait_override_negated_cfg\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+        <AttributeTraitClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x17trait_override_dual_cfg\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+        <AttributeTraitClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x16trait_default_dual_cfg\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+    }
+}
 ///AttributeTraitArgs is a type for building arg lists for functions defined in "AttributeTrait".
 pub struct AttributeTraitArgs;
 impl AttributeTraitArgs {
@@ -1549,6 +1660,43 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__2246acd8c5a7d7a27c1bbb827466a98b2233215f0f3fdd39122db001505b94ef_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__2246acd8c5a7d7a27c1bbb827466a98b2233215f0f3fdd39122db001505b94ef_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x06always\0\0\0\0\0\x01\0\0\0\0\0\0\0\x05value\0\0\0\0\0\x07\xd0\0\0\0\rAttributeType\0\0\0\0\0\0\x01\0\0\0\x04",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0ccfg_included\0\0\0\x01\0\0\0\0\0\0\0\x05value\0\0\0\0\0\0\x04\0\0\0\x01\0\0\0\x04",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x07publish\0\0\0\0\x02\0\0\0\0\0\0\0\x05topic\0\0\0\0\0\0\x04\0\0\0\0\0\0\0\x05value\0\0\0\0\0\0\x04\0\0\0\0",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -1747,16 +1895,31 @@ fn __Contract____2246acd8c5a7d7a27c1bbb827466a98b2233215f0f3fdd39122db001505b94e
             #[allow(deprecated)]
             &__Contract__always__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_always() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "cfg_included",
             #[allow(deprecated)]
             &__Contract__cfg_included__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_cfg_included() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "publish",
             #[allow(deprecated)]
             &__Contract__publish__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_publish() },
+        );
     }
 }
 impl AttributeTrait for Contract {
@@ -2124,6 +2287,42 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__8904c8f92c2fe5f69c478861e0735de7c22bffee3517a491a4450e32ee923b8f_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__8904c8f92c2fe5f69c478861e0735de7c22bffee3517a491a4450e32ee923b8f_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x0etrait_override\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x1atrait_override_stacked_cfg\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x1atrait_override_negated_cfg\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x17trait_override_dual_cfg\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -2692,6 +2891,40 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__8ce1beb22d1cf3a30219e745dd54ab4127700ae1863a7e431a8fddbdbf3f961c_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__8ce1beb22d1cf3a30219e745dd54ab4127700ae1863a7e431a8fddbdbf3f961c_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\rtrait_default\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x19trait_default_stacked_cfg\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x16trait_default_dual_cfg\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -2738,16 +2971,31 @@ fn __Contract__AttributeTrait__8ce1beb22d1cf3a30219e745dd54ab4127700ae1863a7e431
             #[allow(deprecated)]
             &__Contract__trait_default__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_trait_default() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "trait_default_stacked_cfg",
             #[allow(deprecated)]
             &__Contract__trait_default_stacked_cfg__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_trait_default_stacked_cfg() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "trait_default_dual_cfg",
             #[allow(deprecated)]
             &__Contract__trait_default_dual_cfg__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_trait_default_dual_cfg() },
+        );
     }
 }
 #[doc(hidden)]
@@ -2779,21 +3027,41 @@ fn __Contract__AttributeTrait__8904c8f92c2fe5f69c478861e0735de7c22bffee3517a491a
             #[allow(deprecated)]
             &__Contract__trait_override__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_trait_override() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "trait_override_stacked_cfg",
             #[allow(deprecated)]
             &__Contract__trait_override_stacked_cfg__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_trait_override_stacked_cfg() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "trait_override_negated_cfg",
             #[allow(deprecated)]
             &__Contract__trait_override_negated_cfg__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_trait_override_negated_cfg() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "trait_override_dual_cfg",
             #[allow(deprecated)]
             &__Contract__trait_override_dual_cfg__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_attributes",
+            &const { <Contract>::spec_xdr_trait_override_dual_cfg() },
+        );
     }
 }
 mod test {
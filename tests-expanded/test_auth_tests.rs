@@ -21,6 +21,15 @@ pub struct ContractAClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractAClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractAClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -238,6 +247,35 @@ impl<'a> ContractAClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractAClient__7c3764b58a7ababbe8a6b452f6a400d8ae3704b80f8c5ea1b251eebbc8698020_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractAClient__7c3764b58a7ababbe8a6b452f6a400d8ae3704b80f8c5ea1b251eebbc8698020_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractAClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x03fn1\0\0\0\0\x01\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\x13\0\0\0\x01\0\0\0\x06",
+            );
+    }
+}
 impl ContractAArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -319,6 +357,11 @@ fn __ContractA____7c3764b58a7ababbe8a6b452f6a400d8ae3704b80f8c5ea1b251eebbc86980
             #[allow(deprecated)]
             &__ContractA__fn1__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractA>(),
+            "test_auth",
+            &const { <ContractA>::spec_xdr_fn1() },
+        );
     }
 }
 mod test_a {
@@ -676,6 +719,16 @@ mod test_a {
             #[doc(hidden)]
             allow_non_root_auth: bool,
         }
+        impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+            fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+                Self::new(env, address)
+            }
+            fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+                static SPEC: soroban_sdk::testutils::ClientSpec =
+                    soroban_sdk::testutils::ClientSpec::new();
+                &SPEC
+            }
+        }
         impl<'a> ContractClient<'a> {
             pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
                 Self {
@@ -927,6 +980,11 @@ mod test_a {
                     #[allow(deprecated)]
                     &__Contract____check_auth__invoke_raw_slice,
                 );
+                soroban_sdk::testutils::coverage::register_function_spec(
+                    core::any::type_name::<Contract>(),
+                    "test_auth::test_a::auth_approve",
+                    &const { <Contract>::spec_xdr___check_auth() },
+                );
             }
         }
     }
@@ -948,6 +1006,16 @@ mod test_a {
             #[doc(hidden)]
             allow_non_root_auth: bool,
         }
+        impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+            fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+                Self::new(env, address)
+            }
+            fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+                static SPEC: soroban_sdk::testutils::ClientSpec =
+                    soroban_sdk::testutils::ClientSpec::new();
+                &SPEC
+            }
+        }
         impl<'a> ContractClient<'a> {
             pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
                 Self {
@@ -1130,6 +1198,35 @@ mod test_a {
             #[inline(always)]
             fn spec_shaking_marker() {}
         }
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        #[allow(unused)]
+        fn __Error__error_spec_ctor() {
+            #[allow(unsafe_code)]
+            {
+                #[link_section = ".init_array"]
+                #[used]
+                #[allow(non_upper_case_globals, non_snake_case)]
+                #[doc(hidden)]
+                static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                    #[link_section = ".text.startup"]
+                    #[allow(non_snake_case)]
+                    extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                        unsafe {
+                            __Error__error_spec_ctor();
+                        };
+                        core::default::Default::default()
+                    }
+                    f
+                };
+            }
+            {
+                soroban_sdk::testutils::coverage::register_error_spec(
+                    "test_auth::test_a::auth_decline",
+                    b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x05Error\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x07Decline\0\0\0\0\x01",
+                );
+            }
+        }
         impl TryFrom<soroban_sdk::Error> for Error {
             type Error = soroban_sdk::Error;
             #[inline(always)]
@@ -1371,6 +1468,11 @@ mod test_a {
                     #[allow(deprecated)]
                     &__Contract____check_auth__invoke_raw_slice,
                 );
+                soroban_sdk::testutils::coverage::register_function_spec(
+                    core::any::type_name::<Contract>(),
+                    "test_auth::test_a::auth_decline",
+                    &const { <Contract>::spec_xdr___check_auth() },
+                );
             }
         }
     }
@@ -1391,6 +1493,15 @@ pub struct ContractBClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractBClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractBClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -1616,6 +1727,35 @@ impl<'a> ContractBClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractBClient__389cfcb1cb10680376b4cd5cf632e6b11c3e59494c10e1d42514faf6c4c21b84_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractBClient__389cfcb1cb10680376b4cd5cf632e6b11c3e59494c10e1d42514faf6c4c21b84_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractBClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x03fn2\0\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\x13\0\0\0\0\0\0\0\x03sub\0\0\0\0\x13\0\0\0\x01\0\0\0\x06",
+            );
+    }
+}
 impl ContractBArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -1708,6 +1848,11 @@ fn __ContractB____389cfcb1cb10680376b4cd5cf632e6b11c3e59494c10e1d42514faf6c4c21b
             #[allow(deprecated)]
             &__ContractB__fn2__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractB>(),
+            "test_auth",
+            &const { <ContractB>::spec_xdr_fn2() },
+        );
     }
 }
 mod test_b {
@@ -2160,6 +2305,16 @@ mod test_b {
             #[doc(hidden)]
             allow_non_root_auth: bool,
         }
+        impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+            fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+                Self::new(env, address)
+            }
+            fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+                static SPEC: soroban_sdk::testutils::ClientSpec =
+                    soroban_sdk::testutils::ClientSpec::new();
+                &SPEC
+            }
+        }
         impl<'a> ContractClient<'a> {
             pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
                 Self {
@@ -2411,6 +2566,11 @@ mod test_b {
                     #[allow(deprecated)]
                     &__Contract____check_auth__invoke_raw_slice,
                 );
+                soroban_sdk::testutils::coverage::register_function_spec(
+                    core::any::type_name::<Contract>(),
+                    "test_auth::test_b::auth_approve",
+                    &const { <Contract>::spec_xdr___check_auth() },
+                );
             }
         }
     }
@@ -2432,6 +2592,16 @@ mod test_b {
             #[doc(hidden)]
             allow_non_root_auth: bool,
         }
+        impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+            fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+                Self::new(env, address)
+            }
+            fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+                static SPEC: soroban_sdk::testutils::ClientSpec =
+                    soroban_sdk::testutils::ClientSpec::new();
+                &SPEC
+            }
+        }
         impl<'a> ContractClient<'a> {
             pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
                 Self {
@@ -2614,6 +2784,35 @@ mod test_b {
             #[inline(always)]
             fn spec_shaking_marker() {}
         }
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        #[allow(unused)]
+        fn __Error__error_spec_ctor() {
+            #[allow(unsafe_code)]
+            {
+                #[link_section = ".init_array"]
+                #[used]
+                #[allow(non_upper_case_globals, non_snake_case)]
+                #[doc(hidden)]
+                static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                    #[link_section = ".text.startup"]
+                    #[allow(non_snake_case)]
+                    extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                        unsafe {
+                            __Error__error_spec_ctor();
+                        };
+                        core::default::Default::default()
+                    }
+                    f
+                };
+            }
+            {
+                soroban_sdk::testutils::coverage::register_error_spec(
+                    "test_auth::test_b::auth_decline",
+                    b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x05Error\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x07Decline\0\0\0\0\x01",
+                );
+            }
+        }
         impl TryFrom<soroban_sdk::Error> for Error {
             type Error = soroban_sdk::Error;
             #[inline(always)]
@@ -2855,6 +3054,11 @@ mod test_b {
                     #[allow(deprecated)]
                     &__Contract____check_auth__invoke_raw_slice,
                 );
+                soroban_sdk::testutils::coverage::register_function_spec(
+                    core::any::type_name::<Contract>(),
+                    "test_auth::test_b::auth_decline",
+                    &const { <Contract>::spec_xdr___check_auth() },
+                );
             }
         }
     }
@@ -571,6 +571,79 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryDummyProof {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                    (
+                        {
+                            use soroban_sdk::testutils::proptest::{
+                                FieldStrategy as _, FieldStrategyFallback as _,
+                            };
+                            (&soroban_sdk::testutils::proptest::Field::<
+                                <Bls12381Fp as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                        },
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                    <Bls12381Fp2 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <Bls12381G1Affine as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <Bls12381G2Affine as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <Bls12381Fr as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                ),
+                            ),
+                        ),
+                    ),
+                    |(field_0, (field_1, (field_2, (field_3, (field_4, ())))))| ArbitraryDummyProof {
+                        fp: field_0,
+                        fp2: field_1,
+                        g1: field_2,
+                        g2: field_3,
+                        fr: field_4,
+                    },
+                ),
+            )
+            })
+        }
+    }
 };
 #[automatically_derived]
 impl ::core::clone::Clone for DummyProof {
@@ -601,6 +674,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -1148,6 +1230,47 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__9d5f96fce19df1a5d4c4527aba1995b76016454af03e47b778c4fa6ece8e5b9c_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__9d5f96fce19df1a5d4c4527aba1995b76016454af03e47b778c4fa6ece8e5b9c_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x06g1_mul\0\0\0\0\0\x02\0\0\0\0\0\0\0\x01p\0\0\0\0\0\x03\xee\0\0\0`\0\0\0\0\0\0\0\x01s\0\0\0\0\0\0\x0c\0\0\0\x01\0\0\x03\xee\0\0\0`",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x06g2_mul\0\0\0\0\0\x02\0\0\0\0\0\0\0\x01p\0\0\0\0\0\x03\xee\0\0\0\xc0\0\0\0\0\0\0\0\x01s\0\0\0\0\0\0\x0c\0\0\0\x01\0\0\x03\xee\0\0\0\xc0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0cdummy_verify\0\0\0\x01\0\0\0\0\0\0\0\x05proof\0\0\0\0\0\x07\xd0\0\0\0\nDummyProof\0\0\0\0\0\x01\0\0\0\x01",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\nfr_vec_get\0\0\0\0\0\x02\0\0\0\0\0\0\0\x06values\0\0\0\0\x03\xea\0\0\0\x0c\0\0\0\0\0\0\0\x05index\0\0\0\0\0\0\x04\0\0\0\x01\0\0\0\x0c",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -1427,21 +1550,41 @@ fn __Contract____9d5f96fce19df1a5d4c4527aba1995b76016454af03e47b778c4fa6ece8e5b9
             #[allow(deprecated)]
             &__Contract__g1_mul__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_bls",
+            &const { <Contract>::spec_xdr_g1_mul() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "g2_mul",
             #[allow(deprecated)]
             &__Contract__g2_mul__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_bls",
+            &const { <Contract>::spec_xdr_g2_mul() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "dummy_verify",
             #[allow(deprecated)]
             &__Contract__dummy_verify__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_bls",
+            &const { <Contract>::spec_xdr_dummy_verify() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "fr_vec_get",
             #[allow(deprecated)]
             &__Contract__fr_vec_get__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_bls",
+            &const { <Contract>::spec_xdr_fr_vec_get() },
+        );
     }
 }
 mod test {
@@ -21,6 +21,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -688,6 +697,71 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryDataKey {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryDataKey::Persistent(field_0),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryDataKey::Temp(field_0),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryDataKey::Instance(field_0),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 impl Contract {
     pub fn __constructor(env: Env, init_key: u32, init_value: i64) {
@@ -823,6 +897,35 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__24a8e6fc2521286098a0b1f574a234a96b03b852e91f81da9848447a74287c0c_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__24a8e6fc2521286098a0b1f574a234a96b03b852e91f81da9848447a74287c0c_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08get_data\0\0\0\x01\0\0\0\0\0\0\0\x03key\0\0\0\x07\xd0\0\0\0\x07DataKey\0\0\0\0\x01\0\0\x03\xe8\0\0\0\x07",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -969,11 +1072,21 @@ fn __Contract____99dc7227b32e52c8d11ead5dec3dd80bafdad62d74493e7341c782fd8cb1359
             #[allow(deprecated)]
             &__Contract____constructor__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_constructor",
+            &const { <Contract>::spec_xdr___constructor() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "get_data",
             #[allow(deprecated)]
             &__Contract__get_data__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_constructor",
+            &const { <Contract>::spec_xdr_get_data() },
+        );
     }
 }
 extern crate test;
@@ -29,6 +29,16 @@ pub mod traits {
         #[doc(hidden)]
         allow_non_root_auth: bool,
     }
+    impl<'a> soroban_sdk::testutils::ContractClient for CratePathTraitClient<'a> {
+        fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+            Self::new(env, address)
+        }
+        fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+            static SPEC: soroban_sdk::testutils::ClientSpec =
+                soroban_sdk::testutils::ClientSpec::new();
+            &SPEC
+        }
+    }
     impl<'a> CratePathTraitClient<'a> {
         pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
             Self {
@@ -185,6 +195,36 @@ pub mod traits {
             res
         }
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __CratePathTraitClient__1eead55085fa77445f5d7af954169483008ff4874e183c7854e538dc896fb975_spec_ctor(
+    ) {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __CratePathTraitClient__1eead55085fa77445f5d7af954169483008ff4874e183c7854e538dc896fb975_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            <CratePathTraitClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x11crate_path_method\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+            );
+        }
+    }
     ///CratePathTraitArgs is a type for building arg lists for functions defined in "CratePathTrait".
     pub struct CratePathTraitArgs;
     impl CratePathTraitArgs {
@@ -217,6 +257,15 @@ pub struct ContractCratePathClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractCratePathClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractCratePathClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -467,6 +516,36 @@ impl<'a> ContractCratePathClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractCratePathClient__1eead55085fa77445f5d7af954169483008ff4874e183c7854e538dc896fb975_spec_ctor(
+) {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractCratePathClient__1eead55085fa77445f5d7af954169483008ff4874e183c7854e538dc896fb975_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractCratePathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x11crate_path_method\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+            );
+    }
+}
 impl ContractCratePathArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -503,6 +582,11 @@ fn __ContractCratePath__crate_traits_CratePathTrait__1eead55085fa77445f5d7af9541
             #[allow(deprecated)]
             &__ContractCratePath__crate_path_method__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractCratePath>(),
+            "test_contracttrait_path_crate",
+            &const { <ContractCratePath>::spec_xdr_crate_path_method() },
+        );
     }
 }
 #[doc(hidden)]
@@ -25,6 +25,15 @@ pub struct ContractGlobalPathClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractGlobalPathClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractGlobalPathClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -3280,6 +3289,118 @@ impl<'a> ContractGlobalPathClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractGlobalPathClient__959aee9d42336ade92416504111dfbb4e37b0472bbb1e487310c05a170c39d28_spec_ctor(
+) {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractGlobalPathClient__959aee9d42336ade92416504111dfbb4e37b0472bbb1e487310c05a170c39d28_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0-Test u32 values.\nReturns the input unchanged.\0\0\0\0\0\0\x08test_u32\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x04\0\0\0\x01\0\0\0\x04",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\x10Test i32 values.\0\0\0\x08test_i32\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x05\0\0\0\x01\0\0\0\x05",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08test_u64\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x06\0\0\0\x01\0\0\0\x06",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08test_i64\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x07\0\0\0\x01\0\0\0\x07",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_u128\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\n\0\0\0\x01\0\0\0\n",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_i128\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0b\0\0\0\x01\0\0\0\x0b",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_bool\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x01\0\0\0\x01\0\0\0\x01",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0ctest_address\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x13\0\0\0\x01\0\0\0\x13",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ntest_bytes\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0e\0\0\0\x01\0\0\0\x0e",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0ctest_bytes_n\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xee\0\0\0 \0\0\0\x01\0\0\x03\xee\0\0\0 ",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_string\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x10\0\0\0\x01\0\0\0\x10",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_symbol\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x11\0\0\0\x01\0\0\0\x11",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08test_vec\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xea\0\0\0\x04\0\0\0\x01\0\0\x03\xea\0\0\0\x04",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08test_map\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xec\0\0\0\x04\0\0\0\x04\0\0\0\x01\0\0\x03\xec\0\0\0\x04\0\0\0\x04",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\rtest_duration\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\t\0\0\0\x01\0\0\0\t",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_timepoint\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x08\0\0\0\x01\0\0\0\x08",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_i256\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\r\0\0\0\x01\0\0\0\r",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_u256\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0c\0\0\0\x01\0\0\0\x0c",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_env_param\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_struct\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\x08MyStruct\0\0\0\x01\0\0\x07\xd0\0\0\0\x08MyStruct",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_enum_unit\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\nMyEnumUnit\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\nMyEnumUnit\0\0",
+            );
+        <ContractGlobalPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x12test_enum_variants\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\x0eMyEnumVariants\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\x0eMyEnumVariants\0\0",
+            );
+    }
+}
 impl ContractGlobalPathArgs {
     /// Test u32 values.
     /// Returns the input unchanged.
@@ -3424,111 +3545,221 @@ fn __ContractGlobalPath__test_contracttrait_trait_AllTypes__959aee9d42336ade9241
             #[allow(deprecated)]
             &__ContractGlobalPath__test_u32__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_u32() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_i32",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_i32__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_i32() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_u64",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_u64__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_u64() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_i64",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_i64__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_i64() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_u128",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_u128__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_u128() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_i128",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_i128__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_i128() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_bool",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_bool__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_bool() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_address",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_address__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_address() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_bytes",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_bytes__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_bytes() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_bytes_n",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_bytes_n__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_bytes_n() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_string",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_string__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_string() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_symbol",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_symbol__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_symbol() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_vec",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_vec__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_vec() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_map",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_map__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_map() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_duration",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_duration__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_duration() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_timepoint",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_timepoint__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_timepoint() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_i256",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_i256__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_i256() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_u256",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_u256__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_u256() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_env_param",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_env_param__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_env_param() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_struct",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_struct__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_struct() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_enum_unit",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_enum_unit__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_enum_unit() },
+        );
         <ContractGlobalPath as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "test_enum_variants",
             #[allow(deprecated)]
             &__ContractGlobalPath__test_enum_variants__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractGlobalPath>(),
+            "test_contracttrait_path_global",
+            &const { <ContractGlobalPath>::spec_xdr_test_enum_variants() },
+        );
     }
 }
 #[doc(hidden)]
@@ -29,6 +29,16 @@ pub mod traits {
         #[doc(hidden)]
         allow_non_root_auth: bool,
     }
+    impl<'a> soroban_sdk::testutils::ContractClient for RelativePathTraitClient<'a> {
+        fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+            Self::new(env, address)
+        }
+        fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+            static SPEC: soroban_sdk::testutils::ClientSpec =
+                soroban_sdk::testutils::ClientSpec::new();
+            &SPEC
+        }
+    }
     impl<'a> RelativePathTraitClient<'a> {
         pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
             Self {
@@ -185,6 +195,36 @@ pub mod traits {
             res
         }
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __RelativePathTraitClient__4ac15b461ebbdd4a76eab36b99dba87aec581768e17fd8b229f6bd4a5800d478_spec_ctor(
+    ) {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __RelativePathTraitClient__4ac15b461ebbdd4a76eab36b99dba87aec581768e17fd8b229f6bd4a5800d478_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            <RelativePathTraitClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x14relative_path_method\0\0\0\0\0\0\0\x01\0\0\0\x04",
+                );
+        }
+    }
     ///RelativePathTraitArgs is a type for building arg lists for functions defined in "RelativePathTrait".
     pub struct RelativePathTraitArgs;
     impl RelativePathTraitArgs {
@@ -217,6 +257,15 @@ pub struct ContractRelativePathClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractRelativePathClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractRelativePathClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -468,6 +517,36 @@ impl<'a> ContractRelativePathClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractRelativePathClient__4ac15b461ebbdd4a76eab36b99dba87aec581768e17fd8b229f6bd4a5800d478_spec_ctor(
+) {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractRelativePathClient__4ac15b461ebbdd4a76eab36b99dba87aec581768e17fd8b229f6bd4a5800d478_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractRelativePathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x14relative_path_method\0\0\0\0\0\0\0\x01\0\0\0\x04",
+            );
+    }
+}
 impl ContractRelativePathArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -504,6 +583,11 @@ fn __ContractRelativePath__traits_RelativePathTrait__4ac15b461ebbdd4a76eab36b99d
             #[allow(deprecated)]
             &__ContractRelativePath__relative_path_method__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractRelativePath>(),
+            "test_contracttrait_path_relative",
+            &const { <ContractRelativePath>::spec_xdr_relative_path_method() },
+        );
     }
 }
 #[doc(hidden)]
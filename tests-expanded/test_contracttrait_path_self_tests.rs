@@ -27,6 +27,15 @@ pub struct SelfPathTraitClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for SelfPathTraitClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> SelfPathTraitClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -180,6 +189,34 @@ impl<'a> SelfPathTraitClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __SelfPathTraitClient__dc5f37562c93d76ec88b95b8afc4265e99af33ca688eed9a0b515ee0a60c5b8d_spec_ctor(
+) {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __SelfPathTraitClient__dc5f37562c93d76ec88b95b8afc4265e99af33ca688eed9a0b515ee0a60c5b8d_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <SelfPathTraitClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x10self_path_method\0\0\0\0\0\0\0\x01\0\0\0\x04");
+    }
+}
 ///SelfPathTraitArgs is a type for building arg lists for functions defined in "SelfPathTrait".
 pub struct SelfPathTraitArgs;
 impl SelfPathTraitArgs {
@@ -211,6 +248,15 @@ pub struct ContractSelfPathClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractSelfPathClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractSelfPathClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -459,6 +505,34 @@ impl<'a> ContractSelfPathClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractSelfPathClient__dc5f37562c93d76ec88b95b8afc4265e99af33ca688eed9a0b515ee0a60c5b8d_spec_ctor(
+) {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractSelfPathClient__dc5f37562c93d76ec88b95b8afc4265e99af33ca688eed9a0b515ee0a60c5b8d_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractSelfPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x10self_path_method\0\0\0\0\0\0\0\x01\0\0\0\x04");
+    }
+}
 impl ContractSelfPathArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -495,6 +569,11 @@ fn __ContractSelfPath__self_SelfPathTrait__dc5f37562c93d76ec88b95b8afc4265e99af3
             #[allow(deprecated)]
             &__ContractSelfPath__self_path_method__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<ContractSelfPath>(),
+            "test_contracttrait_path_self",
+            &const { <ContractSelfPath>::spec_xdr_self_path_method() },
+        );
     }
 }
 #[doc(hidden)]
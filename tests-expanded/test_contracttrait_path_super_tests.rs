@@ -27,6 +27,15 @@ pub struct SuperPathTraitClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for SuperPathTraitClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> SuperPathTraitClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -180,6 +189,35 @@ impl<'a> SuperPathTraitClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __SuperPathTraitClient__027cbc237c9f13b03e39d11891fd87f1aacbf08c9bcea0bcd8a3a5a7226d3ade_spec_ctor(
+) {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __SuperPathTraitClient__027cbc237c9f13b03e39d11891fd87f1aacbf08c9bcea0bcd8a3a5a7226d3ade_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <SuperPathTraitClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+            b"\0\0\0\0\0\0\0\0\0\0\0\x11super_path_method\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+        );
+    }
+}
 ///SuperPathTraitArgs is a type for building arg lists for functions defined in "SuperPathTrait".
 pub struct SuperPathTraitArgs;
 impl SuperPathTraitArgs {
@@ -213,6 +251,16 @@ pub mod submodule {
         #[doc(hidden)]
         allow_non_root_auth: bool,
     }
+    impl<'a> soroban_sdk::testutils::ContractClient for ContractSuperPathClient<'a> {
+        fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+            Self::new(env, address)
+        }
+        fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+            static SPEC: soroban_sdk::testutils::ClientSpec =
+                soroban_sdk::testutils::ClientSpec::new();
+            &SPEC
+        }
+    }
     impl<'a> ContractSuperPathClient<'a> {
         pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
             Self {
@@ -467,6 +515,36 @@ pub mod submodule {
             res
         }
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __ContractSuperPathClient__027cbc237c9f13b03e39d11891fd87f1aacbf08c9bcea0bcd8a3a5a7226d3ade_spec_ctor(
+    ) {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __ContractSuperPathClient__027cbc237c9f13b03e39d11891fd87f1aacbf08c9bcea0bcd8a3a5a7226d3ade_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            <ContractSuperPathClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x11super_path_method\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+            );
+        }
+    }
     impl ContractSuperPathArgs {
         #[inline(always)]
         #[allow(clippy::unused_unit)]
@@ -503,6 +581,11 @@ pub mod submodule {
                 #[allow(deprecated)]
                 &__ContractSuperPath__super_path_method__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<ContractSuperPath>(),
+                "test_contracttrait_path_super::submodule",
+                &const { <ContractSuperPath>::spec_xdr_super_path_method() },
+            );
         }
     }
     #[doc(hidden)]
@@ -431,6 +431,43 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryMyStruct {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryMyStruct {
+                            a: field_0,
+                            b: field_1,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum MyEnumUnit {
     A = 1,
@@ -760,6 +797,28 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryMyEnumUnit {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryMyEnumUnit::A,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryMyEnumUnit::B,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum MyEnumVariants {
     VarA,
@@ -1340,6 +1399,60 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryMyEnumVariants {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryMyEnumVariants::VarA,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <MyStruct as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryMyEnumVariants::VarB(field_0),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <MyEnumUnit as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryMyEnumVariants::VarC(field_0),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub struct AllTypesSpec;
 /// Macro for `contractimpl`ing the default functions of the trait that are not overridden.
@@ -1429,6 +1542,15 @@ pub struct AllTypesClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for AllTypesClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> AllTypesClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -3224,6 +3346,117 @@ impl<'a> AllTypesClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __AllTypesClient__959aee9d42336ade92416504111dfbb4e37b0472bbb1e487310c05a170c39d28_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __AllTypesClient__959aee9d42336ade92416504111dfbb4e37b0472bbb1e487310c05a170c39d28_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0-Test u32 values.\nReturns the input unchanged.\0\0\0\0\0\0\x08test_u32\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x04\0\0\0\x01\0\0\0\x04",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\x10Test i32 values.\0\0\0\x08test_i32\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x05\0\0\0\x01\0\0\0\x05",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08test_u64\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x06\0\0\0\x01\0\0\0\x06",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08test_i64\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x07\0\0\0\x01\0\0\0\x07",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_u128\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\n\0\0\0\x01\0\0\0\n",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_i128\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0b\0\0\0\x01\0\0\0\x0b",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_bool\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x01\0\0\0\x01\0\0\0\x01",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0ctest_address\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x13\0\0\0\x01\0\0\0\x13",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ntest_bytes\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0e\0\0\0\x01\0\0\0\x0e",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0ctest_bytes_n\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xee\0\0\0 \0\0\0\x01\0\0\x03\xee\0\0\0 ",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_string\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x10\0\0\0\x01\0\0\0\x10",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_symbol\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x11\0\0\0\x01\0\0\0\x11",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08test_vec\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xea\0\0\0\x04\0\0\0\x01\0\0\x03\xea\0\0\0\x04",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08test_map\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xec\0\0\0\x04\0\0\0\x04\0\0\0\x01\0\0\x03\xec\0\0\0\x04\0\0\0\x04",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\rtest_duration\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\t\0\0\0\x01\0\0\0\t",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_timepoint\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x08\0\0\0\x01\0\0\0\x08",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_i256\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\r\0\0\0\x01\0\0\0\r",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\ttest_u256\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0c\0\0\0\x01\0\0\0\x0c",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_env_param\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_struct\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\x08MyStruct\0\0\0\x01\0\0\x07\xd0\0\0\0\x08MyStruct",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_enum_unit\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\nMyEnumUnit\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\nMyEnumUnit\0\0",
+            );
+        <AllTypesClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x12test_enum_variants\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\x0eMyEnumVariants\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\x0eMyEnumVariants\0\0",
+            );
+    }
+}
 ///AllTypesArgs is a type for building arg lists for functions defined in "AllTypes".
 pub struct AllTypesArgs;
 impl AllTypesArgs {
@@ -3497,6 +3730,16 @@ mod test {
         #[doc(hidden)]
         allow_non_root_auth: bool,
     }
+    impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+        fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+            Self::new(env, address)
+        }
+        fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+            static SPEC: soroban_sdk::testutils::ClientSpec =
+                soroban_sdk::testutils::ClientSpec::new();
+            &SPEC
+        }
+    }
     impl<'a> ContractClient<'a> {
         pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
             Self {
@@ -6746,6 +6989,119 @@ mod test {
             res
         }
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __ContractClient__959aee9d42336ade92416504111dfbb4e37b0472bbb1e487310c05a170c39d28_spec_ctor(
+    ) {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __ContractClient__959aee9d42336ade92416504111dfbb4e37b0472bbb1e487310c05a170c39d28_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0-Test u32 values.\nReturns the input unchanged.\0\0\0\0\0\0\x08test_u32\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x04\0\0\0\x01\0\0\0\x04",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\x10Test i32 values.\0\0\0\x08test_i32\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x05\0\0\0\x01\0\0\0\x05",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x08test_u64\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x06\0\0\0\x01\0\0\0\x06",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x08test_i64\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x07\0\0\0\x01\0\0\0\x07",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\ttest_u128\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\n\0\0\0\x01\0\0\0\n",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\ttest_i128\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0b\0\0\0\x01\0\0\0\x0b",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\ttest_bool\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x01\0\0\0\x01\0\0\0\x01",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0ctest_address\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x13\0\0\0\x01\0\0\0\x13",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\ntest_bytes\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0e\0\0\0\x01\0\0\0\x0e",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0ctest_bytes_n\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xee\0\0\0 \0\0\0\x01\0\0\x03\xee\0\0\0 ",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_string\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x10\0\0\0\x01\0\0\0\x10",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_symbol\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x11\0\0\0\x01\0\0\0\x11",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x08test_vec\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xea\0\0\0\x04\0\0\0\x01\0\0\x03\xea\0\0\0\x04",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x08test_map\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xec\0\0\0\x04\0\0\0\x04\0\0\0\x01\0\0\x03\xec\0\0\0\x04\0\0\0\x04",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\rtest_duration\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\t\0\0\0\x01\0\0\0\t",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_timepoint\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x08\0\0\0\x01\0\0\0\x08",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\ttest_i256\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\r\0\0\0\x01\0\0\0\r",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\ttest_u256\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\0\x0c\0\0\0\x01\0\0\0\x0c",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec().register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_env_param\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04",
+            );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0btest_struct\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\x08MyStruct\0\0\0\x01\0\0\x07\xd0\0\0\0\x08MyStruct",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0etest_enum_unit\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\nMyEnumUnit\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\nMyEnumUnit\0\0",
+                );
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x12test_enum_variants\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x07\xd0\0\0\0\x0eMyEnumVariants\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\x0eMyEnumVariants\0\0",
+                );
+        }
+    }
     impl ContractArgs {
         /// Test u32 values.
         /// Returns the input unchanged.
@@ -6890,111 +7246,221 @@ mod test {
                 #[allow(deprecated)]
                 &__Contract__test_u32__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_u32() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_i32",
                 #[allow(deprecated)]
                 &__Contract__test_i32__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_i32() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_u64",
                 #[allow(deprecated)]
                 &__Contract__test_u64__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_u64() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_i64",
                 #[allow(deprecated)]
                 &__Contract__test_i64__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_i64() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_u128",
                 #[allow(deprecated)]
                 &__Contract__test_u128__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_u128() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_i128",
                 #[allow(deprecated)]
                 &__Contract__test_i128__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_i128() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_bool",
                 #[allow(deprecated)]
                 &__Contract__test_bool__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_bool() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_address",
                 #[allow(deprecated)]
                 &__Contract__test_address__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_address() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_bytes",
                 #[allow(deprecated)]
                 &__Contract__test_bytes__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_bytes() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_bytes_n",
                 #[allow(deprecated)]
                 &__Contract__test_bytes_n__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_bytes_n() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_string",
                 #[allow(deprecated)]
                 &__Contract__test_string__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_string() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_symbol",
                 #[allow(deprecated)]
                 &__Contract__test_symbol__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_symbol() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_vec",
                 #[allow(deprecated)]
                 &__Contract__test_vec__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_vec() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_map",
                 #[allow(deprecated)]
                 &__Contract__test_map__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_map() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_duration",
                 #[allow(deprecated)]
                 &__Contract__test_duration__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_duration() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_timepoint",
                 #[allow(deprecated)]
                 &__Contract__test_timepoint__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_timepoint() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_i256",
                 #[allow(deprecated)]
                 &__Contract__test_i256__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_i256() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_u256",
                 #[allow(deprecated)]
                 &__Contract__test_u256__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_u256() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_env_param",
                 #[allow(deprecated)]
                 &__Contract__test_env_param__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_env_param() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_struct",
                 #[allow(deprecated)]
                 &__Contract__test_struct__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_struct() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_enum_unit",
                 #[allow(deprecated)]
                 &__Contract__test_enum_unit__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_enum_unit() },
+            );
             <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
                 "test_enum_variants",
                 #[allow(deprecated)]
                 &__Contract__test_enum_variants__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_contracttrait_trait::test",
+                &const { <Contract>::spec_xdr_test_enum_variants() },
+            );
         }
     }
     #[doc(hidden)]
@@ -21,6 +21,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -194,6 +203,34 @@ impl Transfer {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for Transfer {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["transfer"],
+            2usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["amount", "to_muxed_id"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            from: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            to: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            amount: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            to_muxed_id: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 impl Contract {
     pub fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) {
         Transfer {
@@ -432,6 +469,39 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__a60968eb9ff75bf813738a9007ab5bbea9f174011ab4092819ed57e87eb6b301_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__a60968eb9ff75bf813738a9007ab5bbea9f174011ab4092819ed57e87eb6b301_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08transfer\0\0\0\x03\0\0\0\0\0\0\0\x04from\0\0\0\x13\0\0\0\0\0\0\0\x02to\0\0\0\0\0\x14\0\0\0\0\0\0\0\x06amount\0\0\0\0\0\x0b\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0ffailed_transfer\0\0\0\0\x03\0\0\0\0\0\0\0\x04from\0\0\0\x13\0\0\0\0\0\0\0\x02to\0\0\0\0\0\x13\0\0\0\0\0\0\0\x06amount\0\0\0\0\0\x0b\0\0\0\0",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -610,11 +680,21 @@ fn __Contract____a60968eb9ff75bf813738a9007ab5bbea9f174011ab4092819ed57e87eb6b30
             #[allow(deprecated)]
             &__Contract__transfer__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_events",
+            &const { <Contract>::spec_xdr_transfer() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "failed_transfer",
             #[allow(deprecated)]
             &__Contract__failed_transfer__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_events",
+            &const { <Contract>::spec_xdr_failed_transfer() },
+        );
     }
 }
 mod test {
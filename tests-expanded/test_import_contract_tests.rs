@@ -25,6 +25,16 @@ mod addcontract {
         #[doc(hidden)]
         allow_non_root_auth: bool,
     }
+    impl<'a> soroban_sdk::testutils::ContractClient for Client<'a> {
+        fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+            Self::new(env, address)
+        }
+        fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+            static SPEC: soroban_sdk::testutils::ClientSpec =
+                soroban_sdk::testutils::ClientSpec::new();
+            &SPEC
+        }
+    }
     impl<'a> Client<'a> {
         pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
             Self {
@@ -369,6 +379,43 @@ mod addcontract {
             res
         }
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __Client__6ecf4b81a1826b186b96027a980a40b74ef0e4056b0b7fa44cfd522125765f33_spec_ctor() {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __Client__6ecf4b81a1826b186b96027a980a40b74ef0e4056b0b7fa44cfd522125765f33_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x03add\0\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01b\0\0\0\0\0\0\x06\0\0\0\x01\0\0\0\x06",
+                );
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x08safe_add\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01b\0\0\0\0\0\0\x06\0\0\0\x01\0\0\x03\xe9\0\0\0\x06\0\0\0\x03",
+                );
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0csafe_add_two\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01b\0\0\0\0\0\0\x06\0\0\0\x01\0\0\x03\xe9\0\0\0\x06\0\0\x07\xd0\0\0\0\x07MyError\0",
+                );
+        }
+    }
     ///Args is a type for building arg lists for functions defined in "Contract".
     pub struct Args;
     impl Args {
@@ -857,6 +904,45 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutableRef {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::String as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryContractExecutableRef {
+                                owner: field_0,
+                                tag: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct ContractContext {
         pub args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -1414,6 +1500,61 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractContext {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::Vec<
+                                        soroban_sdk::Val,
+                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                            (),
+                                        ),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryContractContext {
+                                args: field_0,
+                                contract: field_1,
+                                fn_name: field_2,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct SubContractInvocation {
         pub context: ContractContext,
@@ -1904,6 +2045,47 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitrarySubContractInvocation {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Vec<
+                                            InvokerContractAuthEntry,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitrarySubContractInvocation {
+                                context: field_0,
+                                sub_invocations: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct CreateContractHostFnContext {
         pub executable: ContractExecutable,
@@ -2385,6 +2567,47 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryCreateContractHostFnContext {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::BytesN<
+                                            32,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryCreateContractHostFnContext {
+                                executable: field_0,
+                                salt: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct CreateContractWithConstructorHostFnContext {
         pub constructor_args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -2996,6 +3219,67 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy
+            for ArbitraryCreateContractWithConstructorHostFnContext
+        {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::Vec<
+                                        soroban_sdk::Val,
+                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::BytesN<
+                                                32,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                            (),
+                                        ),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| {
+                                ArbitraryCreateContractWithConstructorHostFnContext {
+                                    constructor_args: field_0,
+                                    executable: field_1,
+                                    salt: field_2,
+                                }
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub enum ContractExecutable {
         Wasm(soroban_sdk::BytesN<32>),
@@ -3606,6 +3890,60 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutable {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <soroban_sdk::BytesN<
+                                                        32,
+                                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContractExecutable::Wasm(field_0),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <ContractExecutableRef as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContractExecutable::ExternalRef(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum Context {
         Contract(ContractContext),
@@ -4324,6 +4662,77 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContext {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContext::Contract(field_0),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContext::CreateContractHostFn(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContext::CreateContractWithCtorHostFn(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum InvokerContractAuthEntry {
         Contract(SubContractInvocation),
@@ -5081,6 +5490,79 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryInvokerContractAuthEntry {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <SubContractInvocation as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryInvokerContractAuthEntry::Contract(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractHostFn(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractWithCtorHostFn(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum Executable {
         Wasm(soroban_sdk::BytesN<32>),
@@ -5653,6 +6135,53 @@ mod addcontract {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryExecutable {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <soroban_sdk::BytesN<
+                                                        32,
+                                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryExecutable::Wasm(field_0),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryExecutable::StellarAsset,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryExecutable::Account,
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum Error {
         Overflow = 1,
@@ -5714,6 +6243,35 @@ mod addcontract {
         #[inline(always)]
         fn spec_shaking_marker() {}
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __Error__error_spec_ctor() {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __Error__error_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            soroban_sdk::testutils::coverage::register_error_spec(
+                "test_import_contract::addcontract",
+                b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x05Error\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x08Overflow\0\0\0\x01",
+            );
+        }
+    }
     impl TryFrom<soroban_sdk::Error> for Error {
         type Error = soroban_sdk::Error;
         #[inline(always)]
@@ -5877,6 +6435,35 @@ mod addcontract {
         #[inline(always)]
         fn spec_shaking_marker() {}
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __MyError__error_spec_ctor() {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __MyError__error_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            soroban_sdk::testutils::coverage::register_error_spec(
+                "test_import_contract::addcontract",
+                b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x07MyError\0\0\0\0\x01\0\0\0\0\0\0\0\x08Overflow\0\0\0\x01",
+            );
+        }
+    }
     impl TryFrom<soroban_sdk::Error> for MyError {
         type Error = soroban_sdk::Error;
         #[inline(always)]
@@ -6020,6 +6607,35 @@ impl soroban_sdk::SpecShakingMarker for Error {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __Error__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __Error__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_import_contract",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x05Error\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\x05Abort\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x08Overflow\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for Error {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -6143,6 +6759,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -6595,6 +7220,43 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__7f4ee4d361d09b9f0b349441853c9c2507e71fb15e4ddfe088d43fd41f0e1d27_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__7f4ee4d361d09b9f0b349441853c9c2507e71fb15e4ddfe088d43fd41f0e1d27_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08add_with\0\0\0\x03\0\0\0\0\0\0\0\x0bcontract_id\0\0\0\0\x13\0\0\0\0\0\0\0\x01x\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01y\0\0\0\0\0\0\x06\0\0\0\x01\0\0\0\x06",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\rsafe_add_with\0\0\0\0\0\0\x03\0\0\0\0\0\0\0\x0bcontract_id\0\0\0\0\x13\0\0\0\0\0\0\0\x01x\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01y\0\0\0\0\0\0\x06\0\0\0\x01\0\0\x03\xe9\0\0\0\x06\0\0\0\x03",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x11safe_add_with_two\0\0\0\0\0\0\x03\0\0\0\0\0\0\0\x0bcontract_id\0\0\0\0\x13\0\0\0\0\0\0\0\x01x\0\0\0\0\0\0\x06\0\0\0\0\0\0\0\x01y\0\0\0\0\0\0\x06\0\0\0\x01\0\0\x03\xe9\0\0\0\x06\0\0\0\x03",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -6847,16 +7509,31 @@ fn __Contract____7f4ee4d361d09b9f0b349441853c9c2507e71fb15e4ddfe088d43fd41f0e1d2
             #[allow(deprecated)]
             &__Contract__add_with__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_import_contract",
+            &const { <Contract>::spec_xdr_add_with() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "safe_add_with",
             #[allow(deprecated)]
             &__Contract__safe_add_with__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_import_contract",
+            &const { <Contract>::spec_xdr_safe_add_with() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "safe_add_with_two",
             #[allow(deprecated)]
             &__Contract__safe_add_with_two__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_import_contract",
+            &const { <Contract>::spec_xdr_safe_add_with_two() },
+        );
     }
 }
 mod test {
@@ -113,6 +113,34 @@ mod feat1 {
             res
         }
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __ContractClient__7692c3ad3540bb803c020b3aee66cd8887123234ea0c6e7143c0add73ff431ed_spec_ctor(
+    ) {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __ContractClient__7692c3ad3540bb803c020b3aee66cd8887123234ea0c6e7143c0add73ff431ed_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(b"\0\0\0\0\0\0\0\0\0\0\0\x03one\0\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        }
+    }
     impl ContractArgs {
         #[inline(always)]
         #[allow(clippy::unused_unit)]
@@ -181,6 +209,11 @@ mod feat1 {
                 #[allow(deprecated)]
                 &__Contract__one__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<Contract>(),
+                "test_modular::feat1",
+                &const { <Contract>::spec_xdr_one() },
+            );
         }
     }
 }
@@ -291,6 +324,34 @@ mod feat2 {
             res
         }
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __ContractClient__3fc4ccfe745870e2c0d99f71f30ff0656c8dedd41cc1d7d3d376b0dbe685e2f3_spec_ctor(
+    ) {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __ContractClient__3fc4ccfe745870e2c0d99f71f30ff0656c8dedd41cc1d7d3d376b0dbe685e2f3_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(b"\0\0\0\0\0\0\0\0\0\0\0\x03two\0\0\0\0\0\0\0\0\x01\0\0\0\x04");
+        }
+    }
     impl ContractArgs {
         #[inline(always)]
         #[allow(clippy::unused_unit)]
@@ -360,6 +421,11 @@ mod feat2 {
                 #[allow(deprecated)]
                 &__super__Contract__two__invoke_raw_slice,
             );
+            soroban_sdk::testutils::coverage::register_function_spec(
+                core::any::type_name::<super::Contract>(),
+                "test_modular::feat2",
+                &const { <super::Contract>::spec_xdr_two() },
+            );
         }
     }
 }
@@ -450,6 +516,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -665,6 +740,33 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__f9194e73f9e9459e3450ea10a179cdf77aafa695beecd3b9344a98d111622243_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__f9194e73f9e9459e3450ea10a179cdf77aafa695beecd3b9344a98d111622243_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x04zero\0\0\0\0\0\0\0\x01\0\0\0\x04");
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -733,6 +835,11 @@ fn __Contract____f9194e73f9e9459e3450ea10a179cdf77aafa695beecd3b9344a98d11162224
             #[allow(deprecated)]
             &__Contract__zero__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_modular",
+            &const { <Contract>::spec_xdr_zero() },
+        );
     }
 }
 #[rustc_main]
@@ -429,6 +429,43 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructA {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <bool as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryStructA {
+                            f1: field_0,
+                            f2: field_1,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct StructB {
     pub f1: i64,
@@ -854,6 +891,43 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructB {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::String as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryStructB {
+                            f1: field_0,
+                            f2: field_1,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct StructC {
     pub f1: Vec<u32>,
@@ -1281,6 +1355,45 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructC {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <Vec<
+                                    u32,
+                                > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryStructC {
+                            f1: field_0,
+                            f2: field_1,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct StructTupleA(pub i64, pub i64);
 #[automatically_derived]
@@ -1657,6 +1770,40 @@ const _: () = {
             ))
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructTupleA {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryStructTupleA(field_0, field_1),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct StructTupleB(pub u128, pub u128);
 #[automatically_derived]
@@ -2033,6 +2180,40 @@ const _: () = {
             ))
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructTupleB {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u128 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <u128 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryStructTupleB(field_0, field_1),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct StructTupleC(pub Address, pub i128);
 #[automatically_derived]
@@ -2410,6 +2591,40 @@ const _: () = {
             ))
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructTupleC {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <i128 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryStructTupleC(field_0, field_1),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum EnumA {
     V1,
@@ -2867,6 +3082,38 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumA {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryEnumA::V1,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryEnumA::V2,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryEnumA::V3,
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub enum EnumB {
     V1,
@@ -3465,6 +3712,74 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumB {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryEnumB::V1,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryEnumB::V2(field_0),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                    ),
+                                    |(field_0, (field_1, ()))| ArbitraryEnumB::V3(
+                                        field_0,
+                                        field_1,
+                                    ),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub enum EnumC {
     V1,
@@ -4022,6 +4337,60 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumC {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryEnumC::V1,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <StructA as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryEnumC::V2(field_0),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <StructTupleA as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryEnumC::V3(field_0),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub enum EnumIntA {
     V1 = 1,
@@ -4365,6 +4734,33 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumIntA {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntA::V1,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntA::V2,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntA::V3,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum EnumIntB {
     V1 = 10,
@@ -4708,6 +5104,33 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumIntB {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntB::V1,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntB::V2,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntB::V3,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum EnumIntC {
     V1 = 100,
@@ -5051,6 +5474,33 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumIntC {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntC::V1,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntC::V2,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryEnumIntC::V3,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum ErrorA {
     E1 = 1,
@@ -5109,6 +5559,35 @@ impl soroban_sdk::SpecShakingMarker for ErrorA {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ErrorA__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ErrorA__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_lib",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x06ErrorA\0\0\0\0\0\x03\0\0\0\0\0\0\0\x02E1\0\0\0\0\0\x01\0\0\0\0\0\0\0\x02E2\0\0\0\0\0\x02\0\0\0\0\0\0\0\x02E3\0\0\0\0\0\x03",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for ErrorA {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -5277,6 +5756,35 @@ impl soroban_sdk::SpecShakingMarker for ErrorB {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ErrorB__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ErrorB__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_lib",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x06ErrorB\0\0\0\0\0\x03\0\0\0\0\0\0\0\x02E1\0\0\0\0\0\n\0\0\0\0\0\0\0\x02E2\0\0\0\0\0\x0b\0\0\0\0\0\0\0\x02E3\0\0\0\0\0\x0c",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for ErrorB {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -5445,6 +5953,35 @@ impl soroban_sdk::SpecShakingMarker for ErrorC {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ErrorC__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ErrorC__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_lib",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x06ErrorC\0\0\0\0\0\x03\0\0\0\0\0\0\0\x02E1\0\0\0\0\0d\0\0\0\0\0\0\0\x02E2\0\0\0\0\0e\0\0\0\0\0\0\0\x02E3\0\0\0\0\0f",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for ErrorC {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -5643,6 +6180,30 @@ impl EventA {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for EventA {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["event_a"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["f2"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            f1: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            f2: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub struct EventB {
     pub f1: Address,
     pub f2: Address,
@@ -5737,6 +6298,32 @@ impl EventB {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for EventB {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["event_b"],
+            2usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["f3"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            f1: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            f2: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            f3: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub struct EventC {
     pub f1: soroban_sdk::Symbol,
     pub f2: i64,
@@ -5827,6 +6414,32 @@ impl EventC {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for EventC {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["event_c"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["f2", "f3"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            f1: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            f2: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            f3: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub struct EventD;
 #[automatically_derived]
 impl ::core::clone::Clone for EventD {
@@ -5894,6 +6507,25 @@ impl EventD {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for EventD {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["event_d"],
+            0usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &[],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {})
+    }
+}
 #[rustc_main]
 #[coverage(off)]
 #[doc(hidden)]
@@ -24,6 +24,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -575,6 +584,43 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedParamStruct {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <UsedNestedInStruct as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryUsedParamStruct {
+                            a: field_0,
+                            nested: field_1,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum UsedReturnEnum {
     A(u32),
@@ -1101,6 +1147,54 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedReturnEnum {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryUsedReturnEnum::A(field_0),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryUsedReturnEnum::B(field_0),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub enum UsedParamIntEnum {
     X = 1,
@@ -1432,6 +1526,28 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedParamIntEnum {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUsedParamIntEnum::X,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUsedParamIntEnum::Y,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum UsedErrorEnum {
     NotFound = 1,
@@ -1488,6 +1604,35 @@ impl soroban_sdk::SpecShakingMarker for UsedErrorEnum {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __UsedErrorEnum__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __UsedErrorEnum__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_shaking_v2",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\rUsedErrorEnum\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\x08NotFound\0\0\0\x01\0\0\0\0\0\0\0\x07Invalid\0\0\0\0\x02",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for UsedErrorEnum {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -1641,6 +1786,35 @@ impl soroban_sdk::SpecShakingMarker for UsedPanicErrorEnum {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __UsedPanicErrorEnum__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __UsedPanicErrorEnum__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_shaking_v2",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x12UsedPanicErrorEnum\0\0\0\0\0\x01\0\0\0\0\0\0\0\x04Boom\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for UsedPanicErrorEnum {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -1792,6 +1966,35 @@ impl soroban_sdk::SpecShakingMarker for UsedAssertErrorEnum {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __UsedAssertErrorEnum__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __UsedAssertErrorEnum__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_shaking_v2",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x13UsedAssertErrorEnum\0\0\0\0\x01\0\0\0\0\0\0\0\x03Bad\0\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for UsedAssertErrorEnum {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -2273,6 +2476,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedNestedInStruct {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedNestedInStruct { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedVecElement {
     pub data: u32,
@@ -2643,6 +2869,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedVecElement {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedVecElement { data: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum UsedMapKey {
     K1 = 1,
@@ -2972,6 +3221,28 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedMapKey {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUsedMapKey::K1,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUsedMapKey::K2,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedMapVal {
     pub v: u32,
@@ -3338,6 +3609,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedMapVal {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedMapVal { v: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedOptionElement {
     pub data: u32,
@@ -3713,6 +4007,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedOptionElement {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedOptionElement { data: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedResultOk {
     pub data: u32,
@@ -4081,6 +4398,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedResultOk {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedResultOk { data: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedEventSimple {
     pub kind: Symbol,
@@ -4167,6 +4507,30 @@ impl UsedEventSimple {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for UsedEventSimple {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["used_event_simple"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["amount"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            kind: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            amount: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub enum UsedEventTopicType {
     Transfer = 1,
     Mint = 2,
@@ -4499,6 +4863,28 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedEventTopicType {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUsedEventTopicType::Transfer,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUsedEventTopicType::Mint,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedEventWithTopicType {
     pub kind: UsedEventTopicType,
@@ -4589,6 +4975,30 @@ impl UsedEventWithTopicType {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for UsedEventWithTopicType {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["used_event_with_topic_type"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["amount"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            kind: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            amount: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub struct UsedEventDataType {
     pub x: u32,
     pub y: u32,
@@ -5019,6 +5429,43 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedEventDataType {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryUsedEventDataType {
+                            x: field_0,
+                            y: field_1,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedEventWithDataType {
     pub kind: Symbol,
@@ -5109,6 +5556,30 @@ impl UsedEventWithDataType {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for UsedEventWithDataType {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["used_event_with_data_type"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["payload"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            kind: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            payload: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub struct UsedEventTopicOuter {
     pub inner: UsedEventTopicInner,
 }
@@ -5490,6 +5961,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedEventTopicOuter {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <UsedEventTopicInner as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedEventTopicOuter { inner: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedEventTopicInner {
     pub val: u32,
@@ -5869,6 +6363,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedEventTopicInner {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedEventTopicInner { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedEventWithNestedTopic {
     pub info: UsedEventTopicOuter,
@@ -5959,6 +6476,30 @@ impl UsedEventWithNestedTopic {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for UsedEventWithNestedTopic {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["used_event_with_nested_topic"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["amount"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            info: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            amount: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub struct UsedEventDataOuter {
     pub inner: UsedEventDataInner,
 }
@@ -6338,6 +6879,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedEventDataOuter {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <UsedEventDataInner as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedEventDataOuter { inner: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedEventDataInner {
     pub val: u32,
@@ -6715,6 +7279,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedEventDataInner {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedEventDataInner { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedEventWithNestedData {
     pub kind: Symbol,
@@ -6805,6 +7392,30 @@ impl UsedEventWithNestedData {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for UsedEventWithNestedData {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["used_event_with_nested_data"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["payload"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            kind: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            payload: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub enum UsedRefTopicType {
     Send = 1,
     Recv = 2,
@@ -7135,6 +7746,28 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedRefTopicType {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUsedRefTopicType::Send,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUsedRefTopicType::Recv,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedRefDataType {
     pub nested: UsedRefDataInner,
@@ -7513,6 +8146,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedRefDataType {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <UsedRefDataInner as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedRefDataType { nested: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedRefDataInner {
     pub val: u32,
@@ -7883,6 +8539,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedRefDataInner {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedRefDataInner { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedEventWithRefs<'a> {
     pub kind: &'a UsedRefTopicType,
@@ -8341,6 +9020,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedTupleElement {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedTupleElement { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedTupleReturnElement {
     pub val: u32,
@@ -8721,6 +9423,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedTupleReturnElement {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedTupleReturnElement { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedVecInnerVecElement {
     pub val: u32,
@@ -9101,6 +9826,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedVecInnerVecElement {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedVecInnerVecElement { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedVecInnerElement {
     pub val: u32,
@@ -9480,6 +10228,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedVecInnerElement {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedVecInnerElement { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedVecElementNested {
     pub val: u32,
@@ -9993,6 +10764,57 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedVecElementNested {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <UsedVecInnerElement as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <Vec<
+                                            UsedVecInnerVecElement,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                        ),
+                        |(field_0, (field_1, (field_2, ())))| ArbitraryUsedVecElementNested {
+                            val: field_0,
+                            inner: field_1,
+                            vec_inner: field_2,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 struct UsedNonPubStruct {
     pub val: u32,
@@ -10363,6 +11185,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedNonPubStruct {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedNonPubStruct { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 enum UsedNonPubError {
     Fail = 1,
@@ -10410,6 +11255,35 @@ impl soroban_sdk::SpecShakingMarker for UsedNonPubError {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __UsedNonPubError__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __UsedNonPubError__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_shaking_v2",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x0fUsedNonPubError\0\0\0\0\x01\0\0\0\0\0\0\0\x04Fail\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for UsedNonPubError {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -10887,6 +11761,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedRecursiveRoot {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <UsedRecursiveNode as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedRecursiveRoot { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum UsedRecursiveNode {
     NotRecursive(UsedLeaf),
@@ -11433,6 +12330,58 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedRecursiveNode {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <UsedLeaf as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryUsedRecursiveNode::NotRecursive(
+                                        field_0,
+                                    ),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <UsedRecursiveLeaf as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryUsedRecursiveNode::Recursive(
+                                        field_0,
+                                    ),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub struct UsedRecursiveLeaf {
     pub val: Vec<UsedRecursiveRoot>,
@@ -11814,6 +12763,31 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedRecursiveLeaf {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <Vec<
+                                    UsedRecursiveRoot,
+                                > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedRecursiveLeaf { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UsedLeaf {
     pub val: u32,
@@ -12182,6 +13156,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUsedLeaf {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUsedLeaf { val: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 mod wasm_imported {
     pub const WASM: &[u8] = b"\x00asm\x01\x00\x00\x00\x01*\x07`\x02~~\x01~`\x03~~~\x01~`\x01~\x01~`\x00\x01~`\x02\x7f\x7f\x01~`\x04\x7f\x7f\x7f\x7f\x01~`\x02\x7f~\x00\x02+\x07\x01b\x01j\x00\x00\x01x\x011\x00\x00\x01m\x019\x00\x01\x01v\x01g\x00\x00\x01m\x01b\x00\x01\x01i\x012\x00\x02\x01i\x011\x00\x02\x03\x0c\x0b\x03\x04\x03\x02\x00\x05\x03\x00\x00\x06\x06\x05\x03\x01\x00\x11\x06!\x04\x7f\x01A\x80\x80\xc0\x00\x0b\x7f\x00A\x82\x80\xc0\x00\x0b\x7f\x00A\x80\x81\xc0\x00\x0b\x7f\x00A\x80\x81\xc0\x00\x0b\x07\x8e\x01\x0b\x06memory\x02\x00\tfn_enum_a\x00\x07\rfn_enum_int_a\x00\t\nfn_error_a\x00\n\nfn_event_a\x00\x0b\nfn_event_d\x00\r\x0bfn_struct_a\x00\x0e\x11fn_struct_tuple_a\x00\x0f\x01_\x03\x01\n__data_end\x03\x02\x0b__heap_base\x03\x03\n\x9d\n\x0b\x95\x02\x03\x01\x7f\x01~\x03\x7f#\x80\x80\x80\x80\x00A\x10k\"\x00$\x80\x80\x80\x80\x00A\x00-\x00\x82\x80\xc0\x80\x00\x1aB\x00!\x01A~!\x02\x03~\x02@\x02@\x02@\x02@\x02@ \x02E\r\x00A\x01!\x03 \x02A\x82\x80\xc0\x80\x00j-\x00\x00\"\x04A\xdf\x00F\r\x04 \x04APjA\xff\x01qA\nI\r\x02 \x04A\xbf\x7fjA\xff\x01qA\x1aI\r\x03\x02@ \x04A\x9f\x7fjA\xff\x01qA\x1aO\r\x00 \x04AEj!\x03\x0c\x05\x0b \x00 \x04\xadB\x08\x86B\x01\x847\x03\x00A\x80\x80\xc0\x80\x00\xadB \x86B\x04\x84B\x84\x80\x80\x80 \x10\x80\x80\x80\x80\x00!\x01\x0c\x01\x0b \x00 \x01B\x08\x86B\x0e\x84\"\x017\x02\x04\x0b \x00 \x017\x03\x00 \x00A\x01\x10\x88\x80\x80\x80\x00!\x01 \x00A\x10j$\x80\x80\x80\x80\x00 \x01\x0f\x0b \x04ARj!\x03\x0c\x01\x0b \x04AKj!\x03\x0b \x01B\x06\x86 \x03\xadB\xff\x01\x83\x84!\x01 \x02A\x01j!\x02\x0c\x00\x0b\x0b\x1a\x00 \x00\xadB \x86B\x04\x84 \x01\xadB \x86B\x04\x84\x10\x83\x80\x80\x80\x00\x0b\x12\x00A\x00-\x00\xc8\x80\xc0\x80\x00\x1aB\x84\x80\x80\x800\x0b4\x00\x02@ \x00B\xff\x01\x83B\x04Q\r\x00\x00\x0bA\x00-\x00\x90\x80\xc0\x80\x00\x1aB\x83\x80\x80\x80  \x00B\x84\x80\x80\x80p\x83 \x00B\x80\x80\x80\x80\x10T\x1b\x0b\xe6\x01\x01\x02\x7f#\x80\x80\x80\x80\x00A k\"\x02$\x80\x80\x80\x80\x00\x02@ \x00B\xff\x01\x83B\xcd\x00R\r\x00 \x01B\xff\x01\x83B\xc9\x00R\r\x00A\x00!\x03A\x00-\x00\x9e\x80\xc0\x80\x00\x1a \x02 \x007\x03\x08 \x02B\x8e\xcc\xc1\xfc\xac\xdd\xab\x017\x03\x00\x03@\x02@ \x03A\x10G\r\x00A\x00!\x03\x02@\x03@ \x03A\x10F\r\x01 \x02A\x10j \x03j \x02 \x03j)\x03\x007\x03\x00 \x03A\x08j!\x03\x0c\x00\x0b\x0b \x02A\x10jA\x02\x10\x88\x80\x80\x80\x00!\x00 \x02 \x017\x03\x10 \x00A\xf8\x80\xc0\x80\x00A\x01 \x02A\x10jA\x01\x10\x8c\x80\x80\x80\x00\x10\x81\x80\x80\x80\x00\x1a \x02A j$\x80\x80\x80\x80\x00B\x02\x0f\x0b \x02A\x10j \x03jB\x027\x03\x00 \x03A\x08j!\x03\x0c\x00\x0b\x0b\x00\x0b.\x00\x02@ \x01 \x03F\r\x00\x00\x0b \x00\xadB \x86B\x04\x84 \x02\xadB \x86B\x04\x84 \x01\xadB \x86B\x04\x84\x10\x84\x80\x80\x80\x00\x0b\x91\x01\x03\x01\x7f\x01~\x01\x7f#\x80\x80\x80\x80\x00A\x10k\"\x00$\x80\x80\x80\x80\x00A\x00-\x00\xac\x80\xc0\x80\x00\x1a \x00B\x8e\xd2\xc1\xfc\xac\xdd\xab\x017\x03\x00B\x02!\x01A\x01!\x02\x02@\x03@ \x02E\r\x01 \x02A\x7fj!\x02B\x8e\xd2\xc1\xfc\xac\xdd\xab\x01!\x01\x0c\x00\x0b\x0b \x00 \x017\x03\x08 \x00A\x08jA\x01\x10\x88\x80\x80\x80\x00A\x04A\x00 \x00A\x08jA\x00\x10\x8c\x80\x80\x80\x00\x10\x81\x80\x80\x80\x00\x1a \x00A\x10j$\x80\x80\x80\x80\x00B\x02\x0b\x93\x01\x01\x02\x7f#\x80\x80\x80\x80\x00A\x10k\"\x02$\x80\x80\x80\x80\x00\x02@ \x00B\xff\x01\x83B\x04R\r\x00A\x01A\x02A\x00 \x01\xa7A\xff\x01q\"\x03\x1b \x03A\x01F\x1b\"\x03A\x02F\r\x00A\x00-\x00\xba\x80\xc0\x80\x00\x1a \x02 \x03\xad7\x03\x08 \x02 \x00B\x84\x80\x80\x80p\x837\x03\x00A\xe8\x80\xc0\x80\x00\xadB \x86B\x04\x84 \x02\xadB \x86B\x04\x84B\x84\x80\x80\x80 \x10\x82\x80\x80\x80\x00!\x00 \x02A\x10j$\x80\x80\x80\x80\x00 \x00\x0f\x0b\x00\x0b\xbc\x01\x01\x01\x7f#\x80\x80\x80\x80\x00A k\"\x02$\x80\x80\x80\x80\x00 \x02A\x10j \x00\x10\x90\x80\x80\x80\x00\x02@ \x02(\x02\x10A\x01F\r\x00 \x02)\x03\x18!\x00 \x02A\x10j \x01\x10\x90\x80\x80\x80\x00 \x02(\x02\x10A\x01F\r\x00 \x02)\x03\x18!\x01A\x00-\x00\xd6\x80\xc0\x80\x00\x1a \x02A\x10j \x00\x10\x91\x80\x80\x80\x00 \x02(\x02\x10\r\x00 \x02)\x03\x18!\x00 \x02A\x10j \x01\x10\x91\x80\x80\x80\x00 \x02(\x02\x10A\x01F\r\x00 \x02 \x02)\x03\x187\x03\x08 \x02 \x007\x03\x00 \x02A\x02\x10\x88\x80\x80\x80\x00!\x00 \x02A j$\x80\x80\x80\x80\x00 \x00\x0f\x0b\x00\x0b]\x02\x01\x7f\x01~\x02@\x02@ \x01\xa7A\xff\x01q\"\x02A\xc1\x00F\r\x00\x02@ \x02A\x07F\r\x00B\x01!\x03B\x83\x90\x80\x80\x80\x01!\x01\x0c\x02\x0b \x01B\x08\x87!\x01B\x00!\x03\x0c\x01\x0bB\x00!\x03 \x01\x10\x85\x80\x80\x80\x00!\x01\x0b \x00 \x037\x03\x00 \x00 \x017\x03\x08\x0bF\x00\x02@\x02@ \x01B\x80\x80\x80\x80\x80\x80\x80\xc0\x00|B\xff\xff\xff\xff\xff\xff\xff\xff\x00V\r\x00 \x01B\x08\x86B\x07\x84!\x01\x0c\x01\x0b \x01\x10\x86\x80\x80\x80\x00!\x01\x0b \x00B\x007\x03\x00 \x00 \x017\x03\x08\x0b\x0b\x8a\x01\x01\x00A\x80\x80\xc0\x00\x0b\x80\x01V2SpEcV1\xa2=N\xc1p\x95\x90\xb2SpEcV1\xe9R\xa7\xe8b\x99\xa2\xc3SpEcV1K\xe6\x8ej\x19\x9en\xbdSpEcV1\x15\xf0Wx\x15\x83\xc0:SpEcV1\xb6\x1c\xfd\xdfhY-dSpEcV1V]\x80\\~\x1a\x08/SpEcV1\xcf)\x97]S\xb2\xfd)f1f2d\x00\x10\x00\x02\x00\x00\x00f\x00\x10\x00\x02\x00\x00\x00f\x00\x10\x00\x02\x00\x00\x00\x00\xc7(\x0econtractspecv0\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\tfn_enum_a\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x05EnumA\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\nfn_error_a\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x05input\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00\x01\x00\x00\x03\xe9\x00\x00\x00\x04\x00\x00\x07\xd0\x00\x00\x00\x06ErrorA\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\nfn_event_a\x00\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x00\x13\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x10\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\nfn_event_d\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0bfn_struct_a\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x00\x04\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x07StructA\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\rfn_enum_int_a\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x08EnumIntA\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x11fn_struct_tuple_a\x00\x00\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x00\x07\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x07\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x0cStructTupleA\x00\x00\x00\x02\x00\x00\x00_Contract executable used for creating a new contract and used in\n`CreateContractHostFnContext`.\x00\x00\x00\x00\x00\x00\x00\x00\x12ContractExecutable\x00\x00\x00\x00\x00\x02\x00\x00\x00\x01\x00\x00\x00xExecutable specified by the contract instance as a specific Wasm contract code entry identified by its Wasm sha256 hash.\x00\x00\x00\x04Wasm\x00\x00\x00\x01\x00\x00\x03\xee\x00\x00\x00 \x00\x00\x00\x01\x00\x00\x00_Executable reference via a persistent storage entry owned by this contract or another contract.\x00\x00\x00\x00\x0bExternalRef\x00\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x15ContractExecutableRef\x00\x00\x00\x00\x00\x00\x01\x00\x00\x00\xc0Executable referenced via a persistent storage entry owned by a contract,\neither this contract or another contract.\n\nThe persistent storage entry owned by the `owner` has the `tag` as its key.\x00\x00\x00\x00\x00\x00\x00\x15ContractExecutableRef\x00\x00\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x05owner\x00\x00\x00\x00\x00\x00\x13\x00\x00\x00\x00\x00\x00\x00\x03tag\x00\x00\x00\x00\x10\x00\x00\x00\x02\x00\x00\x00\xe3Context of a single authorized call performed by an address.\n\nCustom account contracts that implement `__check_auth` special function\nreceive a list of `Context` values corresponding to all the calls that\nneed to be authorized.\x00\x00\x00\x00\x00\x00\x00\x00\x07Context\x00\x00\x00\x00\x03\x00\x00\x00\x01\x00\x00\x00\x14Contract invocation.\x00\x00\x00\x08Contract\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x0fContractContext\x00\x00\x00\x00\x01\x00\x00\x00=Contract that has a constructor with no arguments is created.\x00\x00\x00\x00\x00\x00\x14CreateContractHostFn\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x1bCreateContractHostFnContext\x00\x00\x00\x00\x01\x00\x00\x00DContract that has a constructor with 1 or more arguments is created.\x00\x00\x00\x1cCreateContractWithCtorHostFn\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00*CreateContractWithConstructorHostFnContext\x00\x00\x00\x00\x00\x01\x00\x00\x00\xbdAuthorization context of a single contract call.\n\nThis struct corresponds to a `require_auth_for_args` call for an address\nfrom `contract` function with `fn_name` name and `args` arguments.\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0fContractContext\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x04args\x00\x00\x03\xea\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x08contract\x00\x00\x00\x13\x00\x00\x00\x00\x00\x00\x00\x07fn_name\x00\x00\x00\x00\x11\x00\x00\x00\x01\x00\x00\x008Value of contract node in InvokerContractAuthEntry tree.\x00\x00\x00\x00\x00\x00\x00\x15SubContractInvocation\x00\x00\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x07context\x00\x00\x00\x07\xd0\x00\x00\x00\x0fContractContext\x00\x00\x00\x00\x00\x00\x00\x00\x0fsub_invocations\x00\x00\x00\x03\xea\x00\x00\x07\xd0\x00\x00\x00\x18InvokerContractAuthEntry\x00\x00\x00\x02\x00\x00\x01/A node in the tree of authorizations performed on behalf of the current\ncontract as invoker of the contracts deeper in the call stack.\n\nThis is used as an argument of `authorize_as_current_contract` host function.\n\nThis tree corresponds `require_auth[_for_args]` calls on behalf of the\ncurrent contract.\x00\x00\x00\x00\x00\x00\x00\x00\x18InvokerContractAuthEntry\x00\x00\x00\x03\x00\x00\x00\x01\x00\x00\x00\x12Invoke a contract.\x00\x00\x00\x00\x00\x08Contract\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x15SubContractInvocation\x00\x00\x00\x00\x00\x00\x01\x00\x00\x005Create a contract passing 0 arguments to constructor.\x00\x00\x00\x00\x00\x00\x14CreateContractHostFn\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x1bCreateContractHostFnContext\x00\x00\x00\x00\x01\x00\x00\x00=Create a contract passing 0 or more arguments to constructor.\x00\x00\x00\x00\x00\x00\x1cCreateContractWithCtorHostFn\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00*CreateContractWithConstructorHostFnContext\x00\x00\x00\x00\x00\x01\x00\x00\x00vAuthorization context for `create_contract` host function that creates a\nnew contract on behalf of authorizer address.\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1bCreateContractHostFnContext\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\nexecutable\x00\x00\x00\x00\x07\xd0\x00\x00\x00\x12ContractExecutable\x00\x00\x00\x00\x00\x00\x00\x00\x00\x04salt\x00\x00\x03\xee\x00\x00\x00 \x00\x00\x00\x01\x00\x00\x00\xd6Authorization context for `create_contract` host function that creates a\nnew contract on behalf of authorizer address.\nThis is the same as `CreateContractHostFnContext`, but also has\ncontract constructor arguments.\x00\x00\x00\x00\x00\x00\x00\x00\x00*CreateContractWithConstructorHostFnContext\x00\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x10constructor_args\x00\x00\x03\xea\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\nexecutable\x00\x00\x00\x00\x07\xd0\x00\x00\x00\x12ContractExecutable\x00\x00\x00\x00\x00\x00\x00\x00\x00\x04salt\x00\x00\x03\xee\x00\x00\x00 \x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\nExecutable\x00\x00\x00\x00\x00\x03\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x04Wasm\x00\x00\x00\x01\x00\x00\x03\xee\x00\x00\x00 \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0cStellarAsset\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x07Account\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x05EnumA\x00\x00\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02V1\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02V2\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02V3\x00\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x05EnumB\x00\x00\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02V1\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02V2\x00\x00\x00\x00\x00\x01\x00\x00\x00\x07\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02V3\x00\x00\x00\x00\x00\x02\x00\x00\x00\x07\x00\x00\x00\x07\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x05EnumC\x00\x00\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02V1\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02V2\x00\x00\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x07StructA\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02V3\x00\x00\x00\x00\x00\x01\x00\x00\x07\xd0\x00\x00\x00\x0cStructTupleA\x00\x00\x00\x04\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x06ErrorA\x00\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x02E1\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02E2\x00\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02E3\x00\x00\x00\x00\x00\x03\x00\x00\x00\x04\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x06ErrorB\x00\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x02E1\x00\x00\x00\x00\x00\n\x00\x00\x00\x00\x00\x00\x00\x02E2\x00\x00\x00\x00\x00\x0b\x00\x00\x00\x00\x00\x00\x00\x02E3\x00\x00\x00\x00\x00\x0c\x00\x00\x00\x04\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x06ErrorC\x00\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x02E1\x00\x00\x00\x00\x00d\x00\x00\x00\x00\x00\x00\x00\x02E2\x00\x00\x00\x00\x00e\x00\x00\x00\x00\x00\x00\x00\x02E3\x00\x00\x00\x00\x00f\x00\x00\x00\x05\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x06EventA\x00\x00\x00\x00\x00\x01\x00\x00\x00\x07event_a\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x00\x13\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x10\x00\x00\x00\x00\x00\x00\x00\x02\x00\x00\x00\x05\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x06EventB\x00\x00\x00\x00\x00\x01\x00\x00\x00\x07event_b\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x00\x13\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x13\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02f3\x00\x00\x00\x00\x00\x0b\x00\x00\x00\x00\x00\x00\x00\x02\x00\x00\x00\x05\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x06EventC\x00\x00\x00\x00\x00\x01\x00\x00\x00\x07event_c\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x00\x11\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x07\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02f3\x00\x00\x00\x00\x00\x07\x00\x00\x00\x00\x00\x00\x00\x02\x00\x00\x00\x05\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x06EventD\x00\x00\x00\x00\x00\x01\x00\x00\x00\x07event_d\x00\x00\x00\x00\x00\x00\x00\x00\x02\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x07StructA\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x00\x04\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x07StructB\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x00\x07\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x10\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x07StructC\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02f1\x00\x00\x00\x00\x03\xea\x00\x00\x00\x04\x00\x00\x00\x00\x00\x00\x00\x02f2\x00\x00\x00\x00\x00\x13\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x08EnumIntA\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x02V1\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02V2\x00\x00\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x02V3\x00\x00\x00\x00\x00\x03\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x08EnumIntB\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x02V1\x00\x00\x00\x00\x00\n\x00\x00\x00\x00\x00\x00\x00\x02V2\x00\x00\x00\x00\x00\x14\x00\x00\x00\x00\x00\x00\x00\x02V3\x00\x00\x00\x00\x00\x1e\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x08EnumIntC\x00\x00\x00\x03\x00\x00\x00\x00\x00\x00\x00\x02V1\x00\x00\x00\x00\x00d\x00\x00\x00\x00\x00\x00\x00\x02V2\x00\x00\x00\x00\x00\xc8\x00\x00\x00\x00\x00\x00\x00\x02V3\x00\x00\x00\x00\x01,\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0cStructTupleA\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x010\x00\x00\x00\x00\x00\x00\x07\x00\x00\x00\x00\x00\x00\x00\x011\x00\x00\x00\x00\x00\x00\x07\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0cStructTupleB\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x010\x00\x00\x00\x00\x00\x00\n\x00\x00\x00\x00\x00\x00\x00\x011\x00\x00\x00\x00\x00\x00\n\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0cStructTupleC\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x010\x00\x00\x00\x00\x00\x00\x13\x00\x00\x00\x00\x00\x00\x00\x011\x00\x00\x00\x00\x00\x00\x0b\x00\x1e\x11contractenvmetav0\x00\x00\x00\x00\x00\x00\x00\x1c\x00\x00\x00\x00\x00O\x0econtractmetav0\x00\x00\x00\x00\x00\x00\x00\x05rsver\x00\x00\x00\x00\x00\x00\x061.91.0\x00\x00\x00\x00\x00\x00\x00\x00\x00\x12rssdk_spec_shaking\x00\x00\x00\x00\x00\x012\x00\x00\x00";
@@ -12207,6 +13204,16 @@ mod wasm_imported {
         #[doc(hidden)]
         allow_non_root_auth: bool,
     }
+    impl<'a> soroban_sdk::testutils::ContractClient for Client<'a> {
+        fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+            Self::new(env, address)
+        }
+        fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+            static SPEC: soroban_sdk::testutils::ClientSpec =
+                soroban_sdk::testutils::ClientSpec::new();
+            &SPEC
+        }
+    }
     impl<'a> Client<'a> {
         pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
             Self {
@@ -12837,6 +13844,57 @@ mod wasm_imported {
             res
         }
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __Client__894930433559d1826526d5a7c11e699178f356a44132f9e57fe739994c44891f_spec_ctor() {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __Client__894930433559d1826526d5a7c11e699178f356a44132f9e57fe739994c44891f_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\tfn_enum_a\0\0\0\0\0\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\x05EnumA\0\0\0",
+                );
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\nfn_error_a\0\0\0\0\0\x01\0\0\0\0\0\0\0\x05input\0\0\0\0\0\0\x04\0\0\0\x01\0\0\x03\xe9\0\0\0\x04\0\0\x07\xd0\0\0\0\x06ErrorA\0\0",
+                );
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\nfn_event_a\0\0\0\0\0\x02\0\0\0\0\0\0\0\x02f1\0\0\0\0\0\x13\0\0\0\0\0\0\0\x02f2\0\0\0\0\0\x10\0\0\0\0",
+                );
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(b"\0\0\0\0\0\0\0\0\0\0\0\nfn_event_d\0\0\0\0\0\0\0\0\0\0");
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x0bfn_struct_a\0\0\0\0\x02\0\0\0\0\0\0\0\x02f1\0\0\0\0\0\x04\0\0\0\0\0\0\0\x02f2\0\0\0\0\0\x01\0\0\0\x01\0\0\x07\xd0\0\0\0\x07StructA\0",
+                );
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\rfn_enum_int_a\0\0\0\0\0\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\x08EnumIntA",
+                );
+            <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                .register(
+                    b"\0\0\0\0\0\0\0\0\0\0\0\x11fn_struct_tuple_a\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\x02f1\0\0\0\0\0\x07\0\0\0\0\0\0\0\x02f2\0\0\0\0\0\x07\0\0\0\x01\0\0\x07\xd0\0\0\0\x0cStructTupleA",
+                );
+        }
+    }
     ///Args is a type for building arg lists for functions defined in "Contract".
     pub struct Args;
     impl Args {
@@ -13348,6 +14406,45 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutableRef {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::String as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryContractExecutableRef {
+                                owner: field_0,
+                                tag: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct ContractContext {
         pub args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -13905,6 +15002,61 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractContext {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::Vec<
+                                        soroban_sdk::Val,
+                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                            (),
+                                        ),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryContractContext {
+                                args: field_0,
+                                contract: field_1,
+                                fn_name: field_2,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct SubContractInvocation {
         pub context: ContractContext,
@@ -14395,6 +15547,47 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitrarySubContractInvocation {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Vec<
+                                            InvokerContractAuthEntry,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitrarySubContractInvocation {
+                                context: field_0,
+                                sub_invocations: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct CreateContractHostFnContext {
         pub executable: ContractExecutable,
@@ -14876,6 +16069,47 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryCreateContractHostFnContext {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::BytesN<
+                                            32,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryCreateContractHostFnContext {
+                                executable: field_0,
+                                salt: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct CreateContractWithConstructorHostFnContext {
         pub constructor_args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -15487,6 +16721,67 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy
+            for ArbitraryCreateContractWithConstructorHostFnContext
+        {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::Vec<
+                                        soroban_sdk::Val,
+                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::BytesN<
+                                                32,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                            (),
+                                        ),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| {
+                                ArbitraryCreateContractWithConstructorHostFnContext {
+                                    constructor_args: field_0,
+                                    executable: field_1,
+                                    salt: field_2,
+                                }
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct StructA {
         pub f1: u32,
@@ -15936,6 +17231,45 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructA {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <bool as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryStructA {
+                                f1: field_0,
+                                f2: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct StructB {
         pub f1: i64,
@@ -16385,6 +17719,45 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructB {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::String as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryStructB {
+                                f1: field_0,
+                                f2: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct StructC {
         pub f1: soroban_sdk::Vec<u32>,
@@ -16840,6 +18213,47 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructC {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::Vec<
+                                        u32,
+                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryStructC {
+                                f1: field_0,
+                                f2: field_1,
+                            },
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct StructTupleA(pub i64, pub i64);
     #[automatically_derived]
@@ -17245,6 +18659,42 @@ mod wasm_imported {
                 ))
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructTupleA {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryStructTupleA(field_0, field_1),
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct StructTupleB(pub u128, pub u128);
     #[automatically_derived]
@@ -17650,6 +19100,42 @@ mod wasm_imported {
                 ))
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructTupleB {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <u128 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <u128 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryStructTupleB(field_0, field_1),
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub struct StructTupleC(pub soroban_sdk::Address, pub i128);
     #[automatically_derived]
@@ -18056,6 +19542,42 @@ mod wasm_imported {
                 ))
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryStructTupleC {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <i128 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryStructTupleC(field_0, field_1),
+                        ),
+                    )
+                })
+            }
+        }
     };
     pub enum ContractExecutable {
         Wasm(soroban_sdk::BytesN<32>),
@@ -18666,6 +20188,60 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutable {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <soroban_sdk::BytesN<
+                                                        32,
+                                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContractExecutable::Wasm(field_0),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <ContractExecutableRef as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContractExecutable::ExternalRef(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum Context {
         Contract(ContractContext),
@@ -19384,6 +20960,77 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContext {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContext::Contract(field_0),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContext::CreateContractHostFn(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryContext::CreateContractWithCtorHostFn(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum InvokerContractAuthEntry {
         Contract(SubContractInvocation),
@@ -20141,6 +21788,79 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryInvokerContractAuthEntry {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <SubContractInvocation as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryInvokerContractAuthEntry::Contract(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractHostFn(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractWithCtorHostFn(
+                                            field_0,
+                                        ),
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum Executable {
         Wasm(soroban_sdk::BytesN<32>),
@@ -20713,6 +22433,53 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryExecutable {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <soroban_sdk::BytesN<
+                                                        32,
+                                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryExecutable::Wasm(field_0),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryExecutable::StellarAsset,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryExecutable::Account,
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum EnumA {
         V1,
@@ -21193,6 +22960,40 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumA {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryEnumA::V1,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryEnumA::V2,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryEnumA::V3,
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum EnumB {
         V1,
@@ -21847,6 +23648,76 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumB {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryEnumB::V1,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryEnumB::V2(field_0),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                        ),
+                                        |(field_0, (field_1, ()))| ArbitraryEnumB::V3(
+                                            field_0,
+                                            field_1,
+                                        ),
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum EnumC {
         V1,
@@ -22446,6 +24317,62 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumC {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        |()| ArbitraryEnumC::V1,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <StructA as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryEnumC::V2(field_0),
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                        (
+                                            {
+                                                use soroban_sdk::testutils::proptest::{
+                                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                                };
+                                                (&soroban_sdk::testutils::proptest::Field::<
+                                                    <StructTupleA as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                >::new())
+                                                    .field_strategy()
+                                            },
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                        ),
+                                        |(field_0, ())| ArbitraryEnumC::V3(field_0),
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum EnumIntA {
         V1 = 1,
@@ -22811,6 +24738,37 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumIntA {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntA::V1,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntA::V2,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntA::V3,
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum EnumIntB {
         V1 = 10,
@@ -23176,6 +25134,37 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumIntB {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntB::V1,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntB::V2,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntB::V3,
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum EnumIntC {
         V1 = 100,
@@ -23541,6 +25530,37 @@ mod wasm_imported {
                 })
             }
         }
+        impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryEnumIntC {
+            fn strategy(
+            ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+            {
+                soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(
+                            ::alloc::boxed::box_new([
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntC::V1,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntC::V2,
+                                    ),
+                                ),
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                        ArbitraryEnumIntC::V3,
+                                    ),
+                                ),
+                            ]),
+                        ),
+                    ),
+                )
+                })
+            }
+        }
     };
     pub enum ErrorA {
         E1 = 1,
@@ -23611,11 +25631,40 @@ mod wasm_imported {
         pub const fn spec_xdr() -> [u8; 76usize] {
             *b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x06ErrorA\0\0\0\0\0\x03\0\0\0\0\0\0\0\x02E1\0\0\0\0\0\x01\0\0\0\0\0\0\0\x02E2\0\0\0\0\0\x02\0\0\0\0\0\0\0\x02E3\0\0\0\0\0\x03"
         }
-    }
-    impl soroban_sdk::SpecShakingMarker for ErrorA {
-        #[doc(hidden)]
-        #[inline(always)]
-        fn spec_shaking_marker() {}
+    }
+    impl soroban_sdk::SpecShakingMarker for ErrorA {
+        #[doc(hidden)]
+        #[inline(always)]
+        fn spec_shaking_marker() {}
+    }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __ErrorA__error_spec_ctor() {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __ErrorA__error_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            soroban_sdk::testutils::coverage::register_error_spec(
+                "test_spec_shaking_v2::wasm_imported",
+                b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x06ErrorA\0\0\0\0\0\x03\0\0\0\0\0\0\0\x02E1\0\0\0\0\0\x01\0\0\0\0\0\0\0\x02E2\0\0\0\0\0\x02\0\0\0\0\0\0\0\x02E3\0\0\0\0\0\x03",
+            );
+        }
     }
     impl TryFrom<soroban_sdk::Error> for ErrorA {
         type Error = soroban_sdk::Error;
@@ -23803,6 +25852,35 @@ mod wasm_imported {
         #[inline(always)]
         fn spec_shaking_marker() {}
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __ErrorB__error_spec_ctor() {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __ErrorB__error_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            soroban_sdk::testutils::coverage::register_error_spec(
+                "test_spec_shaking_v2::wasm_imported",
+                b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x06ErrorB\0\0\0\0\0\x03\0\0\0\0\0\0\0\x02E1\0\0\0\0\0\n\0\0\0\0\0\0\0\x02E2\0\0\0\0\0\x0b\0\0\0\0\0\0\0\x02E3\0\0\0\0\0\x0c",
+            );
+        }
+    }
     impl TryFrom<soroban_sdk::Error> for ErrorB {
         type Error = soroban_sdk::Error;
         #[inline(always)]
@@ -23989,6 +26067,35 @@ mod wasm_imported {
         #[inline(always)]
         fn spec_shaking_marker() {}
     }
+    #[doc(hidden)]
+    #[allow(non_snake_case)]
+    #[allow(unused)]
+    fn __ErrorC__error_spec_ctor() {
+        #[allow(unsafe_code)]
+        {
+            #[link_section = ".init_array"]
+            #[used]
+            #[allow(non_upper_case_globals, non_snake_case)]
+            #[doc(hidden)]
+            static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                #[link_section = ".text.startup"]
+                #[allow(non_snake_case)]
+                extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                    unsafe {
+                        __ErrorC__error_spec_ctor();
+                    };
+                    core::default::Default::default()
+                }
+                f
+            };
+        }
+        {
+            soroban_sdk::testutils::coverage::register_error_spec(
+                "test_spec_shaking_v2::wasm_imported",
+                b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x06ErrorC\0\0\0\0\0\x03\0\0\0\0\0\0\0\x02E1\0\0\0\0\0d\0\0\0\0\0\0\0\x02E2\0\0\0\0\0e\0\0\0\0\0\0\0\x02E3\0\0\0\0\0f",
+            );
+        }
+    }
     impl TryFrom<soroban_sdk::Error> for ErrorC {
         type Error = soroban_sdk::Error;
         #[inline(always)]
@@ -24209,6 +26316,30 @@ mod wasm_imported {
             <_ as soroban_sdk::Event>::publish(self, env);
         }
     }
+    impl soroban_sdk::testutils::Event for EventA {
+        fn decode(
+            env: &soroban_sdk::Env,
+            event: &soroban_sdk::xdr::ContractEvent,
+        ) -> Result<Self, soroban_sdk::xdr::Error> {
+            use soroban_sdk::TryFromVal;
+            let (topics, data) = soroban_sdk::testutils::decode_event(
+                env,
+                event,
+                &["event_a"],
+                1usize,
+                soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+                &["f2"],
+            )?;
+            let mut topics = topics.iter();
+            let mut data = data.iter();
+            Ok(Self {
+                f1: TryFromVal::try_from_val(env, topics.next().unwrap())
+                    .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+                f2: TryFromVal::try_from_val(env, data.next().unwrap())
+                    .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            })
+        }
+    }
     pub struct EventB {
         pub f1: soroban_sdk::Address,
         pub f2: soroban_sdk::Address,
@@ -24334,6 +26465,32 @@ mod wasm_imported {
             <_ as soroban_sdk::Event>::publish(self, env);
         }
     }
+    impl soroban_sdk::testutils::Event for EventB {
+        fn decode(
+            env: &soroban_sdk::Env,
+            event: &soroban_sdk::xdr::ContractEvent,
+        ) -> Result<Self, soroban_sdk::xdr::Error> {
+            use soroban_sdk::TryFromVal;
+            let (topics, data) = soroban_sdk::testutils::decode_event(
+                env,
+                event,
+                &["event_b"],
+                2usize,
+                soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+                &["f3"],
+            )?;
+            let mut topics = topics.iter();
+            let mut data = data.iter();
+            Ok(Self {
+                f1: TryFromVal::try_from_val(env, topics.next().unwrap())
+                    .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+                f2: TryFromVal::try_from_val(env, topics.next().unwrap())
+                    .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+                f3: TryFromVal::try_from_val(env, data.next().unwrap())
+                    .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            })
+        }
+    }
     pub struct EventC {
         pub f1: soroban_sdk::Symbol,
         pub f2: i64,
@@ -24454,6 +26611,32 @@ mod wasm_imported {
             <_ as soroban_sdk::Event>::publish(self, env);
         }
     }
+    impl soroban_sdk::testutils::Event for EventC {
+        fn decode(
+            env: &soroban_sdk::Env,
+            event: &soroban_sdk::xdr::ContractEvent,
+        ) -> Result<Self, soroban_sdk::xdr::Error> {
+            use soroban_sdk::TryFromVal;
+            let (topics, data) = soroban_sdk::testutils::decode_event(
+                env,
+                event,
+                &["event_c"],
+                1usize,
+                soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+                &["f2", "f3"],
+            )?;
+            let mut topics = topics.iter();
+            let mut data = data.iter();
+            Ok(Self {
+                f1: TryFromVal::try_from_val(env, topics.next().unwrap())
+                    .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+                f2: TryFromVal::try_from_val(env, data.next().unwrap())
+                    .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+                f3: TryFromVal::try_from_val(env, data.next().unwrap())
+                    .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            })
+        }
+    }
     pub struct EventD {}
     #[automatically_derived]
     impl ::core::fmt::Debug for EventD {
@@ -24535,6 +26718,25 @@ mod wasm_imported {
             <_ as soroban_sdk::Event>::publish(self, env);
         }
     }
+    impl soroban_sdk::testutils::Event for EventD {
+        fn decode(
+            env: &soroban_sdk::Env,
+            event: &soroban_sdk::xdr::ContractEvent,
+        ) -> Result<Self, soroban_sdk::xdr::Error> {
+            use soroban_sdk::TryFromVal;
+            let (topics, data) = soroban_sdk::testutils::decode_event(
+                env,
+                event,
+                &["event_d"],
+                0usize,
+                soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+                &[],
+            )?;
+            let mut topics = topics.iter();
+            let mut data = data.iter();
+            Ok(Self {})
+        }
+    }
 }
 pub struct UnusedStruct {
     pub x: u32,
@@ -24901,6 +27103,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUnusedStruct {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUnusedStruct { x: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum UnusedEnum {
     A,
@@ -25377,6 +27602,43 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUnusedEnum {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryUnusedEnum::A,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryUnusedEnum::B(field_0),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub enum UnusedIntEnum {
     U1 = 1,
@@ -25708,6 +27970,28 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUnusedIntEnum {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUnusedIntEnum::U1,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUnusedIntEnum::U2,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UnusedEvent {
     pub kind: Symbol,
@@ -25794,6 +28078,30 @@ impl UnusedEvent {
         <_ as soroban_sdk::Event>::publish(self, env);
     }
 }
+impl soroban_sdk::testutils::Event for UnusedEvent {
+    fn decode(
+        env: &soroban_sdk::Env,
+        event: &soroban_sdk::xdr::ContractEvent,
+    ) -> Result<Self, soroban_sdk::xdr::Error> {
+        use soroban_sdk::TryFromVal;
+        let (topics, data) = soroban_sdk::testutils::decode_event(
+            env,
+            event,
+            &["unused_event"],
+            1usize,
+            soroban_sdk::xdr::ScSpecEventDataFormat::Map,
+            &["data"],
+        )?;
+        let mut topics = topics.iter();
+        let mut data = data.iter();
+        Ok(Self {
+            kind: TryFromVal::try_from_val(env, topics.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+            data: TryFromVal::try_from_val(env, data.next().unwrap())
+                .map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+        })
+    }
+}
 pub enum UnusedPubError {
     Nope = 1,
 }
@@ -25840,6 +28148,35 @@ impl soroban_sdk::SpecShakingMarker for UnusedPubError {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __UnusedPubError__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __UnusedPubError__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_shaking_v2",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x0eUnusedPubError\0\0\0\0\0\x01\0\0\0\0\0\0\0\x04Nope\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for UnusedPubError {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -26324,6 +28661,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUnusedNonContractFnParam {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUnusedNonContractFnParam { x: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UnusedNonContractFnReturn {
     pub x: u32,
@@ -26706,6 +29066,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUnusedNonContractFnReturn {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUnusedNonContractFnReturn { x: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 struct UnusedNonPubStruct {
     pub x: u32,
@@ -27076,6 +29459,29 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUnusedNonPubStruct {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <u32 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                        ),
+                        |(field_0, ())| ArbitraryUnusedNonPubStruct { x: field_0 },
+                    ),
+                )
+            })
+        }
+    }
 };
 enum UnusedNonPubError {
     Bad = 1,
@@ -27123,6 +29529,35 @@ impl soroban_sdk::SpecShakingMarker for UnusedNonPubError {
     #[inline(always)]
     fn spec_shaking_marker() {}
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __UnusedNonPubError__error_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __UnusedNonPubError__error_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        soroban_sdk::testutils::coverage::register_error_spec(
+            "test_spec_shaking_v2",
+            b"\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\x11UnusedNonPubError\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x03Bad\0\0\0\0\x01",
+        );
+    }
+}
 impl TryFrom<soroban_sdk::Error> for UnusedNonPubError {
     type Error = soroban_sdk::Error;
     #[inline(always)]
@@ -29696,6 +32131,127 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__ab801092146d2dc3559e05eefeb8ef94e3d8f8fb931034eff5a7cb779efe417a_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__ab801092146d2dc3559e05eefeb8ef94e3d8f8fb931034eff5a7cb779efe417a_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\nwith_param\0\0\0\0\0\x02\0\0\0\0\0\0\0\x01s\0\0\0\0\0\x07\xd0\0\0\0\x0fUsedParamStruct\0\0\0\0\0\0\0\0\x02ie\0\0\0\0\x07\xd0\0\0\0\x10UsedParamIntEnum\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0bwith_return\0\0\0\0\0\0\0\0\x01\0\0\x07\xd0\0\0\0\x0eUsedReturnEnum\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\nwith_error\0\0\0\0\0\0\0\0\0\x01\0\0\x03\xe9\0\0\0\x04\0\0\x07\xd0\0\0\0\rUsedErrorEnum\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x10with_panic_error\0\0\0\x01\0\0\0\0\0\0\0\x04fail\0\0\0\x01\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x11with_assert_error\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x02ok\0\0\0\0\0\x01\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x14with_panic_raw_error\0\0\0\x01\0\0\0\0\0\0\0\x04fail\0\0\0\x01\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08with_vec\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xea\0\0\x07\xd0\0\0\0\x0eUsedVecElement\0\0\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0fwith_vec_nested\0\0\0\0\x01\0\0\0\0\0\0\0\x01v\0\0\0\0\0\x03\xea\0\0\x07\xd0\0\0\0\x14UsedVecElementNested\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x08with_map\0\0\0\x01\0\0\0\0\0\0\0\x01m\0\0\0\0\0\x03\xec\0\0\x07\xd0\0\0\0\nUsedMapKey\0\0\0\0\x07\xd0\0\0\0\nUsedMapVal\0\0\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0bwith_option\0\0\0\0\x01\0\0\0\0\0\0\0\x01o\0\0\0\0\0\x03\xe8\0\0\x07\xd0\0\0\0\x11UsedOptionElement\0\0\0\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0bwith_result\0\0\0\0\0\0\0\0\x01\0\0\x03\xe9\0\0\x07\xd0\0\0\0\x0cUsedResultOk\0\0\x07\xd0\0\0\0\rUsedErrorEnum\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0ewith_recursion\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01r\0\0\0\0\0\x07\xd0\0\0\0\x11UsedRecursiveRoot\0\0\0\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x12with_auth_contexts\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01c\0\0\0\0\0\x03\xea\0\0\x07\xd0\0\0\0\x07Context\0\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x11with_invoker_auth\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01i\0\0\0\0\0\x07\xd0\0\0\0\x18InvokerContractAuthEntry\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0fwith_executable\0\0\0\0\x01\0\0\0\0\0\0\0\x01e\0\0\0\0\0\x07\xd0\0\0\0\nExecutable\0\0\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x0epublish_simple\0\0\0\0\0\0\0\0\0\0");
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x12publish_topic_type\0\0\0\0\0\0\0\0\0\0");
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x11publish_data_type\0\0\0\0\0\0\0\0\0\0\0");
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x14publish_nested_topic\0\0\0\0\0\0\0\0");
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x13publish_nested_data\0\0\0\0\0\0\0\0\0");
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0fwith_lib_struct\0\0\0\0\x01\0\0\0\0\0\0\0\x01s\0\0\0\0\0\x07\xd0\0\0\0\x07StructC\0\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x12with_wasm_imported\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01s\0\0\0\0\0\x07\xd0\0\0\0\x07StructA\0\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0cwith_non_pub\0\0\0\x01\0\0\0\0\0\0\0\x01s\0\0\0\0\0\x07\xd0\0\0\0\x10UsedNonPubStruct\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x12with_non_pub_error\0\0\0\0\0\0\0\0\0\x01\0\0\x03\xe9\0\0\0\x04\0\0\x07\xd0\0\0\0\x0fUsedNonPubError\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\nwith_tuple\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01t\0\0\0\0\0\x03\xed\0\0\0\x02\0\0\x07\xd0\0\0\0\x10UsedTupleElement\0\0\0\x04\0\0\0\0",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x11with_tuple_return\0\0\0\0\0\0\0\0\0\0\x01\0\0\x03\xed\0\0\0\x02\0\0\x07\xd0\0\0\0\x16UsedTupleReturnElement\0\0\0\0\0\x04",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(b"\0\0\0\0\0\0\0\0\0\0\0\x11publish_ref_event\0\0\0\0\0\0\0\0\0\0\0");
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -31058,136 +33614,271 @@ fn __Contract____ab801092146d2dc3559e05eefeb8ef94e3d8f8fb931034eff5a7cb779efe417
             #[allow(deprecated)]
             &__Contract__with_param__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_param() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_return",
             #[allow(deprecated)]
             &__Contract__with_return__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_return() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_error",
             #[allow(deprecated)]
             &__Contract__with_error__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_error() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_panic_error",
             #[allow(deprecated)]
             &__Contract__with_panic_error__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_panic_error() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_assert_error",
             #[allow(deprecated)]
             &__Contract__with_assert_error__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_assert_error() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_panic_raw_error",
             #[allow(deprecated)]
             &__Contract__with_panic_raw_error__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_panic_raw_error() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_vec",
             #[allow(deprecated)]
             &__Contract__with_vec__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_vec() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_vec_nested",
             #[allow(deprecated)]
             &__Contract__with_vec_nested__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_vec_nested() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_map",
             #[allow(deprecated)]
             &__Contract__with_map__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_map() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_option",
             #[allow(deprecated)]
             &__Contract__with_option__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_option() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_result",
             #[allow(deprecated)]
             &__Contract__with_result__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_result() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_recursion",
             #[allow(deprecated)]
             &__Contract__with_recursion__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_recursion() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_auth_contexts",
             #[allow(deprecated)]
             &__Contract__with_auth_contexts__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_auth_contexts() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_invoker_auth",
             #[allow(deprecated)]
             &__Contract__with_invoker_auth__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_invoker_auth() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_executable",
             #[allow(deprecated)]
             &__Contract__with_executable__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_executable() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "publish_simple",
             #[allow(deprecated)]
             &__Contract__publish_simple__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_publish_simple() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "publish_topic_type",
             #[allow(deprecated)]
             &__Contract__publish_topic_type__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_publish_topic_type() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "publish_data_type",
             #[allow(deprecated)]
             &__Contract__publish_data_type__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_publish_data_type() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "publish_nested_topic",
             #[allow(deprecated)]
             &__Contract__publish_nested_topic__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_publish_nested_topic() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "publish_nested_data",
             #[allow(deprecated)]
             &__Contract__publish_nested_data__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_publish_nested_data() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_lib_struct",
             #[allow(deprecated)]
             &__Contract__with_lib_struct__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_lib_struct() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_wasm_imported",
             #[allow(deprecated)]
             &__Contract__with_wasm_imported__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_wasm_imported() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_non_pub",
             #[allow(deprecated)]
             &__Contract__with_non_pub__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_non_pub() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_non_pub_error",
             #[allow(deprecated)]
             &__Contract__with_non_pub_error__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_non_pub_error() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_tuple",
             #[allow(deprecated)]
             &__Contract__with_tuple__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_tuple() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "with_tuple_return",
             #[allow(deprecated)]
             &__Contract__with_tuple_return__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_with_tuple_return() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "publish_ref_event",
             #[allow(deprecated)]
             &__Contract__publish_ref_event__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_spec_shaking_v2",
+            &const { <Contract>::spec_xdr_publish_ref_event() },
+        );
     }
 }
 #[allow(dead_code)]
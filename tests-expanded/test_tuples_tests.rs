@@ -21,6 +21,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -435,6 +444,43 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__69e94e814d1599c21b8ac3d759295183311eaabe224b3ad8865aaa5d01729db0_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__69e94e814d1599c21b8ac3d759295183311eaabe224b3ad8865aaa5d01729db0_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x07void_fn\0\0\0\0\x01\0\0\0\0\0\0\0\x08void_arg\0\0\0\x02\0\0\0\x01\0\0\0\x02",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x06tuple1\0\0\0\0\0\x01\0\0\0\0\0\0\0\x03arg\0\0\0\x03\xed\0\0\0\x01\0\0\0\x04\0\0\0\x01\0\0\x03\xed\0\0\0\x01\0\0\0\x04",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x06tuple2\0\0\0\0\0\x01\0\0\0\0\0\0\0\x03arg\0\0\0\x03\xed\0\0\0\x02\0\0\0\x04\0\0\0\x07\0\0\0\x01\0\0\x03\xed\0\0\0\x02\0\0\0\x04\0\0\0\x07",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -624,16 +670,31 @@ fn __Contract____69e94e814d1599c21b8ac3d759295183311eaabe224b3ad8865aaa5d01729db
             #[allow(deprecated)]
             &__Contract__void_fn__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_tuples",
+            &const { <Contract>::spec_xdr_void_fn() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "tuple1",
             #[allow(deprecated)]
             &__Contract__tuple1__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_tuples",
+            &const { <Contract>::spec_xdr_tuple1() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "tuple2",
             #[allow(deprecated)]
             &__Contract__tuple2__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_tuples",
+            &const { <Contract>::spec_xdr_tuple2() },
+        );
     }
 }
 mod test {
@@ -659,6 +720,16 @@ mod test {
             #[doc(hidden)]
             allow_non_root_auth: bool,
         }
+        impl<'a> soroban_sdk::testutils::ContractClient for Client<'a> {
+            fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+                Self::new(env, address)
+            }
+            fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+                static SPEC: soroban_sdk::testutils::ClientSpec =
+                    soroban_sdk::testutils::ClientSpec::new();
+                &SPEC
+            }
+        }
         impl<'a> Client<'a> {
             pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
                 Self {
@@ -1002,6 +1073,43 @@ mod test {
                 res
             }
         }
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        #[allow(unused)]
+        fn __Client__52e644ac84789bde16637b4a9a51f9aceea2341de857e2ebeb108616f837a157_spec_ctor() {
+            #[allow(unsafe_code)]
+            {
+                #[link_section = ".init_array"]
+                #[used]
+                #[allow(non_upper_case_globals, non_snake_case)]
+                #[doc(hidden)]
+                static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                    #[link_section = ".text.startup"]
+                    #[allow(non_snake_case)]
+                    extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                        unsafe {
+                            __Client__52e644ac84789bde16637b4a9a51f9aceea2341de857e2ebeb108616f837a157_spec_ctor();
+                        };
+                        core::default::Default::default()
+                    }
+                    f
+                };
+            }
+            {
+                <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                    .register(
+                        b"\0\0\0\0\0\0\0\0\0\0\0\x06tuple1\0\0\0\0\0\x01\0\0\0\0\0\0\0\x03arg\0\0\0\x03\xed\0\0\0\x01\0\0\0\x04\0\0\0\x01\0\0\x03\xed\0\0\0\x01\0\0\0\x04",
+                    );
+                <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                    .register(
+                        b"\0\0\0\0\0\0\0\0\0\0\0\x06tuple2\0\0\0\0\0\x01\0\0\0\0\0\0\0\x03arg\0\0\0\x03\xed\0\0\0\x02\0\0\0\x04\0\0\0\x07\0\0\0\x01\0\0\x03\xed\0\0\0\x02\0\0\0\x04\0\0\0\x07",
+                    );
+                <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                    .register(
+                        b"\0\0\0\0\0\0\0\0\0\0\0\x07void_fn\0\0\0\0\x01\0\0\0\0\0\0\0\x08void_arg\0\0\0\x02\0\0\0\x01\0\0\0\x02",
+                    );
+            }
+        }
         ///Args is a type for building arg lists for functions defined in "Contract".
         pub struct Args;
         impl Args {
@@ -1493,6 +1601,45 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutableRef {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::String as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryContractExecutableRef {
+                                owner: field_0,
+                                tag: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct ContractContext {
             pub args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -2056,6 +2203,59 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Vec<
+                                            soroban_sdk::Val,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <soroban_sdk::Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryContractContext {
+                                args: field_0,
+                                contract: field_1,
+                                fn_name: field_2,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct SubContractInvocation {
             pub context: ContractContext,
@@ -2547,6 +2747,47 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitrarySubContractInvocation {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Vec<
+                                                InvokerContractAuthEntry,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitrarySubContractInvocation {
+                                context: field_0,
+                                sub_invocations: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct CreateContractHostFnContext {
             pub executable: ContractExecutable,
@@ -3037,6 +3278,47 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryCreateContractHostFnContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::BytesN<
+                                                32,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryCreateContractHostFnContext {
+                                executable: field_0,
+                                salt: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct CreateContractWithConstructorHostFnContext {
             pub constructor_args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -3649,6 +3931,63 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy
+                for ArbitraryCreateContractWithConstructorHostFnContext
+            {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Vec<
+                                            soroban_sdk::Val,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <soroban_sdk::BytesN<
+                                                    32,
+                                                > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryCreateContractWithConstructorHostFnContext {
+                                constructor_args: field_0,
+                                executable: field_1,
+                                salt: field_2,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum ContractExecutable {
             Wasm(soroban_sdk::BytesN<32>),
@@ -4267,6 +4606,60 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutable {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <soroban_sdk::BytesN<
+                                                            32,
+                                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContractExecutable::Wasm(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <ContractExecutableRef as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContractExecutable::ExternalRef(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum Context {
             Contract(ContractContext),
@@ -4999,6 +5392,77 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::Contract(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::CreateContractHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::CreateContractWithCtorHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum InvokerContractAuthEntry {
             Contract(SubContractInvocation),
@@ -5780,6 +6244,79 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryInvokerContractAuthEntry {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <SubContractInvocation as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::Contract(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractWithCtorHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum Executable {
             Wasm(soroban_sdk::BytesN<32>),
@@ -6363,6 +6900,53 @@ mod test {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryExecutable {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <soroban_sdk::BytesN<
+                                                            32,
+                                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryExecutable::Wasm(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            |()| ArbitraryExecutable::StellarAsset,
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            |()| ArbitraryExecutable::Account,
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
     }
     extern crate test;
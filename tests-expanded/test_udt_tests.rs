@@ -333,6 +333,28 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtEnum2 {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                        <[_]>::into_vec(::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUdtEnum2::A,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                    ArbitraryUdtEnum2::B,
+                                ),
+                            ),
+                        ])),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum UdtEnum {
     UdtA,
@@ -975,6 +997,77 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtEnum {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryUdtEnum::UdtA,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <UdtStruct as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryUdtEnum::UdtB(field_0),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <UdtEnum2 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryUdtEnum::UdtC(field_0),
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <UdtTuple as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryUdtEnum::UdtD(field_0),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub struct UdtTuple(pub i64, pub Vec<i64>);
 #[automatically_derived]
@@ -1354,6 +1447,42 @@ const _: () = {
             ))
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtTuple {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <Vec<
+                                        i64,
+                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryUdtTuple(field_0, field_1),
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UdtStruct {
     a: i64,
@@ -1842,6 +1971,57 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtStruct {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                        <Vec<
+                                            i64,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                        ),
+                        |(field_0, (field_1, (field_2, ())))| ArbitraryUdtStruct {
+                            a: field_0,
+                            b: field_1,
+                            c: field_2,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct UdtRecursive {
     pub a: Symbol,
@@ -2276,6 +2456,45 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtRecursive {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <Vec<
+                                        UdtRecursive,
+                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryUdtRecursive {
+                            a: field_0,
+                            b: field_1,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub struct RecursiveToEnum {
     pub a: Symbol,
@@ -2717,6 +2936,46 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryRecursiveToEnum {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                        (
+                            {
+                                use soroban_sdk::testutils::proptest::{
+                                    FieldStrategy as _, FieldStrategyFallback as _,
+                                };
+                                (&soroban_sdk::testutils::proptest::Field::<
+                                <Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                            >::new())
+                                .field_strategy()
+                            },
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                    <Map<
+                                        u32,
+                                        RecursiveEnum,
+                                    > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                >::new())
+                                    .field_strategy()
+                                },
+                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                            ),
+                        ),
+                        |(field_0, (field_1, ()))| ArbitraryRecursiveToEnum {
+                            a: field_0,
+                            b: field_1,
+                        },
+                    ),
+                )
+            })
+        }
+    }
 };
 pub enum RecursiveEnum {
     NotRecursive,
@@ -3210,6 +3469,43 @@ const _: () = {
             })
         }
     }
+    impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryRecursiveEnum {
+        fn strategy() -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self> {
+            soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                    <[_]>::into_vec(
+                        ::alloc::boxed::box_new([
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    |()| ArbitraryRecursiveEnum::NotRecursive,
+                                ),
+                            ),
+                            soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <RecursiveToEnum as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                    |(field_0, ())| ArbitraryRecursiveEnum::Recursive(field_0),
+                                ),
+                            ),
+                        ]),
+                    ),
+                ),
+            )
+            })
+        }
+    }
 };
 pub struct Contract;
 ///ContractArgs is a type for building arg lists for functions defined in "Contract".
@@ -3227,6 +3523,15 @@ pub struct ContractClient<'a> {
     #[doc(hidden)]
     allow_non_root_auth: bool,
 }
+impl<'a> soroban_sdk::testutils::ContractClient for ContractClient<'a> {
+    fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+        Self::new(env, address)
+    }
+    fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+        static SPEC: soroban_sdk::testutils::ClientSpec = soroban_sdk::testutils::ClientSpec::new();
+        &SPEC
+    }
+}
 impl<'a> ContractClient<'a> {
     pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
         Self {
@@ -3674,6 +3979,43 @@ impl<'a> ContractClient<'a> {
         res
     }
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+#[allow(unused)]
+fn __ContractClient__ca538446cb58e8272191ab7091913766c05361045f91847da7c92c7de8846af4_spec_ctor() {
+    #[allow(unsafe_code)]
+    {
+        #[link_section = ".init_array"]
+        #[used]
+        #[allow(non_upper_case_globals, non_snake_case)]
+        #[doc(hidden)]
+        static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+            #[link_section = ".text.startup"]
+            #[allow(non_snake_case)]
+            extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                unsafe {
+                    __ContractClient__ca538446cb58e8272191ab7091913766c05361045f91847da7c92c7de8846af4_spec_ctor();
+                };
+                core::default::Default::default()
+            }
+            f
+        };
+    }
+    {
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x03add\0\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\x07\xd0\0\0\0\x07UdtEnum\0\0\0\0\0\0\0\0\x01b\0\0\0\0\0\x07\xd0\0\0\0\x07UdtEnum\0\0\0\0\x01\0\0\0\x07",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\trecursive\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01a\0\0\0\0\0\x07\xd0\0\0\0\x0cUdtRecursive\0\0\0\x01\0\0\x03\xe8\0\0\x07\xd0\0\0\0\x0cUdtRecursive",
+            );
+        <ContractClient as soroban_sdk::testutils::ContractClient>::client_spec()
+            .register(
+                b"\0\0\0\0\0\0\0\0\0\0\0\x0erecursive_enum\0\0\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\x07\xd0\0\0\0\rRecursiveEnum\0\0\0\0\0\0\0\0\0\0\x03key\0\0\0\0\x04\0\0\0\x01\0\0\x03\xe9\0\0\x03\xe8\0\0\x07\xd0\0\0\0\rRecursiveEnum\0\0\0\0\0\0\x03",
+            );
+    }
+}
 impl ContractArgs {
     #[inline(always)]
     #[allow(clippy::unused_unit)]
@@ -3879,16 +4221,31 @@ fn __Contract____ca538446cb58e8272191ab7091913766c05361045f91847da7c92c7de8846af
             #[allow(deprecated)]
             &__Contract__add__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_udt",
+            &const { <Contract>::spec_xdr_add() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "recursive",
             #[allow(deprecated)]
             &__Contract__recursive__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_udt",
+            &const { <Contract>::spec_xdr_recursive() },
+        );
         <Contract as soroban_sdk::testutils::ContractFunctionRegister>::register(
             "recursive_enum",
             #[allow(deprecated)]
             &__Contract__recursive_enum__invoke_raw_slice,
         );
+        soroban_sdk::testutils::coverage::register_function_spec(
+            core::any::type_name::<Contract>(),
+            "test_udt",
+            &const { <Contract>::spec_xdr_recursive_enum() },
+        );
     }
 }
 mod test {
@@ -4241,6 +4598,16 @@ mod test_with_wasm {
             #[doc(hidden)]
             allow_non_root_auth: bool,
         }
+        impl<'a> soroban_sdk::testutils::ContractClient for Client<'a> {
+            fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
+                Self::new(env, address)
+            }
+            fn client_spec() -> &'static soroban_sdk::testutils::ClientSpec {
+                static SPEC: soroban_sdk::testutils::ClientSpec =
+                    soroban_sdk::testutils::ClientSpec::new();
+                &SPEC
+            }
+        }
         impl<'a> Client<'a> {
             pub fn new(env: &soroban_sdk::Env, address: &soroban_sdk::Address) -> Self {
                 Self {
@@ -4590,6 +4957,43 @@ mod test_with_wasm {
                 res
             }
         }
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        #[allow(unused)]
+        fn __Client__ca538446cb58e8272191ab7091913766c05361045f91847da7c92c7de8846af4_spec_ctor() {
+            #[allow(unsafe_code)]
+            {
+                #[link_section = ".init_array"]
+                #[used]
+                #[allow(non_upper_case_globals, non_snake_case)]
+                #[doc(hidden)]
+                static f: extern "C" fn() -> ::ctor::__support::CtorRetType = {
+                    #[link_section = ".text.startup"]
+                    #[allow(non_snake_case)]
+                    extern "C" fn f() -> ::ctor::__support::CtorRetType {
+                        unsafe {
+                            __Client__ca538446cb58e8272191ab7091913766c05361045f91847da7c92c7de8846af4_spec_ctor();
+                        };
+                        core::default::Default::default()
+                    }
+                    f
+                };
+            }
+            {
+                <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                    .register(
+                        b"\0\0\0\0\0\0\0\0\0\0\0\x03add\0\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\x07\xd0\0\0\0\x07UdtEnum\0\0\0\0\0\0\0\0\x01b\0\0\0\0\0\x07\xd0\0\0\0\x07UdtEnum\0\0\0\0\x01\0\0\0\x07",
+                    );
+                <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                    .register(
+                        b"\0\0\0\0\0\0\0\0\0\0\0\trecursive\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x01a\0\0\0\0\0\x07\xd0\0\0\0\x0cUdtRecursive\0\0\0\x01\0\0\x03\xe8\0\0\x07\xd0\0\0\0\x0cUdtRecursive",
+                    );
+                <Client as soroban_sdk::testutils::ContractClient>::client_spec()
+                    .register(
+                        b"\0\0\0\0\0\0\0\0\0\0\0\x0erecursive_enum\0\0\0\0\0\x02\0\0\0\0\0\0\0\x01a\0\0\0\0\0\x07\xd0\0\0\0\rRecursiveEnum\0\0\0\0\0\0\0\0\0\0\x03key\0\0\0\0\x04\0\0\0\x01\0\0\x03\xe9\0\0\x03\xe8\0\0\x07\xd0\0\0\0\rRecursiveEnum\0\0\0\0\0\0\x03",
+                    );
+            }
+        }
         ///Args is a type for building arg lists for functions defined in "Contract".
         pub struct Args;
         impl Args {
@@ -5023,6 +5427,47 @@ mod test_with_wasm {
                     ))
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtTuple {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Vec<
+                                                i64,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryUdtTuple(
+                                field_0,
+                                field_1,
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct UdtStruct {
             pub a: i64,
@@ -5560,6 +6005,59 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtStruct {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <i64 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <soroban_sdk::Vec<
+                                                    i64,
+                                                > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryUdtStruct {
+                                a: field_0,
+                                b: field_1,
+                                c: field_2,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct UdtRecursive {
             pub a: soroban_sdk::Symbol,
@@ -6029,6 +6527,47 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtRecursive {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Vec<
+                                                UdtRecursive,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryUdtRecursive {
+                                a: field_0,
+                                b: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct RecursiveToEnum {
             pub a: soroban_sdk::Symbol,
@@ -6502,6 +7041,48 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryRecursiveToEnum {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Map<
+                                                u32,
+                                                RecursiveEnum,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryRecursiveToEnum {
+                                a: field_0,
+                                b: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct ContractExecutableRef {
             pub owner: soroban_sdk::Address,
@@ -6975,6 +7556,45 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutableRef {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::String as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryContractExecutableRef {
+                                owner: field_0,
+                                tag: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct ContractContext {
             pub args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -7538,6 +8158,59 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Vec<
+                                            soroban_sdk::Val,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Address as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <soroban_sdk::Symbol as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryContractContext {
+                                args: field_0,
+                                contract: field_1,
+                                fn_name: field_2,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct SubContractInvocation {
             pub context: ContractContext,
@@ -8029,6 +8702,47 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitrarySubContractInvocation {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::Vec<
+                                                InvokerContractAuthEntry,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitrarySubContractInvocation {
+                                context: field_0,
+                                sub_invocations: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct CreateContractHostFnContext {
             pub executable: ContractExecutable,
@@ -8519,6 +9233,47 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryCreateContractHostFnContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <soroban_sdk::BytesN<
+                                                32,
+                                            > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                ),
+                            ),
+                            |(field_0, (field_1, ()))| ArbitraryCreateContractHostFnContext {
+                                executable: field_0,
+                                salt: field_1,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub struct CreateContractWithConstructorHostFnContext {
             pub constructor_args: soroban_sdk::Vec<soroban_sdk::Val>,
@@ -9131,6 +9886,63 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy
+                for ArbitraryCreateContractWithConstructorHostFnContext
+            {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                            (
+                                {
+                                    use soroban_sdk::testutils::proptest::{
+                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                    };
+                                    (&soroban_sdk::testutils::proptest::Field::<
+                                        <soroban_sdk::Vec<
+                                            soroban_sdk::Val,
+                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                    >::new())
+                                        .field_strategy()
+                                },
+                                (
+                                    {
+                                        use soroban_sdk::testutils::proptest::{
+                                            FieldStrategy as _, FieldStrategyFallback as _,
+                                        };
+                                        (&soroban_sdk::testutils::proptest::Field::<
+                                            <ContractExecutable as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                        >::new())
+                                            .field_strategy()
+                                    },
+                                    (
+                                        {
+                                            use soroban_sdk::testutils::proptest::{
+                                                FieldStrategy as _, FieldStrategyFallback as _,
+                                            };
+                                            (&soroban_sdk::testutils::proptest::Field::<
+                                                <soroban_sdk::BytesN<
+                                                    32,
+                                                > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                            >::new())
+                                                .field_strategy()
+                                        },
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                    ),
+                                ),
+                            ),
+                            |(field_0, (field_1, (field_2, ())))| ArbitraryCreateContractWithConstructorHostFnContext {
+                                constructor_args: field_0,
+                                executable: field_1,
+                                salt: field_2,
+                            },
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum UdtEnum {
             UdtA,
@@ -9839,6 +10651,79 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtEnum {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            |()| ArbitraryUdtEnum::UdtA,
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <UdtStruct as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryUdtEnum::UdtB(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <UdtEnum2 as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryUdtEnum::UdtC(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <UdtTuple as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryUdtEnum::UdtD(field_0),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum RecursiveEnum {
             NotRecursive,
@@ -10384,6 +11269,45 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryRecursiveEnum {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            |()| ArbitraryRecursiveEnum::NotRecursive,
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <RecursiveToEnum as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryRecursiveEnum::Recursive(field_0),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum ContractExecutable {
             Wasm(soroban_sdk::BytesN<32>),
@@ -11002,6 +11926,60 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContractExecutable {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <soroban_sdk::BytesN<
+                                                            32,
+                                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContractExecutable::Wasm(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <ContractExecutableRef as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContractExecutable::ExternalRef(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum Context {
             Contract(ContractContext),
@@ -11734,6 +12712,77 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryContext {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <ContractContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::Contract(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::CreateContractHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryContext::CreateContractWithCtorHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum InvokerContractAuthEntry {
             Contract(SubContractInvocation),
@@ -12515,6 +13564,79 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryInvokerContractAuthEntry {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <SubContractInvocation as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::Contract(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <CreateContractWithConstructorHostFnContext as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryInvokerContractAuthEntry::CreateContractWithCtorHostFn(
+                                                field_0,
+                                            ),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum Executable {
             Wasm(soroban_sdk::BytesN<32>),
@@ -13098,6 +14220,53 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryExecutable {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            (
+                                                {
+                                                    use soroban_sdk::testutils::proptest::{
+                                                        FieldStrategy as _, FieldStrategyFallback as _,
+                                                    };
+                                                    (&soroban_sdk::testutils::proptest::Field::<
+                                                        <soroban_sdk::BytesN<
+                                                            32,
+                                                        > as soroban_sdk::testutils::arbitrary::SorobanArbitrary>::Prototype,
+                                                    >::new())
+                                                        .field_strategy()
+                                                },
+                                                soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            ),
+                                            |(field_0, ())| ArbitraryExecutable::Wasm(field_0),
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            |()| ArbitraryExecutable::StellarAsset,
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::prop_map(
+                                            soroban_sdk::testutils::proptest::proptest::strategy::Just(()),
+                                            |()| ArbitraryExecutable::Account,
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
         pub enum UdtEnum2 {
             A = 10,
@@ -13455,6 +14624,32 @@ mod test_with_wasm {
                     })
                 }
             }
+            impl soroban_sdk::testutils::proptest::PrototypeStrategy for ArbitraryUdtEnum2 {
+                fn strategy(
+                ) -> soroban_sdk::testutils::proptest::proptest::strategy::BoxedStrategy<Self>
+                {
+                    soroban_sdk::testutils::proptest::recursive_strategy(|| {
+                        soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                        soroban_sdk::testutils::proptest::proptest::strategy::Union::new(
+                            <[_]>::into_vec(
+                                ::alloc::boxed::box_new([
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                            ArbitraryUdtEnum2::A,
+                                        ),
+                                    ),
+                                    soroban_sdk::testutils::proptest::proptest::strategy::Strategy::boxed(
+                                        soroban_sdk::testutils::proptest::proptest::strategy::Just(
+                                            ArbitraryUdtEnum2::B,
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    )
+                    })
+                }
+            }
         };
     }
     extern crate test;